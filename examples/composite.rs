@@ -0,0 +1,283 @@
+//! ## Composite
+//!
+//! `Composite` shows how to build a component out of other components, using `Children`.
+//! The view is rendered to the standard output, so it doesn't need a terminal.
+
+extern crate tuirealm;
+
+use std::collections::HashMap;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{BorderType, Color, Style};
+use tuirealm::ratatui::layout::{Constraint, Layout, Rect};
+use tuirealm::ratatui::widgets::{Block, Paragraph};
+use tuirealm::terminal::render_to_string;
+use tuirealm::{
+    delegate_mock_component, Application, AttrValue, Attribute, Children, Component, Event, Frame,
+    MockComponent, NoUserEvent, State, StateValue,
+};
+
+#[derive(Debug, PartialEq)]
+enum Msg {
+    NameChanged(String),
+    Submit(String),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+enum Id {
+    Name,
+}
+
+// -- children
+
+/// A line of text
+#[derive(Default)]
+struct Text {
+    text: String,
+    color: Color,
+}
+
+impl MockComponent for Text {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(
+            Paragraph::new(self.text.as_str()).style(Style::default().fg(self.color)),
+            area,
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        match attr {
+            Attribute::Text => Some(AttrValue::String(self.text.clone())),
+            Attribute::Foreground => Some(AttrValue::Color(self.color)),
+            _ => None,
+        }
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Text, AttrValue::String(text)) => self.text = text,
+            (Attribute::Foreground, AttrValue::Color(color)) => self.color = color,
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+/// A bordered text input
+#[derive(Default)]
+struct Input {
+    text: String,
+    color: Color,
+    focus: bool,
+}
+
+impl MockComponent for Input {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let color = if self.focus {
+            self.color
+        } else {
+            Color::DarkGray
+        };
+        frame.render_widget(
+            Paragraph::new(self.text.as_str()).block(
+                Block::bordered()
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(color)),
+            ),
+            area,
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        match attr {
+            Attribute::Focus => Some(AttrValue::Flag(self.focus)),
+            Attribute::Foreground => Some(AttrValue::Color(self.color)),
+            _ => None,
+        }
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Focus, AttrValue::Flag(focus)) => self.focus = focus,
+            (Attribute::Foreground, AttrValue::Color(color)) => self.color = color,
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::String(self.text.clone()))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Type(ch) => self.text.push(ch),
+            Cmd::Delete => {
+                self.text.pop();
+            }
+            _ => return CmdResult::None,
+        }
+        CmdResult::Changed(self.state())
+    }
+}
+
+// -- composite
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+enum Part {
+    Label,
+    Input,
+    Error,
+}
+
+/// An input with a label above and an error line below; only letters are accepted
+struct LabeledInput {
+    children: Children<Part>,
+}
+
+impl LabeledInput {
+    fn new(label: &str) -> Self {
+        let mut children = Children::default()
+            .child(Part::Label, Text::default())
+            .child(Part::Input, Input::default())
+            .child(Part::Error, Text::default());
+        children.attr(
+            &Part::Label,
+            Attribute::Text,
+            AttrValue::String(label.to_string()),
+        );
+        children.attr_all(Attribute::Foreground, AttrValue::Color(Color::LightBlue));
+        children.attr(
+            &Part::Error,
+            Attribute::Foreground,
+            AttrValue::Color(Color::Red),
+        );
+        Self { children }
+    }
+
+    fn set_error(&mut self, error: &str) {
+        self.children.attr(
+            &Part::Error,
+            Attribute::Text,
+            AttrValue::String(error.to_string()),
+        );
+    }
+}
+
+impl MockComponent for LabeledInput {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let [label, input, error] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        self.children.view_in(
+            frame,
+            &HashMap::from([
+                (Part::Label, label),
+                (Part::Input, input),
+                (Part::Error, error),
+            ]),
+        );
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.children.query(&Part::Input, attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            // NOTE: only the input can be focused
+            (Attribute::Focus, AttrValue::Flag(true)) => {
+                self.children.focus(&Part::Input);
+            }
+            (Attribute::Focus, _) => self.children.blur(),
+            (Attribute::Text, value) => {
+                self.children.attr(&Part::Label, Attribute::Text, value);
+            }
+            (attr, value) => self.children.attr_all(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        self.children.state(&Part::Input).unwrap_or(State::None)
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Type(ch) if !ch.is_alphabetic() => {
+                self.set_error(&format!("'{ch}' is not a letter"));
+                CmdResult::Invalid(cmd)
+            }
+            cmd => {
+                self.set_error("");
+                // NOTE: commands are forwarded to the focused child
+                self.children.perform(cmd)
+            }
+        }
+    }
+}
+
+struct NameInput {
+    component: LabeledInput,
+}
+
+delegate_mock_component!(NameInput, component);
+
+impl Component<Msg, NoUserEvent> for NameInput {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let cmd = match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => Cmd::Type(ch),
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => Cmd::Delete,
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => return Some(Msg::Submit(self.state().unwrap_one().unwrap_string())),
+            _ => return None,
+        };
+        match self.perform(cmd) {
+            CmdResult::Changed(State::One(StateValue::String(name))) => {
+                Some(Msg::NameChanged(name))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn main() {
+    let mut app: Application<Id, Msg, NoUserEvent> = Application::init_headless();
+    assert!(app
+        .mount(
+            Id::Name,
+            Box::new(NameInput {
+                component: LabeledInput::new("What's your name?"),
+            }),
+            vec![]
+        )
+        .is_ok());
+    assert!(app.active(&Id::Name).is_ok());
+    // type the name, with a digit which is rejected
+    for ch in "omar1".chars() {
+        let messages = app.dispatch(Event::Keyboard(KeyEvent::from(Key::Char(ch))));
+        println!("'{ch}' => {messages:?}");
+    }
+    print!(
+        "{}",
+        render_to_string(&mut app, &[(Id::Name, Rect::new(0, 0, 30, 5))], 30, 5)
+    );
+    let messages = app.dispatch(Event::Keyboard(KeyEvent::from(Key::Enter)));
+    println!("enter => {messages:?}");
+}
@@ -5,3 +5,4 @@
 pub use super::*;
 
 pub mod model;
+pub mod theme;
@@ -4,23 +4,35 @@
 
 use std::time::{Duration, SystemTime};
 
-use tuirealm::event::NoUserEvent;
-use tuirealm::props::{Alignment, Color, TextModifiers};
-use tuirealm::ratatui::layout::{Constraint, Direction, Layout};
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalAdapter, TerminalBridge};
+use tuirealm::listener::{ListenerResult, Poll};
+use tuirealm::props::{Alignment, Color, Layout, SizeSpec};
+use tuirealm::ratatui::layout::{Constraint, Direction};
+use tuirealm::terminal::{
+    CrosstermTerminalAdapter, TerminalAdapter, TerminalBridge, TestTerminalAdapter,
+};
 use tuirealm::{
-    Application, AttrValue, Attribute, EventListenerCfg, Sub, SubClause, SubEventClause, Update,
+    Application, AttrValue, Attribute, Event, EventListenerCfg, MapUserEvent, Sub, Update,
 };
 
 use super::components::{Clock, DigitCounter, Label, LetterCounter};
-use super::{Id, Msg};
+use super::theme::Theme;
+use super::{Id, Msg, UserEvent};
+
+/// Port raising a [`UserEvent::Second`] each time it's polled
+struct ClockPort;
+
+impl Poll<UserEvent> for ClockPort {
+    fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>> {
+        Ok(Some(Event::User(UserEvent::Second)))
+    }
+}
 
 pub struct Model<T>
 where
     T: TerminalAdapter,
 {
     /// Application
-    pub app: Application<Id, Msg, NoUserEvent>,
+    pub app: Application<Id, Msg, UserEvent>,
     /// Indicates that the application must quit
     pub quit: bool,
     /// Tells whether to redraw interface
@@ -40,6 +52,37 @@ impl Default for Model<CrosstermTerminalAdapter> {
     }
 }
 
+impl Model<CrosstermTerminalAdapter> {
+    /// Instantiate a model which renders on the `height` lines below the cursor, without the alternate screen
+    pub fn inline(height: u16) -> Self {
+        // NOTE: the terminal is set up before starting the listener, since the inline viewport reads the cursor position
+        let terminal = TerminalBridge::<CrosstermTerminalAdapter>::builder()
+            .alternate_screen(false)
+            .mouse_capture(false)
+            .inline_viewport(height)
+            .build()
+            .expect("Cannot initialize terminal");
+        Self {
+            app: Self::init_app(),
+            quit: false,
+            redraw: true,
+            terminal,
+        }
+    }
+}
+
+impl Model<TestTerminalAdapter> {
+    /// Instantiate a model which renders on an in-memory `width` x `height` terminal, used to print the view
+    pub fn in_memory(width: u16, height: u16) -> Self {
+        Self {
+            app: Self::init_app(),
+            quit: false,
+            redraw: true,
+            terminal: TerminalBridge::new_test(width, height),
+        }
+    }
+}
+
 impl<T> Model<T>
 where
     T: TerminalAdapter,
@@ -51,83 +94,97 @@ where
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .margin(1)
-                    .constraints(
-                        [
-                            Constraint::Length(3), // Clock
-                            Constraint::Length(3), // Letter Counter
-                            Constraint::Length(3), // Digit Counter
-                            Constraint::Length(1), // Label
-                        ]
-                        .as_ref(),
-                    )
-                    .split(f.area());
-                self.app.view(&Id::Clock, f, chunks[0]);
-                self.app.view(&Id::LetterCounter, f, chunks[1]);
-                self.app.view(&Id::DigitCounter, f, chunks[2]);
-                self.app.view(&Id::Label, f, chunks[3]);
+                    .children_named(vec![
+                        ("clock", Constraint::Length(3)),
+                        ("letter-counter", Constraint::Length(3)),
+                        ("digit-counter", Constraint::Length(3)),
+                        ("label", Constraint::Length(1)),
+                    ])
+                    .chunks_named(f.area());
+                self.app.view(&Id::Clock, f, chunks["clock"]);
+                self.app
+                    .view(&Id::LetterCounter, f, chunks["letter-counter"]);
+                self.app.view(&Id::DigitCounter, f, chunks["digit-counter"]);
+                self.app.view(&Id::Label, f, chunks["label"]);
+                // NOTE: the cursor is set once all the components have been rendered
+                if let Some(position) = self.app.cursor() {
+                    f.set_cursor_position(position);
+                }
             })
             .is_ok());
     }
 
-    fn init_app() -> Application<Id, Msg, NoUserEvent> {
+    fn init_app() -> Application<Id, Msg, UserEvent> {
         // Setup application
-        // NOTE: NoUserEvent is a shorthand to tell tui-realm we're not going to use any custom user event
-        // NOTE: the event listener is configured to use the default crossterm input listener and to raise a Tick event each second
-        // which we will use to update the clock
+        // NOTE: the event listener is configured to use the default crossterm input listener and the clock port,
+        // which raises a `UserEvent::Second` each second, which we will use to update the clock
 
-        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
-            EventListenerCfg::default()
-                .crossterm_input_listener(Duration::from_millis(20), 3)
-                .poll_timeout(Duration::from_millis(10))
-                .tick_interval(Duration::from_secs(1)),
-        );
-        // Mount components
-        assert!(app
+        // Styles are loaded from the theme file
+        let theme = Theme::load();
+        let label_style = theme.style("label");
+        let clock_style = theme.style("clock");
+        // Mount components; subscribe clock to the clock port events
+        // NOTE: components handle `NoUserEvent`; they're wrapped into `MapUserEvent` to be used with `UserEvent`
+        // without any change. The clock receives each `UserEvent::Second` as a tick.
+        Application::builder()
+            .listener(
+                EventListenerCfg::default()
+                    // NOTE: report the terminal focus changes, so that the clock can be paused when unfocused
+                    .crossterm_focus_change(true)
+                    .crossterm_input_listener(Duration::from_millis(20), 3)
+                    .add_port(Box::new(ClockPort), Duration::from_secs(1), 1)
+                    .poll_timeout(Duration::from_millis(10)),
+            )
             .mount(
                 Id::Label,
-                Box::new(
+                Box::new(MapUserEvent::new(
                     Label::default()
                         .text("Waiting for a Msg...")
                         .alignment(Alignment::Left)
-                        .background(Color::Reset)
-                        .foreground(Color::LightYellow)
-                        .modifiers(TextModifiers::BOLD),
-                ),
-                Vec::default(),
+                        .background(label_style.bg.unwrap_or(Color::Reset))
+                        .foreground(label_style.fg.unwrap_or(Color::Reset))
+                        .modifiers(label_style.add_modifier)
+                        .width(SizeSpec::Percent(50)),
+                    Event::without_user,
+                )),
+                vec![],
             )
-            .is_ok());
-        // Mount clock, subscribe to tick
-        assert!(app
             .mount(
                 Id::Clock,
-                Box::new(
+                Box::new(MapUserEvent::new(
                     Clock::new(SystemTime::now())
                         .alignment(Alignment::Center)
-                        .background(Color::Reset)
-                        .foreground(Color::Cyan)
-                        .modifiers(TextModifiers::BOLD)
-                ),
-                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+                        .background(clock_style.bg.unwrap_or(Color::Reset))
+                        .foreground(clock_style.fg.unwrap_or(Color::Reset))
+                        .modifiers(clock_style.add_modifier),
+                    |ev: Event<UserEvent>| match ev {
+                        Event::User(UserEvent::Second) => Some(Event::Tick(Duration::from_secs(1))),
+                        ev => ev.without_user(),
+                    },
+                )),
+                vec![
+                    Sub::user(UserEvent::Second).build(),
+                    Sub::focus_gained().build(),
+                    Sub::focus_lost().build(),
+                ],
             )
-            .is_ok());
-        // Mount counters
-        assert!(app
             .mount(
                 Id::LetterCounter,
-                Box::new(LetterCounter::new(0)),
-                Vec::new()
+                Box::new(MapUserEvent::new(
+                    LetterCounter::new(0),
+                    Event::without_user,
+                )),
+                vec![],
             )
-            .is_ok());
-        assert!(app
             .mount(
                 Id::DigitCounter,
-                Box::new(DigitCounter::new(5)),
-                Vec::default()
+                Box::new(MapUserEvent::new(DigitCounter::new(5), Event::without_user)),
+                vec![],
             )
-            .is_ok());
-        // Active letter counter
-        assert!(app.active(&Id::LetterCounter).is_ok());
-        app
+            // Active letter counter
+            .active(Id::LetterCounter)
+            .build()
+            .expect("Cannot build application")
     }
 }
 
@@ -0,0 +1,37 @@
+//! ## Theme
+//!
+//! the demo theme, loaded from `theme.txt`
+
+use std::collections::HashMap;
+
+use tuirealm::props::Style;
+use tuirealm::utils::parser::parse_style;
+
+const THEME: &str = include_str!("../theme.txt");
+
+/// The styles of the components, by name
+pub struct Theme {
+    styles: HashMap<String, Style>,
+}
+
+impl Theme {
+    /// Load the theme; each line has the syntax `name: style` (e.g. `clock: fg=cyan mods=bold`)
+    pub fn load() -> Self {
+        let styles = THEME
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (name, style) = line.split_once(':').expect("bad theme line");
+                let style = parse_style(style.trim()).expect("bad theme style");
+                (name.trim().to_string(), style)
+            })
+            .collect();
+        Self { styles }
+    }
+
+    /// Get the style for `name`
+    pub fn style(&self, name: &str) -> Style {
+        self.styles.get(name).copied().unwrap_or_default()
+    }
+}
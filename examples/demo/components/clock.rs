@@ -14,7 +14,8 @@ use tuirealm::{
 
 use super::{Label, Msg};
 
-/// Simple clock component which displays current time
+/// Simple clock component which displays current time.
+/// The clock is not updated while the terminal window is unfocused
 pub struct Clock {
     component: Label,
     states: OwnStates,
@@ -94,27 +95,41 @@ impl MockComponent for Clock {
 
 impl Component<Msg, NoUserEvent> for Clock {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
-        if let Event::Tick = ev {
-            self.states.tick();
-            // Set text
-            self.attr(Attribute::Text, AttrValue::String(self.time_to_str()));
-            Some(Msg::Clock)
-        } else {
-            None
+        match ev {
+            Event::Tick(elapsed) => {
+                self.states.tick(elapsed);
+                // NOTE: keep the time, but don't redraw while the terminal is unfocused
+                if self.states.paused {
+                    return None;
+                }
+            }
+            Event::FocusLost => {
+                self.states.paused = true;
+                return None;
+            }
+            Event::FocusGained => self.states.paused = false,
+            _ => return None,
         }
+        // Set text
+        self.attr(Attribute::Text, AttrValue::String(self.time_to_str()));
+        Some(Msg::Clock)
     }
 }
 
 struct OwnStates {
     time: SystemTime,
+    paused: bool,
 }
 
 impl OwnStates {
     pub fn new(time: SystemTime) -> Self {
-        Self { time }
+        Self {
+            time,
+            paused: false,
+        }
     }
 
-    pub fn tick(&mut self) {
-        self.time = self.time.add(Duration::from_secs(1));
+    pub fn tick(&mut self, elapsed: Duration) {
+        self.time = self.time.add(elapsed);
     }
 }
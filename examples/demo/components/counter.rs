@@ -3,32 +3,29 @@
 //! label component
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::event::{Key, KeyEvent, KeyModifiers};
-use tuirealm::props::{Alignment, Borders, Color, Style, TextModifiers};
+use tuirealm::event::Key;
+use tuirealm::props::{
+    Alignment, Borders, Color, Palette, PropPayload, PropValue, Style, TextModifiers,
+};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::{BorderType, Paragraph};
 use tuirealm::{
-    AttrValue, Attribute, Component, Event, Frame, MockComponent, NoUserEvent, Props, State,
-    StateValue,
+    delegate_mock_component, key, AttrValue, Attribute, Component, Event, Frame, MockComponent,
+    NoUserEvent, Props, State, StateValue,
 };
 
 use super::{get_block, Msg};
 
-/// Counter which increments its value on Submit
+/// Custom command which sets the value of the counter; the payload is the new value
+const CMD_SET: &str = "set";
+
+/// Counter which increments its value on Submit and sets it on [`CMD_SET`]
+#[derive(Default)]
 struct Counter {
     props: Props,
     states: OwnStates,
 }
 
-impl Default for Counter {
-    fn default() -> Self {
-        Self {
-            props: Props::default(),
-            states: OwnStates::default(),
-        }
-    }
-}
-
 impl Counter {
     pub fn label<S>(mut self, label: S) -> Self
     where
@@ -70,6 +67,11 @@ impl Counter {
         self.attr(Attribute::Borders, AttrValue::Borders(b));
         self
     }
+
+    pub fn palette(mut self, p: Palette) -> Self {
+        self.attr(Attribute::Palette, AttrValue::Palette(p));
+        self
+    }
 }
 
 impl MockComponent for Counter {
@@ -112,9 +114,13 @@ impl MockComponent for Counter {
                 .props
                 .get_or(Attribute::Focus, AttrValue::Flag(false))
                 .unwrap_flag();
+            let palette = self
+                .props
+                .get_or(Attribute::Palette, AttrValue::Palette(Palette::default()))
+                .unwrap_palette();
             frame.render_widget(
                 Paragraph::new(text)
-                    .block(get_block(borders, title, focus))
+                    .block(get_block(borders, title, focus, &palette))
                     .style(
                         Style::default()
                             .fg(foreground)
@@ -132,6 +138,10 @@ impl MockComponent for Counter {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        // NOTE: the value is the initial value of the counter
+        if let (Attribute::Value, AttrValue::Number(n)) = (attr, &value) {
+            self.states.counter = *n;
+        }
         self.props.set(attr, value);
     }
 
@@ -148,18 +158,39 @@ impl MockComponent for Counter {
             _ => CmdResult::None,
         }
     }
+
+    fn perform_custom(&mut self, name: &'static str, payload: PropPayload) -> CmdResult {
+        match (name, payload) {
+            (CMD_SET, PropPayload::One(PropValue::Isize(value))) => {
+                self.states.counter = value;
+                CmdResult::Custom(CMD_SET, self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
+
+    fn cursor(&self, area: Rect) -> Option<(u16, u16)> {
+        // NOTE: show the cursor after the counter value, inside the borders
+        let width = self.states.counter.to_string().len() as u16;
+        let inner_width = area.width.saturating_sub(2);
+        let offset = match self
+            .props
+            .get_or(Attribute::TextAlign, AttrValue::Alignment(Alignment::Left))
+            .unwrap_alignment()
+        {
+            Alignment::Left => width,
+            Alignment::Center => inner_width.saturating_sub(width) / 2 + width,
+            Alignment::Right => inner_width,
+        };
+        Some((area.x + 1 + offset.min(inner_width), area.y + 1))
+    }
 }
 
+#[derive(Default)]
 struct OwnStates {
     counter: isize,
 }
 
-impl Default for OwnStates {
-    fn default() -> Self {
-        Self { counter: 0 }
-    }
-}
-
 impl OwnStates {
     fn incr(&mut self) {
         self.counter += 1;
@@ -168,11 +199,12 @@ impl OwnStates {
 
 // -- Counter components
 
-#[derive(MockComponent)]
 pub struct LetterCounter {
     component: Counter,
 }
 
+delegate_mock_component!(LetterCounter, component);
+
 impl LetterCounter {
     pub fn new(initial_value: isize) -> Self {
         Self {
@@ -186,6 +218,11 @@ impl LetterCounter {
                 )
                 .foreground(Color::LightGreen)
                 .modifiers(TextModifiers::BOLD)
+                .palette(
+                    Palette::default()
+                        .color("focus", Color::LightGreen)
+                        .color("unfocused", Color::DarkGray),
+                )
                 .value(initial_value)
                 .label("Letter counter"),
         }
@@ -196,22 +233,22 @@ impl Component<Msg, NoUserEvent> for LetterCounter {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         // Get command
         let cmd = match ev {
-            Event::Keyboard(KeyEvent {
-                code: Key::Char(ch),
-                modifiers: KeyModifiers::NONE,
-            }) if ch.is_alphabetic() => Cmd::Submit,
-            Event::Keyboard(KeyEvent {
-                code: Key::Tab,
-                modifiers: KeyModifiers::NONE,
-            }) => return Some(Msg::LetterCounterBlur), // Return focus lost
-            Event::Keyboard(KeyEvent {
-                code: Key::Esc,
-                modifiers: KeyModifiers::NONE,
-            }) => return Some(Msg::AppClose),
+            Event::Keyboard(key!(Key::Char(ch))) if ch.is_alphabetic() => Cmd::Submit,
+            Event::Keyboard(key!(Key::Tab)) => return Some(Msg::LetterCounterBlur), // Return focus lost
+            Event::Keyboard(key!(Key::Backspace)) => {
+                // Reset
+                return match self.perform_custom(CMD_SET, PropPayload::One(PropValue::Isize(0))) {
+                    CmdResult::Custom(_, State::One(StateValue::Isize(c))) => {
+                        Some(Msg::LetterCounterChanged(c))
+                    }
+                    _ => None,
+                };
+            }
+            Event::Keyboard(key!(Key::Esc)) => return Some(Msg::AppClose),
             _ => Cmd::None,
         };
         // perform
-        match self.perform(cmd) {
+        match self.perform_diff(cmd) {
             CmdResult::Changed(State::One(StateValue::Isize(c))) => {
                 Some(Msg::LetterCounterChanged(c))
             }
@@ -220,11 +257,12 @@ impl Component<Msg, NoUserEvent> for LetterCounter {
     }
 }
 
-#[derive(MockComponent)]
 pub struct DigitCounter {
     component: Counter,
 }
 
+delegate_mock_component!(DigitCounter, component);
+
 impl DigitCounter {
     pub fn new(initial_value: isize) -> Self {
         Self {
@@ -248,22 +286,22 @@ impl Component<Msg, NoUserEvent> for DigitCounter {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         // Get command
         let cmd = match ev {
-            Event::Keyboard(KeyEvent {
-                code: Key::Char(ch),
-                modifiers: KeyModifiers::NONE,
-            }) if ch.is_digit(10) => Cmd::Submit,
-            Event::Keyboard(KeyEvent {
-                code: Key::Tab,
-                modifiers: KeyModifiers::NONE,
-            }) => return Some(Msg::DigitCounterBlur), // Return focus lost
-            Event::Keyboard(KeyEvent {
-                code: Key::Esc,
-                modifiers: KeyModifiers::NONE,
-            }) => return Some(Msg::AppClose),
+            Event::Keyboard(key!(Key::Char(ch))) if ch.is_ascii_digit() => Cmd::Submit,
+            Event::Keyboard(key!(Key::Tab)) => return Some(Msg::DigitCounterBlur), // Return focus lost
+            Event::Keyboard(key!(Key::Backspace)) => {
+                // Reset
+                return match self.perform_custom(CMD_SET, PropPayload::One(PropValue::Isize(0))) {
+                    CmdResult::Custom(_, State::One(StateValue::Isize(c))) => {
+                        Some(Msg::DigitCounterChanged(c))
+                    }
+                    _ => None,
+                };
+            }
+            Event::Keyboard(key!(Key::Esc)) => return Some(Msg::AppClose),
             _ => Cmd::None,
         };
         // perform
-        match self.perform(cmd) {
+        match self.perform_diff(cmd) {
             CmdResult::Changed(State::One(StateValue::Isize(c))) => {
                 Some(Msg::DigitCounterChanged(c))
             }
@@ -3,7 +3,7 @@
 //! label component
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{Alignment, Color, Style, TextModifiers};
+use tuirealm::props::{Alignment, Color, SizeSpec, Style, TextModifiers};
 use tuirealm::ratatui::layout::Rect;
 use tuirealm::ratatui::widgets::Paragraph;
 use tuirealm::{
@@ -15,18 +15,11 @@ use super::Msg;
 /// Simple label component; just renders a text
 /// NOTE: since I need just one label, I'm not going to use different object; I will directly implement Component for Label.
 /// This is not ideal actually and in a real app you should differentiate Mock Components from Application Components.
+#[derive(Default)]
 pub struct Label {
     props: Props,
 }
 
-impl Default for Label {
-    fn default() -> Self {
-        Self {
-            props: Props::default(),
-        }
-    }
-}
-
 impl Label {
     pub fn text<S>(mut self, s: S) -> Self
     where
@@ -55,6 +48,11 @@ impl Label {
         self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
         self
     }
+
+    pub fn width(mut self, w: SizeSpec) -> Self {
+        self.attr(Attribute::Width, AttrValue::SizeSpec(w));
+        self
+    }
 }
 
 impl MockComponent for Label {
@@ -85,6 +83,15 @@ impl MockComponent for Label {
                     AttrValue::TextModifiers(TextModifiers::empty()),
                 )
                 .unwrap_text_modifiers();
+            // NOTE: the width is relative to the area; `AttrValue::Size` is accepted too
+            let width = self
+                .props
+                .get_or(
+                    Attribute::Width,
+                    AttrValue::SizeSpec(SizeSpec::Percent(100)),
+                )
+                .unwrap_size_spec()
+                .resolve(area.width);
             frame.render_widget(
                 Paragraph::new(text)
                     .style(
@@ -94,7 +101,7 @@ impl MockComponent for Label {
                             .add_modifier(modifiers),
                     )
                     .alignment(alignment),
-                area,
+                Rect { width, ..area },
             );
         }
     }
@@ -2,7 +2,7 @@
 //!
 //! demo example components
 
-use tuirealm::props::{Alignment, Borders, Color, Style};
+use tuirealm::props::{Alignment, Borders, Color, Palette, Style};
 use tuirealm::ratatui::widgets::Block;
 
 use super::Msg;
@@ -19,13 +19,21 @@ pub use label::Label;
 
 /// ### get_block
 ///
-/// Get block
-pub(crate) fn get_block<'a>(props: Borders, title: (String, Alignment), focus: bool) -> Block<'a> {
+/// Get block. The border colors are taken from the `focus` and `unfocused` colors of the palette, when set
+pub(crate) fn get_block<'a>(
+    props: Borders,
+    title: (String, Alignment),
+    focus: bool,
+    palette: &Palette,
+) -> Block<'a> {
     Block::default()
         .borders(props.sides)
         .border_style(match focus {
+            true if palette.has("focus") => palette.fg_of("focus"),
             true => props.style(),
-            false => Style::default().fg(Color::Reset).bg(Color::Reset),
+            false => Style::default()
+                .fg(palette.get_or("unfocused", Color::Reset))
+                .bg(Color::Reset),
         })
         .border_type(props.modifiers)
         .title(title.0)
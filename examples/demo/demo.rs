@@ -4,7 +4,9 @@
 
 extern crate tuirealm;
 
-use tuirealm::application::PollStrategy;
+use std::time::{Duration, Instant};
+
+use tuirealm::application::{FrameScheduler, PollStrategy};
 use tuirealm::{AttrValue, Attribute, Update};
 // -- internal
 mod app;
@@ -22,6 +24,13 @@ pub enum Msg {
     LetterCounterBlur,
 }
 
+// Let's define the user events of our application
+#[derive(Debug, Eq, PartialEq, Clone, PartialOrd)]
+pub enum UserEvent {
+    /// Raised by the clock port each second
+    Second,
+}
+
 // Let's define the component ids for our application
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub enum Id {
@@ -32,16 +41,37 @@ pub enum Id {
 }
 
 fn main() {
+    // NOTE: with `--print-ui` (or `--print-ui=ansi`) the view is printed to stdout, instead of running the application
+    if let Some(arg) = std::env::args().find(|x| x.starts_with("--print-ui")) {
+        let mut model = Model::in_memory(80, 12);
+        model.view();
+        match arg.as_str() {
+            "--print-ui=ansi" => print!("{}", model.terminal.ansi_text()),
+            _ => print!("{}", model.terminal.plain_text()),
+        }
+        return;
+    }
+    // NOTE: with `--inline` the view is rendered below the prompt, instead of the alternate screen
+    let inline = std::env::args().any(|x| x == "--inline");
     // Setup model
-    let mut model = Model::default();
-    // Enter alternate screen
-    let _ = model.terminal.enter_alternate_screen();
-    let _ = model.terminal.enable_raw_mode();
+    let mut model = if inline {
+        Model::inline(12)
+    } else {
+        let mut model = Model::default();
+        // Enter alternate screen
+        let _ = model.terminal.enter_alternate_screen();
+        let _ = model.terminal.enable_raw_mode();
+        model
+    };
     // Main loop
+    // NOTE: draw at most 30 frames per second, but process the input as soon as it's received
+    let mut scheduler = FrameScheduler::new(30, Duration::from_millis(250));
     // NOTE: loop until quit; quit is set in update if AppClose is received from counter
     while !model.quit {
         // Tick
-        match model.app.tick(PollStrategy::Once) {
+        let budget = scheduler.sleep_budget(Instant::now());
+        let mut had_messages = false;
+        match model.app.tick_ex(PollStrategy::BlockThenDrain(budget)) {
             Err(err) => {
                 assert!(model
                     .app
@@ -51,26 +81,35 @@ fn main() {
                         AttrValue::String(format!("Application error: {}", err)),
                     )
                     .is_ok());
+                scheduler.request_draw();
             }
-            Ok(messages) if messages.len() > 0 => {
-                // NOTE: redraw if at least one msg has been processed
-                model.redraw = true;
-                for msg in messages.into_iter() {
+            Ok(result) => {
+                // NOTE: redraw only if the view may have changed
+                if result.changed {
+                    model.redraw = true;
+                }
+                had_messages = !result.messages.is_empty();
+                for msg in result.messages.into_iter() {
                     let mut msg = Some(msg);
                     while msg.is_some() {
                         msg = model.update(msg);
                     }
                 }
             }
-            _ => {}
         }
-        // Redraw
-        if model.redraw {
+        // Redraw; changes are kept pending by the scheduler until the next frame
+        if scheduler.should_draw(Instant::now(), model.redraw, had_messages) {
             model.view();
-            model.redraw = false;
         }
+        model.redraw = false;
     }
     // Terminate terminal
+    if inline {
+        // NOTE: keep the last frame on the screen
+        let _ = model.terminal.disable_raw_mode();
+        println!();
+        return;
+    }
     let _ = model.terminal.leave_alternate_screen();
     let _ = model.terminal.disable_raw_mode();
     let _ = model.terminal.clear_screen();
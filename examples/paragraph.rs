@@ -0,0 +1,155 @@
+//! ## Paragraph
+//!
+//! `Paragraph` shows how a component displaying a long text can embed a `WrapCache`, so that scrolling
+//! through the text doesn't wrap it again on each render.
+//! The view is rendered to the standard output, so it doesn't need a terminal.
+
+extern crate tuirealm;
+
+use std::time::{Duration, Instant};
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::event::{Key, KeyEvent};
+use tuirealm::props::{Borders, TextWrap};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::text::Line;
+use tuirealm::ratatui::widgets::{Block, Paragraph};
+use tuirealm::terminal::render_to_string;
+use tuirealm::utils::text::{wrap_with, WrapCache};
+use tuirealm::{
+    Application, AttrValue, Attribute, Component, Event, EventListenerCfg, Frame, MockComponent,
+    NoUserEvent, State, StateValue,
+};
+
+/// Amount of lines of the text
+const LINES: usize = 5000;
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+enum Id {
+    Reader,
+}
+
+/// A scrollable paragraph which wraps its text
+struct Reader {
+    text: String,
+    wrap: TextWrap,
+    /// First displayed line
+    offset: usize,
+    /// Whether to cache the wrapped lines
+    use_cache: bool,
+    cache: WrapCache,
+}
+
+impl Reader {
+    fn new(text: String, use_cache: bool) -> Self {
+        Self {
+            text,
+            wrap: TextWrap::Word,
+            offset: 0,
+            use_cache,
+            cache: WrapCache::default(),
+        }
+    }
+}
+
+impl MockComponent for Reader {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::from(&Borders::default());
+        let inner = block.inner(area);
+        let uncached;
+        let lines: &[String] = if self.use_cache {
+            self.cache.get_or_wrap(&self.text, inner.width, self.wrap)
+        } else {
+            uncached = wrap_with(&self.text, inner.width, self.wrap);
+            &uncached
+        };
+        let visible: Vec<Line> = lines
+            .iter()
+            .skip(self.offset)
+            .take(inner.height as usize)
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(visible).block(block), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        match attr {
+            Attribute::Text => Some(AttrValue::String(self.text.clone())),
+            Attribute::TextWrap => Some(AttrValue::TextWrap(self.wrap)),
+            _ => None,
+        }
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (Attribute::Text, AttrValue::String(text)) => self.text = text,
+            (Attribute::TextWrap, AttrValue::TextWrap(wrap)) => self.wrap = wrap,
+            _ => return,
+        }
+        self.cache.invalidate();
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.offset))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Scroll(Direction::Down) => self.offset += 1,
+            Cmd::Scroll(Direction::Up) => self.offset = self.offset.saturating_sub(1),
+            _ => return CmdResult::None,
+        }
+        CmdResult::Changed(self.state())
+    }
+}
+
+impl Component<(), NoUserEvent> for Reader {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<()> {
+        match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => {
+                self.perform(Cmd::Scroll(Direction::Down));
+            }
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Scroll(Direction::Up));
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+/// Scroll down the reader `scrolls` times, rendering it after each scroll, and return the elapsed time
+fn scroll(use_cache: bool, text: &str, scrolls: usize) -> (Duration, String) {
+    let mut app: Application<Id, (), NoUserEvent> = Application::init(EventListenerCfg::default());
+    assert!(app
+        .mount(
+            Id::Reader,
+            Box::new(Reader::new(text.to_string(), use_cache)),
+            vec![]
+        )
+        .is_ok());
+    let views = [(Id::Reader, Rect::new(0, 0, 40, 8))];
+    let started = Instant::now();
+    let mut view = String::new();
+    for _ in 0..scrolls {
+        assert!(app
+            .forward(&Id::Reader, Event::Keyboard(KeyEvent::from(Key::Down)))
+            .is_ok());
+        view = render_to_string(&mut app, &views, 40, 8);
+    }
+    (started.elapsed(), view)
+}
+
+fn main() {
+    let text: String = (0..LINES)
+        .map(|i| format!("{i}: the quick brown fox jumps over the lazy dog\n"))
+        .collect();
+    let (uncached, uncached_view) = scroll(false, &text, 100);
+    let (cached, cached_view) = scroll(true, &text, 100);
+    assert_eq!(uncached_view, cached_view);
+    print!("{cached_view}");
+    println!("100 renders without cache: {uncached:?}");
+    println!("100 renders with cache:    {cached:?}");
+}
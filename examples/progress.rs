@@ -0,0 +1,127 @@
+//! ## Progress
+//!
+//! `Progress` shows how to report the progress of a job running on a worker thread, using a `ChannelPort`.
+//! The view is rendered to the standard output, so it doesn't need a terminal.
+
+extern crate tuirealm;
+
+use std::thread;
+use std::time::Duration;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::listener::ChannelPort;
+use tuirealm::props::{Color, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::widgets::{Block, Gauge};
+use tuirealm::terminal::render_to_string;
+use tuirealm::{
+    Application, AttrValue, Attribute, Component, Event, EventListenerCfg, Frame, MockComponent,
+    PollStrategy, State, StateValue, Sub, SubClause, SubEventClause,
+};
+
+#[derive(Debug, PartialEq)]
+enum Msg {
+    Progress(u16),
+    Done,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+enum Id {
+    Progress,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, PartialOrd)]
+enum UserEvent {
+    /// Progress of the job in percent
+    Progress(u16),
+    Done,
+}
+
+/// A progress bar
+#[derive(Default)]
+struct ProgressBar {
+    progress: u16,
+}
+
+impl MockComponent for ProgressBar {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(
+            Gauge::default()
+                .block(Block::bordered().title("Scanning files"))
+                .gauge_style(Style::default().fg(Color::Green))
+                .percent(self.progress),
+            area,
+        );
+    }
+
+    fn query(&self, _: Attribute) -> Option<AttrValue> {
+        None
+    }
+
+    fn attr(&mut self, _: Attribute, _: AttrValue) {}
+
+    fn state(&self) -> State {
+        State::One(StateValue::U16(self.progress))
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+impl Component<Msg, UserEvent> for ProgressBar {
+    fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        match ev {
+            Event::User(UserEvent::Progress(progress)) => {
+                self.progress = progress.min(100);
+                Some(Msg::Progress(self.progress))
+            }
+            Event::User(UserEvent::Done) => {
+                self.progress = 100;
+                Some(Msg::Done)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn main() {
+    let (sender, port) = ChannelPort::new();
+    let mut app: Application<Id, Msg, UserEvent> = Application::init(
+        EventListenerCfg::default().add_port(Box::new(port), Duration::from_millis(10), 16),
+    );
+    assert!(app
+        .mount(
+            Id::Progress,
+            Box::new(ProgressBar::default()),
+            vec![Sub::new(
+                SubEventClause::UserWhere(|_| true),
+                SubClause::Always
+            )]
+        )
+        .is_ok());
+    // the long-running job
+    let job = thread::spawn(move || {
+        for progress in (0..=100).step_by(20) {
+            thread::sleep(Duration::from_millis(50));
+            let _ = sender.send(UserEvent::Progress(progress));
+        }
+        let _ = sender.send(UserEvent::Done);
+    });
+    'main: loop {
+        let messages = app
+            .tick(PollStrategy::BlockThenDrain(Duration::from_millis(100)))
+            .expect("listener died");
+        for msg in messages {
+            println!("{msg:?}");
+            print!(
+                "{}",
+                render_to_string(&mut app, &[(Id::Progress, Rect::new(0, 0, 40, 3))], 40, 3)
+            );
+            if msg == Msg::Done {
+                break 'main;
+            }
+        }
+    }
+    job.join().expect("job panicked");
+}
@@ -16,18 +16,11 @@ use super::{Msg, UserEvent};
 /// Simple label component; just renders a text
 /// NOTE: since I need just one label, I'm not going to use different object; I will directly implement Component for Label.
 /// This is not ideal actually and in a real app you should differentiate Mock Components from Application Components.
+#[derive(Default)]
 pub struct Label {
     props: Props,
 }
 
-impl Default for Label {
-    fn default() -> Self {
-        Self {
-            props: Props::default(),
-        }
-    }
-}
-
 impl MockComponent for Label {
     fn view(&mut self, frame: &mut Frame, area: Rect) {
         // Check if visible
@@ -25,21 +25,12 @@ pub enum Id {
     Other,
 }
 
-#[derive(Debug, Eq, Clone, Copy, PartialOrd, Ord)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord)]
 pub enum UserEvent {
     GotData(SystemTime),
     None,
 }
 
-impl PartialEq for UserEvent {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (UserEvent::GotData(_), UserEvent::GotData(_)) => true,
-            _ => false,
-        }
-    }
-}
-
 #[derive(Default)]
 struct UserDataPort;
 
@@ -52,11 +43,7 @@ impl Poll<UserEvent> for UserDataPort {
 fn main() {
     let event_listener = EventListenerCfg::default()
         .crossterm_input_listener(Duration::from_millis(10), 3)
-        .add_port(
-            Box::new(UserDataPort::default()),
-            Duration::from_millis(1000),
-            1,
-        );
+        .add_port(Box::new(UserDataPort), Duration::from_millis(1000), 1);
 
     let mut app: Application<Id, Msg, UserEvent> = Application::init(event_listener);
 
@@ -69,7 +56,7 @@ fn main() {
         Id::Label,
         Box::new(Label::default()),
         vec![Sub::new(
-            SubEventClause::User(UserEvent::GotData(SystemTime::UNIX_EPOCH)),
+            SubEventClause::UserWhere(|ev| matches!(ev, UserEvent::GotData(_))),
             SubClause::Always,
         )],
     )
@@ -78,7 +65,7 @@ fn main() {
         Id::Other,
         Box::new(Label::default()),
         vec![Sub::new(
-            SubEventClause::User(UserEvent::GotData(SystemTime::UNIX_EPOCH)),
+            SubEventClause::UserWhere(|ev| matches!(ev, UserEvent::GotData(_))),
             SubClause::Always,
         )],
     )
@@ -98,7 +85,7 @@ fn main() {
             Err(err) => {
                 panic!("application error {err}");
             }
-            Ok(messages) if messages.len() > 0 => {
+            Ok(messages) if !messages.is_empty() => {
                 // NOTE: redraw if at least one msg has been processed
                 model.redraw = true;
                 for msg in messages.into_iter() {
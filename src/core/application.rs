@@ -2,30 +2,75 @@
 //!
 //! This module exposes the Application, which is the core struct of tui-realm.
 
+mod bridges;
+mod builder;
+mod chords;
+mod focus_ring;
+mod frame_scheduler;
+mod hierarchy;
+mod keymap;
+mod layout;
+mod metrics;
+mod modal;
+mod screens;
+mod timers;
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
 use std::hash::Hash;
 use std::time::{Duration, Instant};
 
+use bridges::EventBridges;
+pub use builder::{ApplicationBuildError, ApplicationBuilder, HasActive, NoActive};
+use chords::ChordRecognizer;
+pub use focus_ring::FocusRing;
+pub use frame_scheduler::FrameScheduler;
+use hierarchy::Hierarchy;
+use keymap::Keymap;
+pub use layout::LayoutSnapshot;
+pub use metrics::TickMetrics;
+use modal::Modal;
+pub use modal::ModalHandle;
 use ratatui::Frame;
+use screens::Screen;
+pub use screens::DEFAULT_VIEW;
 use thiserror::Error;
+use timers::Timers;
 
 use super::{Subscription, View, WrappedComponent};
-use crate::listener::{EventListener, EventListenerCfg, ListenerError};
+use crate::event::{KeyEvent, Lifecycle, LifecycleKind, TimerId};
+use crate::listener::{EventListener, EventListenerCfg, EventSender, ListenerError};
 use crate::ratatui::layout::Rect;
-use crate::{AttrValue, Attribute, Event, Injector, State, Sub, SubEventClause, ViewError};
+use crate::{
+    AttrValue, Attribute, Event, Injector, State, Sub, SubClause, SubEventClause, SubInfo,
+    ViewError,
+};
+
+/// Maximum amount of listener errors kept until they're taken with [`Application::take_listener_errors`]
+const LISTENER_ERRORS_CAPACITY: usize = 64;
 
 /// Result retuned by [`Application`].
 /// Ok depends on method
 /// Err is always [`ApplicationError`]
 pub type ApplicationResult<T> = Result<T, ApplicationError>;
 
+/// A function which returns a stable label for a `Msg`, used to collect [`TickMetrics`].
+/// If [`None`] is returned, the message is not counted.
+pub type MsgLabeler<Msg> = Box<dyn Fn(&Msg) -> Option<&'static str>>;
+
+/// A function called with each event which has not been handled by any component (see [`Application::on_unhandled`])
+pub type UnhandledHook<UserEvent> = Box<dyn FnMut(&Event<UserEvent>)>;
+
+/// A function which returns the configuration of a new event listener (see [`Application::set_listener_factory`])
+type ListenerFactory<UserEvent> = Box<dyn Fn() -> EventListenerCfg<UserEvent>>;
+
 /// The application defines a tui-realm application.
 /// It will handle events, subscriptions and the view too.
 /// It provides functions to interact with the view (mount, umount, query, etc), but also
 /// the main function: [`Application::tick`].
 pub struct Application<ComponentId, Msg, UserEvent>
 where
-    ComponentId: Eq + PartialEq + Clone + Hash,
-    Msg: PartialEq,
+    ComponentId: Eq + PartialEq + Clone + Hash + Debug,
     UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     listener: EventListener<UserEvent>,
@@ -33,25 +78,128 @@ where
     /// If true, subs won't be processed. (Default: False)
     sub_lock: bool,
     view: View<ComponentId, Msg, UserEvent>,
+    /// Collected metrics; `None` if metrics are disabled. (Default: None)
+    metrics: Option<TickMetrics>,
+    /// Labeler used to count messages in metrics
+    msg_labeler: Option<MsgLabeler<Msg>>,
+    /// Policy applied when the event listener dies. (Default: Never)
+    restart_policy: RestartPolicy,
+    /// Configuration used to rebuild the listener on restart; `None` if the listener can't be rebuilt
+    listener_template: Option<EventListenerCfg<UserEvent>>,
+    /// Builds the configuration of the listener on restart; preferred to the template. (Default: None)
+    listener_factory: Option<ListenerFactory<UserEvent>>,
+    /// Amount of automatic restarts of the listener
+    listener_restarts: usize,
+    /// The listener mustn't be restarted before this instant
+    next_listener_restart: Instant,
+    /// Whether the view may have changed since the last [`Application::tick_ex`]
+    changed: bool,
+    /// Last non-fatal errors returned by the listener, waiting to be taken
+    listener_errors: VecDeque<ListenerError>,
+    /// Listener errors dropped since the last take, because `listener_errors` was full
+    dropped_listener_errors: usize,
+    /// Whether the application has been initialized without event listener
+    headless: bool,
+    /// Sequence number of the next subscription
+    next_sub_seq: usize,
+    /// Builds the lifecycle events; `None` if lifecycle events are disabled. (Default: None)
+    lifecycle: Option<fn(LifecycleKind, &ComponentId) -> Lifecycle>,
+    /// Lifecycle events raised since the last tick, waiting to be forwarded
+    lifecycle_events: Vec<Event<UserEvent>>,
+    /// Timers of the components, checked on each tick
+    timers: Timers<ComponentId>,
+    /// Key chords, recognized among the polled keyboard events
+    chords: ChordRecognizer<UserEvent>,
+    /// Translates the keyboard events before they're forwarded
+    keymap: Keymap<UserEvent>,
+    /// Event bridges, which produce new events from the received events
+    bridges: EventBridges<ComponentId, UserEvent>,
+    /// Open modals; only the last one receives the subscribed events
+    modal_stack: Vec<Modal<ComponentId>>,
+    /// Children of the components, which are unmounted with their parent
+    hierarchy: Hierarchy<ComponentId>,
+    /// Name of the active view
+    active_view: String,
+    /// The views which are not active, by name
+    screens: HashMap<String, Screen<ComponentId, Msg, UserEvent>>,
+    /// Called with each event which has not been handled by any component. (Default: None)
+    unhandled_hook: Option<UnhandledHook<UserEvent>>,
+    /// Maximum amount of events polled in a single tick. (Default: None)
+    max_events_per_tick: Option<usize>,
+    /// Whether the last tick polled the maximum amount of events
+    events_capped: bool,
 }
 
 impl<K, Msg, UserEvent> Application<K, Msg, UserEvent>
 where
-    K: Eq + PartialEq + Clone + Hash,
-    Msg: PartialEq,
+    K: Eq + PartialEq + Clone + Hash + Debug,
     UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     /// Initialize a new [`Application`].
     /// The event listener is immediately created and started.
     pub fn init(listener_cfg: EventListenerCfg<UserEvent>) -> Self {
+        let listener_template = listener_cfg.restart_template();
+        Self::init_with(listener_cfg.start(), listener_template)
+    }
+
+    /// Returns an [`ApplicationBuilder`], which mounts the components and sets the active one
+    /// before returning the application.
+    pub fn builder() -> ApplicationBuilder<K, Msg, UserEvent> {
+        ApplicationBuilder::default()
+    }
+
+    fn init_with(
+        listener: EventListener<UserEvent>,
+        listener_template: Option<EventListenerCfg<UserEvent>>,
+    ) -> Self {
         Self {
-            listener: listener_cfg.start(),
+            listener_template,
+            listener_factory: None,
+            listener,
             subs: Vec::new(),
             sub_lock: false,
             view: View::default(),
+            metrics: None,
+            msg_labeler: None,
+            restart_policy: RestartPolicy::default(),
+            listener_restarts: 0,
+            next_listener_restart: Instant::now(),
+            changed: true,
+            listener_errors: VecDeque::new(),
+            dropped_listener_errors: 0,
+            headless: false,
+            next_sub_seq: 0,
+            lifecycle: None,
+            lifecycle_events: Vec::new(),
+            timers: Timers::default(),
+            chords: ChordRecognizer::default(),
+            keymap: Keymap::default(),
+            bridges: EventBridges::default(),
+            modal_stack: Vec::new(),
+            hierarchy: Hierarchy::default(),
+            active_view: DEFAULT_VIEW.to_string(),
+            screens: HashMap::new(),
+            unhandled_hook: None,
+            max_events_per_tick: None,
+            events_capped: false,
         }
     }
 
+    /// Initialize a new headless [`Application`], which has no event listener polling the terminal.
+    ///
+    /// This is useful to embed tui-realm components in an existing ratatui application, which owns the event loop:
+    /// the events read by the application must be forwarded to the components with [`Application::dispatch`],
+    /// and the components rendered with [`Application::view`] in the application draw function.
+    ///
+    /// Events can still be injected with [`Application::event_sender`] and processed with [`Application::tick`],
+    /// while [`Application::lock_ports`], [`Application::unlock_ports`] and [`Application::restart_listener`]
+    /// return [`ApplicationError::Headless`].
+    pub fn init_headless() -> Self {
+        let mut app = Self::init_with(EventListenerCfg::default().start_headless(), None);
+        app.headless = true;
+        app
+    }
+
     /// Restart listener in case the previous listener has died or if you want to start a new one with a new configuration.
     ///
     /// > The listener has died if you received a [`ApplicationError::Listener(ListenerError::ListenerDied))`]
@@ -59,48 +207,415 @@ where
         &mut self,
         listener_cfg: EventListenerCfg<UserEvent>,
     ) -> ApplicationResult<()> {
+        if self.headless {
+            return Err(ApplicationError::Headless);
+        }
         self.listener.stop()?;
+        self.listener_template = listener_cfg.restart_template();
         self.listener = listener_cfg.start();
         Ok(())
     }
 
+    /// Set the policy to apply when the event listener dies.
+    ///
+    /// With [`RestartPolicy::OnDeath`], the listener is rebuilt and restarted during [`Application::tick`]
+    /// and [`Event::ListenerRestarted`] is emitted.
+    /// The listener can be restarted only if a factory has been set with [`Application::set_listener_factory`]
+    /// or if all of its ports have been added with [`EventListenerCfg::add_port_factory`] (or with the default input listeners),
+    /// otherwise the [`ListenerError::ListenerDied`] error is returned, as with [`RestartPolicy::Never`].
+    /// If the ports were locked, they're still locked after the restart.
+    pub fn set_listener_restart_policy(&mut self, policy: RestartPolicy) {
+        self.restart_policy = policy;
+        self.listener_restarts = 0;
+    }
+
+    /// Set the function which builds the configuration of the event listener when it's restarted after its death,
+    /// either by [`Application::recover_listener`] or by the [`RestartPolicy`].
+    ///
+    /// Unlike the port factories, the function can build ports which can't be rebuilt otherwise
+    /// (e.g. ports added with [`EventListenerCfg::add_port`]).
+    pub fn set_listener_factory<F>(&mut self, factory: F)
+    where
+        F: Fn() -> EventListenerCfg<UserEvent> + 'static,
+    {
+        self.listener_factory = Some(Box::new(factory));
+    }
+
+    /// Restart the event listener with the function set with [`Application::set_listener_factory`] or,
+    /// if not set, with its port factories; e.g. after [`ListenerError::ListenerDied`] has been returned.
+    ///
+    /// If the ports were locked, they're still locked after the restart.
+    /// Automatic recovery with backoff is configured with [`Application::set_listener_restart_policy`].
+    ///
+    /// Returns [`ApplicationError::ListenerNotRebuildable`] if the listener can't be rebuilt.
+    pub fn recover_listener(&mut self) -> ApplicationResult<()> {
+        if self.headless {
+            return Err(ApplicationError::Headless);
+        }
+        let Some(listener) = self.rebuild_listener() else {
+            return Err(ApplicationError::ListenerNotRebuildable);
+        };
+        self.replace_listener(listener);
+        Ok(())
+    }
+
+    /// Get an [`EventSender`] to inject events into the application.
+    ///
+    /// The injected events are forwarded to the active component and to the subscriptions on [`Application::tick`],
+    /// exactly like the events polled from the ports.
+    ///
+    /// > The sender is bound to the current event listener: after restarting the listener, a new sender must be obtained
+    pub fn event_sender(&self) -> EventSender<UserEvent> {
+        self.listener.sender()
+    }
+
+    /// Request an immediate [`Event::Tick`], which is returned by the next [`Application::tick`] together with
+    /// the other queued events, without waiting for the tick interval; e.g. to refresh the view right after the state
+    /// of an animation has changed.
+    ///
+    /// The requested tick reports an elapsed time of [`Duration::ZERO`] and doesn't affect the schedule of the ticks
+    /// sent by the event listener. It works even if the listener has no tick interval or if the application is headless.
+    ///
+    /// Returns [`ListenerError::QueueFull`] if the queue of the event listener is full.
+    pub fn request_tick(&self) -> ApplicationResult<()> {
+        self.listener
+            .sender()
+            .send(Event::Tick(Duration::ZERO))
+            .map_err(ApplicationError::from)
+    }
+
     /// Lock ports. As long as Ports are locked, ports won't be polled.
     /// Locking ports will also prevent Tick events from being generated.
     pub fn lock_ports(&mut self) -> ApplicationResult<()> {
+        if self.headless {
+            return Err(ApplicationError::Headless);
+        }
         self.listener.pause().map_err(ApplicationError::from)
     }
 
     /// Unlock Ports. Once called, the event listener will resume polling Ports.
     pub fn unlock_ports(&mut self) -> ApplicationResult<()> {
+        if self.headless {
+            return Err(ApplicationError::Headless);
+        }
         self.listener.unpause().map_err(ApplicationError::from)
     }
 
+    /// Returns whether the ports are locked (see [`Application::lock_ports`]).
+    ///
+    /// > Restarting the event listener with [`Application::restart_listener`] unlocks the ports
+    pub fn ports_locked(&self) -> bool {
+        self.listener.is_paused()
+    }
+
+    /// Get the amount of ports the event listener has been started with. Headless applications have no ports.
+    pub fn port_count(&self) -> usize {
+        self.listener.port_count()
+    }
+
+    /// Returns whether the event listener produces the [`Event::Tick`] events
+    pub fn has_tick(&self) -> bool {
+        self.listener.has_tick()
+    }
+
     /// The tick method makes the application to run once.
     /// The workflow of the tick method is the following one:
     ///
-    /// 1. The event listener is fetched according to the provided [`PollStrategy`]
+    /// 1. The event listener is fetched according to the provided [`PollStrategy`],
+    ///    up to the limit set with [`Application::set_max_events_per_tick`];
+    ///    key chords are replaced with their user events (see [`Application::bind_chord`]),
+    ///    keyboard events are translated by the keymap (see [`Application::set_keymap`])
+    ///    and the events produced by the event bridges are appended (see [`Application::bridge`])
     /// 2. All the received events are sent to the current active component
     /// 3. All the received events are forwarded to the subscribed components which satisfy the received events and conditions.
-    /// 4. Returns messages to process
+    /// 4. The expired timers are delivered to the components which own them (see [`Application::set_timer`])
+    /// 5. Returns messages to process
+    ///
+    /// Errors returned by the ports don't make the tick fail: they are collected and can be taken with
+    /// [`Application::take_listener_errors`], while the other events are still delivered.
+    /// Only fatal errors (e.g. [`ListenerError::ListenerDied`]) are returned.
     ///
     /// As soon as function returns, you should call the [`Application::view`] method.
     ///
     /// > You can also call [`Application::view`] from the [`crate::Update`] if you need it
     pub fn tick(&mut self, strategy: PollStrategy) -> ApplicationResult<Vec<Msg>> {
+        let started = self.metrics.is_some().then(Instant::now);
         // Poll event listener
         let events = self.poll(strategy)?;
-        // Forward to active element
-        let mut messages: Vec<Msg> = events
-            .iter()
-            .filter_map(|x| self.forward_to_active_component(x.clone()))
-            .collect();
-        // Forward to subscriptions and extend vector
-        if !self.sub_lock {
-            messages.extend(self.forward_to_subscriptions(events));
+        let messages = self.process_events(events);
+        if let (Some(metrics), Some(started)) = (self.metrics.as_mut(), started) {
+            metrics.record_tick(started.elapsed());
+            if let Some(events) = self.listener.metrics().map(|x| x.events()) {
+                metrics.set_events(events);
+            }
         }
         Ok(messages)
     }
 
+    /// Forward `ev` to the active component and to the subscriptions, exactly as [`Application::tick`] does
+    /// with the events polled from the listener, and returns the produced messages: the key chords are recognized,
+    /// then the expired timers are delivered.
+    ///
+    /// This is meant to be used with an [`Application::init_headless`] application, to dispatch the events read
+    /// by an outer event loop. Since timers and held chord keys are checked only when an event is dispatched,
+    /// call [`Application::dispatch_expired`] when the outer loop has no event to dispatch (e.g. on its timeout).
+    pub fn dispatch(&mut self, ev: Event<UserEvent>) -> Vec<Msg> {
+        self.process_events(vec![ev])
+    }
+
+    /// Release the held chord keys which timed out and deliver the expired timers, as [`Application::tick`] does
+    /// when no event is received, and returns the produced messages.
+    ///
+    /// This is meant to be called by the outer event loop of an [`Application::init_headless`] application
+    /// when there's no event to [`Application::dispatch`].
+    pub fn dispatch_expired(&mut self) -> Vec<Msg> {
+        self.process_events(Vec::new())
+    }
+
+    /// Run [`Application::tick`] and report whether the view may have changed, which means that
+    /// the view should be redrawn.
+    ///
+    /// The view is considered `changed` if, since the previous call to this method:
+    ///
+    /// - at least one message has been returned;
+    /// - at least one event has been forwarded to a component;
+    /// - the view has been modified with mount, umount, remount, attr, active or blur.
+    ///
+    /// The first call always reports a change, so that the first frame is drawn.
+    pub fn tick_ex(&mut self, strategy: PollStrategy) -> ApplicationResult<TickResult<Msg>> {
+        let messages = self.tick(strategy)?;
+        let changed = self.changed || !messages.is_empty();
+        self.changed = false;
+        Ok(TickResult {
+            messages,
+            changed,
+            capped: self.events_capped,
+        })
+    }
+
+    /// Set the function called with each event which has not been handled by [`Application::tick`]
+    /// or [`Application::dispatch`]; e.g. to log the ignored events or to implement a "press any key" splash screen.
+    ///
+    /// An event is unhandled if the active component (if any) returned no message and the event matched no subscription.
+    /// The lifecycle and the timer events are never reported.
+    /// Setting a new function replaces the previous one.
+    pub fn on_unhandled<F>(&mut self, f: F)
+    where
+        F: FnMut(&Event<UserEvent>) + 'static,
+    {
+        self.unhandled_hook = Some(Box::new(f));
+    }
+
+    /// Remove the function set with [`Application::on_unhandled`]
+    pub fn clear_unhandled_hook(&mut self) {
+        self.unhandled_hook = None;
+    }
+
+    /// Set the maximum amount of events polled from the listener in a single [`Application::tick`], whatever
+    /// the [`PollStrategy`]; e.g. to keep drawing the view while a misbehaving port or a huge paste floods the listener.
+    ///
+    /// The events exceeding the limit stay queued and are returned by the next ticks, in order.
+    /// With [`PollStrategy::TryFor`] and [`PollStrategy::BlockThenDrain`] the tick returns as soon as the limit is reached.
+    /// Whether the last tick reached the limit is reported by [`Application::events_capped`] and by [`TickResult::capped`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is 0
+    pub fn set_max_events_per_tick(&mut self, max: usize) {
+        assert!(
+            max > 0,
+            "the maximum amount of events per tick must be greater than 0"
+        );
+        self.max_events_per_tick = Some(max);
+    }
+
+    /// Remove the limit set with [`Application::set_max_events_per_tick`]
+    pub fn clear_max_events_per_tick(&mut self) {
+        self.max_events_per_tick = None;
+        self.events_capped = false;
+    }
+
+    /// Returns whether the last [`Application::tick`] polled the maximum amount of events set with
+    /// [`Application::set_max_events_per_tick`], which means that more events may be waiting; e.g. to show a "catching up" indicator.
+    pub fn events_capped(&self) -> bool {
+        self.events_capped
+    }
+
+    /// Take the non-fatal errors returned by the event listener (e.g. a port which failed to poll)
+    /// since the last call to this method.
+    ///
+    /// Only the last 64 errors are kept: the older ones are dropped and counted by
+    /// [`Application::dropped_listener_errors`].
+    pub fn take_listener_errors(&mut self) -> Vec<ListenerError> {
+        self.dropped_listener_errors = 0;
+        std::mem::take(&mut self.listener_errors).into()
+    }
+
+    /// Get the amount of listener errors dropped since the last call to [`Application::take_listener_errors`],
+    /// since more errors than the ones kept have been returned by the event listener.
+    pub fn dropped_listener_errors(&self) -> usize {
+        self.dropped_listener_errors
+    }
+
+    // -- metrics
+
+    /// Enable metrics collection. Metrics can then be read with [`Application::metrics`].
+    pub fn enable_metrics(&mut self) {
+        if self.metrics.is_none() {
+            self.metrics = Some(TickMetrics::default());
+        }
+    }
+
+    /// Disable metrics collection and drop the collected metrics
+    pub fn disable_metrics(&mut self) {
+        self.metrics = None;
+    }
+
+    /// Get a reference to the collected metrics, if enabled
+    pub fn metrics(&self) -> Option<&TickMetrics> {
+        self.metrics.as_ref()
+    }
+
+    /// Reset collected metrics, if enabled
+    pub fn reset_metrics(&mut self) {
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.reset();
+            if let Some(listener_metrics) = self.listener.metrics() {
+                listener_metrics.reset();
+            }
+        }
+    }
+
+    /// Set the labeler used to count the produced messages in [`TickMetrics`].
+    /// The labeler is invoked only when metrics are enabled.
+    pub fn set_msg_labeler(&mut self, labeler: MsgLabeler<Msg>) {
+        self.msg_labeler = Some(labeler);
+    }
+
+    /// Enable or disable the lifecycle events. (Default: disabled)
+    ///
+    /// When enabled, the application raises an [`Event::Lifecycle`] each time a component is mounted, umounted,
+    /// gets or loses focus. Lifecycle events are forwarded on the next [`Application::tick`] or [`Application::dispatch`],
+    /// before the other events, only to the subscriptions to [`SubEventClause::Lifecycle`]; they're never forwarded to
+    /// the active component, unless it's subscribed to them.
+    ///
+    /// > [`Application::remount`] doesn't raise any lifecycle event.
+    pub fn enable_lifecycle_events(&mut self, enable: bool)
+    where
+        K: Send + Sync + 'static,
+    {
+        if enable {
+            self.lifecycle = Some(|kind, id| Lifecycle::new(kind, id.clone()));
+        } else {
+            self.lifecycle = None;
+            self.lifecycle_events.clear();
+        }
+    }
+
+    // -- timers
+
+    /// Set the timer `timer` of component `id`, which expires after `interval`.
+    /// If `repeating` is `true`, the timer is set again each time it expires, until it's cancelled.
+    ///
+    /// When the timer expires, [`Event::Timer`] is delivered to component `id` on the next [`Application::tick`]
+    /// (or [`Application::dispatch`] and [`Application::dispatch_expired`]) and to the subscriptions to [`SubEventClause::Timer`]; so timers are as accurate as the tick rate.
+    /// Setting a timer which is already set resets it. Timers are cancelled when the component is umounted.
+    ///
+    /// Returns error if the component is not mounted.
+    pub fn set_timer(
+        &mut self,
+        id: &K,
+        timer: TimerId,
+        interval: Duration,
+        repeating: bool,
+    ) -> ApplicationResult<()> {
+        if !self.view.mounted(id) {
+            return Err(self.view.not_found(id).into());
+        }
+        self.timers
+            .set(id.clone(), timer, interval, repeating, Instant::now());
+        Ok(())
+    }
+
+    /// Cancel the timer `timer` of component `id`. Returns whether the timer was set
+    pub fn cancel_timer(&mut self, id: &K, timer: TimerId) -> bool {
+        self.timers.cancel(id, timer)
+    }
+
+    // -- chords
+
+    /// Bind the key chord `keys` (e.g. `g g`) to `event`: when `keys` are received in sequence by [`Application::tick`],
+    /// with at most `timeout` between each other, they are replaced with [`Event::User`] carrying `event`.
+    ///
+    /// Keyboard events which may still become a chord are held until the chord is completed; if the chord is broken
+    /// by another key or times out, they are released unchanged and in order. When a chord is the beginning of
+    /// a longer one (e.g. `g` and `g g`), the longer one wins and the shorter one is raised when the longer is broken
+    /// or times out. Binding the same keys again replaces the previous binding; empty chords are ignored.
+    /// The other events received while keys are held (e.g. ticks) are held behind them, so their order is preserved.
+    ///
+    /// Since held keys are released on tick (or on [`Application::dispatch_expired`]), the timeout is as accurate
+    /// as the tick rate.
+    /// Events forwarded with [`Application::dispatch`] are matched against the chords as well.
+    pub fn bind_chord(&mut self, keys: Vec<KeyEvent>, timeout: Duration, event: UserEvent) {
+        self.chords.bind(keys, timeout, event);
+    }
+
+    /// Remove the key chord bound to `keys`. Returns whether the chord was bound
+    pub fn unbind_chord(&mut self, keys: &[KeyEvent]) -> bool {
+        self.chords.unbind(keys)
+    }
+
+    // -- keymap
+
+    /// Set the keymap, which translates the keyboard events before they're forwarded to the components and
+    /// to the subscriptions (e.g. to rebind `j` to `Down` without the components knowing about it).
+    /// The keymap replaces the previous one.
+    ///
+    /// The keys are translated by [`Application::tick`] and [`Application::dispatch`], after the key chords and before
+    /// the event bridges. The keys are matched regardless of their kind, which is kept by the translated key.
+    /// An active component with [`Attribute::RawInput`] set to `AttrValue::Flag(true)` receives the original keys,
+    /// while the subscriptions always match the translated ones.
+    pub fn set_keymap(&mut self, keymap: HashMap<KeyEvent, KeyEvent>) {
+        self.keymap.set(
+            keymap
+                .into_iter()
+                .map(|(key, translated)| (key, Event::Keyboard(translated)))
+                .collect(),
+        );
+    }
+
+    /// Set the keymap like [`Application::set_keymap`], but each key is translated into an arbitrary event
+    /// (e.g. `q` into a `UserEvent::Quit`)
+    pub fn set_event_keymap(&mut self, keymap: HashMap<KeyEvent, Event<UserEvent>>) {
+        self.keymap.set(keymap);
+    }
+
+    /// Remove the keymap set with [`Application::set_keymap`] or [`Application::set_event_keymap`]
+    pub fn clear_keymap(&mut self) {
+        self.keymap.clear();
+    }
+
+    // -- event bridges
+
+    /// Add an event bridge: each event received by [`Application::tick`] or [`Application::dispatch`] which satisfies
+    /// `ev`, while `when` is satisfied, produces a new event with `f`
+    /// (e.g. a key pressed while a component is mounted can become a user event which other components subscribe to).
+    ///
+    /// The produced events are appended to the batch being processed, after the original events,
+    /// so they're forwarded to the active component and to the subscriptions as if they had been polled.
+    /// The produced events don't trigger the bridges, so bridges can't loop.
+    pub fn bridge<F>(&mut self, ev: SubEventClause<UserEvent>, when: SubClause<K>, f: F)
+    where
+        F: Fn(&Event<UserEvent>) -> Event<UserEvent> + 'static,
+    {
+        self.bridges.add(ev, when, Box::new(f));
+    }
+
+    /// Remove all the event bridges
+    pub fn clear_bridges(&mut self) {
+        self.bridges.clear();
+    }
+
     // -- view bridge
 
     /// Add an injector to the view
@@ -119,22 +634,79 @@ where
     ) -> ApplicationResult<()> {
         // Mount
         self.view.mount(id.clone(), component)?;
+        debug!(component = ?id, "component mounted");
+        self.changed = true;
+        self.raise_lifecycle(LifecycleKind::Mounted, &id);
         // Subscribe
         self.insert_subscriptions(id, subs);
         Ok(())
     }
 
+    /// Mount component `id`, with its subscriptions, as a child of the mounted component `parent`
+    /// (e.g. a tooltip or an inline validator owned by an input).
+    ///
+    /// Children are unmounted, with their subscriptions, when their parent is unmounted (see [`Application::umount`]).
+    /// Returns error if `parent` is not mounted or if `id` is already mounted.
+    pub fn mount_child(
+        &mut self,
+        parent: &K,
+        id: K,
+        component: WrappedComponent<Msg, UserEvent>,
+        subs: Vec<Sub<K, UserEvent>>,
+    ) -> ApplicationResult<()> {
+        if !self.view.mounted(parent) {
+            return Err(self.view.not_found(parent).into());
+        }
+        self.mount(id.clone(), component, subs)?;
+        self.hierarchy.add(parent.clone(), id);
+        Ok(())
+    }
+
+    /// Get the children of `parent` mounted with [`Application::mount_child`], in mount order
+    pub fn children_of(&self, parent: &K) -> Vec<&K> {
+        self.hierarchy.children_of(parent).iter().collect()
+    }
+
     /// Umount component associated to `id` and remove ALL its SUBSCRIPTIONS.
+    /// The children of the component (see [`Application::mount_child`]) are unmounted too, recursively.
+    ///
+    /// If the component or one of its descendants had focus, the focus is given back to the last component
+    /// which had focus before them.
+    /// If the component or one of its descendants is an open modal, the modal is closed as with [`Application::close_modal`].
     /// Returns Error if the component doesn't exist
     pub fn umount(&mut self, id: &K) -> ApplicationResult<()> {
+        if !self.view.mounted(id) {
+            return Err(self.view.not_found(id).into());
+        }
+        let focus = self.view.focus().cloned();
+        let descendants = self.hierarchy.descendants(id);
+        // NOTE: the focus mustn't fall back to a component which is being unmounted
+        for descendant in descendants.iter() {
+            self.view.pop_from_stack(descendant);
+        }
+        for descendant in descendants.iter() {
+            let _ = self.view.umount(descendant);
+        }
         self.view.umount(id)?;
-        self.unsubscribe_component(id);
+        self.hierarchy.remove(id);
+        debug!(component = ?id, children = descendants.len(), "component umounted");
+        self.changed = true;
+        self.drop_modals(|modal| modal == id || descendants.contains(modal));
+        self.raise_focus_change(focus);
+        for umounted in descendants.iter().chain(std::iter::once(id)) {
+            self.raise_lifecycle(LifecycleKind::Umounted, umounted);
+            self.unsubscribe_component(umounted);
+            self.timers.cancel_all_of(umounted);
+        }
         Ok(())
     }
 
     /// Remount provided component.
     /// Returns Err if failed to mount. It ignores whether the component already exists or not.
     /// If component had focus, focus is preserved
+    ///
+    /// The subscriptions to the events the component was already subscribed to keep their place in the delivery order
+    /// and, if the new subscription has no priority set, their previous priority.
     pub fn remount(
         &mut self,
         id: K,
@@ -142,18 +714,80 @@ where
         subs: Vec<Sub<K, UserEvent>>,
     ) -> ApplicationResult<()> {
         // remove subs
-        self.unsubscribe_component(&id);
+        let previous = self.take_subscriptions(&id);
         // remount into view
         self.view.remount(id.clone(), component)?;
+        self.changed = true;
         // re-add subs
-        self.insert_subscriptions(id, subs);
+        self.resubscribe(id, subs, &previous);
+        Ok(())
+    }
+
+    /// Replace the component `old` with `component`, mounted as `new_id` with the provided subscriptions.
+    /// If `old` had focus, the focus is transferred to the new component.
+    ///
+    /// The new component is mounted before `old` is unmounted, so if the swap fails, `old` is kept mounted
+    /// with its subscriptions.
+    /// If `old` is an open modal, the new component takes its place.
+    /// Returns error if `old` is not mounted or if `new_id` is already mounted and it's not `old`.
+    ///
+    /// > If `new_id` is `old`, this behaves like [`Application::remount`]
+    pub fn swap(
+        &mut self,
+        old: &K,
+        new_id: K,
+        component: WrappedComponent<Msg, UserEvent>,
+        subs: Vec<Sub<K, UserEvent>>,
+    ) -> ApplicationResult<()> {
+        if !self.view.mounted(old) {
+            return Err(self.view.not_found(old).into());
+        }
+        if &new_id == old {
+            return self.remount(new_id, component, subs);
+        }
+        let had_focus = self.view.has_focus(old);
+        self.view.mount(new_id.clone(), component)?;
+        self.changed = true;
+        if had_focus {
+            if let Err(err) = self.view.active_no_push(&new_id) {
+                // rollback
+                let _ = self.view.umount(&new_id);
+                return Err(err.into());
+            }
+        }
+        self.raise_lifecycle(LifecycleKind::Mounted, &new_id);
+        if had_focus {
+            self.raise_focus_change(Some(old.clone()));
+        }
+        // NOTE: old is mounted and has no focus anymore, so umount can't fail
+        self.view.move_before(&new_id, old);
+        let _ = self.view.umount(old);
+        self.hierarchy.rename(old, new_id.clone());
+        self.rename_modals(old, &new_id);
+        self.raise_lifecycle(LifecycleKind::Umounted, old);
+        self.unsubscribe_component(old);
+        self.timers.cancel_all_of(old);
+        self.insert_subscriptions(new_id, subs);
         Ok(())
     }
 
     /// Umount all components in the view and removed all associated subscriptions
     pub fn umount_all(&mut self) {
+        if self.lifecycle.is_some() {
+            if let Some(focus) = self.view.focus().cloned() {
+                self.raise_lifecycle(LifecycleKind::FocusLost, &focus);
+            }
+            let ids: Vec<K> = self.view.mounted_ids().into_iter().cloned().collect();
+            for id in ids {
+                self.raise_lifecycle(LifecycleKind::Umounted, &id);
+            }
+        }
         self.view.umount_all();
+        self.changed = true;
+        self.hierarchy.clear();
+        self.modal_stack.clear();
         self.subs.clear();
+        self.timers.clear();
     }
 
     /// Returns whether component `id` is mounted
@@ -161,6 +795,69 @@ where
         self.view.mounted(id)
     }
 
+    /// Returns the ids of the mounted components, in mount order.
+    ///
+    /// Remounted and swapped components keep the position of the component they replace.
+    /// The ids are borrowed from the application, which can't be modified while they're held:
+    ///
+    /// ```compile_fail
+    /// use tuirealm::{Application, NoUserEvent};
+    ///
+    /// let mut app = Application::<u8, (), NoUserEvent>::init_headless();
+    /// let ids = app.mounted_ids();
+    /// app.umount_all();
+    /// assert!(ids.is_empty());
+    /// ```
+    pub fn mounted_ids(&self) -> Vec<&K> {
+        self.view.mounted_ids()
+    }
+
+    /// Returns the ids of the mounted components which satisfy `filter`, in mount order
+    /// (e.g. all the components of a certain kind, to lay them out)
+    pub fn components_with<F>(&self, filter: F) -> Vec<&K>
+    where
+        F: Fn(&K) -> bool,
+    {
+        self.view.components_with(filter)
+    }
+
+    /// Forward `ev` to every mounted component, in mount order, and return the messages they produced
+    /// (e.g. to let each component clean up on shutdown).
+    ///
+    /// The event is forwarded regardless of focus, modals and subscriptions.
+    pub fn broadcast(&mut self, ev: Event<UserEvent>) -> Vec<Msg> {
+        self.broadcast_where(|_| true, ev)
+    }
+
+    /// Forward `ev` to every mounted component which satisfies `filter`, in mount order,
+    /// and return the messages they produced.
+    ///
+    /// The event is forwarded regardless of focus, modals and subscriptions.
+    pub fn broadcast_where<F>(&mut self, filter: F, ev: Event<UserEvent>) -> Vec<Msg>
+    where
+        F: Fn(&K) -> bool,
+    {
+        trace!(event = ev.kind(), "event broadcast");
+        self.changed = true;
+        let messages = self.view.forward_all(&ev, filter);
+        self.collect_msg_metrics(&messages);
+        messages
+    }
+
+    /// Query the attribute `attr` of each component in `ids`; values are returned in the same order of `ids`.
+    /// Returns error if any component doesn't exist
+    pub fn query_many<'a, I>(
+        &self,
+        ids: I,
+        attr: Attribute,
+    ) -> ApplicationResult<Vec<Option<AttrValue>>>
+    where
+        I: IntoIterator<Item = &'a K>,
+        K: 'a,
+    {
+        ids.into_iter().map(|id| self.query(id, attr)).collect()
+    }
+
     /// Render component called `id`
     pub fn view(&mut self, id: &K, f: &mut Frame, area: Rect) {
         self.view.view(id, f, area);
@@ -178,7 +875,20 @@ where
     pub fn attr(&mut self, id: &K, attr: Attribute, value: AttrValue) -> ApplicationResult<()> {
         self.view
             .attr(id, attr, value)
-            .map_err(ApplicationError::from)
+            .map_err(ApplicationError::from)?;
+        self.changed = true;
+        Ok(())
+    }
+
+    /// Remove attribute `attr` from component `id`.
+    /// Shorthand for `attr(id, attr, AttrValue::Unset)`.
+    /// Returns error if the component doesn't exist
+    pub fn remove_attr(&mut self, id: &K, attr: Attribute) -> ApplicationResult<()> {
+        self.view
+            .remove_attr(id, attr)
+            .map_err(ApplicationError::from)?;
+        self.changed = true;
+        Ok(())
     }
 
     /// Get state for component `id`.
@@ -187,6 +897,33 @@ where
         self.view.state(id).map_err(ApplicationError::from)
     }
 
+    /// Get the state of all the mounted components, except for the components whose state is [`State::None`].
+    ///
+    /// The snapshot can be restored later with [`Application::restore_state`]
+    /// (e.g. to save and restore the session of the user).
+    pub fn state_all(&self) -> HashMap<K, State> {
+        self.view.state_all()
+    }
+
+    /// Restore `state` on component `id`, calling [`crate::MockComponent::restore`].
+    /// Returns `Err` if component doesn't exist
+    pub fn restore_state(&mut self, id: &K, state: State) -> ApplicationResult<()> {
+        self.view.restore(id, state)?;
+        self.changed = true;
+        Ok(())
+    }
+
+    /// Forward `ev` to component `id` and return the `Msg` produced by the component, if any.
+    ///
+    /// The event is forwarded regardless of focus and subscriptions, which are both bypassed.
+    /// The returned message is not processed by the application, so it should be passed to your [`crate::Update`].
+    /// Returns error if the component doesn't exist
+    pub fn forward(&mut self, id: &K, ev: Event<UserEvent>) -> ApplicationResult<Option<Msg>> {
+        let msg = self.view.forward(id, ev)?;
+        self.changed = true;
+        Ok(msg)
+    }
+
     /// Shorthand for `attr(id, Attribute::Focus(AttrValue::Flag(true)))`.
     /// It also sets the component as the current one having focus.
     /// Previous active component, if any, GETS PUSHED to the STACK
@@ -194,7 +931,27 @@ where
     ///
     /// > NOTE: users should always use this function to give focus to components.
     pub fn active(&mut self, id: &K) -> ApplicationResult<()> {
-        self.view.active(id).map_err(ApplicationError::from)
+        let focus = self.view.focus().cloned();
+        self.view.active(id).map_err(ApplicationError::from)?;
+        debug!(component = ?id, "component activated");
+        self.changed = true;
+        self.raise_focus_change(focus);
+        Ok(())
+    }
+
+    /// Give focus to component `id`, like [`Application::active`], but the previous active component, if any,
+    /// WON'T BE PUSHED to the STACK.
+    /// This is useful to replace a modal with another one.
+    /// Returns error: if component doesn't exist. Use `mounted()` to check if component exists
+    pub fn active_no_push(&mut self, id: &K) -> ApplicationResult<()> {
+        let focus = self.view.focus().cloned();
+        self.view
+            .active_no_push(id)
+            .map_err(ApplicationError::from)?;
+        debug!(component = ?id, "component activated");
+        self.changed = true;
+        self.raise_focus_change(focus);
+        Ok(())
     }
 
     /// Blur selected element AND DON'T PUSH CURRENT ACTIVE ELEMENT INTO THE STACK
@@ -204,61 +961,425 @@ where
     ///
     /// > NOTE: users should always use this function to remove focus to components.
     pub fn blur(&mut self) -> ApplicationResult<()> {
-        self.view.blur().map_err(ApplicationError::from)
+        let focus = self.view.focus().cloned();
+        self.view.blur().map_err(ApplicationError::from)?;
+        debug!(component = ?focus, "component blurred");
+        self.changed = true;
+        self.raise_focus_change(focus);
+        Ok(())
     }
 
-    /// Get a reference to the id of the current active component in the view
-    pub fn focus(&self) -> Option<&K> {
-        self.view.focus()
+    /// Open the modal `id`: give it focus and stop delivering the subscribed events to all the other components,
+    /// until the modal is closed with [`Application::close_modal`]. Events are still forwarded to the active component,
+    /// which is the modal, unless focus is given to another component.
+    ///
+    /// Modals can be nested: only the last modal opened receives the subscribed events.
+    /// Returns error if the component is not mounted.
+    pub fn open_modal(&mut self, id: &K) -> ApplicationResult<ModalHandle<K>> {
+        let previous_focus = self.view.focus().cloned();
+        self.active(id)?;
+        self.modal_stack.push(Modal {
+            id: id.clone(),
+            previous_focus,
+        });
+        debug!(component = ?id, depth = self.modal_stack.len(), "modal opened");
+        Ok(ModalHandle::new(id.clone(), self.modal_stack.len()))
     }
 
-    // -- subs bridge
-
-    /// Subscribe component to a certain event.
-    /// Returns Error if the component doesn't exist or if the component is already subscribed to this event
-    pub fn subscribe(&mut self, id: &K, sub: Sub<K, UserEvent>) -> ApplicationResult<()> {
-        if !self.view.mounted(id) {
-            return Err(ViewError::ComponentNotFound.into());
-        }
-        let subscription = Subscription::new(id.clone(), sub);
-        if self.subscribed(id, subscription.event()) {
-            return Err(ApplicationError::AlreadySubscribed);
+    /// Close the last modal opened with [`Application::open_modal`]: focus is given back to the component
+    /// which had it when the modal was opened, if still mounted, and the subscribed events are delivered again
+    /// to the other components (or to the previous modal, if any).
+    ///
+    /// Returns [`ApplicationError::NoModal`] if no modal is open.
+    pub fn close_modal(&mut self) -> ApplicationResult<()> {
+        let modal = self.modal_stack.pop().ok_or(ApplicationError::NoModal)?;
+        debug!(component = ?modal.id, depth = self.modal_stack.len(), "modal closed");
+        let focus = self.view.focus().cloned();
+        match modal.previous_focus.filter(|id| self.view.mounted(id)) {
+            Some(previous) => self.view.active_no_push(&previous)?,
+            None if focus.as_ref() == Some(&modal.id) => self.view.blur()?,
+            None => {}
         }
-        self.subs.push(subscription);
+        self.changed = true;
+        self.raise_focus_change(focus);
         Ok(())
     }
 
-    /// Unsubscribe a component from a certain event.
-    /// Returns error if the component doesn't exist or if the component is not subscribed to this event
-    pub fn unsubscribe(&mut self, id: &K, ev: SubEventClause<UserEvent>) -> ApplicationResult<()> {
-        if !self.view.mounted(id) {
-            return Err(ViewError::ComponentNotFound.into());
+    /// Get the id of the last modal opened, if any
+    pub fn modal(&self) -> Option<&K> {
+        self.modal_stack.last().map(|modal| &modal.id)
+    }
+
+    /// Remove the open modals which satisfy `filter`, because they've been unmounted.
+    /// As with [`Application::close_modal`], the focus is given back to the component which had it
+    /// when the last modal removed was opened, if still mounted.
+    fn drop_modals<F>(&mut self, filter: F)
+    where
+        F: Fn(&K) -> bool,
+    {
+        while let Some(pos) = self.modal_stack.iter().rposition(|modal| filter(&modal.id)) {
+            let modal = self.modal_stack.remove(pos);
+            debug!(component = ?modal.id, depth = self.modal_stack.len(), "modal umounted");
+            match self.modal_stack.get_mut(pos) {
+                // the modal opened on top of the removed one gives the focus back to its predecessor
+                Some(next) if next.previous_focus.as_ref() == Some(&modal.id) => {
+                    next.previous_focus = modal.previous_focus;
+                }
+                Some(_) => {}
+                None => {
+                    if let Some(previous) = modal.previous_focus.filter(|id| self.view.mounted(id))
+                    {
+                        let _ = self.view.active_no_push(&previous);
+                    }
+                }
+            }
         }
-        if !self.subscribed(id, &ev) {
-            return Err(ApplicationError::NoSuchSubscription);
+    }
+
+    /// Replace `old` with `new` in the open modals, after `old` has been swapped with `new`
+    fn rename_modals(&mut self, old: &K, new: &K) {
+        for modal in self.modal_stack.iter_mut() {
+            if &modal.id == old {
+                modal.id = new.clone();
+            }
+            if modal.previous_focus.as_ref() == Some(old) {
+                modal.previous_focus = Some(new.clone());
+            }
         }
-        self.subs.retain(|s| s.target() != id && s.event() != &ev);
-        Ok(())
     }
 
-    /// Lock subscriptions. As long as the subscriptions are locked, events won't be propagated to
-    /// subscriptions.
-    pub fn lock_subs(&mut self) {
-        self.sub_lock = true;
+    /// Get the position where the terminal cursor should be shown, as requested by the active component
+    /// with [`crate::MockComponent::cursor`].
+    ///
+    /// Call this method once all the components have been rendered, and show the cursor with `frame.set_cursor_position`.
+    /// Returns [`None`] if the cursor should be hidden.
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.view.cursor()
     }
 
-    /// Unlock subscriptions. Application will now resume propagating events to subscriptions.
-    pub fn unlock_subs(&mut self) {
-        self.sub_lock = false;
+    /// Enable or disable the render cache of the view. (Default: disabled)
+    ///
+    /// When enabled, a component which hasn't changed since the last time it has been rendered in the same area
+    /// is drawn from the cache, without calling its `view` method.
+    /// A component is changed when an event is forwarded to it, when an attribute is set, when its focus changes
+    /// or when it is remounted.
+    ///
+    /// > The render cache must be used only with components which render depends only on their properties and states
+    /// > and which don't overlap with other components; otherwise call [`Application::invalidate_render_cache`]
+    /// > to redraw them.
+    pub fn enable_render_cache(&mut self, enable: bool) {
+        self.view.enable_render_cache(enable);
     }
 
-    // -- private
+    /// Drop the cached render of component `id`, so that it will be redrawn the next time it is rendered.
+    /// This has no effect if the render cache is disabled.
+    pub fn invalidate_render_cache(&mut self, id: &K) {
+        self.view.invalidate_render_cache(id);
+    }
+
+    /// Get a reference to the id of the current active component in the view
+    pub fn focus(&self) -> Option<&K> {
+        self.view.focus()
+    }
+
+    /// Get the focus stack, from the first to the last component which will get focus on [`Application::blur`]
+    pub fn focus_stack(&self) -> Vec<&K> {
+        self.view.focus_stack()
+    }
+
+    /// Clear the focus stack. The current active component, if any, keeps focus
+    pub fn clear_focus_stack(&mut self) {
+        self.view.clear_focus_stack();
+    }
+
+    // -- layout
+
+    /// Take a snapshot of the layout of the active view: the mounted components and their children, the focus,
+    /// the subscriptions and whether they're locked; e.g. to persist a workspace and restore it at startup
+    /// with [`Application::restore_layout`].
+    pub fn layout_snapshot(&self) -> LayoutSnapshot<K, UserEvent> {
+        let mounted: Vec<K> = self.view.mounted_ids().into_iter().cloned().collect();
+        let children = mounted
+            .iter()
+            .map(|id| (id.clone(), self.hierarchy.children_of(id).to_vec()))
+            .filter(|(_, children)| !children.is_empty())
+            .collect();
+        LayoutSnapshot {
+            children,
+            focus: self.view.focus().cloned(),
+            focus_stack: self.view.focus_stack().into_iter().cloned().collect(),
+            subscriptions: self
+                .subs
+                .iter()
+                .map(|s| (s.target().clone(), s.info()))
+                .collect(),
+            subs_locked: self.sub_lock,
+            mounted,
+        }
+    }
+
+    /// Replace the components of the active view with the layout of `snapshot`, taken with [`Application::layout_snapshot`].
+    ///
+    /// The components are built by `factory` and mounted in order, then the children, the subscriptions,
+    /// the subscriptions lock and the focus are restored.
+    ///
+    /// The snapshot is validated and all the components are built before the current ones are unmounted,
+    /// so if the restore fails the view is left untouched:
+    ///
+    /// - if a component is listed twice, [`ViewError::ComponentAlreadyMounted`] is returned
+    /// - if the focused component, a parent, a child or the target of a subscription is not listed,
+    ///   [`ViewError::ComponentNotFound`] is returned
+    /// - if a child has more than one parent or the children form a cycle, [`ApplicationError::InvalidHierarchy`]
+    ///   is returned with the id of the child
+    /// - if `factory` returns `None`, [`ApplicationError::ComponentNotBuilt`] is returned with the id of the component
+    pub fn restore_layout<F>(
+        &mut self,
+        snapshot: LayoutSnapshot<K, UserEvent>,
+        mut factory: F,
+    ) -> ApplicationResult<()>
+    where
+        F: FnMut(&K) -> Option<WrappedComponent<Msg, UserEvent>>,
+    {
+        // NOTE: these are the only errors mount and active can return once the view has been cleared
+        snapshot.validate()?;
+        let components = snapshot
+            .mounted
+            .into_iter()
+            .map(|id| match factory(&id) {
+                Some(component) => Ok((id, component)),
+                None => Err(ApplicationError::ComponentNotBuilt(format!("{id:?}"))),
+            })
+            .collect::<ApplicationResult<Vec<_>>>()?;
+        self.umount_all();
+        for (id, component) in components {
+            self.mount(id, component, Vec::new())?;
+        }
+        for (parent, children) in snapshot.children {
+            for child in children {
+                self.hierarchy.add(parent.clone(), child);
+            }
+        }
+        for (target, info) in snapshot.subscriptions {
+            let sub = Sub::new(info.event, info.when).priority(info.priority);
+            let mut subscription = Subscription::new(target, sub).with_seq(self.next_seq());
+            subscription.set_active(info.active);
+            self.push_subscription(subscription);
+        }
+        self.sub_lock = snapshot.subs_locked;
+        if let Some(focus) = snapshot.focus.as_ref() {
+            self.active(focus)?;
+        }
+        self.view.set_focus_stack(snapshot.focus_stack);
+        Ok(())
+    }
+
+    // -- views
+
+    /// Add a new empty view named `name`; the application starts with the [`DEFAULT_VIEW`] only.
+    ///
+    /// Each view has its own components, focus stack, modals, timers and subscriptions, while the event listener
+    /// is shared. All the methods which manipulate components and subscriptions operate on the active view,
+    /// so to populate the new view, switch to it with [`Application::switch_view`].
+    /// Returns error if a view with the same name already exists.
+    pub fn add_view(&mut self, name: &str) -> ApplicationResult<()> {
+        if self.has_view(name) {
+            return Err(ApplicationError::ViewAlreadyExists(name.to_string()));
+        }
+        self.screens.insert(name.to_string(), Screen::default());
+        Ok(())
+    }
+
+    /// Make view `name` the active one.
+    ///
+    /// Only the active view receives the events and renders its components; the components of the other views
+    /// are kept untouched, including their states and their focus, until their view becomes active again.
+    /// Returns error if there's no view named `name`.
+    pub fn switch_view(&mut self, name: &str) -> ApplicationResult<()> {
+        if self.active_view == name {
+            return Ok(());
+        }
+        let Some(next) = self.screens.remove(name) else {
+            return Err(ApplicationError::NoSuchView(name.to_string()));
+        };
+        let previous = Screen {
+            view: std::mem::replace(&mut self.view, next.view),
+            subs: std::mem::replace(&mut self.subs, next.subs),
+            modal_stack: std::mem::replace(&mut self.modal_stack, next.modal_stack),
+            hierarchy: std::mem::replace(&mut self.hierarchy, next.hierarchy),
+            timers: std::mem::replace(&mut self.timers, next.timers),
+        };
+        let previous_name = std::mem::replace(&mut self.active_view, name.to_string());
+        self.screens.insert(previous_name, previous);
+        self.changed = true;
+        debug!(view = name, "view switched");
+        Ok(())
+    }
+
+    /// Get the name of the active view
+    pub fn active_view(&self) -> &str {
+        &self.active_view
+    }
+
+    /// Returns whether a view named `name` exists
+    pub fn has_view(&self, name: &str) -> bool {
+        self.active_view == name || self.screens.contains_key(name)
+    }
+
+    // -- subs bridge
+
+    /// Subscribe component to a certain event.
+    /// Returns Error if the component doesn't exist or if the component is already subscribed to this event
+    pub fn subscribe(&mut self, id: &K, sub: Sub<K, UserEvent>) -> ApplicationResult<()> {
+        if !self.view.mounted(id) {
+            return Err(self.view.not_found(id).into());
+        }
+        if self.subscribed(id, sub.event()) {
+            return Err(ApplicationError::AlreadySubscribed);
+        }
+        let subscription = Subscription::new(id.clone(), sub).with_seq(self.next_seq());
+        self.push_subscription(subscription);
+        Ok(())
+    }
+
+    /// Unsubscribe a component from a certain event.
+    /// Returns error if the component doesn't exist or if the component is not subscribed to this event
+    ///
+    /// > Clauses with a predicate ([`SubEventClause::UserWhere`]) are never equal to any clause,
+    /// > so they can't be unsubscribed by value: [`ApplicationError::PredicateClause`] is returned;
+    /// > use [`Application::unsubscribe_where`] instead.
+    pub fn unsubscribe(&mut self, id: &K, ev: SubEventClause<UserEvent>) -> ApplicationResult<()> {
+        if !self.view.mounted(id) {
+            return Err(self.view.not_found(id).into());
+        }
+        if matches!(ev, SubEventClause::UserWhere(_)) {
+            return Err(ApplicationError::PredicateClause);
+        }
+        if !self.subscribed(id, &ev) {
+            return Err(ApplicationError::NoSuchSubscription);
+        }
+        self.subs
+            .retain(|s| !(s.target() == id && s.event() == &ev));
+        Ok(())
+    }
+
+    /// Unsubscribe the component `id` from all the events it's subscribed to.
+    /// Returns error if the component doesn't exist
+    pub fn unsubscribe_all(&mut self, id: &K) -> ApplicationResult<()> {
+        if !self.view.mounted(id) {
+            return Err(self.view.not_found(id).into());
+        }
+        self.unsubscribe_component(id);
+        Ok(())
+    }
+
+    /// Remove all the subscriptions for which `f`, called with the target component and the event clause, returns `true`.
+    /// This is useful when the exact event clause can't be rebuilt (e.g. to remove all the keyboard subscriptions).
+    /// Returns the amount of removed subscriptions
+    pub fn unsubscribe_where<F>(&mut self, f: F) -> usize
+    where
+        F: Fn(&K, &SubEventClause<UserEvent>) -> bool,
+    {
+        let len = self.subs.len();
+        self.subs.retain(|s| !f(s.target(), s.event()));
+        len - self.subs.len()
+    }
+
+    /// Pause all the subscriptions of the component `id`.
+    /// Paused subscriptions keep their clauses, but won't forward any event until [`Application::resume_subs_of`] is called.
+    /// Subscriptions are resumed when the component is remounted.
+    /// Returns error if the component doesn't exist
+    pub fn pause_subs_of(&mut self, id: &K) -> ApplicationResult<()> {
+        self.set_subs_active(id, false)
+    }
+
+    /// Resume the subscriptions of the component `id` previously paused with [`Application::pause_subs_of`].
+    /// Returns error if the component doesn't exist
+    pub fn resume_subs_of(&mut self, id: &K) -> ApplicationResult<()> {
+        self.set_subs_active(id, true)
+    }
+
+    /// Returns the subscriptions of the component `id`, in the order they receive the events.
+    /// Returns an empty vector if the component has no subscriptions or if it's not mounted.
+    pub fn subscriptions_of(&self, id: &K) -> Vec<SubInfo<K, UserEvent>> {
+        self.subs
+            .iter()
+            .filter(|s| s.target() == id)
+            .map(|s| s.info())
+            .collect()
+    }
+
+    /// Describe all the subscriptions, one per line, in the order they receive the events; e.g.
+    ///
+    /// ```text
+    /// Clock: tick when Clock is visible (priority 0)
+    /// Editor: key CTRL+s when not (Focus of Popup is Flag(true)) (priority 10, paused)
+    /// ```
+    ///
+    /// This is meant for debugging, e.g. to show the subscriptions in a popup.
+    pub fn dump_subscriptions(&self) -> String
+    where
+        UserEvent: Debug,
+    {
+        self.subs
+            .iter()
+            .map(|s| {
+                let info = s.info();
+                format!(
+                    "{:?}: {} when {} (priority {}{})\n",
+                    s.target(),
+                    info.event,
+                    info.when,
+                    info.priority,
+                    if info.active { "" } else { ", paused" }
+                )
+            })
+            .collect()
+    }
+
+    /// Lock subscriptions. As long as the subscriptions are locked, events won't be propagated to
+    /// subscriptions.
+    pub fn lock_subs(&mut self) {
+        self.sub_lock = true;
+    }
+
+    /// Unlock subscriptions. Application will now resume propagating events to subscriptions.
+    pub fn unlock_subs(&mut self) {
+        self.sub_lock = false;
+    }
+
+    /// Returns whether the subscriptions are locked (see [`Application::lock_subs`])
+    pub fn subs_locked(&self) -> bool {
+        self.sub_lock
+    }
+
+    // -- private
 
     /// remove all subscriptions for component
     fn unsubscribe_component(&mut self, id: &K) {
         self.subs.retain(|x| x.target() != id)
     }
 
+    /// remove all subscriptions for component, returning them
+    fn take_subscriptions(&mut self, id: &K) -> Vec<Subscription<K, UserEvent>> {
+        let (taken, kept) = std::mem::take(&mut self.subs)
+            .into_iter()
+            .partition(|x| x.target() == id);
+        self.subs = kept;
+        taken
+    }
+
+    /// Set the active flag on all the subscriptions for component
+    fn set_subs_active(&mut self, id: &K, active: bool) -> ApplicationResult<()> {
+        if !self.view.mounted(id) {
+            return Err(self.view.not_found(id).into());
+        }
+        self.subs
+            .iter_mut()
+            .filter(|s| s.target() == id)
+            .for_each(|s| s.set_active(active));
+        Ok(())
+    }
+
     /// Returns whether component `id` is subscribed to event described by `clause`
     fn subscribed(&self, id: &K, clause: &SubEventClause<UserEvent>) -> bool {
         self.subs
@@ -268,24 +1389,115 @@ where
 
     /// Insert subscriptions
     fn insert_subscriptions(&mut self, id: K, subs: Vec<Sub<K, UserEvent>>) {
+        self.resubscribe(id, subs, &[]);
+    }
+
+    /// Insert subscriptions; subscriptions to an event of a `previous` subscription keep its sequence number
+    /// and, if not set, its priority.
+    fn resubscribe(
+        &mut self,
+        id: K,
+        subs: Vec<Sub<K, UserEvent>>,
+        previous: &[Subscription<K, UserEvent>],
+    ) {
         subs.into_iter().for_each(|x| {
             // Push only if not already subscribed
-            let subscription = Subscription::new(id.clone(), x);
-            if !self.subscribed(&id, subscription.event()) {
-                self.subs.push(subscription);
+            if self.subscribed(&id, x.event()) {
+                return;
             }
+            let subscription = match previous.iter().find(|s| s.event() == x.event()) {
+                Some(prev) if x.explicit_priority().is_none() => {
+                    Subscription::new(id.clone(), x.priority(prev.priority())).with_seq(prev.seq())
+                }
+                Some(prev) => Subscription::new(id.clone(), x).with_seq(prev.seq()),
+                None => Subscription::new(id.clone(), x).with_seq(self.next_seq()),
+            };
+            self.push_subscription(subscription);
         });
     }
 
-    /// Poll listener according to provided strategy
+    /// Insert `subscription` keeping subscriptions sorted by priority and sequence number
+    fn push_subscription(&mut self, subscription: Subscription<K, UserEvent>) {
+        let index = self.subs.partition_point(|s| s.precedes(&subscription));
+        self.subs.insert(index, subscription);
+    }
+
+    /// Returns the next subscription sequence number
+    fn next_seq(&mut self) -> usize {
+        self.next_sub_seq += 1;
+        self.next_sub_seq
+    }
+
+    /// Raise a lifecycle event of `kind` for component `id`, if lifecycle events are enabled
+    fn raise_lifecycle(&mut self, kind: LifecycleKind, id: &K) {
+        if let Some(lifecycle) = self.lifecycle {
+            self.lifecycle_events
+                .push(Event::Lifecycle(lifecycle(kind, id)));
+        }
+    }
+
+    /// Raise the focus lifecycle events, if the active component is not `previous` anymore
+    fn raise_focus_change(&mut self, previous: Option<K>) {
+        let current = self.view.focus().cloned();
+        if previous == current {
+            return;
+        }
+        if let Some(id) = previous {
+            self.raise_lifecycle(LifecycleKind::FocusLost, &id);
+        }
+        if let Some(id) = current {
+            self.raise_lifecycle(LifecycleKind::FocusGained, &id);
+        }
+    }
+
+    /// Count produced messages using the msg labeler, if metrics are enabled
+    fn collect_msg_metrics(&mut self, messages: &[Msg]) {
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.count_msgs(messages.len());
+        }
+        if let (Some(metrics), Some(labeler)) = (self.metrics.as_mut(), self.msg_labeler.as_ref()) {
+            messages
+                .iter()
+                .filter_map(labeler)
+                .for_each(|label| metrics.count_label(label));
+        }
+    }
+
+    /// Poll listener according to provided strategy, up to the maximum amount of events per tick
     fn poll(&mut self, strategy: PollStrategy) -> ApplicationResult<Vec<Event<UserEvent>>> {
-        match strategy {
+        self.events_capped = false;
+        let max = self.max_events_per_tick.unwrap_or(usize::MAX);
+        let events = match strategy {
             PollStrategy::Once => self
                 .poll_listener()
                 .map(|x| x.map(|x| vec![x]).unwrap_or_default()),
-            PollStrategy::TryFor(timeout) => self.poll_with_timeout(timeout),
-            PollStrategy::UpTo(times) => self.poll_times(times),
+            PollStrategy::TryFor(timeout) => self.poll_with_timeout(timeout, max),
+            PollStrategy::UpTo(times) => self.poll_times(times.min(max)),
+            PollStrategy::BlockThenDrain(timeout) => self.poll_block_then_drain(timeout, max),
+        }?;
+        self.events_capped = events.len() >= max;
+        if self.events_capped {
+            debug!(max, "maximum amount of events per tick reached");
+        }
+        Ok(events)
+    }
+
+    /// Wait up to `timeout` for the first event, then poll up to `max` events immediately available
+    fn poll_block_then_drain(
+        &mut self,
+        timeout: Duration,
+        max: usize,
+    ) -> ApplicationResult<Vec<Event<UserEvent>>> {
+        let mut evs: Vec<Event<UserEvent>> = Vec::new();
+        let mut next = self.poll_listener_for(Some(timeout))?;
+        while let Some(ev) = next {
+            evs.push(ev);
+            if evs.len() >= max {
+                break;
+            }
+            next = self.poll_listener_for(Some(Duration::ZERO))?;
         }
+        Ok(evs)
     }
 
     /// Poll event listener up to `t` times
@@ -301,11 +1513,15 @@ where
         Ok(evs)
     }
 
-    /// Poll event listener until `timeout` is elapsed
-    fn poll_with_timeout(&mut self, timeout: Duration) -> ApplicationResult<Vec<Event<UserEvent>>> {
+    /// Poll event listener until `timeout` is elapsed or `max` events have been polled
+    fn poll_with_timeout(
+        &mut self,
+        timeout: Duration,
+        max: usize,
+    ) -> ApplicationResult<Vec<Event<UserEvent>>> {
         let started = Instant::now();
         let mut evs: Vec<Event<UserEvent>> = Vec::new();
-        while started.elapsed() < timeout {
+        while started.elapsed() < timeout && evs.len() < max {
             match self.poll_listener() {
                 Err(err) => return Err(err),
                 Ok(None) => continue,
@@ -317,44 +1533,285 @@ where
 
     /// Poll event listener once
     fn poll_listener(&mut self) -> ApplicationResult<Option<Event<UserEvent>>> {
-        self.listener.poll().map_err(ApplicationError::from)
+        self.poll_listener_for(None)
     }
 
-    /// Forward event to current active component, if any.
-    fn forward_to_active_component(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+    /// Poll event listener once, waiting up to `timeout` or up to the listener poll timeout if `None`.
+    ///
+    /// Non-fatal errors are collected into `listener_errors`, which keeps the last [`LISTENER_ERRORS_CAPACITY`] errors;
+    /// then the next message is polled without waiting.
+    fn poll_listener_for(
+        &mut self,
+        mut timeout: Option<Duration>,
+    ) -> ApplicationResult<Option<Event<UserEvent>>> {
+        loop {
+            let res = match timeout {
+                Some(timeout) => self.listener.poll_for(timeout),
+                None => self.listener.poll(),
+            };
+            match res {
+                Err(ListenerError::ListenerDied) => return self.restart_dead_listener(),
+                Err(err) => {
+                    debug!(error = %err, "listener error");
+                    if self.listener_errors.len() == LISTENER_ERRORS_CAPACITY {
+                        self.listener_errors.pop_front();
+                        self.dropped_listener_errors += 1;
+                    }
+                    self.listener_errors.push_back(err);
+                    timeout = Some(Duration::ZERO);
+                }
+                Ok(Some(ev)) => {
+                    trace!(event = ev.kind(), "event received");
+                    return Ok(Some(ev));
+                }
+                Ok(None) => return Ok(None),
+            }
+        }
+    }
+
+    /// Build a new listener with the listener factory or with the template, if any
+    fn rebuild_listener(&self) -> Option<EventListener<UserEvent>> {
+        match (
+            self.listener_factory.as_ref(),
+            self.listener_template.as_ref(),
+        ) {
+            (Some(factory), _) => Some(factory().start()),
+            (None, Some(template)) => Some(template.rebuild().start()),
+            (None, None) => None,
+        }
+    }
+
+    /// Stop the current listener and replace it with `listener`, keeping the ports locked if they were
+    fn replace_listener(&mut self, listener: EventListener<UserEvent>) {
+        let locked = self.listener.is_paused();
+        // NOTE: the worker may be dead; joining may fail if it panicked
+        let _ = self.listener.stop();
+        self.listener = listener;
+        if locked {
+            let _ = self.listener.pause();
+        }
+    }
+
+    /// Restart the dead listener according to the restart policy.
+    /// Returns `Ok(None)` if the listener is waiting for the backoff to restart.
+    fn restart_dead_listener(&mut self) -> ApplicationResult<Option<Event<UserEvent>>> {
+        let backoff = match self.restart_policy {
+            RestartPolicy::OnDeath {
+                max_retries,
+                backoff,
+            } if self.listener_restarts < max_retries => backoff,
+            _ => return Err(ListenerError::ListenerDied.into()),
+        };
+        if self.listener_factory.is_none() && self.listener_template.is_none() {
+            return Err(ListenerError::ListenerDied.into());
+        }
+        if Instant::now() < self.next_listener_restart {
+            return Ok(None);
+        }
+        if let Some(listener) = self.rebuild_listener() {
+            self.replace_listener(listener);
+        }
+        self.listener_restarts += 1;
+        debug!(
+            restarts = self.listener_restarts,
+            "event listener restarted"
+        );
+        // backoff doubles after each restart
+        let exp = (self.listener_restarts - 1).min(16) as u32;
+        self.next_listener_restart = Instant::now() + backoff * 2_u32.pow(exp);
+        Ok(Some(Event::ListenerRestarted))
+    }
+
+    /// Forward events to the active component and to the subscriptions; returns the produced messages
+    fn forward_events(&mut self, mut events: Vec<Event<UserEvent>>) -> Vec<Msg> {
+        // the active component with raw input receives the original keys
+        let raw_events = self
+            .keymap
+            .translate(&mut events)
+            .filter(|_| self.focus_has_flag(Attribute::RawInput));
+        if !self.bridges.is_empty() {
+            self.bridges.bridge(
+                &mut events,
+                |id, q| self.view.query(id, q).ok().flatten(),
+                |id| self.view.state(id).ok(),
+                |id| self.view.mounted(id),
+            );
+        }
+        // Forward lifecycle events to subscriptions only
+        let lifecycle_events = std::mem::take(&mut self.lifecycle_events);
+        let mut messages: Vec<Msg> = Vec::new();
+        if !self.sub_lock && !lifecycle_events.is_empty() {
+            messages.extend(self.forward_to_subscriptions(&lifecycle_events, None, &mut []));
+        }
+        // Whether each event has been handled; tracked only if there's a hook for the unhandled events
+        let mut handled = vec![false; self.unhandled_hook.as_ref().map_or(0, |_| events.len())];
+        // Forward to active element
+        for (i, ev) in events.iter().enumerate() {
+            let ev = raw_events.as_ref().and_then(|raw| raw.get(i)).unwrap_or(ev);
+            if let Some(msg) = self.forward_to_active_component(ev.clone()) {
+                if let Some(handled) = handled.get_mut(i) {
+                    *handled = true;
+                }
+                messages.push(msg);
+            }
+        }
+        // Forward to subscriptions and extend vector
+        if !self.sub_lock {
+            let active = self.view.focus().cloned();
+            messages.extend(self.forward_to_subscriptions(&events, active, &mut handled));
+        }
+        if let Some(hook) = self.unhandled_hook.as_mut() {
+            events
+                .iter()
+                .zip(handled)
+                .filter(|(_, handled)| !handled)
+                .for_each(|(ev, _)| hook(ev));
+        }
+        self.collect_msg_metrics(&messages);
+        messages
+    }
+
+    /// Deliver the expired timers to the components which own them and to the subscriptions to their ids;
+    /// returns the produced messages
+    /// Recognize the key chords among `events`, forward them and deliver the expired timers
+    fn process_events(&mut self, mut events: Vec<Event<UserEvent>>) -> Vec<Msg> {
+        if !self.chords.is_empty() {
+            events = self.chords.feed(events, Instant::now());
+        }
+        let mut messages = self.forward_events(events);
+        messages.extend(self.fire_timers());
+        messages
+    }
+
+    fn fire_timers(&mut self) -> Vec<Msg> {
+        if self.timers.is_empty() {
+            return Vec::new();
+        }
+        let mut messages: Vec<Msg> = Vec::new();
+        for (owner, timer) in self.timers.expired(Instant::now()) {
+            let ev = Event::Timer(timer);
+            self.changed = true;
+            if let Ok(Some(msg)) = self.view.forward(&owner, ev.clone()) {
+                debug!(component = ?owner, "message produced");
+                messages.push(msg);
+            }
+            if !self.sub_lock {
+                messages.extend(self.forward_to_subscriptions(&[ev], Some(owner), &mut []));
+            }
+        }
+        self.collect_msg_metrics(&messages);
+        messages
+    }
+
+    /// Returns whether the active component has the flag `attr` set (e.g. [`Attribute::ConsumesInput`])
+    fn focus_has_flag(&self, attr: Attribute) -> bool {
         self.view
             .focus()
-            .cloned()
-            .and_then(|x| self.view.forward(&x, ev).ok().unwrap())
+            .is_some_and(|id| matches!(self.view.query(id, attr), Ok(Some(AttrValue::Flag(true)))))
+    }
+
+    /// Forward event to current active component, if any.
+    /// If the active component is not mounted anymore, the event is skipped.
+    fn forward_to_active_component(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        let active = self.view.focus().cloned()?;
+        trace!(component = ?active, event = ev.kind(), "event forwarded to active component");
+        self.changed = true;
+        let Ok(msg) = self.view.forward(&active, ev) else {
+            debug!(component = ?active, "active component not mounted; event skipped");
+            return None;
+        };
+        if msg.is_some() {
+            debug!(component = ?active, "message produced");
+        }
+        msg
     }
 
     /// Forward events to subscriptions listening to the incoming event.
-    fn forward_to_subscriptions(&mut self, events: Vec<Event<UserEvent>>) -> Vec<Msg> {
+    /// Subscriptions of the `skip` component, which has already received the events, are skipped.
+    /// Subscriptions whose target is not mounted anymore when an event is delivered are skipped and removed.
+    /// The flag in `handled` at the index of each event matched by at least a subscription is set, if any.
+    fn forward_to_subscriptions(
+        &mut self,
+        events: &[Event<UserEvent>],
+        skip: Option<K>,
+        handled: &mut [bool],
+    ) -> Vec<Msg> {
         let mut messages: Vec<Msg> = Vec::new();
+        let mut evaluated = 0;
+        let mut matched = 0;
+        let consumes_input = self.focus_has_flag(Attribute::ConsumesInput);
+        let modal = self.modal_stack.last().map(|modal| &modal.id);
+        // subscriptions whose target is not mounted anymore
+        let mut stale: Vec<K> = Vec::new();
         // NOTE: don't touch this code again and don't try to use iterators, cause it's not gonna work :)
-        for ev in events.iter() {
+        for (i, ev) in events.iter().enumerate() {
+            // keyboard events are consumed by the active component
+            if consumes_input && matches!(ev, Event::Keyboard(_)) {
+                continue;
+            }
             for sub in self.subs.iter() {
-                // ! Active component must be different from sub !
-                if self.view.has_focus(sub.target()) {
+                // ! Skipped component must be different from sub !
+                if skip.as_ref() == Some(sub.target()) {
+                    continue;
+                }
+                // while a modal is open, only its subscriptions are evaluated
+                if modal.is_some_and(|modal| modal != sub.target()) {
+                    continue;
+                }
+                if !self.view.mounted(sub.target()) {
+                    if !stale.contains(sub.target()) {
+                        stale.push(sub.target().clone());
+                    }
                     continue;
                 }
-                if !sub.forward(
+                evaluated += 1;
+                let forwarded = sub.forward(
                     ev,
                     |id, q| self.view.query(id, q).ok().flatten(),
                     |id| self.view.state(id).ok(),
                     |id| self.view.mounted(id),
-                ) {
+                );
+                trace!(
+                    component = ?sub.target(),
+                    event = ev.kind(),
+                    forwarded,
+                    "subscription evaluated"
+                );
+                if !forwarded {
                     continue;
                 }
-                if let Some(msg) = self.view.forward(sub.target(), ev.clone()).ok().unwrap() {
+                matched += 1;
+                if let Some(handled) = handled.get_mut(i) {
+                    *handled = true;
+                }
+                self.changed = true;
+                if let Ok(Some(msg)) = self.view.forward(sub.target(), ev.clone()) {
+                    debug!(component = ?sub.target(), "message produced");
                     messages.push(msg);
                 }
             }
         }
+        if !stale.is_empty() {
+            self.subs.retain(|sub| !stale.contains(sub.target()));
+        }
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.count_subs(evaluated, matched);
+        }
         messages
     }
 }
 
+/// The result of [`Application::tick_ex`]
+#[derive(Debug, PartialEq)]
+pub struct TickResult<Msg> {
+    /// Messages to process
+    pub messages: Vec<Msg>,
+    /// Whether the view may have changed and should be redrawn
+    pub changed: bool,
+    /// Whether the tick polled the maximum amount of events (see [`Application::set_max_events_per_tick`])
+    pub capped: bool,
+}
+
 /// Poll strategy defines how to call `Application::poll` on the event listener.
 pub enum PollStrategy {
     /// `Application::poll` function will be called once
@@ -363,6 +1820,25 @@ pub enum PollStrategy {
     TryFor(Duration),
     /// `Application::poll` function will be called up to `n` times, until it will return [`Option::None`].
     UpTo(usize),
+    /// The application will wait up to the provided duration for the first event,
+    /// then it will poll all the events which are immediately available, until [`Option::None`] is returned.
+    /// This allows to process a burst of events (e.g. a paste) in a single tick, without busy waiting.
+    BlockThenDrain(Duration),
+}
+
+/// Defines what the [`Application`] does when the event listener dies.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// The [`ListenerError::ListenerDied`] error is returned by [`Application::tick`]
+    #[default]
+    Never,
+    /// The listener is restarted up to `max_retries` times.
+    /// The first restart is immediate, then the time between two restarts starts from `backoff`
+    /// and doubles after each restart.
+    OnDeath {
+        max_retries: usize,
+        backoff: Duration,
+    },
 }
 
 // -- error
@@ -372,12 +1848,28 @@ pub enum PollStrategy {
 pub enum ApplicationError {
     #[error("already subscribed")]
     AlreadySubscribed,
+    #[error("the factory can't build the component {0}")]
+    ComponentNotBuilt(String),
+    #[error("the application has no event listener")]
+    Headless,
     #[error("listener error: {0}")]
     Listener(ListenerError),
+    #[error("invalid component hierarchy at {0}")]
+    InvalidHierarchy(String),
+    #[error("the event listener can't be rebuilt")]
+    ListenerNotRebuildable,
+    #[error("there's no modal to close")]
+    NoModal,
     #[error("no such subscription")]
     NoSuchSubscription,
+    #[error("no such view: {0}")]
+    NoSuchView(String),
+    #[error("clauses with a predicate can't be unsubscribed by value; use unsubscribe_where")]
+    PredicateClause,
     #[error("view error: {0}")]
     View(ViewError),
+    #[error("view already exists: {0}")]
+    ViewAlreadyExists(String),
 }
 
 impl From<ListenerError> for ApplicationError {
@@ -395,16 +1887,22 @@ impl From<ViewError> for ApplicationError {
 #[cfg(test)]
 mod test {
 
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
     use std::time::Duration;
 
     use pretty_assertions::assert_eq;
 
     use super::*;
-    use crate::event::{Key, KeyEvent};
+    use crate::event::{Key, KeyEvent, LifecycleEvent};
+    use crate::listener::Poll;
     use crate::mock::{
-        MockBarInput, MockComponentId, MockEvent, MockFooInput, MockInjector, MockMsg, MockPoll,
+        MockBarInput, MockComponentId, MockDyingPoll, MockEvent, MockFlakyPoll, MockFloodPoll,
+        MockFooInput, MockIdlePoll, MockInjector, MockList, MockMsg, MockPoll,
     };
-    use crate::{StateValue, SubClause};
+    use crate::{LifecycleClause, MapUserEvent, StateValue, SubClause};
 
     #[test]
     fn should_initialize_application() {
@@ -423,110 +1921,2142 @@ mod test {
     }
 
     #[test]
-    fn should_manipulate_components() {
+    fn should_restart_dead_listener_with_restart_policy() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let builds_t = Arc::clone(&builds);
+        // the first port kills the worker, the rebuilt one works
+        let cfg = EventListenerCfg::default().add_port_factory(
+            move || -> Box<dyn Poll<MockEvent>> {
+                match builds_t.fetch_add(1, Ordering::SeqCst) {
+                    0 => Box::new(MockDyingPoll),
+                    _ => Box::new(MockPoll::default()),
+                }
+            },
+            Duration::from_millis(10),
+            1,
+        );
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
-            Application::init(listener_config());
-        // Mount
+            Application::init(cfg);
+        application.set_listener_restart_policy(RestartPolicy::OnDeath {
+            max_retries: 1,
+            backoff: Duration::from_millis(10),
+        });
         assert!(application
             .mount(
                 MockComponentId::InputFoo,
                 Box::new(MockFooInput::default()),
-                vec![]
+                vec![Sub::new(SubEventClause::Any, SubClause::Always)]
             )
             .is_ok());
-        // Remount with mount
-        assert!(application
-            .mount(
-                MockComponentId::InputFoo,
-                Box::new(MockFooInput::default()),
-                vec![]
-            )
-            .is_err());
         assert!(application.active(&MockComponentId::InputFoo).is_ok());
-        assert_eq!(application.focus().unwrap(), &MockComponentId::InputFoo);
-        // Remount
+        let mut messages = Vec::new();
+        for _ in 0..100 {
+            messages.extend(application.tick(PollStrategy::Once).unwrap());
+            if !messages.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(messages, vec![MockMsg::FooSubmit(String::new())]);
+        assert_eq!(builds.load(Ordering::SeqCst), 2);
+        assert_eq!(application.listener_restarts, 1);
+    }
+
+    #[test]
+    fn should_recover_dead_listener_with_listener_factory() {
+        // the port can't be rebuilt without the listener factory
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockDyingPoll),
+                Duration::from_millis(10),
+                1,
+            ));
         assert!(application
-            .remount(
+            .mount(
                 MockComponentId::InputFoo,
                 Box::new(MockFooInput::default()),
                 vec![]
             )
             .is_ok());
-        assert!(application.view.has_focus(&MockComponentId::InputFoo));
-        // Mount bar
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let mut result = Ok(Vec::new());
+        for _ in 0..100 {
+            result = application.tick(PollStrategy::Once);
+            if result.is_err() {
+                break;
+            }
+        }
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Listener(ListenerError::ListenerDied))
+        ));
+        assert!(matches!(
+            application.recover_listener(),
+            Err(ApplicationError::ListenerNotRebuildable)
+        ));
+        // recover keeping the ports locked
+        application.set_listener_factory(listener_config);
+        assert!(application.lock_ports().is_ok());
+        assert!(application.recover_listener().is_ok());
+        assert_eq!(application.ports_locked(), true);
         assert!(application
-            .mount(
-                MockComponentId::InputBar,
-                Box::new(MockBarInput::default()),
-                vec![]
-            )
-            .is_ok());
-        // Mounted
-        assert!(application.mounted(&MockComponentId::InputFoo));
-        assert!(application.mounted(&MockComponentId::InputBar));
+            .tick(PollStrategy::TryFor(Duration::from_millis(200)))
+            .unwrap()
+            .is_empty());
+        // events flow again once unlocked
+        assert!(application.unlock_ports().is_ok());
+        assert_eq!(
+            application
+                .tick(PollStrategy::TryFor(Duration::from_millis(200)))
+                .unwrap()
+                .first(),
+            Some(&MockMsg::FooSubmit(String::new()))
+        );
+    }
+
+    #[test]
+    fn should_restart_dead_listener_with_listener_factory_keeping_ports_locked() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockDyingPoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        application.set_listener_factory(listener_config);
+        application.set_listener_restart_policy(RestartPolicy::OnDeath {
+            max_retries: 1,
+            backoff: Duration::from_millis(10),
+        });
+        // wait for the worker to die before locking the ports
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(application.lock_ports().is_ok());
+        let mut restarted = false;
+        for _ in 0..100 {
+            let ev = application.poll_listener().unwrap();
+            if ev == Some(Event::ListenerRestarted) {
+                restarted = true;
+                break;
+            }
+        }
+        assert!(restarted);
+        assert_eq!(application.ports_locked(), true);
+        assert_eq!(application.port_count(), 1);
+    }
+
+    #[test]
+    fn should_not_recover_headless_listener() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        application.set_listener_factory(listener_config);
+        assert!(matches!(
+            application.recover_listener(),
+            Err(ApplicationError::Headless)
+        ));
+    }
+
+    #[test]
+    fn should_return_listener_died_without_restart_policy() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port_factory(
+                || Box::new(MockDyingPoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        let mut result = Ok(Vec::new());
+        for _ in 0..100 {
+            result = application.tick(PollStrategy::Once);
+            if result.is_err() {
+                break;
+            }
+        }
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Listener(ListenerError::ListenerDied))
+        ));
+    }
+
+    #[test]
+    fn should_forward_events_injected_with_event_sender() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let sender = application.event_sender();
+        let handle = std::thread::spawn(move || {
+            sender
+                .send(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+                .unwrap();
+            sender.send(Event::Tick(Duration::ZERO)).unwrap();
+        });
+        handle.join().unwrap();
+        assert_eq!(
+            application.tick(PollStrategy::UpTo(10)).ok().unwrap(),
+            vec![
+                MockMsg::FooInputChanged(String::from("a")),
+                MockMsg::BarTick
+            ]
+        );
+    }
+
+    #[test]
+    fn should_skip_and_drop_subscriptions_of_umounted_targets() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        for id in [MockComponentId::InputBar, MockComponentId::InputOmar] {
+            assert!(application
+                .mount(
+                    id,
+                    Box::new(MockBarInput::default()),
+                    vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+                )
+                .is_ok());
+        }
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        // the target is umounted from the view only, as if it were umounted while the batch is being delivered,
+        // so its subscription is stale
+        assert!(application.view.umount(&MockComponentId::InputBar).is_ok());
+        assert_eq!(application.subs.len(), 2);
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![MockMsg::BarTick]
+        );
+        assert_eq!(application.subs.len(), 1);
+        assert!(application
+            .subscriptions_of(&MockComponentId::InputBar)
+            .is_empty());
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![MockMsg::BarTick]
+        );
+    }
+
+    #[test]
+    fn should_tick_once_on_request() {
+        // NOTE: the listener has no tick interval
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application.request_tick().is_ok());
+        assert_eq!(
+            application
+                .tick(PollStrategy::BlockThenDrain(Duration::from_secs(1)))
+                .ok()
+                .unwrap(),
+            vec![MockMsg::BarTick]
+        );
+        assert!(application
+            .tick(PollStrategy::UpTo(10))
+            .ok()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_deliver_events_and_collect_listener_errors() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockFlakyPoll::default()),
+                Duration::from_millis(1),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let mut messages = Vec::new();
+        while messages.len() < 3 {
+            messages.extend(
+                application
+                    .tick(PollStrategy::TryFor(Duration::from_millis(20)))
+                    .ok()
+                    .unwrap(),
+            );
+        }
+        assert!(messages
+            .iter()
+            .all(|x| *x == MockMsg::FooSubmit(String::new())));
+        let errors = application.take_listener_errors();
+        assert!(errors.len() >= 2);
+        assert!(errors
+            .iter()
+            .all(|x| matches!(x, ListenerError::PollFailed)));
+        assert!(application.take_listener_errors().is_empty());
+        // only the last errors are kept, until they're taken
+        while application.dropped_listener_errors() == 0 {
+            assert!(application
+                .tick(PollStrategy::TryFor(Duration::from_millis(20)))
+                .is_ok());
+        }
+        assert_eq!(
+            application.take_listener_errors().len(),
+            LISTENER_ERRORS_CAPACITY
+        );
+        assert_eq!(application.dropped_listener_errors(), 0);
+    }
+
+    #[test]
+    fn should_handle_messages_without_partial_eq() {
+        use crate::{Component, Update};
+
+        /// A message which can't be compared
+        enum CallbackMsg {
+            Call(Box<dyn FnOnce() -> usize>),
+        }
+
+        #[derive(Default)]
+        struct Caller {
+            component: crate::mock::MockInput,
+        }
+
+        crate::delegate_mock_component!(Caller, component);
+
+        impl Component<CallbackMsg, MockEvent> for Caller {
+            fn on(&mut self, ev: Event<MockEvent>) -> Option<CallbackMsg> {
+                match ev {
+                    Event::Keyboard(KeyEvent {
+                        code: Key::Char(ch),
+                        ..
+                    }) => Some(CallbackMsg::Call(Box::new(move || ch as usize))),
+                    _ => None,
+                }
+            }
+        }
+
+        #[derive(Default)]
+        struct Model {
+            called: Vec<usize>,
+        }
+
+        impl Update<CallbackMsg> for Model {
+            fn update(&mut self, msg: Option<CallbackMsg>) -> Option<CallbackMsg> {
+                if let Some(CallbackMsg::Call(callback)) = msg {
+                    self.called.push(callback());
+                }
+                None
+            }
+        }
+
+        let mut application: Application<MockComponentId, CallbackMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(Caller::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let mut model = Model::default();
+        for msg in application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('a')))) {
+            model.update(Some(msg));
+        }
+        assert_eq!(model.called, vec![97]);
+    }
+
+    #[test]
+    fn should_append_bridged_events_to_the_batch() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::user_where(|ev| matches!(ev, MockEvent::Hello(_))).build()]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        application.bridge(
+            SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+            SubClause::IsMounted(MockComponentId::InputBar),
+            |_| Event::User(MockEvent::Hello(String::from("enter"))),
+        );
+        // never satisfied
+        application.bridge(
+            SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+            SubClause::IsMounted(MockComponentId::InputOmar),
+            |_| Event::User(MockEvent::Hello(String::from("omar"))),
+        );
+        // bridged events are processed after the original batch
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Enter))),
+            vec![
+                MockMsg::FooSubmit(String::new()),
+                MockMsg::BarHello(String::from("enter"))
+            ]
+        );
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('a')))),
+            vec![MockMsg::FooInputChanged(String::from("a"))]
+        );
+        application.clear_bridges();
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Enter))),
+            vec![MockMsg::FooSubmit(String::from("a"))]
+        );
+    }
+
+    #[test]
+    fn bridged_events_should_not_trigger_bridges() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        // each bridge would trigger the other one, and itself, forever
+        application.bridge(
+            SubEventClause::UserWhere(|ev| matches!(ev, MockEvent::Hello(_))),
+            SubClause::Always,
+            |ev| match ev {
+                Event::User(MockEvent::Hello(name)) => {
+                    Event::User(MockEvent::Hello(format!("{name}!")))
+                }
+                ev => ev.clone(),
+            },
+        );
+        application.bridge(
+            SubEventClause::UserWhere(|ev| matches!(ev, MockEvent::Hello(_))),
+            SubClause::Always,
+            |_| Event::User(MockEvent::Hello(String::from("again"))),
+        );
+        assert_eq!(
+            application.dispatch(Event::User(MockEvent::Hello(String::from("omar")))),
+            vec![
+                MockMsg::BarHello(String::from("omar")),
+                MockMsg::BarHello(String::from("omar!")),
+                MockMsg::BarHello(String::from("again")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_restore_layout_snapshot() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::keyboard(Key::Enter).build()]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![
+                    Sub::tick()
+                        .when_mounted(MockComponentId::InputBar)
+                        .priority(5)
+                        .build(),
+                    Sub::user(MockEvent::Foo).build(),
+                ]
+            )
+            .is_ok());
+        assert!(application
+            .mount_child(
+                &MockComponentId::InputFoo,
+                MockComponentId::InputOmar,
+                Box::new(MockFooInput::default()),
+                vec![Sub::any()
+                    .when(SubClause::not(SubClause::IsVisible(
+                        MockComponentId::InputBar
+                    )))
+                    .build()]
+            )
+            .is_ok());
+        assert!(application
+            .pause_subs_of(&MockComponentId::InputOmar)
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert!(application.active(&MockComponentId::InputOmar).is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        application.lock_subs();
+        let snapshot = application.layout_snapshot();
+        assert_eq!(
+            snapshot.mounted,
+            vec![
+                MockComponentId::InputBar,
+                MockComponentId::InputFoo,
+                MockComponentId::InputOmar
+            ]
+        );
+        assert_eq!(
+            snapshot.children,
+            vec![(MockComponentId::InputFoo, vec![MockComponentId::InputOmar])]
+        );
+        assert_eq!(snapshot.focus, Some(MockComponentId::InputBar));
+        assert_eq!(
+            snapshot.focus_stack,
+            vec![MockComponentId::InputFoo, MockComponentId::InputOmar]
+        );
+        assert_eq!(snapshot.subscriptions.len(), 4);
+        assert_eq!(snapshot.subscriptions[0].0, MockComponentId::InputFoo);
+        assert!(snapshot.subs_locked);
+        // rebuild into a fresh application
+        let mut restored: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(restored
+            .mount(
+                MockComponentId::Dyn(String::from("stale")),
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        let mut built = Vec::new();
+        assert!(restored
+            .restore_layout(snapshot.clone(), |id| {
+                built.push(id.clone());
+                match id {
+                    MockComponentId::InputBar => Some(Box::new(MockBarInput::default())),
+                    _ => Some(Box::new(MockFooInput::default())),
+                }
+            })
+            .is_ok());
+        assert_eq!(built, snapshot.mounted);
+        assert_eq!(restored.layout_snapshot(), snapshot);
+        assert_eq!(restored.focus(), Some(&MockComponentId::InputBar));
+        assert_eq!(
+            restored.children_of(&MockComponentId::InputFoo),
+            vec![&MockComponentId::InputOmar]
+        );
+        assert_eq!(
+            restored.subscriptions_of(&MockComponentId::InputOmar)[0].active,
+            false
+        );
+        assert!(restored.subs_locked());
+        // the factory fails to build a component
+        let err = restored
+            .restore_layout(snapshot.clone(), |id| match id {
+                MockComponentId::InputOmar => None,
+                _ => Some(Box::new(MockFooInput::default())),
+            })
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            ApplicationError::ComponentNotBuilt(id) if id == "InputOmar"
+        ));
+        // the application is not modified
+        assert_eq!(restored.mounted_ids().len(), 3);
+        assert_eq!(restored.focus(), Some(&MockComponentId::InputBar));
+        // invalid snapshots are rejected before anything is built or unmounted
+        let mut duplicated = snapshot.clone();
+        duplicated.mounted.push(MockComponentId::InputBar);
+        let mut unknown_focus = snapshot.clone();
+        unknown_focus.focus = Some(MockComponentId::Dyn(String::from("ghost")));
+        for invalid in [duplicated, unknown_focus] {
+            let mut built = 0;
+            assert!(matches!(
+                restored
+                    .restore_layout(invalid, |_| {
+                        built += 1;
+                        Some(Box::new(MockFooInput::default()))
+                    })
+                    .unwrap_err(),
+                ApplicationError::View(
+                    ViewError::ComponentAlreadyMounted(_) | ViewError::ComponentNotFound { .. }
+                )
+            ));
+            assert_eq!(built, 0);
+            assert_eq!(restored.layout_snapshot(), snapshot);
+        }
+    }
+
+    #[test]
+    fn should_reject_layout_snapshot_with_invalid_hierarchy() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount_child(
+                &MockComponentId::InputFoo,
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        let snapshot = application.layout_snapshot();
+        let mut cyclic = snapshot.clone();
+        cyclic
+            .children
+            .push((MockComponentId::InputBar, vec![MockComponentId::InputFoo]));
+        let mut self_parent = snapshot.clone();
+        self_parent.children = vec![(MockComponentId::InputFoo, vec![MockComponentId::InputFoo])];
+        let mut two_parents = snapshot.clone();
+        two_parents.mounted.push(MockComponentId::InputOmar);
+        two_parents
+            .children
+            .push((MockComponentId::InputOmar, vec![MockComponentId::InputBar]));
+        for (invalid, offending) in [
+            (cyclic, "InputBar"),
+            (self_parent, "InputFoo"),
+            (two_parents, "InputBar"),
+        ] {
+            let err = application
+                .restore_layout(invalid, |_| Some(Box::new(MockFooInput::default())))
+                .unwrap_err();
+            assert!(
+                matches!(&err, ApplicationError::InvalidHierarchy(id) if id == offending),
+                "{err}"
+            );
+            assert_eq!(application.layout_snapshot(), snapshot);
+        }
+        // the application can still be unmounted
+        assert!(application.umount(&MockComponentId::InputFoo).is_ok());
+        assert!(application.mounted_ids().is_empty());
+    }
+
+    #[test]
+    fn should_reject_layout_snapshot_with_unmounted_references() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![Sub::keyboard(Key::Enter).build()]
+            )
+            .is_ok());
+        let snapshot = application.layout_snapshot();
+        let ghost = MockComponentId::Dyn(String::from("ghost"));
+        let mut unmounted_target = snapshot.clone();
+        unmounted_target.subscriptions[0].0 = ghost.clone();
+        let mut unmounted_child = snapshot.clone();
+        unmounted_child.children = vec![(MockComponentId::InputFoo, vec![ghost.clone()])];
+        let mut unmounted_parent = snapshot.clone();
+        unmounted_parent.children = vec![(ghost, vec![MockComponentId::InputFoo])];
+        for invalid in [unmounted_target, unmounted_child, unmounted_parent] {
+            let mut built = 0;
+            assert!(matches!(
+                application
+                    .restore_layout(invalid, |_| {
+                        built += 1;
+                        Some(Box::new(MockFooInput::default()))
+                    })
+                    .unwrap_err(),
+                ApplicationError::View(ViewError::ComponentNotFound { id, .. }) if id == "Dyn(\"ghost\")"
+            ));
+            assert_eq!(built, 0);
+            assert_eq!(application.layout_snapshot(), snapshot);
+        }
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn should_serialize_layout_snapshot() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![
+                    Sub::keyboard(Key::Enter).with_ctrl().build(),
+                    Sub::lifecycle(LifecycleClause::any_mounted())
+                        .when_mounted(MockComponentId::InputBar)
+                        .build(),
+                    Sub::user(MockEvent::Hello(String::from("omar"))).build(),
+                ]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        let snapshot = application.layout_snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            serde_json::from_str::<LayoutSnapshot<MockComponentId, MockEvent>>(&json).unwrap(),
+            snapshot
+        );
+        // predicates can't be serialized
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputBar,
+                Sub::user_where(|ev| matches!(ev, MockEvent::Foo)).build()
+            )
+            .is_ok());
+        assert!(serde_json::to_string(&application.layout_snapshot()).is_err());
+    }
+
+    #[test]
+    fn should_umount_children_with_their_parent() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        let id = |name: &str| MockComponentId::Dyn(name.to_string());
+        let any = || vec![Sub::new(SubEventClause::Any, SubClause::Always)];
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                any()
+            )
+            .is_ok());
+        assert!(application
+            .mount(id("root"), Box::new(MockFooInput::default()), any())
+            .is_ok());
+        assert!(application
+            .mount_child(
+                &id("root"),
+                id("child"),
+                Box::new(MockFooInput::default()),
+                any()
+            )
+            .is_ok());
+        assert!(application
+            .mount_child(
+                &id("child"),
+                id("grandchild"),
+                Box::new(MockFooInput::default()),
+                any()
+            )
+            .is_ok());
+        assert!(application
+            .mount_child(
+                &id("root"),
+                id("sibling"),
+                Box::new(MockFooInput::default()),
+                any()
+            )
+            .is_ok());
+        // parent must be mounted
+        assert!(application
+            .mount_child(
+                &id("nope"),
+                id("orphan"),
+                Box::new(MockFooInput::default()),
+                any()
+            )
+            .is_err());
+        assert_eq!(application.mounted(&id("orphan")), false);
+        assert_eq!(
+            application.children_of(&id("root")),
+            vec![&id("child"), &id("sibling")]
+        );
+        assert_eq!(
+            application.children_of(&id("child")),
+            vec![&id("grandchild")]
+        );
+        // focus the hierarchy, after the bar
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        assert!(application.active(&id("root")).is_ok());
+        assert!(application.active(&id("child")).is_ok());
+        assert!(application.active(&id("grandchild")).is_ok());
+        // umount the root
+        assert!(application.umount(&id("root")).is_ok());
+        assert_eq!(application.mounted_ids(), vec![&MockComponentId::InputBar]);
+        assert!(application.children_of(&id("root")).is_empty());
+        assert!(application.children_of(&id("child")).is_empty());
+        assert_eq!(application.subs.len(), 1);
+        assert_eq!(application.subs[0].target(), &MockComponentId::InputBar);
+        // focus is given back to the bar
+        assert_eq!(application.focus(), Some(&MockComponentId::InputBar));
+        assert!(application.view.focus_stack().is_empty());
+        // a child can be unmounted alone
+        assert!(application
+            .mount(id("root"), Box::new(MockFooInput::default()), vec![])
+            .is_ok());
+        assert!(application
+            .mount_child(
+                &id("root"),
+                id("child"),
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.umount(&id("child")).is_ok());
+        assert!(application.children_of(&id("root")).is_empty());
+        assert!(application.umount(&id("root")).is_ok());
+        assert!(application.umount(&id("root")).is_err());
+    }
+
+    #[test]
+    fn should_cap_events_per_tick() {
+        let received: Rc<RefCell<Vec<Event<MockEvent>>>> = Rc::default();
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockFloodPoll::default()),
+                Duration::from_millis(1),
+                64,
+            ));
+        let collected = Rc::clone(&received);
+        application.on_unhandled(move |ev| collected.borrow_mut().push(ev.clone()));
+        application.set_max_events_per_tick(10);
+        std::thread::sleep(Duration::from_millis(100));
+        let mut total = 0;
+        for strategy in [
+            PollStrategy::UpTo(1000),
+            PollStrategy::TryFor(Duration::from_secs(5)),
+            PollStrategy::BlockThenDrain(Duration::from_secs(5)),
+        ] {
+            let started = Instant::now();
+            let result = application.tick_ex(strategy).unwrap();
+            assert!(started.elapsed() < Duration::from_secs(1));
+            assert!(result.capped);
+            assert!(application.events_capped());
+            total += 10;
+            assert_eq!(received.borrow().len(), total);
+        }
+        assert!(application.tick(PollStrategy::Once).is_ok());
+        assert!(!application.events_capped());
+        total += 1;
+        assert_eq!(received.borrow().len(), total);
+        // without limit the flood is drained in a single tick
+        application.clear_max_events_per_tick();
+        assert!(application.tick(PollStrategy::UpTo(100)).is_ok());
+        assert!(!application.events_capped());
+        total += 100;
+        // no event has been lost
+        let expected: Vec<Event<MockEvent>> = (0..total)
+            .map(|x| Event::User(MockEvent::Hello(x.to_string())))
+            .collect();
+        assert_eq!(received.take(), expected);
+    }
+
+    #[test]
+    fn should_report_unhandled_events() {
+        let unhandled: Rc<RefCell<Vec<Event<MockEvent>>>> = Rc::default();
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        let reported = Rc::clone(&unhandled);
+        application.on_unhandled(move |ev| reported.borrow_mut().push(ev.clone()));
+        // no active component and no subscriptions
+        assert!(application
+            .dispatch(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+            .is_empty());
+        assert_eq!(
+            unhandled.take(),
+            vec![Event::Keyboard(KeyEvent::from(Key::Char('a')))]
+        );
+        // matched by a subscription
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(
+                    SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+                    SubClause::Always
+                )]
+            )
+            .is_ok());
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Enter))),
+            vec![MockMsg::BarSubmit(String::new())]
+        );
+        assert!(unhandled.borrow().is_empty());
+        // not matched by the subscription
+        assert!(application.dispatch(Event::Tick(Duration::ZERO)).is_empty());
+        assert_eq!(unhandled.take(), vec![Event::Tick(Duration::ZERO)]);
+        // active component returns a message
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('b')))),
+            vec![MockMsg::FooInputChanged(String::from("b"))]
+        );
+        assert!(unhandled.borrow().is_empty());
+        // active component returns no message
+        application.dispatch(Event::Keyboard(KeyEvent::from(Key::Right)));
+        assert_eq!(
+            unhandled.take(),
+            vec![Event::Keyboard(KeyEvent::from(Key::Right))]
+        );
+        // subscriptions are locked
+        application.lock_subs();
+        application.blur().ok();
+        application.dispatch(Event::Keyboard(KeyEvent::from(Key::Enter)));
+        assert_eq!(
+            unhandled.take(),
+            vec![Event::Keyboard(KeyEvent::from(Key::Enter))]
+        );
+        // hook removed
+        application.clear_unhandled_hook();
+        application.dispatch(Event::Keyboard(KeyEvent::from(Key::Enter)));
+        assert!(unhandled.borrow().is_empty());
+    }
+
+    #[test]
+    fn dispatch_should_produce_the_same_messages_as_tick() {
+        fn setup(application: &mut Application<MockComponentId, MockMsg, MockEvent>) {
+            assert!(application
+                .mount(
+                    MockComponentId::InputFoo,
+                    Box::new(MockFooInput::default()),
+                    vec![]
+                )
+                .is_ok());
+            assert!(application
+                .mount(
+                    MockComponentId::InputBar,
+                    Box::new(MockBarInput::default()),
+                    vec![
+                        Sub::new(SubEventClause::Tick, SubClause::Always),
+                        Sub::user_where(|ev| matches!(ev, MockEvent::Hello(_))).build()
+                    ]
+                )
+                .is_ok());
+            assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        }
+        let events: Vec<Event<MockEvent>> = vec![
+            Event::Keyboard(KeyEvent::from(Key::Char('a'))),
+            Event::Tick(Duration::ZERO),
+            Event::User(MockEvent::Hello(String::from("omar"))),
+            Event::Keyboard(KeyEvent::from(Key::Char('b'))),
+            Event::Keyboard(KeyEvent::from(Key::Enter)),
+        ];
+        // tick
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        setup(&mut application);
+        let sender = application.event_sender();
+        for ev in events.iter() {
+            assert!(sender.send(ev.clone()).is_ok());
+        }
+        let ticked = application.tick(PollStrategy::UpTo(10)).ok().unwrap();
+        // dispatch
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        setup(&mut application);
+        let dispatched: Vec<MockMsg> = events
+            .into_iter()
+            .flat_map(|ev| application.dispatch(ev))
+            .collect();
+        // NOTE: tick forwards all the events to the active component first, then to the subscriptions
+        assert_eq!(dispatched.len(), ticked.len());
+        assert!(ticked.iter().all(|x| dispatched.contains(x)));
+        assert_eq!(
+            dispatched,
+            vec![
+                MockMsg::FooInputChanged(String::from("a")),
+                MockMsg::BarTick,
+                MockMsg::BarHello(String::from("omar")),
+                MockMsg::FooInputChanged(String::from("ab")),
+                MockMsg::FooSubmit(String::from("ab")),
+            ]
+        );
+    }
+
+    #[test]
+    fn headless_application_should_not_have_ports() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(matches!(
+            application.lock_ports(),
+            Err(ApplicationError::Headless)
+        ));
+        assert!(matches!(
+            application.unlock_ports(),
+            Err(ApplicationError::Headless)
+        ));
+        assert!(matches!(
+            application.restart_listener(listener_config()),
+            Err(ApplicationError::Headless)
+        ));
+        // injected events are still processed on tick
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert!(application.tick(PollStrategy::Once).unwrap().is_empty());
+        assert!(application
+            .event_sender()
+            .send(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+            .is_ok());
+        assert_eq!(
+            application.tick(PollStrategy::UpTo(10)).unwrap(),
+            vec![MockMsg::FooInputChanged(String::from("a"))]
+        );
+    }
+
+    #[test]
+    fn should_forward_user_events_matching_predicate() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(
+                    SubEventClause::UserWhere(|ev| matches!(ev, MockEvent::Hello(_))),
+                    SubClause::Always
+                )]
+            )
+            .is_ok());
+        let sender = application.event_sender();
+        sender
+            .send(Event::User(MockEvent::Hello(String::from("omar"))))
+            .unwrap();
+        sender.send(Event::User(MockEvent::Foo)).unwrap();
+        sender
+            .send(Event::User(MockEvent::Hello(String::from("pippo"))))
+            .unwrap();
+        assert_eq!(
+            application.tick(PollStrategy::UpTo(10)).ok().unwrap(),
+            vec![
+                MockMsg::BarHello(String::from("omar")),
+                MockMsg::BarHello(String::from("pippo"))
+            ]
+        );
+    }
+
+    #[test]
+    fn should_manipulate_components() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        // Mount
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        // Remount with mount
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_err());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(application.focus().unwrap(), &MockComponentId::InputFoo);
+        assert!(application.focus_stack().is_empty());
+        // Remount
+        assert!(application
+            .remount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.view.has_focus(&MockComponentId::InputFoo));
+        // Mount bar
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        // Mounted
+        assert!(application.mounted(&MockComponentId::InputFoo));
+        assert!(application.mounted(&MockComponentId::InputBar));
+        assert_eq!(application.mounted(&MockComponentId::InputOmar), false);
+        // Attribute and Query
+        assert!(application
+            .query(&MockComponentId::InputFoo, Attribute::InputLength)
+            .ok()
+            .unwrap()
+            .is_none());
+        assert!(application
+            .attr(
+                &MockComponentId::InputFoo,
+                Attribute::InputLength,
+                AttrValue::Length(8)
+            )
+            .is_ok());
+        assert_eq!(
+            application
+                .query(&MockComponentId::InputFoo, Attribute::InputLength)
+                .ok()
+                .unwrap()
+                .unwrap(),
+            AttrValue::Length(8)
+        );
+        assert!(application
+            .remove_attr(&MockComponentId::InputFoo, Attribute::InputLength)
+            .is_ok());
+        assert!(application
+            .query(&MockComponentId::InputFoo, Attribute::InputLength)
+            .ok()
+            .unwrap()
+            .is_none());
+        // State
+        assert_eq!(
+            application.state(&MockComponentId::InputFoo).ok().unwrap(),
+            State::One(StateValue::String(String::default()))
+        );
+        // Active / blur
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        assert!(application.active(&MockComponentId::InputOmar).is_err());
+        assert!(application
+            .active_no_push(&MockComponentId::InputOmar)
+            .is_err());
+        assert_eq!(application.focus_stack(), vec![&MockComponentId::InputFoo]);
+        assert!(application.blur().is_ok());
+        assert!(application.blur().is_ok());
+        // no focus
+        assert!(application.blur().is_err());
+        // Umount
+        assert!(application.umount(&MockComponentId::InputFoo).is_ok());
+        assert!(application.umount(&MockComponentId::InputFoo).is_err());
+        assert!(application.umount(&MockComponentId::InputBar).is_ok());
+    }
+
+    #[test]
+    fn should_subscribe_components() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                    Sub::new(
+                        SubEventClause::Tick,
+                        SubClause::HasAttrValue(
+                            MockComponentId::InputFoo,
+                            Attribute::InputLength,
+                            AttrValue::Length(8)
+                        )
+                    ), // NOTE: This event will be ignored
+                    Sub::new(
+                        SubEventClause::User(MockEvent::Bar),
+                        SubClause::HasAttrValue(
+                            MockComponentId::InputFoo,
+                            Attribute::Focus,
+                            AttrValue::Flag(true)
+                        )
+                    )
+                ]
+            )
+            .is_ok());
+        assert_eq!(application.subs.len(), 2);
+        // Subscribe for another event
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputFoo,
+                Sub::new(
+                    SubEventClause::User(MockEvent::Foo),
+                    SubClause::HasAttrValue(
+                        MockComponentId::InputFoo,
+                        Attribute::Focus,
+                        AttrValue::Flag(false)
+                    )
+                )
+            )
+            .is_ok());
+        assert_eq!(application.subs.len(), 3);
+        // Try to re-subscribe
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputFoo,
+                Sub::new(
+                    SubEventClause::User(MockEvent::Foo),
+                    SubClause::HasAttrValue(
+                        MockComponentId::InputFoo,
+                        Attribute::Focus,
+                        AttrValue::Flag(false)
+                    )
+                )
+            )
+            .is_err());
+        // Subscribe for unexisting component
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputBar,
+                Sub::new(
+                    SubEventClause::User(MockEvent::Foo),
+                    SubClause::HasAttrValue(
+                        MockComponentId::InputBar,
+                        Attribute::Focus,
+                        AttrValue::Flag(false)
+                    )
+                )
+            )
+            .is_err());
+        // Unsubscribe element
+        assert!(application
+            .unsubscribe(
+                &MockComponentId::InputFoo,
+                SubEventClause::User(MockEvent::Foo)
+            )
+            .is_ok());
+        // Unsubcribe twice
+        assert!(application
+            .unsubscribe(
+                &MockComponentId::InputFoo,
+                SubEventClause::User(MockEvent::Foo)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn unsubscribe_should_keep_other_subscriptions() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        for id in [MockComponentId::InputFoo, MockComponentId::InputBar] {
+            assert!(application
+                .mount(
+                    id,
+                    Box::new(MockFooInput::default()),
+                    vec![
+                        Sub::new(SubEventClause::Tick, SubClause::Always),
+                        Sub::new(SubEventClause::User(MockEvent::Foo), SubClause::Always),
+                        Sub::new(
+                            SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+                            SubClause::Always
+                        ),
+                    ]
+                )
+                .is_ok());
+        }
+        // unsubscribing foo's tick doesn't drop bar's tick, nor foo's other subscriptions
+        assert!(application
+            .unsubscribe(&MockComponentId::InputFoo, SubEventClause::Tick)
+            .is_ok());
+        assert_eq!(application.subs.len(), 5);
+        assert!(!application.subscribed(&MockComponentId::InputFoo, &SubEventClause::Tick));
+        assert!(application.subscribed(&MockComponentId::InputBar, &SubEventClause::Tick));
+        assert!(application.subscribed(
+            &MockComponentId::InputFoo,
+            &SubEventClause::User(MockEvent::Foo)
+        ));
+        // unsubscribe by predicate
+        assert_eq!(
+            application.unsubscribe_where(|id, ev| id == &MockComponentId::InputBar
+                && matches!(ev, SubEventClause::Keyboard(_))),
+            1
+        );
+        assert_eq!(
+            application.unsubscribe_where(|_, ev| matches!(ev, SubEventClause::User(_))),
+            2
+        );
+        assert_eq!(application.unsubscribe_where(|_, _| false), 0);
+        assert_eq!(application.subs.len(), 2);
+        // predicate clauses can't be unsubscribed by value
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputFoo,
+                Sub::user_where(|ev| matches!(ev, MockEvent::Hello(_))).build()
+            )
+            .is_ok());
+        assert!(matches!(
+            application.unsubscribe(
+                &MockComponentId::InputFoo,
+                SubEventClause::UserWhere(|ev| matches!(ev, MockEvent::Hello(_)))
+            ),
+            Err(ApplicationError::PredicateClause)
+        ));
+        assert_eq!(
+            application.unsubscribe_where(|_, ev| matches!(ev, SubEventClause::UserWhere(_))),
+            1
+        );
+        // unsubscribe all
+        assert!(application
+            .unsubscribe_all(&MockComponentId::InputFoo)
+            .is_ok());
+        assert!(application
+            .subscriptions_of(&MockComponentId::InputFoo)
+            .is_empty());
+        assert_eq!(
+            application
+                .subscriptions_of(&MockComponentId::InputBar)
+                .len(),
+            1
+        );
+        assert!(application
+            .unsubscribe_all(&MockComponentId::InputOmar)
+            .is_err());
+    }
+
+    #[test]
+    fn should_report_mounted_components_in_order() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        for id in ["c", "a", "b"] {
+            assert!(application
+                .mount(
+                    MockComponentId::Dyn(id.to_string()),
+                    Box::new(MockFooInput::default()),
+                    vec![]
+                )
+                .is_ok());
+        }
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        let dyn_id = |id: &str| MockComponentId::Dyn(id.to_string());
+        assert_eq!(
+            application.mounted_ids(),
+            vec![
+                &dyn_id("c"),
+                &dyn_id("a"),
+                &dyn_id("b"),
+                &MockComponentId::InputBar
+            ]
+        );
+        // swap keeps the position
+        assert!(application
+            .swap(
+                &dyn_id("a"),
+                dyn_id("d"),
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert_eq!(
+            application.mounted_ids(),
+            vec![
+                &dyn_id("c"),
+                &dyn_id("d"),
+                &dyn_id("b"),
+                &MockComponentId::InputBar
+            ]
+        );
+        assert!(application.umount(&dyn_id("c")).is_ok());
+        let dyns = application.components_with(|id| matches!(id, MockComponentId::Dyn(_)));
+        assert_eq!(dyns, vec![&dyn_id("d"), &dyn_id("b")]);
+        assert!(application
+            .attr(
+                &dyn_id("b"),
+                Attribute::Text,
+                AttrValue::String(String::from("omar"))
+            )
+            .is_ok());
+        assert_eq!(
+            application
+                .query_many(
+                    application.components_with(|id| matches!(id, MockComponentId::Dyn(_))),
+                    Attribute::Text
+                )
+                .unwrap(),
+            vec![None, Some(AttrValue::String(String::from("omar")))]
+        );
+        assert!(application
+            .query_many(&[dyn_id("b"), dyn_id("c")], Attribute::Text)
+            .is_err());
+    }
+
+    #[test]
+    fn should_swap_components() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        // swap and transfer focus
+        assert!(application
+            .swap(
+                &MockComponentId::InputFoo,
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Any, SubClause::Always)]
+            )
+            .is_ok());
+        assert_eq!(application.mounted(&MockComponentId::InputFoo), false);
+        assert_eq!(application.mounted(&MockComponentId::InputBar), true);
+        assert_eq!(application.focus(), Some(&MockComponentId::InputBar));
+        assert!(application.focus_stack().is_empty());
+        assert_eq!(
+            application
+                .view
+                .query(&MockComponentId::InputBar, Attribute::Focus)
+                .unwrap(),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(
+            application.subscribed(&MockComponentId::InputFoo, &SubEventClause::Tick),
+            false
+        );
+        assert!(application.subscribed(&MockComponentId::InputBar, &SubEventClause::Any));
+        // swap without focus
+        assert!(application
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .swap(
+                &MockComponentId::InputOmar,
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert_eq!(application.focus(), Some(&MockComponentId::InputBar));
         assert_eq!(application.mounted(&MockComponentId::InputOmar), false);
-        // Attribute and Query
+    }
+
+    #[test]
+    fn should_rollback_failed_swap() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        // new id is already mounted
+        assert!(matches!(
+            application.swap(
+                &MockComponentId::InputFoo,
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            ),
+            Err(ApplicationError::View(ViewError::ComponentAlreadyMounted(
+                _
+            )))
+        ));
+        assert!(application.mounted(&MockComponentId::InputFoo));
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        assert!(application.subscribed(&MockComponentId::InputFoo, &SubEventClause::Tick));
+        // old is not mounted
+        assert!(matches!(
+            application.swap(
+                &MockComponentId::InputOmar,
+                MockComponentId::Dyn(String::from("omar")),
+                Box::new(MockBarInput::default()),
+                vec![]
+            ),
+            Err(ApplicationError::View(ViewError::ComponentNotFound { .. }))
+        ));
+        assert_eq!(
+            application.mounted(&MockComponentId::Dyn(String::from("omar"))),
+            false
+        );
+        // swap with the same id
+        assert!(application
+            .swap(
+                &MockComponentId::InputFoo,
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    fn should_umount_all() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                    Sub::new(
+                        SubEventClause::User(MockEvent::Bar),
+                        SubClause::HasAttrValue(
+                            MockComponentId::InputFoo,
+                            Attribute::Focus,
+                            AttrValue::Flag(true)
+                        )
+                    )
+                ]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockFooInput::default()),
+                vec![Sub::new(SubEventClause::Any, SubClause::Always)]
+            )
+            .is_ok());
+        assert_eq!(application.subs.len(), 3);
+        // Let's umount all
+        application.umount_all();
+        assert_eq!(application.mounted(&MockComponentId::InputFoo), false);
+        assert_eq!(application.mounted(&MockComponentId::InputBar), false);
+        assert!(application.subs.is_empty());
+    }
+
+    #[test]
+    fn should_translate_keys_with_keymap() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        let key = |ch| Event::Keyboard(KeyEvent::from(Key::Char(ch)));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockList::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockList::default()),
+                vec![Sub::new(
+                    SubEventClause::Keyboard(KeyEvent::from(Key::Down)),
+                    SubClause::Always
+                )]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![
+                    Sub::new(
+                        SubEventClause::Keyboard(KeyEvent::from(Key::Char('j'))),
+                        SubClause::Always
+                    ),
+                    Sub::new(
+                        SubEventClause::User(MockEvent::Hello(String::from("keymap"))),
+                        SubClause::Always
+                    ),
+                ]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        application.set_keymap(HashMap::from([(
+            KeyEvent::from(Key::Char('j')),
+            KeyEvent::from(Key::Down),
+        )]));
+        // the list and the subscriptions receive the translated key
+        assert_eq!(
+            application.dispatch(key('j')),
+            vec![MockMsg::ListSelected(1), MockMsg::ListSelected(1)]
+        );
+        // a raw input component receives the original key, while the subscriptions still get the translated one
+        assert!(application
+            .attr(
+                &MockComponentId::InputFoo,
+                Attribute::RawInput,
+                AttrValue::Flag(true)
+            )
+            .is_ok());
+        assert_eq!(
+            application.dispatch(key('j')),
+            vec![MockMsg::ListKey('j'), MockMsg::ListSelected(2)]
+        );
+        // keys can be translated into any event; the keymap replaces the previous one
+        application.set_event_keymap(HashMap::from([(
+            KeyEvent::from(Key::Char('q')),
+            Event::User(MockEvent::Hello(String::from("keymap"))),
+        )]));
+        assert_eq!(
+            application.dispatch(key('q')),
+            vec![
+                MockMsg::ListKey('q'),
+                MockMsg::BarHello(String::from("keymap"))
+            ]
+        );
+        application.clear_keymap();
+        assert_eq!(application.dispatch(key('q')), vec![MockMsg::ListKey('q')]);
+        assert_eq!(
+            application.dispatch(key('j')),
+            vec![
+                MockMsg::ListKey('j'),
+                MockMsg::BarInputChanged(String::from("j"))
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_forward_keys_to_subscriptions_when_focus_consumes_input() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        let quit = Event::Keyboard(KeyEvent::from(Key::Char('q')));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
         assert!(application
-            .query(&MockComponentId::InputFoo, Attribute::InputLength)
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                    Sub::new(
+                        SubEventClause::Keyboard(KeyEvent::from(Key::Char('q'))),
+                        SubClause::Always
+                    ),
+                ]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert!(application
+            .attr(
+                &MockComponentId::InputFoo,
+                Attribute::ConsumesInput,
+                AttrValue::Flag(true)
+            )
+            .is_ok());
+        // the key is consumed by the input
+        assert_eq!(
+            application.dispatch(quit.clone()),
+            vec![MockMsg::FooInputChanged(String::from("q"))]
+        );
+        // other events are still forwarded
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![MockMsg::BarTick]
+        );
+        // the key is forwarded when the input doesn't consume input
+        assert!(application
+            .attr(
+                &MockComponentId::InputFoo,
+                Attribute::ConsumesInput,
+                AttrValue::Flag(false)
+            )
+            .is_ok());
+        assert_eq!(
+            application.dispatch(quit.clone()),
+            vec![
+                MockMsg::FooInputChanged(String::from("qq")),
+                MockMsg::BarInputChanged(String::from("q"))
+            ]
+        );
+        // or when it's not focused
+        assert!(application
+            .attr(
+                &MockComponentId::InputFoo,
+                Attribute::ConsumesInput,
+                AttrValue::Flag(true)
+            )
+            .is_ok());
+        assert!(application.blur().is_ok());
+        assert_eq!(
+            application.dispatch(quit),
+            vec![MockMsg::BarInputChanged(String::from("qq"))]
+        );
+    }
+
+    #[test]
+    fn should_do_tick() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config_with_tick(Duration::from_secs(60)));
+        // Mount foo and bar
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![
+                    Sub::new(SubEventClause::Tick, SubClause::Always),
+                    Sub::new(
+                        // NOTE: won't be thrown, since requires focus
+                        SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+                        SubClause::HasAttrValue(
+                            MockComponentId::InputBar,
+                            Attribute::Focus,
+                            AttrValue::Flag(true)
+                        )
+                    )
+                ]
+            )
+            .is_ok());
+        // Active FOO
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        /*
+         * Here we should:
+         *
+         * - receive an Enter from MockPoll, sent to FOO and will return a `FooSubmit`
+         * - receive a Tick from MockPoll, sent to FOO, but won't return a msg
+         * - the Tick will be sent also to BAR since is subscribed and will return a `BarTick`
+         */
+        assert_eq!(
+            application
+                .tick(PollStrategy::UpTo(5))
+                .ok()
+                .unwrap()
+                .as_slice(),
+            &[MockMsg::FooSubmit(String::from("")), MockMsg::BarTick]
+        );
+        // Active BAR
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        // Wait 200ms (wait for poll)
+        std::thread::sleep(Duration::from_millis(100));
+        /*
+         * Here we should:
+         *
+         * - receive an Enter from MockPoll, sent to BAR and will return a `BarSubmit`
+         */
+        assert_eq!(
+            application
+                .tick(PollStrategy::Once)
+                .ok()
+                .unwrap()
+                .as_slice(),
+            &[MockMsg::BarSubmit(String::from(""))]
+        );
+        // Let's try TryFor strategy
+        let events = application
+            .tick(PollStrategy::TryFor(Duration::from_millis(300)))
             .ok()
+            .unwrap();
+        assert!(events.len() >= 2);
+    }
+
+    #[test]
+    fn should_fire_timers_on_tick() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        let interval = Duration::from_millis(20);
+        assert!(application
+            .set_timer(&MockComponentId::InputBar, TimerId(1), interval, false)
+            .is_err());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::timer(TimerId(1)).build()]
+            )
+            .is_ok());
+        // foo is subscribed to the timers with id 2
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockBarInput::default()),
+                vec![Sub::timer(TimerId(2)).build()]
+            )
+            .is_ok());
+        // one-shot timer fires once; bar isn't notified twice by its subscription
+        assert!(application
+            .set_timer(&MockComponentId::InputBar, TimerId(1), interval, false)
+            .is_ok());
+        assert!(application.tick(PollStrategy::Once).unwrap().is_empty());
+        std::thread::sleep(interval);
+        assert_eq!(
+            application.tick(PollStrategy::Once).unwrap(),
+            vec![MockMsg::BarTimer(TimerId(1))]
+        );
+        std::thread::sleep(interval);
+        assert!(application.tick(PollStrategy::Once).unwrap().is_empty());
+        // repeating timer fires until cancelled; foo receives it through its subscription
+        assert!(application
+            .set_timer(&MockComponentId::InputBar, TimerId(2), interval, true)
+            .is_ok());
+        for _ in 0..3 {
+            std::thread::sleep(interval);
+            assert_eq!(
+                application.tick(PollStrategy::Once).unwrap(),
+                vec![MockMsg::BarTimer(TimerId(2)), MockMsg::BarTimer(TimerId(2))]
+            );
+        }
+        assert_eq!(
+            application.cancel_timer(&MockComponentId::InputBar, TimerId(2)),
+            true
+        );
+        assert_eq!(
+            application.cancel_timer(&MockComponentId::InputBar, TimerId(2)),
+            false
+        );
+        std::thread::sleep(interval);
+        assert!(application.tick(PollStrategy::Once).unwrap().is_empty());
+        // timers are cancelled on umount
+        assert!(application
+            .set_timer(&MockComponentId::InputFoo, TimerId(1), interval, true)
+            .is_ok());
+        assert!(application.umount(&MockComponentId::InputFoo).is_ok());
+        std::thread::sleep(interval);
+        assert!(application.tick(PollStrategy::Once).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_replace_chords_on_tick() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(10),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        // keys which don't start a chord are forwarded immediately
+        application.bind_chord(
+            vec![KeyEvent::plain(Key::Char('a')), KeyEvent::plain(Key::Enter)],
+            Duration::from_secs(1),
+            MockEvent::Hello(String::from("a")),
+        );
+        let messages = tick_until(&mut application, 1);
+        assert_eq!(messages[0], MockMsg::BarSubmit(String::new()));
+        // each pair of enter is replaced by the user event
+        application.bind_chord(
+            vec![KeyEvent::plain(Key::Enter), KeyEvent::plain(Key::Enter)],
+            Duration::from_secs(1),
+            MockEvent::Hello(String::from("enter")),
+        );
+        let messages = tick_until(&mut application, 2);
+        assert_eq!(
+            &messages[..2],
+            &[
+                MockMsg::BarHello(String::from("enter")),
+                MockMsg::BarHello(String::from("enter"))
+            ]
+        );
+        assert!(
+            application.unbind_chord(&[KeyEvent::plain(Key::Enter), KeyEvent::plain(Key::Enter)])
+        );
+        assert!(
+            !application.unbind_chord(&[KeyEvent::plain(Key::Enter), KeyEvent::plain(Key::Enter)])
+        );
+    }
+
+    #[test]
+    fn dispatch_should_recognize_chords_and_deliver_timers() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        application.bind_chord(
+            vec![KeyEvent::plain(Key::Enter), KeyEvent::plain(Key::Enter)],
+            Duration::from_millis(10),
+            MockEvent::Hello(String::from("enter")),
+        );
+        let enter = || Event::Keyboard(KeyEvent::plain(Key::Enter));
+        assert!(application.dispatch(enter()).is_empty());
+        assert_eq!(
+            application.dispatch(enter()),
+            vec![MockMsg::BarHello(String::from("enter"))]
+        );
+        // the held key is released once it times out
+        assert!(application.dispatch(enter()).is_empty());
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(
+            application.dispatch_expired(),
+            vec![MockMsg::BarSubmit(String::new())]
+        );
+        // timers
+        assert!(application
+            .set_timer(
+                &MockComponentId::InputBar,
+                TimerId(1),
+                Duration::from_millis(5),
+                false
+            )
+            .is_ok());
+        assert!(application.dispatch_expired().is_empty());
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(
+            application.dispatch(Event::User(MockEvent::Bar)),
+            vec![MockMsg::BarTimer(TimerId(1))]
+        );
+        assert!(application.dispatch_expired().is_empty());
+    }
+
+    /// Tick `application` until it returns at least `count` messages
+    fn tick_until(
+        application: &mut Application<MockComponentId, MockMsg, MockEvent>,
+        count: usize,
+    ) -> Vec<MockMsg> {
+        let mut messages = Vec::new();
+        while messages.len() < count {
+            messages.extend(application.tick(PollStrategy::Once).unwrap());
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        messages
+    }
+
+    #[test]
+    fn should_block_then_drain_events() {
+        // no events: wait up to timeout
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        let started = Instant::now();
+        assert!(application
+            .poll(PollStrategy::BlockThenDrain(Duration::from_millis(200)))
             .unwrap()
-            .is_none());
+            .is_empty());
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert!(started.elapsed() < Duration::from_secs(2));
+        // burst: return as soon as the available events are drained
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockPoll::<MockEvent>::default()),
+                Duration::from_secs(1),
+                8,
+            ));
+        std::thread::sleep(Duration::from_millis(100));
+        let started = Instant::now();
+        let events = application
+            .poll(PollStrategy::BlockThenDrain(Duration::from_secs(5)))
+            .unwrap();
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert_eq!(events.len(), 8);
+        assert!(events
+            .iter()
+            .all(|x| *x == Event::Keyboard(KeyEvent::from(Key::Enter))));
+    }
+
+    #[test]
+    fn should_report_view_changes_on_tick_ex() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        // first tick is always changed
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap(),
+            TickResult {
+                messages: vec![],
+                changed: true,
+                capped: false
+            }
+        );
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            false
+        );
+        // mount
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            true
+        );
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            false
+        );
+        // attr
+        assert!(application
+            .attr(
+                &MockComponentId::InputFoo,
+                Attribute::Text,
+                AttrValue::String(String::from("hello"))
+            )
+            .is_ok());
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            true
+        );
+        // failed attr doesn't change the view
+        assert!(application
+            .attr(
+                &MockComponentId::InputBar,
+                Attribute::Text,
+                AttrValue::String(String::from("hello"))
+            )
+            .is_err());
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            false
+        );
+        // active
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            true
+        );
+        // forwarded events
+        let sender = application.event_sender();
+        assert!(sender
+            .send(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+            .is_ok());
+        let mut result = application.tick_ex(PollStrategy::Once).unwrap();
+        for _ in 0..100 {
+            if result.changed {
+                break;
+            }
+            result = application.tick_ex(PollStrategy::Once).unwrap();
+        }
+        assert_eq!(
+            result,
+            TickResult {
+                messages: vec![MockMsg::FooInputChanged(String::from("a"))],
+                changed: true,
+                capped: false
+            }
+        );
+        assert_eq!(
+            application.tick_ex(PollStrategy::Once).unwrap().changed,
+            false
+        );
+    }
+
+    #[test]
+    fn should_forward_event_to_component() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
         assert!(application
-            .attr(
-                &MockComponentId::InputFoo,
-                Attribute::InputLength,
-                AttrValue::Length(8)
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
             )
             .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        // forward to a component without focus and subscriptions
         assert_eq!(
             application
-                .query(&MockComponentId::InputFoo, Attribute::InputLength)
-                .ok()
-                .unwrap()
+                .forward(
+                    &MockComponentId::InputBar,
+                    Event::Tick(Duration::from_millis(10))
+                )
                 .unwrap(),
-            AttrValue::Length(8)
+            Some(MockMsg::BarTick)
         );
-        // State
         assert_eq!(
-            application.state(&MockComponentId::InputFoo).ok().unwrap(),
-            State::One(StateValue::String(String::default()))
+            application
+                .forward(&MockComponentId::InputFoo, Event::WindowResize(0, 0))
+                .unwrap(),
+            None
         );
-        // Active / blur
-        assert!(application.active(&MockComponentId::InputFoo).is_ok());
-        assert!(application.active(&MockComponentId::InputBar).is_ok());
-        assert!(application.active(&MockComponentId::InputOmar).is_err());
-        assert!(application.blur().is_ok());
-        assert!(application.blur().is_ok());
-        // no focus
-        assert!(application.blur().is_err());
-        // Umount
-        assert!(application.umount(&MockComponentId::InputFoo).is_ok());
-        assert!(application.umount(&MockComponentId::InputFoo).is_err());
-        assert!(application.umount(&MockComponentId::InputBar).is_ok());
+        // not mounted
+        assert!(matches!(
+            application.forward(
+                &MockComponentId::Dyn(String::from("omar")),
+                Event::FocusGained
+            ),
+            Err(ApplicationError::View(ViewError::ComponentNotFound { .. }))
+        ));
     }
 
     #[test]
-    fn should_subscribe_components() {
+    fn should_not_propagate_event_when_subs_are_locked() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
-            Application::init(listener_config());
+            Application::init(listener_config_with_tick(Duration::from_secs(60)));
+        // Mount foo and bar
         assert!(application
             .mount(
                 MockComponentId::InputFoo,
                 Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
                 vec![
                     Sub::new(SubEventClause::Tick, SubClause::Always),
                     Sub::new(
-                        SubEventClause::Tick,
-                        SubClause::HasAttrValue(
-                            MockComponentId::InputFoo,
-                            Attribute::InputLength,
-                            AttrValue::Length(8)
-                        )
-                    ), // NOTE: This event will be ignored
-                    Sub::new(
-                        SubEventClause::User(MockEvent::Bar),
+                        // NOTE: won't be thrown, since requires focus
+                        SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
                         SubClause::HasAttrValue(
-                            MockComponentId::InputFoo,
+                            MockComponentId::InputBar,
                             Attribute::Focus,
                             AttrValue::Flag(true)
                         )
@@ -534,107 +4064,342 @@ mod test {
                 ]
             )
             .is_ok());
-        assert_eq!(application.subs.len(), 2);
-        // Subscribe for another event
+        // Active FOO
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        // lock subs
+        assert_eq!(application.subs_locked(), false);
+        application.lock_subs();
+        assert_eq!(application.subs_locked(), true);
+        assert_eq!(
+            application
+                .tick(PollStrategy::UpTo(5))
+                .ok()
+                .unwrap()
+                .as_slice(),
+            &[MockMsg::FooSubmit(String::from(""))]
+        );
+        // unlock subs
+        application.unlock_subs();
+        assert_eq!(application.subs_locked(), false);
+    }
+
+    #[test]
+    fn should_forward_events_to_subs_by_priority() {
+        // a component which replies to any user event with its own name
+        fn hello(name: &'static str) -> WrappedComponent<MockMsg, MockEvent> {
+            Box::new(MapUserEvent::new(
+                MockBarInput::default(),
+                move |ev: Event<MockEvent>| {
+                    Some(ev.map_user(|_| MockEvent::Hello(name.to_string())))
+                },
+            ))
+        }
+        fn subs(priority: Option<i32>) -> Vec<Sub<MockComponentId, MockEvent>> {
+            let sub = Sub::user(MockEvent::Foo);
+            vec![match priority {
+                Some(priority) => sub.priority(priority).build(),
+                None => sub.build(),
+            }]
+        }
+        let expected = vec![
+            MockMsg::BarHello(String::from("bar")),
+            MockMsg::BarHello(String::from("omar")),
+            MockMsg::BarHello(String::from("foo")),
+            MockMsg::BarHello(String::from("dyn")),
+        ];
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
         assert!(application
-            .subscribe(
-                &MockComponentId::InputFoo,
-                Sub::new(
-                    SubEventClause::User(MockEvent::Foo),
-                    SubClause::HasAttrValue(
-                        MockComponentId::InputFoo,
-                        Attribute::Focus,
-                        AttrValue::Flag(false)
-                    )
-                )
+            .mount(MockComponentId::InputFoo, hello("foo"), subs(None))
+            .is_ok());
+        assert!(application
+            .mount(MockComponentId::InputBar, hello("bar"), subs(Some(10)))
+            .is_ok());
+        assert!(application
+            .mount(MockComponentId::InputOmar, hello("omar"), subs(Some(5)))
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::Dyn(String::from("dyn")),
+                hello("dyn"),
+                vec![]
             )
             .is_ok());
-        assert_eq!(application.subs.len(), 3);
-        // Try to re-subscribe
         assert!(application
             .subscribe(
-                &MockComponentId::InputFoo,
-                Sub::new(
-                    SubEventClause::User(MockEvent::Foo),
-                    SubClause::HasAttrValue(
-                        MockComponentId::InputFoo,
-                        Attribute::Focus,
-                        AttrValue::Flag(false)
-                    )
-                )
+                &MockComponentId::Dyn(String::from("dyn")),
+                Sub::user(MockEvent::Foo).build()
             )
-            .is_err());
-        // Subscribe for unexisting component
+            .is_ok());
+        assert_eq!(application.dispatch(Event::User(MockEvent::Foo)), expected);
+        // remount doesn't change the order
         assert!(application
-            .subscribe(
-                &MockComponentId::InputBar,
-                Sub::new(
-                    SubEventClause::User(MockEvent::Foo),
-                    SubClause::HasAttrValue(
-                        MockComponentId::InputBar,
-                        Attribute::Focus,
-                        AttrValue::Flag(false)
-                    )
-                )
+            .remount(MockComponentId::InputFoo, hello("foo"), subs(None))
+            .is_ok());
+        assert!(application
+            .remount(MockComponentId::InputBar, hello("bar"), subs(Some(10)))
+            .is_ok());
+        // priority is kept if not set
+        assert!(application
+            .remount(MockComponentId::InputOmar, hello("omar"), subs(None))
+            .is_ok());
+        assert_eq!(application.dispatch(Event::User(MockEvent::Foo)), expected);
+        let info = application.subscriptions_of(&MockComponentId::InputOmar);
+        assert_eq!(info.len(), 1);
+        assert_eq!(info[0].event, SubEventClause::User(MockEvent::Foo));
+        assert_eq!(info[0].when, SubClause::Always);
+        assert_eq!(info[0].priority, 5);
+        assert_eq!(info[0].active, true);
+        // change priority on remount
+        assert!(application
+            .remount(MockComponentId::InputOmar, hello("omar"), subs(Some(-1)))
+            .is_ok());
+        assert_eq!(
+            application.dispatch(Event::User(MockEvent::Foo)),
+            vec![
+                MockMsg::BarHello(String::from("bar")),
+                MockMsg::BarHello(String::from("foo")),
+                MockMsg::BarHello(String::from("dyn")),
+                MockMsg::BarHello(String::from("omar")),
+            ]
+        );
+        assert!(application
+            .subscriptions_of(&MockComponentId::Dyn(String::from("none")))
+            .is_empty());
+    }
+
+    #[test]
+    fn should_switch_views_preserving_components_and_focus() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert_eq!(application.active_view(), DEFAULT_VIEW);
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
             )
-            .is_err());
-        // Unsubscribe element
+            .is_ok());
         assert!(application
-            .unsubscribe(
-                &MockComponentId::InputFoo,
-                SubEventClause::User(MockEvent::Foo)
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
             )
             .is_ok());
-        // Unsubcribe twice
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('a')))),
+            vec![MockMsg::FooInputChanged(String::from("a"))]
+        );
+        // add views
+        assert!(application.add_view("settings").is_ok());
+        assert!(application.has_view("settings"));
+        assert!(matches!(
+            application.add_view("settings"),
+            Err(ApplicationError::ViewAlreadyExists(_))
+        ));
+        assert!(matches!(
+            application.add_view(DEFAULT_VIEW),
+            Err(ApplicationError::ViewAlreadyExists(_))
+        ));
+        assert!(matches!(
+            application.switch_view("help"),
+            Err(ApplicationError::NoSuchView(_))
+        ));
+        // the new view is empty
+        assert!(application.switch_view("settings").is_ok());
+        assert_eq!(application.active_view(), "settings");
+        assert!(application.mounted_ids().is_empty());
+        assert_eq!(application.focus(), None);
+        // subscriptions of the inactive view are not evaluated
         assert!(application
-            .unsubscribe(
-                &MockComponentId::InputFoo,
-                SubEventClause::User(MockEvent::Foo)
+            .dispatch(Event::Tick(Duration::from_millis(10)))
+            .is_empty());
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
             )
-            .is_err());
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('b')))),
+            vec![MockMsg::FooInputChanged(String::from("b"))]
+        );
+        // switch back
+        assert!(application.switch_view(DEFAULT_VIEW).is_ok());
+        assert_eq!(application.active_view(), DEFAULT_VIEW);
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        assert_eq!(
+            application.state(&MockComponentId::InputFoo).unwrap(),
+            State::One(StateValue::String(String::from("a")))
+        );
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::from_millis(10))),
+            vec![MockMsg::BarTick]
+        );
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('c')))),
+            vec![MockMsg::FooInputChanged(String::from("ac"))]
+        );
+        // and again
+        assert!(application.switch_view("settings").is_ok());
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        assert_eq!(
+            application.state(&MockComponentId::InputFoo).unwrap(),
+            State::One(StateValue::String(String::from("b")))
+        );
+        assert!(!application.mounted(&MockComponentId::InputBar));
+        // switching to the active view does nothing
+        assert!(application.switch_view("settings").is_ok());
+        assert_eq!(application.active_view(), "settings");
     }
 
     #[test]
-    fn should_umount_all() {
+    fn should_broadcast_events_to_mounted_components_in_mount_order() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
             Application::init(listener_config());
+        for name in ["omar", "pippo", "bart"] {
+            let id = MockComponentId::Dyn(name.to_string());
+            assert!(application
+                .mount(id.clone(), Box::new(MockBarInput::default()), vec![])
+                .is_ok());
+            for ch in name.chars() {
+                assert!(application
+                    .forward(&id, Event::Keyboard(KeyEvent::from(Key::Char(ch))))
+                    .is_ok());
+            }
+        }
         assert!(application
             .mount(
                 MockComponentId::InputFoo,
                 Box::new(MockFooInput::default()),
-                vec![
-                    Sub::new(SubEventClause::Tick, SubClause::Always),
-                    Sub::new(
-                        SubEventClause::User(MockEvent::Bar),
-                        SubClause::HasAttrValue(
-                            MockComponentId::InputFoo,
-                            Attribute::Focus,
-                            AttrValue::Flag(true)
-                        )
-                    )
-                ]
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.broadcast(Event::User(MockEvent::Hello(String::from("quit")))),
+            vec![
+                MockMsg::BarHello(String::from("quit")),
+                MockMsg::BarHello(String::from("quit")),
+                MockMsg::BarHello(String::from("quit")),
+            ]
+        );
+        assert_eq!(
+            application.broadcast(Event::Keyboard(KeyEvent::from(Key::Enter))),
+            vec![
+                MockMsg::BarSubmit(String::from("omar")),
+                MockMsg::BarSubmit(String::from("pippo")),
+                MockMsg::BarSubmit(String::from("bart")),
+                MockMsg::FooSubmit(String::new()),
+            ]
+        );
+        assert_eq!(
+            application.broadcast_where(
+                |id| id != &MockComponentId::Dyn(String::from("pippo")),
+                Event::Keyboard(KeyEvent::from(Key::Enter))
+            ),
+            vec![
+                MockMsg::BarSubmit(String::from("omar")),
+                MockMsg::BarSubmit(String::from("bart")),
+                MockMsg::FooSubmit(String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_dump_subscriptions() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![Sub::tick()
+                    .when_mounted(MockComponentId::InputBar)
+                    .and(SubClause::not(SubClause::IsVisible(
+                        MockComponentId::InputOmar
+                    )))
+                    .build()]
             )
             .is_ok());
         assert!(application
             .mount(
                 MockComponentId::InputBar,
-                Box::new(MockFooInput::default()),
-                vec![Sub::new(SubEventClause::Any, SubClause::Always)]
+                Box::new(MockBarInput::default()),
+                vec![Sub::user(MockEvent::Foo).priority(10).build()]
             )
             .is_ok());
-        assert_eq!(application.subs.len(), 3);
-        // Let's umount all
+        assert!(application
+            .pause_subs_of(&MockComponentId::InputBar)
+            .is_ok());
+        assert_eq!(
+            application.dump_subscriptions(),
+            "InputBar: user event Foo when always (priority 10, paused)\n\
+             InputFoo: tick when InputBar is mounted and not (InputOmar is visible) (priority 0)\n"
+        );
         application.umount_all();
-        assert_eq!(application.mounted(&MockComponentId::InputFoo), false);
-        assert_eq!(application.mounted(&MockComponentId::InputBar), false);
-        assert!(application.subs.is_empty());
+        assert_eq!(application.dump_subscriptions(), "");
     }
 
     #[test]
-    fn should_do_tick() {
+    fn should_snapshot_and_restore_states() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
-            Application::init(listener_config_with_tick(Duration::from_secs(60)));
-        // Mount foo and bar
+            Application::init_headless();
+        let inputs = [
+            (MockComponentId::InputFoo, "foo"),
+            (MockComponentId::InputOmar, "omar"),
+        ];
+        for (id, text) in inputs.iter() {
+            assert!(application
+                .mount(id.clone(), Box::new(MockFooInput::default()), vec![])
+                .is_ok());
+            assert!(application.active(id).is_ok());
+            for ch in text.chars() {
+                application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char(ch))));
+            }
+        }
+        let snapshot = application.state_all();
+        assert_eq!(snapshot.len(), 2);
+        // remount from scratch
+        for (id, _) in inputs.iter() {
+            assert!(application.umount(id).is_ok());
+            assert!(application
+                .mount(id.clone(), Box::new(MockFooInput::default()), vec![])
+                .is_ok());
+            assert_eq!(
+                application.state(id).ok().unwrap(),
+                State::One(StateValue::String(String::new()))
+            );
+        }
+        for (id, state) in snapshot.into_iter() {
+            assert!(application.restore_state(&id, state).is_ok());
+        }
+        for (id, text) in inputs.iter() {
+            assert_eq!(
+                application.state(id).ok().unwrap(),
+                State::One(StateValue::String(text.to_string()))
+            );
+        }
+        // the restored input keeps working
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.dispatch(Event::Keyboard(KeyEvent::from(Key::Char('!')))),
+            vec![MockMsg::FooInputChanged(String::from("foo!"))]
+        );
+        assert!(application
+            .restore_state(&MockComponentId::InputBar, State::None)
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn should_serialize_states_snapshot() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
         assert!(application
             .mount(
                 MockComponentId::InputFoo,
@@ -643,70 +4408,138 @@ mod test {
             )
             .is_ok());
         assert!(application
-            .mount(
-                MockComponentId::InputBar,
-                Box::new(MockBarInput::default()),
+            .restore_state(
+                &MockComponentId::InputFoo,
+                State::One(StateValue::String(String::from("hello")))
+            )
+            .is_ok());
+        let snapshot: Vec<State> = application.state_all().into_values().collect();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let states: Vec<State> = serde_json::from_str(&json).unwrap();
+        assert_eq!(states, snapshot);
+        // nested states
+        let state = State::Nested(HashMap::from([
+            (
+                String::from("cursor"),
+                State::One(StateValue::Position { x: 4, y: 2 }),
+            ),
+            (
+                String::from("list"),
+                State::Linked(std::collections::LinkedList::from([
+                    State::Vec(vec![StateValue::Usize(1), StateValue::Bool(true)]),
+                    State::Tup2((
+                        StateValue::Duration(Duration::from_millis(1500)),
+                        StateValue::Color(crate::props::Color::Indexed(42)),
+                    )),
+                    State::None,
+                ])),
+            ),
+        ]));
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<State>(&json).unwrap(), state);
+    }
+
+    #[test]
+    fn should_forward_lifecycle_events_to_subs() {
+        // a component which replies to lifecycle events with their description
+        fn watcher() -> WrappedComponent<MockMsg, MockEvent> {
+            Box::new(MapUserEvent::new(
+                MockBarInput::default(),
+                |ev: Event<MockEvent>| match ev {
+                    Event::Lifecycle(ev) => Some(Event::User(MockEvent::Hello(format!(
+                        "{:?}",
+                        ev.event::<MockComponentId>().unwrap()
+                    )))),
+                    _ => None,
+                },
+            ))
+        }
+        fn hello(ev: LifecycleEvent<MockComponentId>) -> MockMsg {
+            MockMsg::BarHello(format!("{:?}", ev))
+        }
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        // disabled by default
+        assert!(application
+            .mount(
+                MockComponentId::InputOmar,
+                watcher(),
+                vec![Sub::lifecycle(LifecycleClause::any()).build()]
+            )
+            .is_ok());
+        assert!(application
+            .mount(MockComponentId::InputBar, watcher(), vec![])
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        assert_eq!(application.dispatch(Event::Tick(Duration::ZERO)), vec![]);
+        application.enable_lifecycle_events(true);
+        // mount
+        assert!(application
+            .mount(MockComponentId::InputFoo, watcher(), vec![])
+            .is_ok());
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![hello(LifecycleEvent::Mounted(MockComponentId::InputFoo))]
+        );
+        // focus
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert!(application.blur().is_ok());
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![
+                hello(LifecycleEvent::FocusLost(MockComponentId::InputBar)),
+                hello(LifecycleEvent::FocusGained(MockComponentId::InputFoo)),
+                hello(LifecycleEvent::FocusLost(MockComponentId::InputFoo)),
+                hello(LifecycleEvent::FocusGained(MockComponentId::InputBar)),
+            ]
+        );
+        // the subscriber gets the events even while it is active
+        assert!(application.active(&MockComponentId::InputOmar).is_ok());
+        assert!(application.umount(&MockComponentId::InputBar).is_ok());
+        assert!(application.umount(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![
+                hello(LifecycleEvent::FocusLost(MockComponentId::InputBar)),
+                hello(LifecycleEvent::FocusGained(MockComponentId::InputOmar)),
+                hello(LifecycleEvent::Umounted(MockComponentId::InputBar)),
+                hello(LifecycleEvent::Umounted(MockComponentId::InputFoo)),
+            ]
+        );
+        // remount raises nothing
+        assert!(application
+            .remount(
+                MockComponentId::InputOmar,
+                watcher(),
                 vec![
-                    Sub::new(SubEventClause::Tick, SubClause::Always),
-                    Sub::new(
-                        // NOTE: won't be thrown, since requires focus
-                        SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
-                        SubClause::HasAttrValue(
-                            MockComponentId::InputBar,
-                            Attribute::Focus,
-                            AttrValue::Flag(true)
-                        )
-                    )
+                    Sub::lifecycle(LifecycleClause::focus_gained_of(MockComponentId::InputBar))
+                        .build()
                 ]
             )
             .is_ok());
-        // Active FOO
-        assert!(application.active(&MockComponentId::InputFoo).is_ok());
-        /*
-         * Here we should:
-         *
-         * - receive an Enter from MockPoll, sent to FOO and will return a `FooSubmit`
-         * - receive a Tick from MockPoll, sent to FOO, but won't return a msg
-         * - the Tick will be sent also to BAR since is subscribed and will return a `BarTick`
-         */
-        assert_eq!(
-            application
-                .tick(PollStrategy::UpTo(5))
-                .ok()
-                .unwrap()
-                .as_slice(),
-            &[MockMsg::FooSubmit(String::from("")), MockMsg::BarTick]
-        );
-        // Active BAR
+        assert_eq!(application.dispatch(Event::Tick(Duration::ZERO)), vec![]);
+        // filtered by clause
+        assert!(application
+            .mount(MockComponentId::InputBar, watcher(), vec![])
+            .is_ok());
         assert!(application.active(&MockComponentId::InputBar).is_ok());
-        // Wait 200ms (wait for poll)
-        std::thread::sleep(Duration::from_millis(100));
-        /*
-         * Here we should:
-         *
-         * - receive an Enter from MockPoll, sent to BAR and will return a `BarSubmit`
-         */
         assert_eq!(
-            application
-                .tick(PollStrategy::Once)
-                .ok()
-                .unwrap()
-                .as_slice(),
-            &[MockMsg::BarSubmit(String::from(""))]
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![hello(LifecycleEvent::FocusGained(
+                MockComponentId::InputBar
+            ))]
         );
-        // Let's try TryFor strategy
-        let events = application
-            .tick(PollStrategy::TryFor(Duration::from_millis(300)))
-            .ok()
-            .unwrap();
-        assert!(events.len() >= 2);
+        // disable discards pending events
+        assert!(application.active(&MockComponentId::InputOmar).is_ok());
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        application.enable_lifecycle_events(false);
+        assert_eq!(application.dispatch(Event::Tick(Duration::ZERO)), vec![]);
     }
 
     #[test]
-    fn should_not_propagate_event_when_subs_are_locked() {
+    fn should_not_propagate_events_to_paused_subs() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
-            Application::init(listener_config_with_tick(Duration::from_secs(60)));
-        // Mount foo and bar
+            Application::init_headless();
         assert!(application
             .mount(
                 MockComponentId::InputFoo,
@@ -718,36 +4551,40 @@ mod test {
             .mount(
                 MockComponentId::InputBar,
                 Box::new(MockBarInput::default()),
-                vec![
-                    Sub::new(SubEventClause::Tick, SubClause::Always),
-                    Sub::new(
-                        // NOTE: won't be thrown, since requires focus
-                        SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
-                        SubClause::HasAttrValue(
-                            MockComponentId::InputBar,
-                            Attribute::Focus,
-                            AttrValue::Flag(true)
-                        )
-                    )
-                ]
+                vec![Sub::tick().when_visible(MockComponentId::InputBar).build()]
             )
             .is_ok());
-        // Active FOO
         assert!(application.active(&MockComponentId::InputFoo).is_ok());
-        // lock subs
-        application.lock_subs();
-        assert_eq!(application.sub_lock, true);
-        assert_eq!(
-            application
-                .tick(PollStrategy::UpTo(5))
-                .ok()
-                .unwrap()
-                .as_slice(),
-            &[MockMsg::FooSubmit(String::from(""))]
-        );
-        // unlock subs
-        application.unlock_subs();
-        assert_eq!(application.sub_lock, false);
+        let tick = Event::Tick(Duration::ZERO);
+        assert_eq!(application.dispatch(tick.clone()), vec![MockMsg::BarTick]);
+        // pause
+        assert!(application
+            .pause_subs_of(&MockComponentId::InputBar)
+            .is_ok());
+        assert!(application.dispatch(tick.clone()).is_empty());
+        // subscriptions are kept while paused
+        assert!(application.subscribed(&MockComponentId::InputBar, &SubEventClause::Tick));
+        // resume
+        assert!(application
+            .resume_subs_of(&MockComponentId::InputBar)
+            .is_ok());
+        assert_eq!(application.dispatch(tick.clone()), vec![MockMsg::BarTick]);
+        // hide
+        assert!(application
+            .attr(
+                &MockComponentId::InputBar,
+                Attribute::Display,
+                AttrValue::Flag(false)
+            )
+            .is_ok());
+        assert!(application.dispatch(tick).is_empty());
+        // not mounted
+        assert!(application
+            .pause_subs_of(&MockComponentId::InputOmar)
+            .is_err());
+        assert!(application
+            .resume_subs_of(&MockComponentId::InputOmar)
+            .is_err());
     }
 
     #[test]
@@ -967,6 +4804,100 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_count_labeled_messages_when_metrics_are_enabled() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config_with_tick(Duration::from_secs(60)));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        application.set_msg_labeler(Box::new(|msg| match msg {
+            MockMsg::FooSubmit(_) => Some("foo-submit"),
+            MockMsg::BarTick => Some("bar-tick"),
+            _ => None,
+        }));
+        // Metrics are disabled
+        assert!(application.tick(PollStrategy::UpTo(5)).is_ok());
+        assert!(application.metrics().is_none());
+        // Enable metrics
+        application.enable_metrics();
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(
+            application
+                .tick(PollStrategy::Once)
+                .ok()
+                .unwrap()
+                .as_slice(),
+            &[MockMsg::FooSubmit(String::from(""))]
+        );
+        let metrics = application.metrics().unwrap();
+        assert_eq!(metrics.label_count("foo-submit"), 1);
+        assert_eq!(metrics.label_count("bar-tick"), 0);
+        // Reset
+        application.reset_metrics();
+        assert_eq!(application.metrics().unwrap().label_count("foo-submit"), 0);
+        application.disable_metrics();
+        assert!(application.metrics().is_none());
+    }
+
+    #[test]
+    fn should_collect_tick_metrics() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> = Application::init(
+            listener_config_with_tick(Duration::from_millis(100)).with_event_metrics(),
+        );
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        application.enable_metrics();
+        let mut messages = 0;
+        for _ in 0..5 {
+            messages += application
+                .tick(PollStrategy::TryFor(Duration::from_millis(100)))
+                .unwrap()
+                .len() as u64;
+        }
+        let metrics = application.metrics().unwrap();
+        // MockPoll emits a keyboard event every 100ms, which is forwarded to foo
+        assert!(metrics.events_received("Keyboard") >= 2);
+        assert!(metrics.events_received("Tick") >= 2);
+        assert_eq!(metrics.events_received("Mouse"), 0);
+        assert_eq!(metrics.msgs_produced(), messages);
+        assert!(messages >= 4);
+        // bar is subscribed only to ticks
+        assert!(metrics.subs_evaluated() >= 4);
+        assert!(metrics.subs_matched() >= 2);
+        assert!(metrics.subs_matched() < metrics.subs_evaluated());
+        assert!(metrics.last_tick_duration() >= Duration::from_millis(100));
+        assert!(metrics.max_tick_duration() >= metrics.last_tick_duration());
+        // Reset
+        application.reset_metrics();
+        assert_eq!(application.metrics().unwrap(), &TickMetrics::default());
+    }
+
     #[test]
     fn should_lock_ports() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
@@ -1000,7 +4931,12 @@ mod test {
             &[MockMsg::FooSubmit(String::from("")), MockMsg::BarTick]
         );
         // Lock ports
+        assert_eq!(application.ports_locked(), false);
         assert!(application.lock_ports().is_ok());
+        assert_eq!(application.ports_locked(), true);
+        // locking twice keeps the ports locked
+        assert!(application.lock_ports().is_ok());
+        assert_eq!(application.ports_locked(), true);
         // Wait 1 sec
         std::thread::sleep(Duration::from_millis(1000));
         // Tick ( No tick event )
@@ -1014,6 +4950,7 @@ mod test {
         );
         // Unlock ports
         assert!(application.unlock_ports().is_ok());
+        assert_eq!(application.ports_locked(), false);
         // Wait 100 ms
         std::thread::sleep(Duration::from_millis(50));
         // Tick
@@ -1031,7 +4968,294 @@ mod test {
     fn application_should_add_injectors() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
             Application::init(listener_config_with_tick(Duration::from_millis(500)));
-        application.add_injector(Box::new(MockInjector::default()));
+        application.add_injector(Box::new(MockInjector));
+    }
+
+    /// Mount foo and bar, both subscribed to enter, and omar, which is a bar input, with foo active
+    fn modal_application() -> Application<MockComponentId, MockMsg, MockEvent> {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        let enter = || {
+            vec![Sub::new(
+                SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+                SubClause::Always,
+            )]
+        };
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                enter()
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                enter()
+            )
+            .is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        application
+    }
+
+    #[test]
+    fn should_deliver_events_only_to_modal() {
+        let mut application = modal_application();
+        let enter = Event::Keyboard(KeyEvent::from(Key::Enter));
+        let handle = application.open_modal(&MockComponentId::InputOmar).unwrap();
+        assert_eq!(handle.id(), &MockComponentId::InputOmar);
+        assert_eq!(handle.depth(), 1);
+        assert_eq!(application.modal(), Some(&MockComponentId::InputOmar));
+        assert_eq!(application.focus(), Some(&MockComponentId::InputOmar));
+        // foo and bar subscriptions are suppressed
+        assert_eq!(
+            application.dispatch(enter.clone()),
+            vec![MockMsg::BarSubmit(String::default())]
+        );
+        // the modal is the active component, so tick is delivered once
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![MockMsg::BarTick]
+        );
+        // close
+        assert!(application.close_modal().is_ok());
+        assert_eq!(application.modal(), None);
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        assert_eq!(
+            application.dispatch(enter),
+            vec![
+                MockMsg::FooSubmit(String::default()),
+                MockMsg::BarSubmit(String::default())
+            ]
+        );
+        assert_eq!(
+            application.dispatch(Event::Tick(Duration::ZERO)),
+            vec![MockMsg::BarTick]
+        );
+        assert!(matches!(
+            application.close_modal().err().unwrap(),
+            ApplicationError::NoModal
+        ));
+    }
+
+    #[test]
+    fn should_restore_nested_modals_in_order() {
+        let mut application = modal_application();
+        let enter = Event::Keyboard(KeyEvent::from(Key::Enter));
+        assert!(application.open_modal(&MockComponentId::InputBar).is_ok());
+        assert_eq!(
+            application
+                .open_modal(&MockComponentId::InputOmar)
+                .unwrap()
+                .depth(),
+            2
+        );
+        // only the last modal receives the subscribed events
+        assert_eq!(
+            application.dispatch(enter.clone()),
+            vec![MockMsg::BarSubmit(String::default())]
+        );
+        assert!(application.close_modal().is_ok());
+        assert_eq!(application.modal(), Some(&MockComponentId::InputBar));
+        assert_eq!(application.focus(), Some(&MockComponentId::InputBar));
+        // foo's subscription is still suppressed
+        assert_eq!(
+            application.dispatch(enter),
+            vec![MockMsg::BarSubmit(String::default())]
+        );
+        assert!(application.close_modal().is_ok());
+        assert_eq!(application.modal(), None);
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        assert!(application.focus_stack().is_empty());
+    }
+
+    #[test]
+    fn should_close_modal_on_umount() {
+        let mut application = modal_application();
+        let enter = Event::Keyboard(KeyEvent::from(Key::Enter));
+        assert!(application.open_modal(&MockComponentId::InputOmar).is_ok());
+        assert!(application.umount(&MockComponentId::InputOmar).is_ok());
+        assert_eq!(application.modal(), None);
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        // subscriptions are delivered again
+        assert_eq!(
+            application.dispatch(enter.clone()),
+            vec![
+                MockMsg::FooSubmit(String::default()),
+                MockMsg::BarSubmit(String::default())
+            ]
+        );
+        // nested modals: the modal on top gives the focus back to the predecessor of the unmounted one
+        assert!(application.open_modal(&MockComponentId::InputBar).is_ok());
+        assert!(application
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockBarInput::default()),
+                Vec::new()
+            )
+            .is_ok());
+        assert!(application.open_modal(&MockComponentId::InputOmar).is_ok());
+        assert!(application.umount(&MockComponentId::InputBar).is_ok());
+        assert_eq!(application.modal(), Some(&MockComponentId::InputOmar));
+        assert!(application.close_modal().is_ok());
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        assert_eq!(
+            application.dispatch(enter),
+            vec![MockMsg::FooSubmit(String::default())]
+        );
+    }
+
+    #[test]
+    fn should_keep_modal_open_on_swap() {
+        let mut application = modal_application();
+        let enter = Event::Keyboard(KeyEvent::from(Key::Enter));
+        assert!(application.open_modal(&MockComponentId::InputBar).is_ok());
+        assert!(application
+            .swap(
+                &MockComponentId::InputBar,
+                MockComponentId::Dyn(String::from("dialog")),
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(
+                    SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+                    SubClause::Always,
+                )]
+            )
+            .is_ok());
+        let dialog = MockComponentId::Dyn(String::from("dialog"));
+        assert_eq!(application.modal(), Some(&dialog));
+        assert_eq!(application.focus(), Some(&dialog));
+        assert_eq!(
+            application.dispatch(enter),
+            vec![MockMsg::BarSubmit(String::default())]
+        );
+        assert!(application.close_modal().is_ok());
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    fn should_not_open_modal_if_not_mounted() {
+        let mut application = modal_application();
+        assert!(application
+            .open_modal(&MockComponentId::Dyn(String::from("modal")))
+            .is_err());
+        assert_eq!(application.modal(), None);
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn should_trace_tick() {
+        use std::sync::Mutex;
+
+        /// Collects the formatted tracing events
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_writer(move || writer.clone())
+            .with_target(false)
+            .without_time()
+            .finish();
+        // NOTE: the subscriber is set for this thread only, so the worker events are not collected
+        tracing::subscriber::with_default(subscriber, || {
+            let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+                Application::init(listener_config());
+            assert!(application
+                .mount(
+                    MockComponentId::InputFoo,
+                    Box::new(MockFooInput::default()),
+                    vec![]
+                )
+                .is_ok());
+            assert!(application
+                .mount(
+                    MockComponentId::InputBar,
+                    Box::new(MockBarInput::default()),
+                    vec![Sub::new(
+                        SubEventClause::Keyboard(KeyEvent::from(Key::Enter)),
+                        SubClause::Always
+                    )]
+                )
+                .is_ok());
+            assert!(application.active(&MockComponentId::InputFoo).is_ok());
+            assert_eq!(
+                application.tick(PollStrategy::UpTo(1)).unwrap(),
+                vec![
+                    MockMsg::FooSubmit(String::default()),
+                    MockMsg::BarSubmit(String::default())
+                ]
+            );
+        });
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            output
+                .lines()
+                .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>(),
+            vec![
+                "DEBUG component mounted component=InputFoo",
+                "DEBUG component mounted component=InputBar",
+                "DEBUG component activated component=InputFoo",
+                "TRACE event received event=\"Keyboard\"",
+                "TRACE event forwarded to active component component=InputFoo event=\"Keyboard\"",
+                "DEBUG message produced component=InputFoo",
+                "TRACE subscription evaluated component=InputBar event=\"Keyboard\" forwarded=true",
+                "DEBUG message produced component=InputBar",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_listener_configuration() {
+        let application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert_eq!(application.port_count(), 1);
+        assert_eq!(application.has_tick(), false);
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> = Application::init(
+            listener_config_with_tick(Duration::from_millis(500)).add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(100),
+                1,
+            ),
+        );
+        assert_eq!(application.port_count(), 2);
+        assert_eq!(application.has_tick(), true);
+        // restarting the listener updates the configuration and unlocks the ports
+        assert!(application.lock_ports().is_ok());
+        assert!(application
+            .restart_listener(EventListenerCfg::default().tick_interval(Duration::from_secs(1)))
+            .is_ok());
+        assert_eq!(application.port_count(), 0);
+        assert_eq!(application.has_tick(), true);
+        assert_eq!(application.ports_locked(), false);
+        // headless
+        let application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert_eq!(application.port_count(), 0);
+        assert_eq!(application.has_tick(), false);
+        assert_eq!(application.ports_locked(), false);
     }
 
     fn listener_config() -> EventListenerCfg<MockEvent> {
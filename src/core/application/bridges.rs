@@ -0,0 +1,93 @@
+//! ## Bridges
+//!
+//! This module exposes the event bridges, which produce new events from the events received by the application
+
+use std::hash::Hash;
+
+use crate::{AttrValue, Attribute, Event, State, SubClause, SubEventClause};
+
+/// A function which produces the bridged event from the event which matched the bridge
+type BridgeFn<U> = Box<dyn Fn(&Event<U>) -> Event<U>>;
+
+/// A rule which produces a new event each time an event satisfying `ev` is received while `when` is satisfied
+struct EventBridge<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    ev: SubEventClause<U>,
+    when: SubClause<K>,
+    produce: BridgeFn<U>,
+}
+
+/// The event bridges of the application
+pub(crate) struct EventBridges<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    bridges: Vec<EventBridge<K, U>>,
+}
+
+impl<K, U> Default for EventBridges<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    fn default() -> Self {
+        Self {
+            bridges: Vec::new(),
+        }
+    }
+}
+
+impl<K, U> EventBridges<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Add a bridge which produces an event with `produce` from the events satisfying `ev` and `when`
+    pub fn add(&mut self, ev: SubEventClause<U>, when: SubClause<K>, produce: BridgeFn<U>) {
+        self.bridges.push(EventBridge { ev, when, produce });
+    }
+
+    /// Remove all the bridges
+    pub fn clear(&mut self) {
+        self.bridges.clear();
+    }
+
+    /// Returns whether there's no bridge
+    pub fn is_empty(&self) -> bool {
+        self.bridges.is_empty()
+    }
+
+    /// Append to `events` the events produced by the bridges, in the order of the events which produced them
+    /// and then in the order the bridges have been added.
+    ///
+    /// The bridged events are not matched against the bridges again, so bridges can't loop.
+    pub fn bridge<HasAttrFn, GetStateFn, MountedFn>(
+        &self,
+        events: &mut Vec<Event<U>>,
+        has_attr_fn: HasAttrFn,
+        get_state_fn: GetStateFn,
+        mounted_fn: MountedFn,
+    ) where
+        HasAttrFn: Fn(&K, Attribute) -> Option<AttrValue>,
+        GetStateFn: Fn(&K) -> Option<State>,
+        MountedFn: Fn(&K) -> bool,
+    {
+        let mut bridged = Vec::new();
+        for ev in events.iter() {
+            for bridge in self.bridges.iter() {
+                if bridge.ev.forward(ev)
+                    && bridge
+                        .when
+                        .forward(&has_attr_fn, &get_state_fn, &mounted_fn)
+                {
+                    bridged.push((bridge.produce)(ev));
+                }
+            }
+        }
+        events.extend(bridged);
+    }
+}
@@ -0,0 +1,250 @@
+//! ## Builder
+//!
+//! This module exposes the builder of the [`Application`], which requires an active component to be chosen
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use thiserror::Error;
+
+use super::{Application, ApplicationError};
+use crate::{Component, EventListenerCfg, Injector, Sub};
+
+/// State of an [`ApplicationBuilder`] which has no active component yet; the application can't be built
+pub struct NoActive;
+
+/// State of an [`ApplicationBuilder`] which has an active component; the application can be built
+pub struct HasActive;
+
+/// A component to mount on build
+type Mount<K, Msg, UserEvent> = (
+    K,
+    Box<dyn Component<Msg, UserEvent>>,
+    Vec<Sub<K, UserEvent>>,
+);
+
+/// Builds an [`Application`], mounting its components and giving focus to the active one.
+///
+/// All the errors are collected and reported at once by [`ApplicationBuilder::build`]:
+///
+/// ```rust
+/// use tuirealm::{Application, EventListenerCfg, NoUserEvent};
+///
+/// let app = Application::<u8, (), NoUserEvent>::builder()
+///     .listener(EventListenerCfg::default())
+///     // .mount(0, Box::new(Label::default()), vec![])
+///     .active(0)
+///     .build();
+/// // the active component is not mounted
+/// assert!(app.is_err());
+/// ```
+///
+/// [`ApplicationBuilder::build`] is available only once the active component has been set with
+/// [`ApplicationBuilder::active`], so an application where no component receives the keyboard events
+/// can't be built:
+///
+/// ```compile_fail
+/// use tuirealm::{ApplicationBuilder, EventListenerCfg, NoUserEvent};
+///
+/// let app = ApplicationBuilder::<u8, (), NoUserEvent>::default()
+///     .listener(EventListenerCfg::default())
+///     .build();
+/// ```
+pub struct ApplicationBuilder<K, Msg, UserEvent, S = NoActive>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    listener: Option<EventListenerCfg<UserEvent>>,
+    injectors: Vec<Box<dyn Injector<K>>>,
+    mounts: Vec<Mount<K, Msg, UserEvent>>,
+    active: Option<K>,
+    state: PhantomData<S>,
+}
+
+impl<K, Msg, UserEvent> Default for ApplicationBuilder<K, Msg, UserEvent, NoActive>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn default() -> Self {
+        Self {
+            listener: None,
+            injectors: Vec::new(),
+            mounts: Vec::new(),
+            active: None,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<K, Msg, UserEvent, S> ApplicationBuilder<K, Msg, UserEvent, S>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    /// Set the configuration of the event listener.
+    /// If not set, the application is built headless (see [`Application::init_headless`])
+    pub fn listener(mut self, listener_cfg: EventListenerCfg<UserEvent>) -> Self {
+        self.listener = Some(listener_cfg);
+        self
+    }
+
+    /// Add an injector to the view; injectors are added before mounting the components
+    pub fn injector(mut self, injector: Box<dyn Injector<K>>) -> Self {
+        self.injectors.push(injector);
+        self
+    }
+
+    /// Mount the component `id` with the provided subscriptions. Components are mounted in order
+    pub fn mount(
+        mut self,
+        id: K,
+        component: Box<dyn Component<Msg, UserEvent>>,
+        subs: Vec<Sub<K, UserEvent>>,
+    ) -> Self {
+        self.mounts.push((id, component, subs));
+        self
+    }
+
+    /// Set the component which is active once the application is built
+    pub fn active(self, id: K) -> ApplicationBuilder<K, Msg, UserEvent, HasActive> {
+        ApplicationBuilder {
+            listener: self.listener,
+            injectors: self.injectors,
+            mounts: self.mounts,
+            active: Some(id),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<K, Msg, UserEvent> ApplicationBuilder<K, Msg, UserEvent, HasActive>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    /// Build the [`Application`]: start the event listener, mount the components and activate the active one.
+    ///
+    /// Returns error if any component can't be mounted (e.g. the same id is mounted twice) or if the active
+    /// component is not mounted; all the errors are reported, not just the first one.
+    pub fn build(self) -> Result<Application<K, Msg, UserEvent>, ApplicationBuildError> {
+        let mut app = match self.listener {
+            Some(listener_cfg) => Application::init(listener_cfg),
+            None => Application::init_headless(),
+        };
+        for injector in self.injectors {
+            app.add_injector(injector);
+        }
+        let mut errors = Vec::new();
+        for (id, component, subs) in self.mounts {
+            if let Err(err) = app.mount(id, component, subs) {
+                errors.push(err);
+            }
+        }
+        if let Some(Err(err)) = self.active.map(|id| app.active(&id)) {
+            errors.push(err);
+        }
+        if errors.is_empty() {
+            Ok(app)
+        } else {
+            Err(ApplicationBuildError { errors })
+        }
+    }
+}
+
+/// Error returned by [`ApplicationBuilder::build`], which reports all the errors occurred while building the application
+#[derive(Debug, Error)]
+#[error("cannot build application: {}", .errors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+pub struct ApplicationBuildError {
+    errors: Vec<ApplicationError>,
+}
+
+impl ApplicationBuildError {
+    /// Returns the errors occurred while building the application, in order
+    pub fn errors(&self) -> &[ApplicationError] {
+        &self.errors
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::{MockBarInput, MockComponentId, MockEvent, MockFooInput, MockMsg};
+    use crate::{SubClause, SubEventClause, ViewError};
+
+    type Builder<S> = ApplicationBuilder<MockComponentId, MockMsg, MockEvent, S>;
+
+    #[test]
+    fn should_build_application() {
+        let app = Builder::default()
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![],
+            )
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![Sub::new(SubEventClause::Tick, SubClause::Always)],
+            )
+            .active(MockComponentId::InputBar)
+            .build()
+            .unwrap();
+        assert!(app.mounted(&MockComponentId::InputFoo));
+        assert_eq!(app.focus(), Some(&MockComponentId::InputBar));
+        assert_eq!(app.subscriptions_of(&MockComponentId::InputBar).len(), 1);
+    }
+
+    #[test]
+    fn should_fail_if_active_component_is_not_mounted() {
+        let err = Builder::default()
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![],
+            )
+            .active(MockComponentId::InputBar)
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err.errors(),
+            [ApplicationError::View(ViewError::ComponentNotFound { .. })]
+        ));
+        assert_eq!(
+            err.to_string(),
+            "cannot build application: view error: component not found: InputBar"
+        );
+    }
+
+    #[test]
+    fn should_report_all_errors() {
+        let err = Builder::default()
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![],
+            )
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![],
+            )
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockBarInput::default()),
+                vec![],
+            )
+            .active(MockComponentId::InputOmar)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err.errors().len(), 3);
+        assert!(err.to_string().starts_with("cannot build application: "));
+    }
+}
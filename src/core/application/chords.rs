@@ -0,0 +1,377 @@
+//! ## Chords
+//!
+//! This module exposes the recognizer of the key chords, which replaces sequences of keyboard events with user events
+
+use std::time::{Duration, Instant};
+
+use crate::event::KeyEvent;
+use crate::Event;
+
+/// A sequence of keys which raises `event`
+struct Chord<U> {
+    keys: Vec<KeyEvent>,
+    timeout: Duration,
+    event: U,
+}
+
+/// How a sequence of keys matches the chords
+enum Lookup<U> {
+    /// The sequence is the beginning of at least one chord; wait up to the timeout for the next key
+    Prefix(Duration),
+    /// The sequence is a chord and it's not the beginning of any other chord
+    Complete(U),
+    /// The sequence doesn't match any chord
+    None,
+}
+
+/// Recognizes the key chords among the keyboard events.
+///
+/// The keyboard events which may still become a chord are held, until the chord is completed, broken
+/// or it times out; in the last two cases, they're released unchanged.
+/// The other events received meanwhile are held behind them, so that the order of the events is preserved.
+pub(crate) struct ChordRecognizer<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    chords: Vec<Chord<U>>,
+    /// Keys held, since they're the beginning of a chord
+    pending: Vec<KeyEvent>,
+    /// Events held behind the pending keys, with the amount of pending keys received before them
+    held: Vec<(usize, Event<U>)>,
+    /// The held keys are released if no key is received before this instant
+    deadline: Option<Instant>,
+}
+
+impl<U> Default for ChordRecognizer<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    fn default() -> Self {
+        Self {
+            chords: Vec::new(),
+            pending: Vec::new(),
+            held: Vec::new(),
+            deadline: None,
+        }
+    }
+}
+
+impl<U> ChordRecognizer<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Bind `keys` to `event`, replacing the previous binding of the same keys.
+    /// Empty chords are ignored
+    pub fn bind(&mut self, keys: Vec<KeyEvent>, timeout: Duration, event: U) {
+        if keys.is_empty() {
+            return;
+        }
+        self.unbind(&keys);
+        self.chords.push(Chord {
+            keys,
+            timeout,
+            event,
+        });
+    }
+
+    /// Remove the chord bound to `keys`. Returns whether the chord was bound
+    pub fn unbind(&mut self, keys: &[KeyEvent]) -> bool {
+        let len = self.chords.len();
+        self.chords.retain(|chord| chord.keys != keys);
+        self.chords.len() != len
+    }
+
+    /// Returns whether there's no chord bound
+    pub fn is_empty(&self) -> bool {
+        self.chords.is_empty()
+    }
+
+    /// Feed the recognizer with `events` received at `now` and return the events to forward.
+    ///
+    /// The events which aren't keyboard events are returned as they are, after the keys held before them.
+    pub fn feed(&mut self, events: Vec<Event<U>>, now: Instant) -> Vec<Event<U>> {
+        let mut out = Vec::with_capacity(events.len());
+        if self.deadline.is_some_and(|deadline| deadline <= now) {
+            self.expire(&mut out);
+        }
+        for ev in events {
+            match ev {
+                Event::Keyboard(key) => self.feed_key(key, now, &mut out),
+                ev if self.pending.is_empty() => out.push(ev),
+                ev => self.held.push((self.pending.len(), ev)),
+            }
+        }
+        out
+    }
+
+    /// Process the keyboard event `key`
+    fn feed_key(&mut self, key: KeyEvent, now: Instant, out: &mut Vec<Event<U>>) {
+        self.pending.push(key);
+        while !self.pending.is_empty() {
+            match self.lookup(&self.pending) {
+                Lookup::Prefix(timeout) => {
+                    self.deadline = Some(now + timeout);
+                    return;
+                }
+                Lookup::Complete(event) => self.resolve(self.pending.len(), Some(event), out),
+                Lookup::None => {
+                    // the chord is broken: complete or release the head of the sequence, then retry with the rest
+                    let head = self.pending.len() - 1;
+                    match self.complete(&self.pending[..head]) {
+                        Some(event) => self.resolve(head, Some(event), out),
+                        None => self.resolve(1, None, out),
+                    }
+                }
+            }
+        }
+        self.deadline = None;
+    }
+
+    /// The held keys timed out: complete the chord, if they're a chord, or release them
+    fn expire(&mut self, out: &mut Vec<Event<U>>) {
+        self.deadline = None;
+        let chord = self.complete(&self.pending);
+        self.resolve(self.pending.len(), chord, out);
+    }
+
+    /// Take the first `n` held keys, replacing them with the event of `chord` if any, or releasing them otherwise.
+    /// The events held behind these keys are returned in the order they've been received
+    fn resolve(&mut self, n: usize, chord: Option<U>, out: &mut Vec<Event<U>>) {
+        let keys: Vec<KeyEvent> = self.pending.drain(..n).collect();
+        match chord {
+            Some(event) => {
+                // the chord is raised by its last key
+                self.release_held(n - 1, out);
+                out.push(Event::User(event));
+            }
+            None => {
+                for (i, key) in keys.into_iter().enumerate() {
+                    self.release_held(i, out);
+                    out.push(Event::Keyboard(key));
+                }
+            }
+        }
+        self.release_held(n, out);
+        self.held.iter_mut().for_each(|(pos, _)| *pos -= n);
+    }
+
+    /// Release the held events received after at most `keys` pending keys
+    fn release_held(&mut self, keys: usize, out: &mut Vec<Event<U>>) {
+        let count = self.held.partition_point(|(pos, _)| *pos <= keys);
+        out.extend(self.held.drain(..count).map(|(_, ev)| ev));
+    }
+
+    /// Look up `keys` among the chords
+    fn lookup(&self, keys: &[KeyEvent]) -> Lookup<U> {
+        let timeout = self
+            .chords
+            .iter()
+            .filter(|chord| chord.keys.len() > keys.len() && chord.keys.starts_with(keys))
+            .map(|chord| chord.timeout)
+            .max();
+        match (timeout, self.complete(keys)) {
+            (Some(timeout), _) => Lookup::Prefix(timeout),
+            (None, Some(event)) => Lookup::Complete(event),
+            (None, None) => Lookup::None,
+        }
+    }
+
+    /// Returns the event of the chord bound to `keys`, if any
+    fn complete(&self, keys: &[KeyEvent]) -> Option<U> {
+        self.chords
+            .iter()
+            .find(|chord| chord.keys == keys)
+            .map(|chord| chord.event.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::event::{Key, KeyModifiers};
+    use crate::mock::MockEvent;
+
+    fn key(ch: char) -> Event<MockEvent> {
+        Event::Keyboard(KeyEvent::plain(Key::Char(ch)))
+    }
+
+    fn ctrl(ch: char) -> KeyEvent {
+        KeyEvent::new(Key::Char(ch), KeyModifiers::CONTROL)
+    }
+
+    fn recognizer() -> ChordRecognizer<MockEvent> {
+        let mut recognizer = ChordRecognizer::default();
+        recognizer.bind(
+            vec![
+                KeyEvent::plain(Key::Char('g')),
+                KeyEvent::plain(Key::Char('g')),
+            ],
+            Duration::from_secs(1),
+            MockEvent::Foo,
+        );
+        recognizer.bind(
+            vec![ctrl('x'), ctrl('s')],
+            Duration::from_secs(1),
+            MockEvent::Bar,
+        );
+        recognizer
+    }
+
+    #[test]
+    fn should_complete_chords() {
+        let mut recognizer = recognizer();
+        let now = Instant::now();
+        assert_eq!(recognizer.feed(vec![key('g')], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![key('g')], now + Duration::from_millis(500)),
+            vec![Event::User(MockEvent::Foo)]
+        );
+        // within the same batch, with other events in between
+        assert_eq!(
+            recognizer.feed(
+                vec![
+                    Event::Keyboard(ctrl('x')),
+                    Event::Tick(Duration::ZERO),
+                    Event::Keyboard(ctrl('s'))
+                ],
+                now
+            ),
+            vec![Event::Tick(Duration::ZERO), Event::User(MockEvent::Bar)]
+        );
+    }
+
+    #[test]
+    fn should_release_broken_chords_in_order() {
+        let mut recognizer = recognizer();
+        let now = Instant::now();
+        assert_eq!(recognizer.feed(vec![key('g')], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![key('x')], now),
+            vec![key('g'), key('x')]
+        );
+        // the breaking key can start a new chord
+        assert_eq!(
+            recognizer.feed(vec![Event::Keyboard(ctrl('x')), key('g')], now),
+            vec![Event::Keyboard(ctrl('x'))]
+        );
+        assert_eq!(
+            recognizer.feed(vec![key('g')], now),
+            vec![Event::User(MockEvent::Foo)]
+        );
+    }
+
+    #[test]
+    fn should_hold_other_events_behind_held_keys() {
+        let mut recognizer = recognizer();
+        let now = Instant::now();
+        let tick = Event::Tick(Duration::ZERO);
+        // broken chord
+        assert_eq!(recognizer.feed(vec![key('g'), tick.clone()], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![key('x')], now),
+            vec![key('g'), tick.clone(), key('x')]
+        );
+        // the breaking key starts a new chord
+        assert_eq!(
+            recognizer.feed(
+                vec![
+                    key('g'),
+                    Event::FocusLost,
+                    Event::Keyboard(ctrl('x')),
+                    tick.clone()
+                ],
+                now
+            ),
+            vec![key('g'), Event::FocusLost]
+        );
+        assert_eq!(
+            recognizer.feed(vec![Event::Keyboard(ctrl('s'))], now),
+            vec![tick.clone(), Event::User(MockEvent::Bar)]
+        );
+        // timeout
+        assert_eq!(recognizer.feed(vec![key('g'), tick.clone()], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![Event::FocusGained], now + Duration::from_secs(1)),
+            vec![key('g'), tick, Event::FocusGained]
+        );
+    }
+
+    #[test]
+    fn should_release_chords_on_timeout() {
+        let mut recognizer = recognizer();
+        let now = Instant::now();
+        assert_eq!(recognizer.feed(vec![key('g')], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![], now + Duration::from_millis(999)),
+            vec![]
+        );
+        assert_eq!(
+            recognizer.feed(vec![], now + Duration::from_secs(1)),
+            vec![key('g')]
+        );
+        // a timed out key doesn't complete the chord
+        assert_eq!(recognizer.feed(vec![key('g')], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![key('g')], now + Duration::from_secs(2)),
+            vec![key('g')]
+        );
+    }
+
+    #[test]
+    fn should_handle_overlapping_chords() {
+        let mut recognizer = recognizer();
+        recognizer.bind(
+            vec![KeyEvent::plain(Key::Char('g'))],
+            Duration::from_secs(1),
+            MockEvent::Hello(String::from("g")),
+        );
+        recognizer.bind(
+            vec![
+                KeyEvent::plain(Key::Char('g')),
+                KeyEvent::plain(Key::Char('g')),
+                KeyEvent::plain(Key::Char('x')),
+            ],
+            Duration::from_secs(1),
+            MockEvent::Hello(String::from("ggx")),
+        );
+        let now = Instant::now();
+        // the longest chord wins
+        assert_eq!(
+            recognizer.feed(vec![key('g'), key('g'), key('x')], now),
+            vec![Event::User(MockEvent::Hello(String::from("ggx")))]
+        );
+        // the shorter chord is completed when the longer one is broken
+        assert_eq!(
+            recognizer.feed(vec![key('g'), key('g'), key('y')], now),
+            vec![Event::User(MockEvent::Foo), key('y')]
+        );
+        // or when it times out
+        assert_eq!(recognizer.feed(vec![key('g')], now), vec![]);
+        assert_eq!(
+            recognizer.feed(vec![], now + Duration::from_secs(1)),
+            vec![Event::User(MockEvent::Hello(String::from("g")))]
+        );
+    }
+
+    #[test]
+    fn should_pass_other_keys_through() {
+        let mut recognizer = recognizer();
+        let now = Instant::now();
+        assert_eq!(
+            recognizer.feed(vec![key('a'), key('b')], now),
+            vec![key('a'), key('b')]
+        );
+        // unbind
+        assert!(recognizer.unbind(&[
+            KeyEvent::plain(Key::Char('g')),
+            KeyEvent::plain(Key::Char('g'))
+        ]));
+        assert_eq!(recognizer.feed(vec![key('g')], now), vec![key('g')]);
+        // empty chords are ignored
+        recognizer.bind(vec![], Duration::from_secs(1), MockEvent::Foo);
+        assert_eq!(recognizer.feed(vec![key('a')], now), vec![key('a')]);
+    }
+}
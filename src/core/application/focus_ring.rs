@@ -0,0 +1,327 @@
+//! ## Focus ring
+//!
+//! This module exposes the [`FocusRing`], which cycles the focus among a group of components
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use super::{Application, ApplicationResult};
+use crate::{AttrValue, Attribute};
+
+/// A focus ring moves the focus among an ordered group of components (e.g. the inputs of a form),
+/// wrapping around at the ends.
+///
+/// Components which are not mounted, disabled (`Attribute::Disabled` is `Flag(true)`) or hidden
+/// (`Attribute::Display` is `Flag(false)`) are skipped.
+///
+/// The ring interacts with the [`Application`] only through its public methods, so it can be stored in the model
+/// next to the application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusRing<K>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+{
+    ids: Vec<K>,
+    /// Index of the last component focused through the ring
+    current: Option<usize>,
+}
+
+impl<K> FocusRing<K>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+{
+    /// Create a new [`FocusRing`] with the provided ids, in focus order
+    pub fn new(ids: Vec<K>) -> Self {
+        Self { ids, current: None }
+    }
+
+    /// Get the ids in the ring
+    pub fn ids(&self) -> &[K] {
+        &self.ids
+    }
+
+    /// Get the id of the last component focused through the ring
+    pub fn current(&self) -> Option<&K> {
+        self.current.and_then(|i| self.ids.get(i))
+    }
+
+    /// Give focus to the next focusable component in the ring.
+    ///
+    /// Returns the id of the focused component, or `None` if no component in the ring can be focused;
+    /// in this case focus is left unchanged.
+    pub fn next<Msg, UserEvent>(
+        &mut self,
+        app: &mut Application<K, Msg, UserEvent>,
+    ) -> ApplicationResult<Option<&K>>
+    where
+        UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+    {
+        let len = self.ids.len();
+        let start = match self.position(app) {
+            Some(i) => i + 1,
+            None => 0,
+        };
+        self.cycle(app, (0..len).map(|step| (start + step) % len))
+    }
+
+    /// Give focus to the previous focusable component in the ring.
+    ///
+    /// Returns the id of the focused component, or `None` if no component in the ring can be focused;
+    /// in this case focus is left unchanged.
+    pub fn prev<Msg, UserEvent>(
+        &mut self,
+        app: &mut Application<K, Msg, UserEvent>,
+    ) -> ApplicationResult<Option<&K>>
+    where
+        UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+    {
+        let len = self.ids.len();
+        let start = self.position(app).unwrap_or(len);
+        self.cycle(app, (1..=len).map(|step| (start + len - step) % len))
+    }
+
+    /// Give focus to `id`.
+    ///
+    /// Returns the id of the focused component, or `None` if `id` is not in the ring or it can't be focused;
+    /// in this case focus is left unchanged.
+    pub fn set<Msg, UserEvent>(
+        &mut self,
+        app: &mut Application<K, Msg, UserEvent>,
+        id: &K,
+    ) -> ApplicationResult<Option<&K>>
+    where
+        UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+    {
+        match self.ids.iter().position(|x| x == id) {
+            Some(i) => self.cycle(app, std::iter::once(i)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the position in the ring of the focused component.
+    /// If the focused component is not in the ring, the last position focused through the ring is returned.
+    fn position<Msg, UserEvent>(&self, app: &Application<K, Msg, UserEvent>) -> Option<usize>
+    where
+        UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+    {
+        app.focus()
+            .and_then(|focus| self.ids.iter().position(|x| x == focus))
+            .or(self.current)
+    }
+
+    /// Give focus to the first focusable component among `candidates`
+    fn cycle<Msg, UserEvent>(
+        &mut self,
+        app: &mut Application<K, Msg, UserEvent>,
+        candidates: impl Iterator<Item = usize>,
+    ) -> ApplicationResult<Option<&K>>
+    where
+        UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+    {
+        for i in candidates {
+            let id = &self.ids[i];
+            if Self::focusable(app, id)? {
+                app.active(id)?;
+                self.current = Some(i);
+                return Ok(self.ids.get(i));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns whether the component `id` can be focused
+    fn focusable<Msg, UserEvent>(
+        app: &Application<K, Msg, UserEvent>,
+        id: &K,
+    ) -> ApplicationResult<bool>
+    where
+        UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+    {
+        if !app.mounted(id) {
+            return Ok(false);
+        }
+        let disabled = app.query(id, Attribute::Disabled)? == Some(AttrValue::Flag(true));
+        let hidden = app.query(id, Attribute::Display)? == Some(AttrValue::Flag(false));
+        Ok(!disabled && !hidden)
+    }
+}
+
+impl<K> From<Vec<K>> for FocusRing<K>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+{
+    fn from(ids: Vec<K>) -> Self {
+        Self::new(ids)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::{MockBarInput, MockComponentId, MockEvent, MockFooInput, MockMsg, MockPoll};
+    use crate::EventListenerCfg;
+
+    type App = Application<MockComponentId, MockMsg, MockEvent>;
+
+    fn application() -> App {
+        let mut app: App = Application::init(EventListenerCfg::default().add_port(
+            Box::new(MockPoll::<MockEvent>::default()),
+            Duration::from_millis(100),
+            1,
+        ));
+        assert!(app
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(app
+            .mount(
+                MockComponentId::InputBar,
+                Box::new(MockBarInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(app
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        app
+    }
+
+    fn ring() -> FocusRing<MockComponentId> {
+        FocusRing::new(vec![
+            MockComponentId::InputFoo,
+            MockComponentId::InputBar,
+            MockComponentId::InputOmar,
+        ])
+    }
+
+    #[test]
+    fn focus_ring_should_cycle_and_wrap() {
+        let mut app = application();
+        let mut ring = ring();
+        assert_eq!(ring.current(), None);
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputFoo)
+        );
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputBar)
+        );
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputOmar)
+        );
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputFoo)
+        );
+        assert_eq!(app.focus(), Some(&MockComponentId::InputFoo));
+        assert_eq!(
+            ring.prev(&mut app).unwrap(),
+            Some(&MockComponentId::InputOmar)
+        );
+        assert_eq!(ring.current(), Some(&MockComponentId::InputOmar));
+        // set
+        assert_eq!(
+            ring.set(&mut app, &MockComponentId::InputBar).unwrap(),
+            Some(&MockComponentId::InputBar)
+        );
+        assert_eq!(app.focus(), Some(&MockComponentId::InputBar));
+        assert_eq!(
+            ring.set(&mut app, &MockComponentId::Dyn(String::from("a")))
+                .unwrap(),
+            None
+        );
+        // follow focus changed outside of the ring
+        assert!(app.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputBar)
+        );
+    }
+
+    #[test]
+    fn focus_ring_should_skip_disabled_hidden_and_unmounted_components() {
+        let mut app = application();
+        let mut ring = ring();
+        assert!(app
+            .attr(
+                &MockComponentId::InputBar,
+                Attribute::Disabled,
+                AttrValue::Flag(true)
+            )
+            .is_ok());
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputFoo)
+        );
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputOmar)
+        );
+        assert_eq!(
+            ring.prev(&mut app).unwrap(),
+            Some(&MockComponentId::InputFoo)
+        );
+        assert_eq!(
+            ring.set(&mut app, &MockComponentId::InputBar).unwrap(),
+            None
+        );
+        assert_eq!(app.focus(), Some(&MockComponentId::InputFoo));
+        // hide omar; umount foo
+        assert!(app
+            .attr(
+                &MockComponentId::InputOmar,
+                Attribute::Display,
+                AttrValue::Flag(false)
+            )
+            .is_ok());
+        assert!(app.umount(&MockComponentId::InputFoo).is_ok());
+        assert!(app
+            .attr(
+                &MockComponentId::InputBar,
+                Attribute::Disabled,
+                AttrValue::Flag(false)
+            )
+            .is_ok());
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputBar)
+        );
+        assert_eq!(
+            ring.next(&mut app).unwrap(),
+            Some(&MockComponentId::InputBar)
+        );
+    }
+
+    #[test]
+    fn focus_ring_should_not_change_focus_if_every_component_is_disabled() {
+        let mut app = application();
+        let mut ring = ring();
+        assert!(app.active(&MockComponentId::InputBar).is_ok());
+        for id in ring.ids().to_vec() {
+            assert!(app
+                .attr(&id, Attribute::Disabled, AttrValue::Flag(true))
+                .is_ok());
+        }
+        assert_eq!(ring.next(&mut app).unwrap(), None);
+        assert_eq!(ring.prev(&mut app).unwrap(), None);
+        assert_eq!(app.focus(), Some(&MockComponentId::InputBar));
+        // empty ring
+        let mut ring: FocusRing<MockComponentId> = FocusRing::new(vec![]);
+        assert_eq!(ring.next(&mut app).unwrap(), None);
+        assert_eq!(ring.prev(&mut app).unwrap(), None);
+    }
+}
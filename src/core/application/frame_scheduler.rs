@@ -0,0 +1,192 @@
+//! ## Frame scheduler
+//!
+//! This module exposes the [`FrameScheduler`], which decides when the main loop should draw the view
+
+use std::time::{Duration, Instant};
+
+/// A frame scheduler caps the rate at which the view is drawn, without delaying the processing of the events.
+///
+/// The main loop waits for the events up to [`FrameScheduler::sleep_budget`] (e.g. with
+/// [`super::PollStrategy::BlockThenDrain`]), so that the events are processed as soon as they're received,
+/// and then draws the view only if [`FrameScheduler::should_draw`] returns `true`:
+/// the view is drawn only if something has changed, and at most once per frame.
+///
+/// ```rust,no_run
+/// use std::time::{Duration, Instant};
+///
+/// use tuirealm::application::{FrameScheduler, PollStrategy};
+/// use tuirealm::{Application, EventListenerCfg, NoUserEvent};
+///
+/// # #[derive(Debug, Eq, PartialEq, Clone, Hash)]
+/// # enum Id {}
+/// let mut app: Application<Id, (), NoUserEvent> = Application::init(EventListenerCfg::default());
+/// let mut scheduler = FrameScheduler::new(60, Duration::from_millis(250));
+/// loop {
+///     let budget = scheduler.sleep_budget(Instant::now());
+///     let result = app.tick_ex(PollStrategy::BlockThenDrain(budget)).unwrap();
+///     let had_messages = !result.messages.is_empty();
+///     // ... update the model with the messages
+///     if scheduler.should_draw(Instant::now(), result.changed, had_messages) {
+///         // ... draw the view
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameScheduler {
+    /// Minimum time between two draws
+    frame_interval: Duration,
+    /// Maximum time to wait for events
+    max_input_latency: Duration,
+    /// Time of the last draw
+    last_draw: Option<Instant>,
+    /// Whether something has changed since the last draw
+    pending: bool,
+}
+
+impl FrameScheduler {
+    /// Create a new [`FrameScheduler`] which draws at most `fps` frames per second and waits for the events
+    /// at most `max_input_latency`.
+    ///
+    /// The first call to [`FrameScheduler::should_draw`] always draws. `fps` lower than 1 is treated as 1.
+    pub fn new(fps: u32, max_input_latency: Duration) -> Self {
+        Self {
+            frame_interval: Duration::from_secs(1) / fps.max(1),
+            max_input_latency,
+            last_draw: None,
+            pending: true,
+        }
+    }
+
+    /// Get the minimum time between two draws
+    pub fn frame_interval(&self) -> Duration {
+        self.frame_interval
+    }
+
+    /// Returns whether the view should be drawn at `now`, given whether the last tick had events which may
+    /// have changed the view and whether it produced messages.
+    ///
+    /// The view is drawn if something has changed since the last draw and a frame interval has passed;
+    /// otherwise the change is kept pending and drawn in a later frame.
+    pub fn should_draw(&mut self, now: Instant, had_events: bool, had_messages: bool) -> bool {
+        self.pending |= had_events || had_messages;
+        if !self.pending {
+            return false;
+        }
+        if self
+            .last_draw
+            .is_some_and(|last_draw| now.saturating_duration_since(last_draw) < self.frame_interval)
+        {
+            return false;
+        }
+        self.pending = false;
+        self.last_draw = Some(now);
+        true
+    }
+
+    /// Get how long the main loop can wait for the events at `now`.
+    ///
+    /// This is the time left until the next frame if a change is waiting to be drawn,
+    /// otherwise the max input latency.
+    pub fn sleep_budget(&self, now: Instant) -> Duration {
+        match (self.pending, self.last_draw) {
+            (true, Some(last_draw)) => (last_draw + self.frame_interval)
+                .saturating_duration_since(now)
+                .min(self.max_input_latency),
+            (true, None) => Duration::ZERO,
+            (false, _) => self.max_input_latency,
+        }
+    }
+
+    /// Draw the view on the next frame, even if nothing has changed (e.g. after the terminal has been resized)
+    pub fn request_draw(&mut self) {
+        self.pending = true;
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn should_draw_first_frame_immediately() {
+        let start = Instant::now();
+        let mut scheduler = FrameScheduler::new(50, ms(250));
+        assert_eq!(scheduler.frame_interval(), ms(20));
+        assert_eq!(scheduler.sleep_budget(start), Duration::ZERO);
+        assert!(scheduler.should_draw(start, false, false));
+        assert!(!scheduler.should_draw(start, false, false));
+        // fps can't be 0
+        assert_eq!(
+            FrameScheduler::new(0, ms(250)).frame_interval(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn should_draw_burst_of_events_at_frame_rate() {
+        let start = Instant::now();
+        let mut scheduler = FrameScheduler::new(50, ms(250));
+        assert!(scheduler.should_draw(start, true, false));
+        // a burst of events within the frame is drawn at the next frame
+        for t in 1..20 {
+            assert!(!scheduler.should_draw(start + ms(t), true, t % 2 == 0));
+            assert_eq!(scheduler.sleep_budget(start + ms(t)), ms(20 - t));
+        }
+        assert_eq!(scheduler.sleep_budget(start + ms(20)), Duration::ZERO);
+        assert!(scheduler.should_draw(start + ms(20), false, false));
+        // nothing pending anymore
+        assert!(!scheduler.should_draw(start + ms(45), false, false));
+        assert_eq!(scheduler.sleep_budget(start + ms(45)), ms(250));
+    }
+
+    #[test]
+    fn should_not_draw_while_idle() {
+        let start = Instant::now();
+        let mut scheduler = FrameScheduler::new(60, ms(250));
+        assert!(scheduler.should_draw(start, false, false));
+        for t in (250..10_000).step_by(250) {
+            assert_eq!(scheduler.sleep_budget(start + ms(t)), ms(250));
+            assert!(!scheduler.should_draw(start + ms(t), false, false));
+        }
+        // the first event after a long idle is drawn immediately
+        assert!(scheduler.should_draw(start + ms(10_000), false, true));
+        // unless a draw is requested
+        scheduler.request_draw();
+        assert!(scheduler.should_draw(start + ms(10_100), false, false));
+    }
+
+    #[test]
+    fn should_draw_steady_ticks_at_most_once_per_frame() {
+        let start = Instant::now();
+        let mut scheduler = FrameScheduler::new(50, ms(250));
+        // ticks every 10ms, frames every 20ms
+        let draws: Vec<u64> = (0..=100)
+            .step_by(10)
+            .filter(|t| scheduler.should_draw(start + ms(*t), true, false))
+            .collect();
+        assert_eq!(draws, vec![0, 20, 40, 60, 80, 100]);
+        // ticks slower than frames are drawn each time
+        let draws = (120..=300)
+            .step_by(60)
+            .filter(|t| scheduler.should_draw(start + ms(*t), true, false))
+            .count();
+        assert_eq!(draws, 4);
+    }
+
+    #[test]
+    fn sleep_budget_should_not_exceed_max_input_latency() {
+        let start = Instant::now();
+        let mut scheduler = FrameScheduler::new(1, ms(100));
+        assert!(scheduler.should_draw(start, true, false));
+        assert!(!scheduler.should_draw(start + ms(10), true, false));
+        assert_eq!(scheduler.sleep_budget(start + ms(10)), ms(100));
+        assert_eq!(scheduler.sleep_budget(start + ms(950)), ms(50));
+    }
+}
@@ -0,0 +1,139 @@
+//! ## Hierarchy
+//!
+//! This module exposes the ownership of the components mounted with `Application::mount_child`,
+//! which are unmounted together with their parent
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Relates the parent components to the children they own
+pub(crate) struct Hierarchy<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    /// Children of each parent, in mount order
+    children: HashMap<K, Vec<K>>,
+}
+
+impl<K> Default for Hierarchy<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    fn default() -> Self {
+        Self {
+            children: HashMap::new(),
+        }
+    }
+}
+
+impl<K> Hierarchy<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    /// Make `child` a child of `parent`
+    pub fn add(&mut self, parent: K, child: K) {
+        self.children.entry(parent).or_default().push(child);
+    }
+
+    /// Get the children of `parent`, in mount order
+    pub fn children_of(&self, parent: &K) -> &[K] {
+        self.children
+            .get(parent)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Get all the descendants of `parent`, each one before its parent (the deepest first)
+    pub fn descendants(&self, parent: &K) -> Vec<K> {
+        let mut descendants = Vec::new();
+        self.collect_descendants(parent, &mut descendants);
+        descendants
+    }
+
+    /// Remove `id` and all its descendants from the hierarchy, including `id` from the children of its parent
+    pub fn remove(&mut self, id: &K) {
+        if self.children.is_empty() {
+            return;
+        }
+        for descendant in self.descendants(id) {
+            self.children.remove(&descendant);
+        }
+        self.children.remove(id);
+        self.children.retain(|_, children| {
+            children.retain(|x| x != id);
+            !children.is_empty()
+        });
+    }
+
+    /// Replace `old` with `new`, which takes its place among the children of its parent and owns its children
+    pub fn rename(&mut self, old: &K, new: K) {
+        if let Some(children) = self.children.remove(old) {
+            self.children.insert(new.clone(), children);
+        }
+        for children in self.children.values_mut() {
+            if let Some(child) = children.iter_mut().find(|x| *x == old) {
+                *child = new;
+                break;
+            }
+        }
+    }
+
+    /// Remove all the relations
+    pub fn clear(&mut self) {
+        self.children.clear();
+    }
+
+    fn collect_descendants(&self, parent: &K, descendants: &mut Vec<K>) {
+        for child in self.children_of(parent) {
+            self.collect_descendants(child, descendants);
+            descendants.push(child.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_collect_descendants_deepest_first() {
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.add("root", "a");
+        hierarchy.add("root", "b");
+        hierarchy.add("a", "a1");
+        hierarchy.add("a1", "a11");
+        assert_eq!(hierarchy.children_of(&"root"), &["a", "b"]);
+        assert!(hierarchy.children_of(&"b").is_empty());
+        assert_eq!(hierarchy.descendants(&"root"), vec!["a11", "a1", "a", "b"]);
+        assert_eq!(hierarchy.descendants(&"a1"), vec!["a11"]);
+    }
+
+    #[test]
+    fn should_remove_subtrees() {
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.add("root", "a");
+        hierarchy.add("root", "b");
+        hierarchy.add("a", "a1");
+        hierarchy.remove(&"a");
+        assert_eq!(hierarchy.children_of(&"root"), &["b"]);
+        assert!(hierarchy.children_of(&"a").is_empty());
+        hierarchy.remove(&"b");
+        assert!(hierarchy.children.is_empty());
+    }
+
+    #[test]
+    fn should_rename_components() {
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.add("root", "a");
+        hierarchy.add("a", "a1");
+        hierarchy.rename(&"a", "b");
+        assert_eq!(hierarchy.children_of(&"root"), &["b"]);
+        assert_eq!(hierarchy.children_of(&"b"), &["a1"]);
+        assert!(hierarchy.children_of(&"a").is_empty());
+        hierarchy.clear();
+        assert!(hierarchy.descendants(&"root").is_empty());
+    }
+}
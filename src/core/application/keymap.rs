@@ -0,0 +1,149 @@
+//! ## Keymap
+//!
+//! This module exposes the keymap, which translates the keyboard events before they're forwarded to the components
+
+use std::collections::HashMap;
+
+use crate::event::{KeyEvent, KeyEventKind};
+use crate::Event;
+
+/// Translates the keyboard events into other events (e.g. `j` into `Down`)
+pub(crate) struct Keymap<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Events bound to the keys; the kind of the keys is always [`KeyEventKind::Press`]
+    bindings: HashMap<KeyEvent, Event<U>>,
+}
+
+impl<U> Default for Keymap<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+}
+
+impl<U> Keymap<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Replace the bindings with `bindings`
+    pub fn set(&mut self, bindings: HashMap<KeyEvent, Event<U>>) {
+        self.bindings = bindings
+            .into_iter()
+            .map(|(key, ev)| (key.with_kind(KeyEventKind::Press), ev))
+            .collect();
+    }
+
+    /// Remove all the bindings
+    pub fn clear(&mut self) {
+        self.bindings.clear();
+    }
+
+    /// Returns whether there's no binding
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Translate the keyboard events of `events` in place.
+    /// The kind of the key is kept when a key is translated into another key.
+    ///
+    /// Returns the original events, if at least one has been translated
+    pub fn translate(&self, events: &mut [Event<U>]) -> Option<Vec<Event<U>>> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut original = None;
+        for i in 0..events.len() {
+            if let Some(translated) = self.translate_event(&events[i]) {
+                original.get_or_insert_with(|| events.to_vec());
+                events[i] = translated;
+            }
+        }
+        original
+    }
+
+    fn translate_event(&self, ev: &Event<U>) -> Option<Event<U>> {
+        let Event::Keyboard(key) = ev else {
+            return None;
+        };
+        match self.bindings.get(&key.with_kind(KeyEventKind::Press))? {
+            Event::Keyboard(translated) => Some(Event::Keyboard(translated.with_kind(key.kind))),
+            translated => Some(translated.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::event::Key;
+    use crate::mock::MockEvent;
+
+    fn key(ch: char) -> Event<MockEvent> {
+        Event::Keyboard(KeyEvent::plain(Key::Char(ch)))
+    }
+
+    #[test]
+    fn should_translate_keyboard_events() {
+        let mut keymap = Keymap::default();
+        assert!(keymap.is_empty());
+        assert!(keymap.translate(&mut [key('j')]).is_none());
+        keymap.set(HashMap::from([
+            (
+                KeyEvent::plain(Key::Char('j')),
+                Event::Keyboard(KeyEvent::plain(Key::Down)),
+            ),
+            (KeyEvent::plain(Key::Char('q')), Event::User(MockEvent::Foo)),
+        ]));
+        let mut events = vec![
+            key('j'),
+            key('a'),
+            Event::Tick(Default::default()),
+            key('q'),
+        ];
+        let original = keymap.translate(&mut events);
+        assert_eq!(
+            original,
+            Some(vec![
+                key('j'),
+                key('a'),
+                Event::Tick(Default::default()),
+                key('q')
+            ])
+        );
+        assert_eq!(
+            events,
+            vec![
+                Event::Keyboard(KeyEvent::plain(Key::Down)),
+                key('a'),
+                Event::Tick(Default::default()),
+                Event::User(MockEvent::Foo)
+            ]
+        );
+        // nothing to translate
+        let mut events = vec![key('a')];
+        assert!(keymap.translate(&mut events).is_none());
+        assert_eq!(events, vec![key('a')]);
+        // the kind of the key is kept
+        let mut events = vec![Event::Keyboard(
+            KeyEvent::plain(Key::Char('j')).with_kind(KeyEventKind::Repeat),
+        )];
+        assert!(keymap.translate(&mut events).is_some());
+        assert_eq!(
+            events,
+            vec![Event::Keyboard(
+                KeyEvent::plain(Key::Down).with_kind(KeyEventKind::Repeat)
+            )]
+        );
+        keymap.clear();
+        assert!(keymap.is_empty());
+    }
+}
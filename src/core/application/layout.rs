@@ -0,0 +1,95 @@
+//! ## Layout
+//!
+//! This module exposes the snapshot of the layout of an application, which can be persisted
+//! and restored with `Application::restore_layout`
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::{ApplicationError, ApplicationResult};
+use crate::{SubInfo, ViewError};
+
+/// The layout of the active view of an [`crate::Application`]: the mounted components, the focus and the subscriptions,
+/// without the components themselves. It's taken with [`crate::Application::layout_snapshot`]
+/// and restored with [`crate::Application::restore_layout`].
+///
+/// With the `serialize` feature the snapshot can be serialized, as long as its subscriptions have no predicates
+/// (e.g. [`crate::SubClause::StateSatisfies`]), no attribute clauses and no lifecycle clauses of a component.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct LayoutSnapshot<ComponentId, UserEvent>
+where
+    ComponentId: Eq + PartialEq + Clone + Hash,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Mounted components, in mount order
+    pub mounted: Vec<ComponentId>,
+    /// Children of the components mounted with `Application::mount_child`, by parent, in mount order
+    pub children: Vec<(ComponentId, Vec<ComponentId>)>,
+    /// The component which has focus
+    pub focus: Option<ComponentId>,
+    /// Focus stack, from the first to the last component which will get focus on blur
+    pub focus_stack: Vec<ComponentId>,
+    /// Subscriptions with their target, in the order they receive the events
+    pub subscriptions: Vec<(ComponentId, SubInfo<ComponentId, UserEvent>)>,
+    /// Whether the subscriptions are locked
+    pub subs_locked: bool,
+}
+
+impl<K, U> LayoutSnapshot<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Check that the snapshot can be restored: each component is listed once, the focus, the parents, the children
+    /// and the subscription targets are listed, each child has one parent and the children don't form a cycle
+    pub(crate) fn validate(&self) -> ApplicationResult<()> {
+        for (i, id) in self.mounted.iter().enumerate() {
+            if self.mounted[..i].contains(id) {
+                return Err(ViewError::ComponentAlreadyMounted(format!("{id:?}")).into());
+            }
+        }
+        let mut referenced = self
+            .focus
+            .iter()
+            .chain(
+                self.children
+                    .iter()
+                    .flat_map(|(parent, children)| std::iter::once(parent).chain(children)),
+            )
+            .chain(self.subscriptions.iter().map(|(target, _)| target));
+        if let Some(id) = referenced.find(|id| !self.mounted.contains(id)) {
+            return Err(ViewError::ComponentNotFound {
+                id: format!("{id:?}"),
+                suggestion: None,
+            }
+            .into());
+        }
+        let mut parents: HashMap<&K, &K> = HashMap::new();
+        for (parent, children) in self.children.iter() {
+            for child in children {
+                if parents.insert(child, parent).is_some() {
+                    return Err(ApplicationError::InvalidHierarchy(format!("{child:?}")));
+                }
+            }
+        }
+        // NOTE: walking up the parents from a component of a cycle leads back to it within `parents.len()` steps
+        for child in self.children.iter().flat_map(|(_, children)| children) {
+            let mut ancestor = child;
+            for _ in 0..parents.len() {
+                match parents.get(ancestor) {
+                    Some(parent) if *parent == child => {
+                        return Err(ApplicationError::InvalidHierarchy(format!("{child:?}")));
+                    }
+                    Some(parent) => ancestor = parent,
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+}
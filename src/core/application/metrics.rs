@@ -0,0 +1,147 @@
+//! ## Metrics
+//!
+//! This module exposes the metrics collected by the [`super::Application`]
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Metrics collected by the application on each [`super::Application::tick`].
+///
+/// Metrics are disabled by default and must be enabled with [`super::Application::enable_metrics`].
+/// The events received for each kind are counted only if enabled on the event listener
+/// with [`crate::EventListenerCfg::with_event_metrics`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TickMetrics {
+    /// Amount of messages produced for each label returned by the msg labeler
+    labels: HashMap<&'static str, u64>,
+    /// Amount of events emitted by the event listener for each event kind
+    events: HashMap<&'static str, u64>,
+    msgs_produced: u64,
+    subs_evaluated: u64,
+    subs_matched: u64,
+    last_tick_duration: Duration,
+    max_tick_duration: Duration,
+}
+
+impl TickMetrics {
+    /// Returns the amount of events of `kind` (see [`crate::Event::kind`]) emitted by the event listener
+    pub fn events_received(&self, kind: &str) -> u64 {
+        self.events.get(kind).copied().unwrap_or_default()
+    }
+
+    /// Iterate over the event kinds and the amount of events emitted by the event listener
+    pub fn events(&self) -> impl Iterator<Item = (&&'static str, &u64)> {
+        self.events.iter()
+    }
+
+    /// Returns the amount of messages produced by the components
+    pub fn msgs_produced(&self) -> u64 {
+        self.msgs_produced
+    }
+
+    /// Returns the amount of times a subscription has been evaluated against an event
+    pub fn subs_evaluated(&self) -> u64 {
+        self.subs_evaluated
+    }
+
+    /// Returns the amount of times a subscription has matched an event, which has then been forwarded
+    /// to the subscribed component
+    pub fn subs_matched(&self) -> u64 {
+        self.subs_matched
+    }
+
+    /// Returns the duration of the last tick
+    pub fn last_tick_duration(&self) -> Duration {
+        self.last_tick_duration
+    }
+
+    /// Returns the duration of the slowest tick
+    pub fn max_tick_duration(&self) -> Duration {
+        self.max_tick_duration
+    }
+
+    /// Returns the amount of messages counted for `label`
+    pub fn label_count(&self, label: &str) -> u64 {
+        self.labels.get(label).copied().unwrap_or_default()
+    }
+
+    /// Iterate over all the labels and their counters
+    pub fn labels(&self) -> impl Iterator<Item = (&&'static str, &u64)> {
+        self.labels.iter()
+    }
+
+    /// Reset all counters
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Increment the counter for `label`
+    pub(crate) fn count_label(&mut self, label: &'static str) {
+        *self.labels.entry(label).or_default() += 1;
+    }
+
+    /// Set the amount of events emitted by the event listener for each kind
+    pub(crate) fn set_events(&mut self, events: HashMap<&'static str, u64>) {
+        self.events = events;
+    }
+
+    /// Count the messages produced in a tick
+    pub(crate) fn count_msgs(&mut self, msgs: usize) {
+        self.msgs_produced += msgs as u64;
+    }
+
+    /// Count the evaluated and the matched subscriptions in a tick
+    pub(crate) fn count_subs(&mut self, evaluated: usize, matched: usize) {
+        self.subs_evaluated += evaluated as u64;
+        self.subs_matched += matched as u64;
+    }
+
+    /// Record the duration of a tick
+    pub(crate) fn record_tick(&mut self, duration: Duration) {
+        self.last_tick_duration = duration;
+        self.max_tick_duration = self.max_tick_duration.max(duration);
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_count_labels() {
+        let mut metrics = TickMetrics::default();
+        assert_eq!(metrics.label_count("help"), 0);
+        metrics.count_label("help");
+        metrics.count_label("help");
+        metrics.count_label("quit");
+        assert_eq!(metrics.label_count("help"), 2);
+        assert_eq!(metrics.label_count("quit"), 1);
+        assert_eq!(metrics.labels().count(), 2);
+        metrics.reset();
+        assert_eq!(metrics.label_count("help"), 0);
+    }
+
+    #[test]
+    fn should_collect_tick_metrics() {
+        let mut metrics = TickMetrics::default();
+        metrics.count_msgs(3);
+        metrics.count_msgs(2);
+        metrics.count_subs(4, 1);
+        metrics.count_subs(2, 2);
+        metrics.record_tick(Duration::from_millis(20));
+        metrics.record_tick(Duration::from_millis(5));
+        metrics.set_events(HashMap::from([("Keyboard", 4)]));
+        assert_eq!(metrics.msgs_produced(), 5);
+        assert_eq!(metrics.subs_evaluated(), 6);
+        assert_eq!(metrics.subs_matched(), 3);
+        assert_eq!(metrics.last_tick_duration(), Duration::from_millis(5));
+        assert_eq!(metrics.max_tick_duration(), Duration::from_millis(20));
+        assert_eq!(metrics.events_received("Keyboard"), 4);
+        assert_eq!(metrics.events_received("Tick"), 0);
+        metrics.reset();
+        assert_eq!(metrics, TickMetrics::default());
+    }
+}
@@ -0,0 +1,38 @@
+//! ## Modal
+//!
+//! This module exposes the modals of the application, which receive the subscribed events while the other
+//! components don't
+
+/// A modal opened with [`super::Application::open_modal`]
+pub(crate) struct Modal<K> {
+    /// The modal component
+    pub id: K,
+    /// The component which had focus when the modal was opened
+    pub previous_focus: Option<K>,
+}
+
+/// Returned by [`super::Application::open_modal`], describes the opened modal.
+///
+/// The modal stays open until it's closed with [`super::Application::close_modal`] or unmounted;
+/// nested modals are closed in reverse order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModalHandle<K> {
+    id: K,
+    depth: usize,
+}
+
+impl<K> ModalHandle<K> {
+    pub(crate) fn new(id: K, depth: usize) -> Self {
+        Self { id, depth }
+    }
+
+    /// Get the id of the modal component
+    pub fn id(&self) -> &K {
+        &self.id
+    }
+
+    /// Get the amount of modals open, including this one, when the modal was opened
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
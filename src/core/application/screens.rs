@@ -0,0 +1,43 @@
+//! ## Screens
+//!
+//! This module exposes the screens of the application, which keep the components, the focus and the
+//! subscriptions of the views which are not active
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use super::{Hierarchy, Modal, Subscription, Timers, View};
+
+/// The name of the view the application starts with
+pub const DEFAULT_VIEW: &str = "default";
+
+/// A view added with [`super::Application::add_view`], while it's not active.
+///
+/// The state of the active view lives in the application, and it's swapped with a screen on switch.
+pub(crate) struct Screen<K, Msg, UserEvent>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    pub view: View<K, Msg, UserEvent>,
+    pub subs: Vec<Subscription<K, UserEvent>>,
+    pub modal_stack: Vec<Modal<K>>,
+    pub hierarchy: Hierarchy<K>,
+    pub timers: Timers<K>,
+}
+
+impl<K, Msg, UserEvent> Default for Screen<K, Msg, UserEvent>
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn default() -> Self {
+        Self {
+            view: View::default(),
+            subs: Vec::new(),
+            modal_stack: Vec::new(),
+            hierarchy: Hierarchy::default(),
+            timers: Timers::default(),
+        }
+    }
+}
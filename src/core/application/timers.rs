@@ -0,0 +1,230 @@
+//! ## Timers
+//!
+//! This module exposes the scheduler of the component timers, which are checked by the application on each tick
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::event::TimerId;
+
+/// A timer owned by a component
+struct Timer<K> {
+    owner: K,
+    id: TimerId,
+    interval: Duration,
+    repeating: bool,
+}
+
+/// Schedules the timers of the components.
+///
+/// Deadlines are kept in a min-heap, associated to the sequence number of the timer;
+/// cancelled timers are removed from the map only, so their deadlines are discarded when popped.
+pub(crate) struct Timers<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    deadlines: BinaryHeap<Reverse<(Instant, u64)>>,
+    timers: HashMap<u64, Timer<K>>,
+    next_seq: u64,
+}
+
+impl<K> Default for Timers<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    fn default() -> Self {
+        Self {
+            deadlines: BinaryHeap::new(),
+            timers: HashMap::new(),
+            next_seq: 0,
+        }
+    }
+}
+
+impl<K> Timers<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    /// Set timer `id` of `owner` to expire after `interval` from `now`, replacing the previous timer with the same id
+    pub fn set(
+        &mut self,
+        owner: K,
+        id: TimerId,
+        interval: Duration,
+        repeating: bool,
+        now: Instant,
+    ) {
+        self.cancel(&owner, id);
+        self.next_seq += 1;
+        self.deadlines
+            .push(Reverse((now + interval, self.next_seq)));
+        self.timers.insert(
+            self.next_seq,
+            Timer {
+                owner,
+                id,
+                interval,
+                repeating,
+            },
+        );
+    }
+
+    /// Cancel timer `id` of `owner`. Returns whether the timer was set
+    pub fn cancel(&mut self, owner: &K, id: TimerId) -> bool {
+        let len = self.timers.len();
+        self.timers
+            .retain(|_, timer| &timer.owner != owner || timer.id != id);
+        self.timers.len() != len
+    }
+
+    /// Cancel all the timers of `owner`
+    pub fn cancel_all_of(&mut self, owner: &K) {
+        self.timers.retain(|_, timer| &timer.owner != owner);
+    }
+
+    /// Cancel all the timers
+    pub fn clear(&mut self) {
+        self.deadlines.clear();
+        self.timers.clear();
+    }
+
+    /// Returns whether there's no timer set
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Take the timers expired at `now`, in deadline order.
+    ///
+    /// Repeating timers are scheduled again; a repeating timer which missed more intervals expires only once.
+    pub fn expired(&mut self, now: Instant) -> Vec<(K, TimerId)> {
+        let mut expired = Vec::new();
+        let mut rescheduled = Vec::new();
+        while let Some(Reverse((deadline, seq))) = self.deadlines.peek().copied() {
+            if deadline > now {
+                break;
+            }
+            self.deadlines.pop();
+            let Some(timer) = self.timers.get(&seq) else {
+                // cancelled
+                continue;
+            };
+            expired.push((timer.owner.clone(), timer.id));
+            if timer.repeating {
+                let next = deadline + timer.interval;
+                rescheduled.push(Reverse((next.max(now + timer.interval), seq)));
+            } else {
+                self.timers.remove(&seq);
+            }
+        }
+        self.deadlines.extend(rescheduled);
+        expired
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::MockComponentId;
+
+    #[test]
+    fn should_expire_timers_in_deadline_order() {
+        let mut timers: Timers<MockComponentId> = Timers::default();
+        let now = Instant::now();
+        timers.set(
+            MockComponentId::InputFoo,
+            TimerId(1),
+            Duration::from_secs(2),
+            false,
+            now,
+        );
+        timers.set(
+            MockComponentId::InputBar,
+            TimerId(1),
+            Duration::from_secs(1),
+            false,
+            now,
+        );
+        assert_eq!(timers.expired(now), vec![]);
+        assert_eq!(
+            timers.expired(now + Duration::from_secs(2)),
+            vec![
+                (MockComponentId::InputBar, TimerId(1)),
+                (MockComponentId::InputFoo, TimerId(1)),
+            ]
+        );
+        assert_eq!(timers.expired(now + Duration::from_secs(10)), vec![]);
+        assert!(timers.is_empty());
+    }
+
+    #[test]
+    fn should_reschedule_repeating_timers() {
+        let mut timers: Timers<MockComponentId> = Timers::default();
+        let now = Instant::now();
+        timers.set(
+            MockComponentId::InputFoo,
+            TimerId(1),
+            Duration::from_secs(1),
+            true,
+            now,
+        );
+        for i in 1..=3 {
+            assert_eq!(
+                timers.expired(now + Duration::from_secs(i)),
+                vec![(MockComponentId::InputFoo, TimerId(1))]
+            );
+        }
+        // missed intervals expire once
+        assert_eq!(
+            timers.expired(now + Duration::from_secs(10)),
+            vec![(MockComponentId::InputFoo, TimerId(1))]
+        );
+        assert_eq!(timers.expired(now + Duration::from_secs(10)), vec![]);
+        // zero interval doesn't loop
+        timers.set(
+            MockComponentId::InputBar,
+            TimerId(2),
+            Duration::ZERO,
+            true,
+            now,
+        );
+        assert_eq!(
+            timers.expired(now),
+            vec![(MockComponentId::InputBar, TimerId(2))]
+        );
+    }
+
+    #[test]
+    fn should_cancel_timers() {
+        let mut timers: Timers<MockComponentId> = Timers::default();
+        let now = Instant::now();
+        let interval = Duration::from_secs(1);
+        timers.set(MockComponentId::InputFoo, TimerId(1), interval, true, now);
+        timers.set(MockComponentId::InputFoo, TimerId(2), interval, false, now);
+        timers.set(MockComponentId::InputBar, TimerId(1), interval, false, now);
+        assert_eq!(timers.cancel(&MockComponentId::InputFoo, TimerId(1)), true);
+        assert_eq!(timers.cancel(&MockComponentId::InputFoo, TimerId(1)), false);
+        timers.cancel_all_of(&MockComponentId::InputBar);
+        assert_eq!(
+            timers.expired(now + interval),
+            vec![(MockComponentId::InputFoo, TimerId(2))]
+        );
+        // setting a timer again replaces it
+        timers.set(MockComponentId::InputFoo, TimerId(3), interval, false, now);
+        timers.set(
+            MockComponentId::InputFoo,
+            TimerId(3),
+            interval * 2,
+            false,
+            now,
+        );
+        assert_eq!(timers.expired(now + interval), vec![]);
+        timers.clear();
+        assert!(timers.is_empty());
+        assert_eq!(timers.expired(now + interval * 2), vec![]);
+    }
+}
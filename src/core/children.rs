@@ -0,0 +1,418 @@
+//! ## Children
+//!
+//! This module exposes the container of the child components of a composite component
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use ratatui::Frame;
+
+use crate::command::{Cmd, CmdResult};
+use crate::ratatui::layout::Rect;
+use crate::{AttrValue, Attribute, MockComponent, State};
+
+/// An ordered collection of named child [`MockComponent`]s, used to implement composite components
+/// (e.g. a labeled input made of a label, an input and an error line).
+///
+/// Children are rendered in insertion order; at most one child is focused, which is the one receiving
+/// the commands passed to [`Children::perform`]. The focused child has [`Attribute::Focus`] set to `true`,
+/// while the other children have it set to `false`.
+///
+/// ```rust,ignore
+/// struct LabeledInput {
+///     children: Children<&'static str>,
+/// }
+///
+/// impl LabeledInput {
+///     fn new() -> Self {
+///         let mut children = Children::default()
+///             .child("label", Label::default())
+///             .child("input", Input::default());
+///         children.focus(&"input");
+///         Self { children }
+///     }
+/// }
+///
+/// impl MockComponent for LabeledInput {
+///     fn view(&mut self, frame: &mut Frame, area: Rect) {
+///         let [label, input] = Layout::vertical([Constraint::Length(1), Constraint::Length(3)]).areas(area);
+///         self.children.view_in(frame, &HashMap::from([("label", label), ("input", input)]));
+///     }
+///
+///     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+///         self.children.attr_all(attr, value);
+///     }
+///
+///     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+///         self.children.perform(cmd)
+///     }
+///
+///     // ...
+/// }
+/// ```
+pub struct Children<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    children: Vec<(K, Box<dyn MockComponent>)>,
+    focus: Option<K>,
+}
+
+impl<K> Default for Children<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            focus: None,
+        }
+    }
+}
+
+impl<K> Children<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    /// Add the child `id` (builder)
+    pub fn child<C>(mut self, id: K, component: C) -> Self
+    where
+        C: MockComponent + 'static,
+    {
+        self.insert(id, Box::new(component));
+        self
+    }
+
+    /// Insert the child `id`, after the other children.
+    /// If a child with the same id already exists, it's replaced keeping its position, and it's returned
+    pub fn insert(
+        &mut self,
+        id: K,
+        component: Box<dyn MockComponent>,
+    ) -> Option<Box<dyn MockComponent>> {
+        match self.position(&id) {
+            Some(i) => Some(std::mem::replace(&mut self.children[i].1, component)),
+            None => {
+                self.children.push((id, component));
+                None
+            }
+        }
+    }
+
+    /// Remove and return the child `id`. If the child was focused, no child is focused anymore
+    pub fn remove(&mut self, id: &K) -> Option<Box<dyn MockComponent>> {
+        let i = self.position(id)?;
+        if self.focus.as_ref() == Some(id) {
+            self.focus = None;
+        }
+        Some(self.children.remove(i).1)
+    }
+
+    /// Get a reference to the child `id`
+    pub fn get(&self, id: &K) -> Option<&dyn MockComponent> {
+        self.position(id).map(|i| self.children[i].1.as_ref())
+    }
+
+    /// Get a mutable reference to the child `id`
+    pub fn get_mut(&mut self, id: &K) -> Option<&mut (dyn MockComponent + 'static)> {
+        self.position(id).map(|i| self.children[i].1.as_mut())
+    }
+
+    /// Returns the ids of the children, in order
+    pub fn ids(&self) -> impl Iterator<Item = &K> {
+        self.children.iter().map(|(id, _)| id)
+    }
+
+    /// Returns the amount of children
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns whether there are no children
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    // -- props
+
+    /// Set `attr` on the child `id`. Returns whether the child exists
+    pub fn attr(&mut self, id: &K, attr: Attribute, value: AttrValue) -> bool {
+        match self.get_mut(id) {
+            Some(child) => {
+                child.attr(attr, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Set `attr` on all the children (e.g. to propagate the colors of the composite component)
+    pub fn attr_all(&mut self, attr: Attribute, value: AttrValue) {
+        for (_, child) in self.children.iter_mut() {
+            child.attr(attr, value.clone());
+        }
+    }
+
+    /// Query `attr` on the child `id`
+    pub fn query(&self, id: &K, attr: Attribute) -> Option<AttrValue> {
+        self.get(id).and_then(|child| child.query(attr))
+    }
+
+    /// Get the state of the child `id`
+    pub fn state(&self, id: &K) -> Option<State> {
+        self.get(id).map(|child| child.state())
+    }
+
+    // -- view
+
+    /// Render the children which have an area in `areas`, in order
+    pub fn view_in(&mut self, frame: &mut Frame, areas: &HashMap<K, Rect>) {
+        for (id, child) in self.children.iter_mut() {
+            if let Some(area) = areas.get(id) {
+                child.view(frame, *area);
+            }
+        }
+    }
+
+    // -- commands
+
+    /// Perform `cmd` on the child `id`. Returns [`CmdResult::None`] if the child doesn't exist
+    pub fn forward_to(&mut self, id: &K, cmd: Cmd) -> CmdResult {
+        match self.get_mut(id) {
+            Some(child) => child.perform(cmd),
+            None => CmdResult::None,
+        }
+    }
+
+    /// Perform `cmd` on the focused child. Returns [`CmdResult::None`] if no child is focused
+    pub fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match self.focus.clone() {
+            Some(id) => self.forward_to(&id, cmd),
+            None => CmdResult::None,
+        }
+    }
+
+    // -- focus
+
+    /// Returns the id of the focused child
+    pub fn focused(&self) -> Option<&K> {
+        self.focus.as_ref()
+    }
+
+    /// Give focus to the child `id`, removing it from the previously focused child.
+    /// Returns whether the child exists
+    pub fn focus(&mut self, id: &K) -> bool {
+        if self.position(id).is_none() {
+            return false;
+        }
+        self.set_focus(Some(id.clone()));
+        true
+    }
+
+    /// Remove focus from the focused child, if any
+    pub fn blur(&mut self) {
+        self.set_focus(None);
+    }
+
+    /// Give focus to the child after the focused one, wrapping around; if no child is focused, the first one
+    /// gets focus. Returns the id of the focused child
+    pub fn focus_next(&mut self) -> Option<&K> {
+        let next = match self.focus.as_ref().and_then(|id| self.position(id)) {
+            Some(i) => (i + 1) % self.children.len(),
+            None => 0,
+        };
+        self.focus_at(next)
+    }
+
+    /// Give focus to the child before the focused one, wrapping around; if no child is focused, the last one
+    /// gets focus. Returns the id of the focused child
+    pub fn focus_prev(&mut self) -> Option<&K> {
+        let prev = match self.focus.as_ref().and_then(|id| self.position(id)) {
+            Some(0) | None => self.children.len().checked_sub(1)?,
+            Some(i) => i - 1,
+        };
+        self.focus_at(prev)
+    }
+
+    // -- private
+
+    fn position(&self, id: &K) -> Option<usize> {
+        self.children.iter().position(|(x, _)| x == id)
+    }
+
+    fn focus_at(&mut self, i: usize) -> Option<&K> {
+        let id = self.children.get(i)?.0.clone();
+        self.set_focus(Some(id));
+        self.focus.as_ref()
+    }
+
+    fn set_focus(&mut self, focus: Option<K>) {
+        if let Some(old) = self.focus.take() {
+            self.attr(&old, Attribute::Focus, AttrValue::Flag(false));
+        }
+        if let Some(new) = focus.as_ref() {
+            self.attr(new, Attribute::Focus, AttrValue::Flag(true));
+        }
+        self.focus = focus;
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::command::Direction;
+    use crate::mock::MockInput;
+    use crate::props::Color;
+    use crate::ratatui::widgets::Paragraph;
+    use crate::terminal::TerminalBridge;
+    use crate::StateValue;
+
+    /// Renders its text
+    struct Text(&'static str);
+
+    impl MockComponent for Text {
+        fn view(&mut self, frame: &mut Frame, area: Rect) {
+            frame.render_widget(Paragraph::new(self.0), area);
+        }
+
+        fn query(&self, _: Attribute) -> Option<AttrValue> {
+            None
+        }
+
+        fn attr(&mut self, _: Attribute, _: AttrValue) {}
+
+        fn state(&self) -> State {
+            State::None
+        }
+
+        fn perform(&mut self, _: Cmd) -> CmdResult {
+            CmdResult::None
+        }
+    }
+
+    fn children() -> Children<&'static str> {
+        Children::default()
+            .child("label", MockInput::default())
+            .child("input", MockInput::default())
+            .child("error", MockInput::default())
+    }
+
+    fn focus_of(children: &Children<&'static str>, id: &'static str) -> Option<AttrValue> {
+        children.query(&id, Attribute::Focus)
+    }
+
+    #[test]
+    fn should_switch_child_focus() {
+        let mut children = children();
+        assert_eq!(children.focused(), None);
+        assert_eq!(children.focus(&"omar"), false);
+        assert_eq!(children.focus(&"input"), true);
+        assert_eq!(focus_of(&children, "input"), Some(AttrValue::Flag(true)));
+        assert_eq!(children.focus_next(), Some(&"error"));
+        assert_eq!(focus_of(&children, "input"), Some(AttrValue::Flag(false)));
+        assert_eq!(focus_of(&children, "error"), Some(AttrValue::Flag(true)));
+        assert_eq!(children.focus_next(), Some(&"label"));
+        assert_eq!(children.focus_prev(), Some(&"error"));
+        children.blur();
+        assert_eq!(children.focused(), None);
+        assert_eq!(focus_of(&children, "error"), Some(AttrValue::Flag(false)));
+        assert_eq!(children.focus_prev(), Some(&"error"));
+        // removing the focused child removes focus
+        assert!(children.remove(&"error").is_some());
+        assert_eq!(children.focused(), None);
+        assert_eq!(children.focus_next(), Some(&"label"));
+        assert_eq!(Children::<&str>::default().focus_next(), None);
+        assert_eq!(Children::<&str>::default().focus_prev(), None);
+    }
+
+    #[test]
+    fn should_fan_out_attributes() {
+        let mut children = children();
+        children.attr_all(Attribute::Foreground, AttrValue::Color(Color::Red));
+        for id in ["label", "input", "error"] {
+            assert_eq!(
+                children.query(&id, Attribute::Foreground),
+                Some(AttrValue::Color(Color::Red))
+            );
+        }
+        assert_eq!(
+            children.attr(
+                &"input",
+                Attribute::Foreground,
+                AttrValue::Color(Color::Blue)
+            ),
+            true
+        );
+        assert_eq!(
+            children.attr(
+                &"omar",
+                Attribute::Foreground,
+                AttrValue::Color(Color::Blue)
+            ),
+            false
+        );
+        assert_eq!(
+            children.query(&"input", Attribute::Foreground),
+            Some(AttrValue::Color(Color::Blue))
+        );
+        assert_eq!(
+            children.query(&"label", Attribute::Foreground),
+            Some(AttrValue::Color(Color::Red))
+        );
+    }
+
+    #[test]
+    fn should_forward_commands_to_children() {
+        let mut children = children();
+        assert_eq!(children.perform(Cmd::Type('a')), CmdResult::None);
+        children.focus(&"input");
+        assert_ne!(children.perform(Cmd::Type('a')), CmdResult::None);
+        assert_ne!(
+            children.forward_to(&"error", Cmd::Type('b')),
+            CmdResult::None
+        );
+        assert_eq!(
+            children.forward_to(&"omar", Cmd::Type('b')),
+            CmdResult::None
+        );
+        assert_eq!(
+            children.state(&"input"),
+            Some(State::One(StateValue::String(String::from("a"))))
+        );
+        assert_eq!(
+            children.state(&"error"),
+            Some(State::One(StateValue::String(String::from("b"))))
+        );
+        assert_eq!(
+            children.forward_to(&"label", Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+    }
+
+    #[test]
+    fn should_insert_and_render_children() {
+        let mut children = children();
+        assert_eq!(children.len(), 3);
+        assert_eq!(
+            children.ids().copied().collect::<Vec<_>>(),
+            vec!["label", "input", "error"]
+        );
+        // replacing keeps the position
+        children.forward_to(&"label", Cmd::Type('a'));
+        let old = children.insert("label", Box::new(MockInput::default()));
+        assert_eq!(
+            old.unwrap().state(),
+            State::One(StateValue::String(String::from("a")))
+        );
+        assert_eq!(children.ids().next(), Some(&"label"));
+        children.insert("error", Box::new(Text("omar")));
+        let mut terminal = TerminalBridge::new_test(4, 2);
+        let areas = HashMap::from([("error", Rect::new(0, 1, 4, 1))]);
+        assert!(terminal.draw(|f| children.view_in(f, &areas)).is_ok());
+        assert_eq!(terminal.plain_text(), "    \nomar\n");
+        assert!(!children.is_empty());
+    }
+}
@@ -12,6 +12,9 @@ use super::State;
 /// when calling `perform` method of the `MockComponent`.
 /// There is not a default conversion from `Event -> Cmd`, but it must be implmented by the user in the
 /// `Component` in a match case.
+///
+/// Components should return [`CmdResult::None`] for the commands they don't handle, custom commands included.
+/// Custom commands which carry some data are performed with [`crate::MockComponent::perform_custom`].
 #[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd, Hash)]
 pub enum Cmd {
     /// Describes a "user" typed a character
@@ -20,6 +23,8 @@ pub enum Cmd {
     Move(Direction),
     /// An expansion of `Move` which defines the scroll. The step should be defined in props, if any.
     Scroll(Direction),
+    /// Scroll by the given amount of steps in the given direction
+    ScrollBy(Direction, usize),
     /// Describes a movement with a position
     GoTo(Position),
     /// User submit field
@@ -81,3 +86,99 @@ pub enum CmdResult {
     /// No result to report
     None,
 }
+
+impl CmdResult {
+    /// Returns the first command rejected by the component, looking into batches too.
+    ///
+    /// This distinguishes a command which made no sense (e.g. a letter typed into a number input),
+    /// which the `Component` may report with a dedicated `Msg`, from a command which changed nothing.
+    pub fn invalid(&self) -> Option<&Cmd> {
+        match self {
+            CmdResult::Invalid(cmd) => Some(cmd),
+            CmdResult::Batch(batch) => batch.iter().find_map(CmdResult::invalid),
+            _ => None,
+        }
+    }
+
+    /// Flatten the command result: nested batches are merged, [`CmdResult::None`] results are dropped and
+    /// a batch with a single result is unwrapped. An empty batch becomes [`CmdResult::None`].
+    pub fn flatten(self) -> CmdResult {
+        let mut results = Vec::new();
+        self.flatten_into(&mut results);
+        match results.len() {
+            0 => CmdResult::None,
+            1 => results.pop().unwrap(),
+            _ => CmdResult::Batch(results),
+        }
+    }
+
+    fn flatten_into(self, results: &mut Vec<CmdResult>) {
+        match self {
+            CmdResult::Batch(batch) => batch.into_iter().for_each(|x| x.flatten_into(results)),
+            CmdResult::None => {}
+            result => results.push(result),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::StateValue;
+
+    #[test]
+    fn should_find_invalid_cmd() {
+        assert_eq!(CmdResult::None.invalid(), None);
+        assert_eq!(CmdResult::Changed(State::None).invalid(), None);
+        assert_eq!(
+            CmdResult::Invalid(Cmd::Type('a')).invalid(),
+            Some(&Cmd::Type('a'))
+        );
+        assert_eq!(
+            CmdResult::Batch(vec![
+                CmdResult::Changed(State::None),
+                CmdResult::Batch(vec![CmdResult::Invalid(Cmd::Submit)]),
+                CmdResult::Invalid(Cmd::Delete),
+            ])
+            .invalid(),
+            Some(&Cmd::Submit)
+        );
+    }
+
+    #[test]
+    fn should_flatten_cmd_results() {
+        assert_eq!(CmdResult::None.flatten(), CmdResult::None);
+        assert_eq!(CmdResult::Batch(vec![]).flatten(), CmdResult::None);
+        assert_eq!(
+            CmdResult::Submit(State::None).flatten(),
+            CmdResult::Submit(State::None)
+        );
+        assert_eq!(
+            CmdResult::Batch(vec![
+                CmdResult::None,
+                CmdResult::Batch(vec![CmdResult::Invalid(Cmd::Submit), CmdResult::None]),
+            ])
+            .flatten(),
+            CmdResult::Invalid(Cmd::Submit)
+        );
+        assert_eq!(
+            CmdResult::Batch(vec![
+                CmdResult::Changed(State::One(StateValue::Bool(true))),
+                CmdResult::None,
+                CmdResult::Batch(vec![
+                    CmdResult::Batch(vec![CmdResult::Submit(State::None)]),
+                    CmdResult::Custom("omar", State::None),
+                ]),
+            ])
+            .flatten(),
+            CmdResult::Batch(vec![
+                CmdResult::Changed(State::One(StateValue::Bool(true))),
+                CmdResult::Submit(State::None),
+                CmdResult::Custom("omar", State::None),
+            ])
+        );
+    }
+}
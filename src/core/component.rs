@@ -5,6 +5,7 @@
 use ratatui::Frame;
 
 use crate::command::{Cmd, CmdResult};
+use crate::props::PropPayload;
 use crate::ratatui::layout::Rect;
 use crate::{AttrValue, Attribute, Event, State};
 
@@ -40,6 +41,55 @@ pub trait MockComponent {
     /// The command will may change the component state.
     /// The method returns the result of the command applied (what changed if any)
     fn perform(&mut self, cmd: Cmd) -> CmdResult;
+
+    /// Perform a command on the component, comparing the state before and after the command.
+    ///
+    /// Returns [`CmdResult::Changed`] with the new state if the command changed the component state,
+    /// otherwise [`CmdResult::None`]. The result returned by [`MockComponent::perform`] is ignored.
+    fn perform_diff(&mut self, cmd: Cmd) -> CmdResult {
+        let before = self.state();
+        self.perform(cmd);
+        let after = self.state();
+        if after != before {
+            CmdResult::Changed(after)
+        } else {
+            CmdResult::None
+        }
+    }
+
+    /// Perform the user defined command `name`, which carries `payload` (e.g. the value to set).
+    /// This allows sending typed data to the component, which [`Cmd`] can't carry.
+    /// (Default: does nothing and returns [`CmdResult::None`])
+    ///
+    /// The component can report the outcome with [`CmdResult::Custom`].
+    ///
+    /// `#[derive(MockComponent)]` doesn't forward this method to the inner component:
+    /// use [`crate::delegate_mock_component`] or implement it by hand on the wrapper component.
+    fn perform_custom(&mut self, _name: &'static str, _payload: PropPayload) -> CmdResult {
+        CmdResult::None
+    }
+
+    /// Returns the position where the terminal cursor should be shown, when the component has focus and
+    /// it has been rendered in `area`. (Default: [`None`], the cursor is hidden)
+    ///
+    /// Use [`crate::Application::cursor`] to get the cursor position of the active component once all
+    /// the components have been rendered, instead of setting it in [`MockComponent::view`].
+    ///
+    /// Components implemented with `#[derive(MockComponent)]` always return the default, since the derive macro
+    /// doesn't forward `cursor`, `restore`, `perform_diff` and `perform_custom` to the inner component.
+    /// Use [`crate::delegate_mock_component`] instead, or implement these methods by hand.
+    fn cursor(&self, _area: Rect) -> Option<(u16, u16)> {
+        None
+    }
+
+    /// Restore a state previously returned by [`MockComponent::state`] (e.g. to restore a saved session).
+    /// (Default: does nothing)
+    ///
+    /// Use [`crate::Application::restore_state`] to restore the state of a mounted component.
+    ///
+    /// The state is silently ignored by components implemented with `#[derive(MockComponent)]`:
+    /// see [`MockComponent::cursor`].
+    fn restore(&mut self, _state: State) {}
 }
 
 /// The component describes the application level component, which is a wrapper around the [`MockComponent`],
@@ -54,7 +104,6 @@ pub trait MockComponent {
 /// about components in the repository documentation.
 pub trait Component<Msg, UserEvent>: MockComponent
 where
-    Msg: PartialEq,
     UserEvent: Eq + PartialEq + Clone + PartialOrd,
 {
     /// Handle input event and update internal states.
@@ -62,3 +111,241 @@ where
     /// If [`None`] is returned it means there's no message to return for the provided event.
     fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg>;
 }
+
+/// A [`Component`] adapting a `Component<Msg, A>` into a `Component<Msg, B>`, given the function to map the events.
+///
+/// Each event is mapped with the mapping function before being forwarded to the inner component;
+/// if the function returns [`None`], the event is not forwarded.
+/// This allows to use components written for a different user event type (e.g. [`crate::NoUserEvent`])
+/// without changing them:
+///
+/// ```rust,ignore
+/// app.mount(Id::Clock, Box::new(MapUserEvent::new(Clock::default(), Event::without_user)), vec![]);
+/// ```
+pub struct MapUserEvent<C, F> {
+    component: C,
+    map: F,
+}
+
+impl<C, F> MapUserEvent<C, F> {
+    /// Instantiate a new `MapUserEvent` wrapping `component`, using `map` to map the events
+    pub fn new(component: C, map: F) -> Self {
+        Self { component, map }
+    }
+
+    /// Get a reference to the inner component
+    pub fn inner(&self) -> &C {
+        &self.component
+    }
+
+    /// Get a mutable reference to the inner component
+    pub fn inner_mut(&mut self) -> &mut C {
+        &mut self.component
+    }
+
+    /// Take the inner component out of the wrapper
+    pub fn into_inner(self) -> C {
+        self.component
+    }
+}
+
+impl<C, F> MockComponent for MapUserEvent<C, F>
+where
+    C: MockComponent,
+{
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        self.component.view(frame, area)
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.component.query(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.component.attr(attr, value)
+    }
+
+    fn state(&self) -> State {
+        self.component.state()
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform(cmd)
+    }
+
+    fn perform_diff(&mut self, cmd: Cmd) -> CmdResult {
+        self.component.perform_diff(cmd)
+    }
+
+    fn perform_custom(&mut self, name: &'static str, payload: PropPayload) -> CmdResult {
+        self.component.perform_custom(name, payload)
+    }
+
+    fn cursor(&self, area: Rect) -> Option<(u16, u16)> {
+        self.component.cursor(area)
+    }
+
+    fn restore(&mut self, state: State) {
+        self.component.restore(state)
+    }
+}
+
+impl<Msg, A, B, C, F> Component<Msg, B> for MapUserEvent<C, F>
+where
+    A: Eq + PartialEq + Clone + PartialOrd,
+    B: Eq + PartialEq + Clone + PartialOrd,
+    C: Component<Msg, A>,
+    F: Fn(Event<B>) -> Option<Event<A>>,
+{
+    fn on(&mut self, ev: Event<B>) -> Option<Msg> {
+        (self.map)(ev).and_then(|ev| self.component.on(ev))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::command::{Direction, Position};
+    use crate::event::{Key, KeyEvent};
+    use crate::mock::MockBarInput;
+    use crate::mock::{MockEvent, MockFooInput, MockInput, MockMsg, MOCK_CMD_SET_TEXT};
+    use crate::props::{InputType, PropPayload, PropValue};
+    use crate::{AttrValue, Attribute, NoUserEvent, StateValue};
+
+    #[test]
+    fn perform_diff_should_report_state_changes() {
+        let mut component = MockInput::default();
+        assert_eq!(
+            component.perform_diff(Cmd::Type('a')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("a"))))
+        );
+        // cursor movement doesn't change state
+        assert_eq!(
+            component.perform_diff(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.perform_diff(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn perform_should_distinguish_invalid_commands_from_no_change() {
+        let mut component = MockInput::default();
+        component.attr(
+            Attribute::InputType,
+            AttrValue::InputType(InputType::UnsignedInteger),
+        );
+        assert_eq!(
+            component.perform(Cmd::Type('4')),
+            CmdResult::Changed(State::One(StateValue::String(String::from("4"))))
+        );
+        // a letter makes no sense in a number
+        assert_eq!(
+            component.perform(Cmd::Type('a')),
+            CmdResult::Invalid(Cmd::Type('a'))
+        );
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("4")))
+        );
+        // moving left at the beginning changes nothing, but it's not invalid
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+    }
+
+    #[test]
+    fn perform_should_handle_custom_commands() {
+        let mut component = MockInput::default();
+        assert_eq!(
+            component.perform_custom(
+                MOCK_CMD_SET_TEXT,
+                PropPayload::One(PropValue::Str(String::from("omar")))
+            ),
+            CmdResult::Custom(
+                MOCK_CMD_SET_TEXT,
+                State::One(StateValue::String(String::from("omar")))
+            )
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::At(2))),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(4)))
+        );
+        // unknown custom commands are ignored
+        assert_eq!(
+            component.perform_custom(
+                "unknown",
+                PropPayload::One(PropValue::Str(String::from("bar")))
+            ),
+            CmdResult::None
+        );
+        assert_eq!(component.perform(Cmd::Custom("unknown")), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::ScrollBy(Direction::Down, 3)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("omar")))
+        );
+    }
+
+    #[test]
+    fn map_user_event_should_adapt_component_user_event() {
+        // use a component with user events where user events are not available
+        let mut component: Box<dyn Component<MockMsg, NoUserEvent>> = Box::new(MapUserEvent::new(
+            MockFooInput::default(),
+            |ev: Event<NoUserEvent>| Some(ev.with_user::<MockEvent>()),
+        ));
+        assert_eq!(
+            component.on(Event::Keyboard(KeyEvent::from(Key::Char('a')))),
+            Some(MockMsg::FooInputChanged(String::from("a")))
+        );
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(
+            component.query(Attribute::Focus),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("a")))
+        );
+        // map user events
+        let mut component = MapUserEvent::new(MockBarInput::default(), |ev: Event<u8>| {
+            ev.filter_map_user(|x| (x > 0).then(|| MockEvent::Hello(x.to_string())))
+        });
+        assert_eq!(
+            component.on(Event::User(1)),
+            Some(MockMsg::BarHello(String::from("1")))
+        );
+        assert_eq!(component.on(Event::User(0)), None);
+        assert_eq!(
+            component.on(Event::Tick(Default::default())),
+            Some(MockMsg::BarTick)
+        );
+        assert_eq!(
+            component.inner().state(),
+            State::One(StateValue::String(String::new()))
+        );
+        assert!(component.inner_mut().perform(Cmd::Type('b')) != CmdResult::None);
+        assert_eq!(
+            component.into_inner().state(),
+            State::One(StateValue::String(String::from("b")))
+        );
+    }
+}
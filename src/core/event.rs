@@ -2,14 +2,24 @@
 //!
 //! `events` exposes the event raised by a user interaction or by the runtime
 
+use std::fmt;
+use std::time::Duration;
+
 use bitflags::bitflags;
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
+pub use super::lifecycle::{Lifecycle, LifecycleEvent, LifecycleKind};
+
 // -- event
 
 /// An event raised by a user interaction
 #[derive(Debug, Eq, PartialEq, Clone, PartialOrd)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 pub enum Event<UserEvent>
 where
     UserEvent: Eq + PartialEq + Clone + PartialOrd,
@@ -26,8 +36,20 @@ where
     FocusLost,
     /// Clipboard content pasted
     Paste(String),
-    /// A ui tick event (should be configurable)
-    Tick,
+    /// A ui tick event (should be configurable).
+    /// Carries the time elapsed since the previous tick emitted by the event listener
+    Tick(Duration),
+    /// The event listener has died and has been restarted by the application restart policy
+    ListenerRestarted,
+    /// A component has been mounted, umounted, or its focus changed.
+    /// These events are raised by the application only if enabled with `Application::enable_lifecycle_events`
+    /// and they're forwarded only to the subscriptions to [`crate::SubEventClause::Lifecycle`].
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    Lifecycle(Lifecycle),
+    /// A timer set with `Application::set_timer` has expired.
+    /// This event is delivered only to the component which owns the timer and to the subscriptions to
+    /// [`crate::SubEventClause::Timer`].
+    Timer(TimerId),
     /// Unhandled event; Empty event
     None,
     /// User event; won't be used by standard library or by default input event listener;
@@ -39,6 +61,85 @@ impl<U> Event<U>
 where
     U: Eq + PartialEq + Clone + PartialOrd,
 {
+    /// Returns a stable name for the event variant (e.g. `"Keyboard"`, `"Tick"`).
+    ///
+    /// The returned name never contains the event payload, so it can be safely used for telemetry.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Keyboard(_) => "Keyboard",
+            Self::Mouse(_) => "Mouse",
+            Self::WindowResize(_, _) => "WindowResize",
+            Self::FocusGained => "FocusGained",
+            Self::FocusLost => "FocusLost",
+            Self::Paste(_) => "Paste",
+            Self::Tick(_) => "Tick",
+            Self::ListenerRestarted => "ListenerRestarted",
+            Self::Lifecycle(_) => "Lifecycle",
+            Self::Timer(_) => "Timer",
+            Self::None => "None",
+            Self::User(_) => "User",
+        }
+    }
+
+    /// Returns the event name with the key detail for keyboard events (e.g. `"Keyboard(CTRL+c)"`).
+    /// For any other event, it is the same as [`Event::kind`].
+    pub fn kind_detail(&self) -> String {
+        match self {
+            Self::Keyboard(key) => format!("{}({})", self.kind(), key),
+            _ => self.kind().to_string(),
+        }
+    }
+
+    /// Convert the event into an event with a different user event type, mapping the user event with `f`.
+    /// Any other event is kept as it is.
+    pub fn map_user<V, F>(self, f: F) -> Event<V>
+    where
+        V: Eq + PartialEq + Clone + PartialOrd,
+        F: FnOnce(U) -> V,
+    {
+        match self {
+            Self::Keyboard(k) => Event::Keyboard(k),
+            Self::Mouse(m) => Event::Mouse(m),
+            Self::WindowResize(w, h) => Event::WindowResize(w, h),
+            Self::FocusGained => Event::FocusGained,
+            Self::FocusLost => Event::FocusLost,
+            Self::Paste(s) => Event::Paste(s),
+            Self::Tick(elapsed) => Event::Tick(elapsed),
+            Self::ListenerRestarted => Event::ListenerRestarted,
+            Self::Lifecycle(ev) => Event::Lifecycle(ev),
+            Self::Timer(id) => Event::Timer(id),
+            Self::None => Event::None,
+            Self::User(u) => Event::User(f(u)),
+        }
+    }
+
+    /// Convert the event into an event with a different user event type, mapping the user event with `f`.
+    /// Returns [`None`] if the event is a user event and `f` returns [`None`].
+    pub fn filter_map_user<V, F>(self, f: F) -> Option<Event<V>>
+    where
+        V: Eq + PartialEq + Clone + PartialOrd,
+        F: FnOnce(U) -> Option<V>,
+    {
+        match self {
+            Self::User(u) => f(u).map(Event::User),
+            ev => Some(ev.map_user(|_| unreachable!("user events are matched above"))),
+        }
+    }
+
+    /// Convert the event into an event without user events.
+    /// Returns [`None`] if the event is a user event.
+    ///
+    /// This can be used as the mapping of a [`crate::MapUserEvent`] to use components which don't handle
+    /// user events in an application which does.
+    pub fn without_user(self) -> Option<Event<NoUserEvent>> {
+        self.filter_map_user(|_| None)
+    }
+
+    /// Convert the event into an event without user events, replacing a user event with [`Event::None`].
+    pub fn drop_user(self) -> Event<NoUserEvent> {
+        self.without_user().unwrap_or(Event::None)
+    }
+
     pub(crate) fn is_keyboard(&self) -> Option<&KeyEvent> {
         if let Event::Keyboard(k) = self {
             Some(k)
@@ -60,7 +161,23 @@ where
     }
 
     pub(crate) fn is_tick(&self) -> bool {
-        matches!(self, Self::Tick)
+        matches!(self, Self::Tick(_))
+    }
+
+    pub(crate) fn is_lifecycle(&self) -> Option<&Lifecycle> {
+        if let Event::Lifecycle(ev) = self {
+            Some(ev)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn is_timer(&self) -> Option<&TimerId> {
+        if let Event::Timer(id) = self {
+            Some(id)
+        } else {
+            None
+        }
     }
 
     pub(crate) fn is_user(&self) -> Option<&U> {
@@ -72,10 +189,35 @@ where
     }
 }
 
+/// The identifier of a timer set with `Application::set_timer`.
+///
+/// Timer ids are scoped to the component which owns the timer, so different components can use the same ids.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct TimerId(pub u32);
+
 /// When using event you can use this as type parameter if you don't want to use user events
 #[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub enum NoUserEvent {}
 
+impl NoUserEvent {
+    /// Convert into any user event type. Since `NoUserEvent` has no values, this can never be called
+    pub fn into_any<U>(self) -> U {
+        match self {}
+    }
+}
+
+impl Event<NoUserEvent> {
+    /// Convert an event without user events into an event with the user event type `U`
+    pub fn with_user<U>(self) -> Event<U>
+    where
+        U: Eq + PartialEq + Clone + PartialOrd,
+    {
+        self.map_user(NoUserEvent::into_any)
+    }
+}
+
 // -- keyboard
 
 /// A keyboard event
@@ -88,6 +230,25 @@ pub enum NoUserEvent {}
 pub struct KeyEvent {
     pub code: Key,
     pub modifiers: KeyModifiers,
+    /// The kind of key event. Only [`KeyEventKind::Press`] events are emitted by default;
+    /// see [`crate::EventListenerCfg::with_key_event_kinds`]
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub kind: KeyEventKind,
+}
+
+/// Defines the kind of a [`KeyEvent`].
+///
+/// > Release and repeat events are reported only by some terminals (e.g. on Windows or with the kitty keyboard protocol)
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone, PartialOrd, Hash)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum KeyEventKind {
+    /// The key has been pressed
+    #[default]
+    Press,
+    /// The key is being held down
+    Repeat,
+    /// The key has been released
+    Release,
 }
 
 /// A keyboard event
@@ -152,33 +313,33 @@ pub enum Key {
     Media(MediaKeyCode),
     /// Escape key.
     Esc,
-    /// Shift left
+    /// Shift+Left; deprecated: the input listeners report [`Key::Left`] with [`KeyModifiers::SHIFT`]
     ShiftLeft,
-    /// Alt left; warning: it is supported only on termion
+    /// Alt+Left; deprecated: the input listeners report [`Key::Left`] with [`KeyModifiers::ALT`]
     AltLeft,
-    /// warning: it is supported only on termion
+    /// Ctrl+Left; deprecated: the input listeners report [`Key::Left`] with [`KeyModifiers::CONTROL`]
     CtrlLeft,
-    /// warning: it is supported only on termion
+    /// Shift+Right; deprecated: the input listeners report [`Key::Right`] with [`KeyModifiers::SHIFT`]
     ShiftRight,
-    /// warning: it is supported only on termion
+    /// Alt+Right; deprecated: the input listeners report [`Key::Right`] with [`KeyModifiers::ALT`]
     AltRight,
-    /// warning: it is supported only on termion
+    /// Ctrl+Right; deprecated: the input listeners report [`Key::Right`] with [`KeyModifiers::CONTROL`]
     CtrlRight,
-    /// warning: it is supported only on termion
+    /// Shift+Up; deprecated: the input listeners report [`Key::Up`] with [`KeyModifiers::SHIFT`]
     ShiftUp,
-    /// warning: it is supported only on termion
+    /// Alt+Up; deprecated: the input listeners report [`Key::Up`] with [`KeyModifiers::ALT`]
     AltUp,
-    /// warning: it is supported only on termion
+    /// Ctrl+Up; deprecated: the input listeners report [`Key::Up`] with [`KeyModifiers::CONTROL`]
     CtrlUp,
-    /// warning: it is supported only on termion
+    /// Shift+Down; deprecated: the input listeners report [`Key::Down`] with [`KeyModifiers::SHIFT`]
     ShiftDown,
-    /// warning: it is supported only on termion
+    /// Alt+Down; deprecated: the input listeners report [`Key::Down`] with [`KeyModifiers::ALT`]
     AltDown,
-    /// warning: it is supported only on termion
+    /// Ctrl+Down; deprecated: the input listeners report [`Key::Down`] with [`KeyModifiers::CONTROL`]
     CtrlDown,
-    /// warning: it is supported only on termion
+    /// Ctrl+Home; deprecated: the input listeners report [`Key::Home`] with [`KeyModifiers::CONTROL`]
     CtrlHome,
-    /// warning: it is supported only on termion
+    /// Ctrl+End; deprecated: the input listeners report [`Key::End`] with [`KeyModifiers::CONTROL`]
     CtrlEnd,
 }
 
@@ -193,12 +354,62 @@ bitflags! {
         const SHIFT = 0b0000_0001;
         const CONTROL = 0b0000_0010;
         const ALT = 0b0000_0100;
+        /// Super (e.g. Windows or Command key); reported only by terminals supporting the kitty keyboard protocol
+        const SUPER = 0b0000_1000;
+        /// Hyper; reported only by terminals supporting the kitty keyboard protocol
+        const HYPER = 0b0001_0000;
+        /// Meta; reported only by terminals supporting the kitty keyboard protocol
+        const META = 0b0010_0000;
     }
 }
 
 impl KeyEvent {
-    pub fn new(code: Key, modifiers: KeyModifiers) -> Self {
-        Self { code, modifiers }
+    pub const fn new(code: Key, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    /// Create a new [`KeyEvent`] for `code` without modifiers
+    pub const fn plain(code: Key) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    /// Create a new [`KeyEvent`] for `code` with CONTROL
+    pub const fn ctrl(code: Key) -> Self {
+        Self::new(code, KeyModifiers::CONTROL)
+    }
+
+    /// Create a new [`KeyEvent`] for `code` with ALT
+    pub const fn alt(code: Key) -> Self {
+        Self::new(code, KeyModifiers::ALT)
+    }
+
+    /// Create a new [`KeyEvent`] for `code` with SHIFT
+    pub const fn shift(code: Key) -> Self {
+        Self::new(code, KeyModifiers::SHIFT)
+    }
+
+    /// Returns whether the event is CONTROL + `ch`.
+    ///
+    /// Since terminals report uppercase chars with SHIFT (e.g. `CONTROL | SHIFT` + `Q` or `q`),
+    /// the case of the char and SHIFT are ignored, so `is_ctrl_char('q')` matches both `CTRL+q` and `CTRL+Q`.
+    pub fn is_ctrl_char(&self, ch: char) -> bool {
+        match self.code {
+            Key::Char(c) => {
+                self.modifiers.difference(KeyModifiers::SHIFT) == KeyModifiers::CONTROL
+                    && c.to_lowercase().eq(ch.to_lowercase())
+            }
+            _ => false,
+        }
+    }
+
+    /// Set the kind of key event
+    pub fn with_kind(mut self, kind: KeyEventKind) -> Self {
+        self.kind = kind;
+        self
     }
 }
 
@@ -208,6 +419,47 @@ impl From<Key> for KeyEvent {
     }
 }
 
+impl From<(Key, KeyModifiers)> for KeyEvent {
+    fn from((k, modifiers): (Key, KeyModifiers)) -> Self {
+        Self::new(k, modifiers)
+    }
+}
+
+impl fmt::Display for KeyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "CTRL+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "ALT+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "SHIFT+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SUPER) {
+            write!(f, "SUPER+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::HYPER) {
+            write!(f, "HYPER+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::META) {
+            write!(f, "META+")?;
+        }
+        write!(f, "{}", self.code)
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Char(c) => write!(f, "{c}"),
+            Self::Function(n) => write!(f, "F{n}"),
+            Self::Media(m) => write!(f, "{m:?}"),
+            key => write!(f, "{key:?}"),
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd, Hash)]
 #[cfg_attr(
     feature = "serialize",
@@ -312,6 +564,112 @@ mod test {
     use super::*;
     use crate::mock::MockEvent;
 
+    #[test]
+    fn should_map_user_events() {
+        let ev: Event<MockEvent> = Event::User(MockEvent::Hello(String::from("omar")));
+        assert_eq!(
+            ev.clone().map_user(|_| MockEvent::Foo),
+            Event::User(MockEvent::Foo)
+        );
+        assert_eq!(
+            ev.clone().filter_map_user(|u| match u {
+                MockEvent::Hello(name) => Some(name),
+                _ => None,
+            }),
+            Some(Event::User(String::from("omar")))
+        );
+        assert_eq!(ev.clone().filter_map_user(|_| None::<u8>), None);
+        assert_eq!(ev.without_user(), None);
+        // other events are kept
+        let ev: Event<MockEvent> = Event::Tick(Duration::from_secs(1));
+        assert_eq!(
+            ev.clone().map_user(|_| 0u8),
+            Event::Tick(Duration::from_secs(1))
+        );
+        assert_eq!(
+            ev.clone().filter_map_user(|_| None::<u8>),
+            Some(Event::Tick(Duration::from_secs(1)))
+        );
+        assert_eq!(
+            ev.without_user(),
+            Some(Event::<NoUserEvent>::Tick(Duration::from_secs(1)))
+        );
+        // from no user event
+        let ev: Event<NoUserEvent> = Event::Paste(String::from("pippo"));
+        assert_eq!(
+            ev.with_user::<MockEvent>(),
+            Event::Paste(String::from("pippo"))
+        );
+    }
+
+    #[test]
+    fn should_map_every_event_but_user_events_as_it_is() {
+        let key = KeyEvent::new(Key::Char('a'), KeyModifiers::CONTROL | KeyModifiers::ALT)
+            .with_kind(KeyEventKind::Repeat);
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::SHIFT,
+            column: 4,
+            row: 2,
+        };
+        let lifecycle = Lifecycle::new(LifecycleKind::Mounted, String::from("omar"));
+        let events: Vec<(Event<MockEvent>, Event<u8>, Event<NoUserEvent>)> = vec![
+            (
+                Event::Keyboard(key),
+                Event::Keyboard(key),
+                Event::Keyboard(key),
+            ),
+            (
+                Event::Mouse(mouse),
+                Event::Mouse(mouse),
+                Event::Mouse(mouse),
+            ),
+            (
+                Event::WindowResize(80, 24),
+                Event::WindowResize(80, 24),
+                Event::WindowResize(80, 24),
+            ),
+            (Event::FocusGained, Event::FocusGained, Event::FocusGained),
+            (Event::FocusLost, Event::FocusLost, Event::FocusLost),
+            (
+                Event::Paste(String::from("pippo")),
+                Event::Paste(String::from("pippo")),
+                Event::Paste(String::from("pippo")),
+            ),
+            (
+                Event::Tick(Duration::from_millis(10)),
+                Event::Tick(Duration::from_millis(10)),
+                Event::Tick(Duration::from_millis(10)),
+            ),
+            (
+                Event::ListenerRestarted,
+                Event::ListenerRestarted,
+                Event::ListenerRestarted,
+            ),
+            (
+                Event::Lifecycle(lifecycle.clone()),
+                Event::Lifecycle(lifecycle.clone()),
+                Event::Lifecycle(lifecycle),
+            ),
+            (
+                Event::Timer(TimerId(3)),
+                Event::Timer(TimerId(3)),
+                Event::Timer(TimerId(3)),
+            ),
+            (Event::None, Event::None, Event::None),
+            (Event::User(MockEvent::Bar), Event::User(7), Event::None),
+        ];
+        for (ev, mapped, dropped) in events {
+            assert_eq!(ev.clone().map_user(|_| 7u8), mapped);
+            assert_eq!(ev.clone().filter_map_user(|_| Some(7u8)), Some(mapped));
+            assert_eq!(ev.clone().drop_user(), dropped);
+            if ev.is_user().is_none() {
+                assert_eq!(ev.clone().without_user(), Some(dropped.clone()));
+                assert_eq!(dropped.with_user::<MockEvent>(), ev);
+            }
+        }
+    }
+
     #[test]
     fn new_key_event() {
         let k = KeyEvent::new(Key::Down, KeyModifiers::CONTROL);
@@ -326,6 +684,72 @@ mod test {
         assert_eq!(k.modifiers, KeyModifiers::NONE);
     }
 
+    #[test]
+    fn should_build_key_events_with_shortcuts() {
+        const QUIT: KeyEvent = KeyEvent::ctrl(Key::Char('q'));
+        assert_eq!(QUIT, KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL));
+        assert_eq!(
+            KeyEvent::alt(Key::Enter),
+            KeyEvent::new(Key::Enter, KeyModifiers::ALT)
+        );
+        assert_eq!(
+            KeyEvent::shift(Key::Tab),
+            KeyEvent::new(Key::Tab, KeyModifiers::SHIFT)
+        );
+        assert_eq!(KeyEvent::plain(Key::Esc), KeyEvent::from(Key::Esc));
+        assert_eq!(
+            KeyEvent::from((Key::Up, KeyModifiers::CONTROL | KeyModifiers::ALT)),
+            KeyEvent::new(Key::Up, KeyModifiers::CONTROL | KeyModifiers::ALT)
+        );
+        // macro
+        assert_eq!(crate::key!('a'), KeyEvent::plain(Key::Char('a')));
+        assert_eq!(crate::key!(ctrl + 'q'), QUIT);
+        assert_eq!(crate::key!(alt + Key::Enter), KeyEvent::alt(Key::Enter));
+        assert_eq!(
+            crate::key!(ctrl + alt + shift + Key::Function(1)),
+            KeyEvent::new(
+                Key::Function(1),
+                KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT
+            )
+        );
+        assert_eq!(
+            crate::key!(ctrl + alt + shift + super + hyper + meta + 'k'),
+            KeyEvent::new(Key::Char('k'), KeyModifiers::all())
+        );
+        let matched = match QUIT {
+            crate::key!(ctrl + 'c') => "copy",
+            crate::key!(ctrl + 'q') => "quit",
+            _ => "none",
+        };
+        assert_eq!(matched, "quit");
+    }
+
+    #[test]
+    fn should_match_ctrl_char_ignoring_case_and_shift() {
+        assert!(KeyEvent::ctrl(Key::Char('q')).is_ctrl_char('q'));
+        assert!(KeyEvent::ctrl(Key::Char('q')).is_ctrl_char('Q'));
+        // uppercase chars are reported with SHIFT (crossterm) or lowercased with SHIFT (termion)
+        assert!(
+            KeyEvent::new(Key::Char('Q'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+                .is_ctrl_char('q')
+        );
+        assert!(
+            KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+                .is_ctrl_char('q')
+        );
+        assert!(KeyEvent::ctrl(Key::Char('Q')).is_ctrl_char('q'));
+        // other modifiers or keys don't match
+        assert_eq!(KeyEvent::plain(Key::Char('q')).is_ctrl_char('q'), false);
+        assert_eq!(KeyEvent::shift(Key::Char('Q')).is_ctrl_char('q'), false);
+        assert_eq!(
+            KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL | KeyModifiers::ALT)
+                .is_ctrl_char('q'),
+            false
+        );
+        assert_eq!(KeyEvent::ctrl(Key::Char('c')).is_ctrl_char('q'), false);
+        assert_eq!(KeyEvent::ctrl(Key::Enter).is_ctrl_char('q'), false);
+    }
+
     #[test]
     fn check_events() {
         let e: Event<MockEvent> = Event::Keyboard(KeyEvent::new(Key::Down, KeyModifiers::CONTROL));
@@ -337,7 +761,7 @@ mod test {
         let e: Event<MockEvent> = Event::WindowResize(0, 24);
         assert!(e.is_window_resize());
         assert!(e.is_keyboard().is_none());
-        let e: Event<MockEvent> = Event::Tick;
+        let e: Event<MockEvent> = Event::Tick(Duration::ZERO);
         assert!(e.is_tick());
         let e: Event<MockEvent> = Event::User(MockEvent::Bar);
         assert_eq!(e.is_user().unwrap(), &MockEvent::Bar);
@@ -354,6 +778,53 @@ mod test {
         assert_eq!(e.is_window_resize(), false);
     }
 
+    #[test]
+    fn should_return_event_kind() {
+        let mouse = MouseEvent {
+            kind: MouseEventKind::Moved,
+            modifiers: KeyModifiers::NONE,
+            column: 0,
+            row: 0,
+        };
+        let events: Vec<(Event<MockEvent>, &str)> = vec![
+            (Event::Keyboard(KeyEvent::from(Key::Enter)), "Keyboard"),
+            (Event::Mouse(mouse), "Mouse"),
+            (Event::WindowResize(0, 0), "WindowResize"),
+            (Event::FocusGained, "FocusGained"),
+            (Event::FocusLost, "FocusLost"),
+            (Event::Paste(String::from("a")), "Paste"),
+            (Event::Tick(Duration::from_secs(1)), "Tick"),
+            (Event::ListenerRestarted, "ListenerRestarted"),
+            (Event::Timer(TimerId(1)), "Timer"),
+            (Event::None, "None"),
+            (Event::User(MockEvent::Foo), "User"),
+        ];
+        for (ev, kind) in events {
+            assert_eq!(ev.kind(), kind);
+        }
+    }
+
+    #[test]
+    fn should_return_event_kind_detail() {
+        let e: Event<MockEvent> = Event::Keyboard(KeyEvent::new(
+            Key::Char('c'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ));
+        assert_eq!(e.kind_detail().as_str(), "Keyboard(CTRL+SHIFT+c)");
+        let e: Event<MockEvent> =
+            Event::Keyboard(KeyEvent::new(Key::Char('k'), KeyModifiers::all()));
+        assert_eq!(
+            e.kind_detail().as_str(),
+            "Keyboard(CTRL+ALT+SHIFT+SUPER+HYPER+META+k)"
+        );
+        let e: Event<MockEvent> = Event::Keyboard(KeyEvent::from(Key::Function(5)));
+        assert_eq!(e.kind_detail().as_str(), "Keyboard(F5)");
+        let e: Event<MockEvent> = Event::Keyboard(KeyEvent::from(Key::Esc));
+        assert_eq!(e.kind_detail().as_str(), "Keyboard(Esc)");
+        let e: Event<MockEvent> = Event::WindowResize(10, 10);
+        assert_eq!(e.kind_detail().as_str(), "WindowResize");
+    }
+
     // -- serde
     #[cfg(feature = "serialize")]
     use std::fs::File;
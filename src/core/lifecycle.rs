@@ -0,0 +1,248 @@
+//! ## Lifecycle
+//!
+//! This module exposes the lifecycle events of the view components, raised by the application when
+//! components are mounted, umounted, get or lose focus.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Describes what happened to a component
+#[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd, Hash)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum LifecycleKind {
+    /// The component has been mounted
+    Mounted,
+    /// The component has been umounted
+    Umounted,
+    /// The component got focus
+    FocusGained,
+    /// The component lost focus
+    FocusLost,
+}
+
+/// A lifecycle event of the component with id `ComponentId`
+#[derive(Debug, Eq, PartialEq, Clone, PartialOrd, Hash)]
+pub enum LifecycleEvent<ComponentId> {
+    Mounted(ComponentId),
+    Umounted(ComponentId),
+    FocusGained(ComponentId),
+    FocusLost(ComponentId),
+}
+
+impl<K> LifecycleEvent<K> {
+    /// Instantiate a new `LifecycleEvent` of `kind` for component `id`
+    pub fn new(kind: LifecycleKind, id: K) -> Self {
+        match kind {
+            LifecycleKind::Mounted => Self::Mounted(id),
+            LifecycleKind::Umounted => Self::Umounted(id),
+            LifecycleKind::FocusGained => Self::FocusGained(id),
+            LifecycleKind::FocusLost => Self::FocusLost(id),
+        }
+    }
+
+    /// Returns the kind of the event
+    pub fn kind(&self) -> LifecycleKind {
+        match self {
+            Self::Mounted(_) => LifecycleKind::Mounted,
+            Self::Umounted(_) => LifecycleKind::Umounted,
+            Self::FocusGained(_) => LifecycleKind::FocusGained,
+            Self::FocusLost(_) => LifecycleKind::FocusLost,
+        }
+    }
+
+    /// Returns the id of the component
+    pub fn id(&self) -> &K {
+        match self {
+            Self::Mounted(id)
+            | Self::Umounted(id)
+            | Self::FocusGained(id)
+            | Self::FocusLost(id) => id,
+        }
+    }
+}
+
+/// A lifecycle event as carried by [`crate::Event::Lifecycle`].
+///
+/// Since events are not generic over the component id, the id is type-erased; use [`Lifecycle::event`]
+/// or [`Lifecycle::id`] with the application component id type to get it back.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Lifecycle {
+    kind: LifecycleKind,
+    id: ErasedId,
+}
+
+impl Lifecycle {
+    /// Instantiate a new `Lifecycle` of `kind` for component `id`
+    pub fn new<K>(kind: LifecycleKind, id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self {
+            kind,
+            id: ErasedId::new(id),
+        }
+    }
+
+    /// Returns the kind of the event
+    pub fn kind(&self) -> LifecycleKind {
+        self.kind
+    }
+
+    /// Returns the id of the component, if the component id is of type `K`
+    pub fn id<K: 'static>(&self) -> Option<&K> {
+        self.id.downcast()
+    }
+
+    /// Returns the event with the component id, if the component id is of type `K`
+    pub fn event<K: Clone + 'static>(&self) -> Option<LifecycleEvent<K>> {
+        self.id()
+            .map(|id: &K| LifecycleEvent::new(self.kind, id.clone()))
+    }
+
+    /// Returns whether the event is of `kind` and it's about component `id`
+    pub fn is<K: PartialEq + 'static>(&self, kind: LifecycleKind, id: &K) -> bool {
+        self.kind == kind && self.id() == Some(id)
+    }
+
+    pub(crate) fn erased_id(&self) -> &ErasedId {
+        &self.id
+    }
+}
+
+impl fmt::Debug for Lifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lifecycle")
+            .field("kind", &self.kind)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialOrd for Lifecycle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.kind.partial_cmp(&other.kind) {
+            Some(Ordering::Equal) if self.id == other.id => Some(Ordering::Equal),
+            Some(Ordering::Equal) => None,
+            ord => ord,
+        }
+    }
+}
+
+/// A type-erased component id, which can be compared with other erased ids
+#[derive(Clone)]
+pub(crate) struct ErasedId {
+    id: Arc<dyn Any + Send + Sync>,
+    eq: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl ErasedId {
+    pub fn new<K>(id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self {
+            id: Arc::new(id),
+            eq: erased_eq::<K>,
+        }
+    }
+
+    pub fn downcast<K: 'static>(&self) -> Option<&K> {
+        self.id.downcast_ref()
+    }
+}
+
+impl PartialEq for ErasedId {
+    fn eq(&self, other: &Self) -> bool {
+        (self.eq)(self.id.as_ref(), other.id.as_ref())
+    }
+}
+
+impl Eq for ErasedId {}
+
+impl fmt::Debug for ErasedId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErasedId")
+    }
+}
+
+fn erased_eq<K: PartialEq + 'static>(a: &dyn Any, b: &dyn Any) -> bool {
+    matches!((a.downcast_ref::<K>(), b.downcast_ref::<K>()), (Some(a), Some(b)) if a == b)
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::MockComponentId;
+
+    #[test]
+    fn should_get_lifecycle_event_back() {
+        let ev = Lifecycle::new(LifecycleKind::Mounted, MockComponentId::InputFoo);
+        assert_eq!(ev.kind(), LifecycleKind::Mounted);
+        assert_eq!(ev.id(), Some(&MockComponentId::InputFoo));
+        assert_eq!(ev.id::<String>(), None);
+        assert_eq!(
+            ev.event(),
+            Some(LifecycleEvent::Mounted(MockComponentId::InputFoo))
+        );
+        assert_eq!(ev.event::<u8>(), None);
+        assert!(ev.is(LifecycleKind::Mounted, &MockComponentId::InputFoo));
+        assert!(!ev.is(LifecycleKind::Umounted, &MockComponentId::InputFoo));
+        assert!(!ev.is(LifecycleKind::Mounted, &MockComponentId::InputBar));
+        assert_eq!(format!("{:?}", ev), "Lifecycle { kind: Mounted, .. }");
+    }
+
+    #[test]
+    fn should_compare_lifecycle_events() {
+        let ev = Lifecycle::new(LifecycleKind::FocusGained, MockComponentId::InputFoo);
+        assert_eq!(
+            ev,
+            Lifecycle::new(LifecycleKind::FocusGained, MockComponentId::InputFoo)
+        );
+        assert_ne!(
+            ev,
+            Lifecycle::new(LifecycleKind::FocusGained, MockComponentId::InputBar)
+        );
+        assert_ne!(
+            ev,
+            Lifecycle::new(LifecycleKind::FocusLost, MockComponentId::InputFoo)
+        );
+        // different id types are never equal
+        assert_ne!(ev, Lifecycle::new(LifecycleKind::FocusGained, 0u8));
+        assert_eq!(ev.partial_cmp(&ev.clone()), Some(Ordering::Equal));
+        assert_eq!(
+            ev.partial_cmp(&Lifecycle::new(
+                LifecycleKind::FocusGained,
+                MockComponentId::InputBar
+            )),
+            None
+        );
+        assert_eq!(
+            ev.partial_cmp(&Lifecycle::new(
+                LifecycleKind::Mounted,
+                MockComponentId::InputBar
+            )),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn should_build_typed_lifecycle_events() {
+        for kind in [
+            LifecycleKind::Mounted,
+            LifecycleKind::Umounted,
+            LifecycleKind::FocusGained,
+            LifecycleKind::FocusLost,
+        ] {
+            let ev = LifecycleEvent::new(kind, MockComponentId::InputBar);
+            assert_eq!(ev.kind(), kind);
+            assert_eq!(ev.id(), &MockComponentId::InputBar);
+        }
+    }
+}
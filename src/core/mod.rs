@@ -3,17 +3,21 @@
 //! Core implements the core functionalities and types for tui-realm
 
 pub mod application;
+mod children;
 pub mod command;
 mod component;
 pub mod event;
 pub mod injector;
+mod lifecycle;
 pub mod props;
+mod render_cache;
 mod state;
 pub mod subscription;
 mod view;
 
 // -- export
-pub use component::{Component, MockComponent};
+pub use children::Children;
+pub use component::{Component, MapUserEvent, MockComponent};
 pub use state::{State, StateValue};
 // -- internal
 pub(crate) use subscription::Subscription;
@@ -23,10 +27,7 @@ pub use view::{View, ViewError};
 // -- Update
 
 /// The update trait defines the prototype of the function to be used to handle the events coming from the View.
-pub trait Update<Msg>
-where
-    Msg: PartialEq,
-{
+pub trait Update<Msg> {
     /// update the current state handling a message from the view.
     /// This function may return a Message,
     /// so this function has to be intended to be call recursively if necessary
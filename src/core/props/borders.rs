@@ -2,16 +2,23 @@
 //!
 //! `Borders` is the module which defines the border properties
 
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
 use super::{Color, Style};
+use crate::ratatui::widgets::Block;
 // Exports
 pub use crate::ratatui::widgets::{BorderType, Borders as BorderSides};
 
 // -- Border
 
 /// Defines the properties of the borders
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub struct Borders {
+    #[cfg_attr(feature = "serialize", serde(with = "bitflags::serde"))]
     pub sides: BorderSides,
+    #[cfg_attr(feature = "serialize", serde(with = "super::remote::BorderTypeDef"))]
     pub modifiers: BorderType,
     pub color: Color,
 }
@@ -49,12 +56,25 @@ impl Borders {
     }
 }
 
+impl From<&Borders> for Block<'static> {
+    /// Build a [`Block`] with the sides, the border type and the color of the borders
+    fn from(borders: &Borders) -> Self {
+        Block::default()
+            .borders(borders.sides)
+            .border_type(borders.modifiers)
+            .border_style(borders.style())
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::ratatui::backend::TestBackend;
+    use crate::ratatui::buffer::Buffer;
+    use crate::ratatui::Terminal;
 
     #[test]
     fn borders() {
@@ -75,4 +95,39 @@ mod test {
         let style: Style = props.style();
         assert_eq!(*style.fg.as_ref().unwrap(), Color::Yellow);
     }
+
+    #[test]
+    fn should_render_borders_as_block() {
+        let borders = Borders::default()
+            .sides(BorderSides::TOP | BorderSides::LEFT)
+            .modifiers(BorderType::Rounded)
+            .color(Color::Yellow);
+        let expected = render(
+            Block::default()
+                .borders(BorderSides::TOP | BorderSides::LEFT)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        assert_eq!(render(Block::from(&borders)), expected);
+    }
+
+    fn render(block: Block<'static>) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(block, frame.area()))
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn should_serialize_borders() {
+        let borders = Borders::default()
+            .sides(BorderSides::TOP | BorderSides::LEFT)
+            .modifiers(BorderType::Rounded)
+            .color(Color::LightBlue);
+        let json = serde_json::to_string(&borders).unwrap();
+        let deserialized: Borders = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, borders);
+    }
 }
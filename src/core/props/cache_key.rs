@@ -0,0 +1,520 @@
+//! ## Cache key
+//!
+//! This module exposes the cache key computation and the hashing of attribute values
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+use super::{AttrValue, Dataset, InputType, PropPayload, PropValue, Shape};
+
+impl AttrValue {
+    /// Returns a key which identifies the attribute value, to be used in attribute-value keyed caches.
+    ///
+    /// The key is computed by hashing a canonical encoding of the value, so that equal values always
+    /// produce the same key:
+    ///
+    /// - floats are hashed by their bit pattern, with `-0.0` hashed as `0.0`;
+    /// - the entries of `PropPayload::Map` are hashed in key order;
+    /// - values which are ignored by `PartialEq` (e.g. the callbacks of `InputType::Custom` and the
+    ///   resolution of `Shape::Map`) are ignored by the key too;
+    /// - `PropPayload::Any` values are hashed by their type only, so values of the same type have the same key.
+    ///
+    /// Keys are stable for the same build, but they shouldn't be persisted across different versions.
+    pub fn cache_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_key(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the value as [`HashableAttrValue`], which implements `Eq` and `Hash` and so can be used as a key
+    /// of hash maps and sets (e.g. `HashMap<(Attribute, HashableAttrValue), Style>`).
+    ///
+    /// Returns `None` if the value contains a `NaN` float (in a `Dataset`, `Shape` or `Payload`),
+    /// since `NaN` is not equal to itself.
+    pub fn hashable(&self) -> Option<HashableAttrValue> {
+        match self.has_nan() {
+            true => None,
+            false => Some(HashableAttrValue(self.clone())),
+        }
+    }
+}
+
+/// Hash is consistent with `PartialEq`: equal values have the same hash, as for [`AttrValue::cache_key`].
+///
+/// `AttrValue` doesn't implement `Eq`, since floats may be `NaN`; use [`AttrValue::hashable`] to get a value
+/// which can be used as a key of hash maps.
+impl Hash for AttrValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash_key(state);
+    }
+}
+
+/// An [`AttrValue`] which contains no `NaN` float, so that it can implement `Eq` and `Hash`.
+/// It's created with [`AttrValue::hashable`]
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct HashableAttrValue(AttrValue);
+
+impl Eq for HashableAttrValue {}
+
+impl HashableAttrValue {
+    /// Get a reference to the attribute value
+    pub fn get(&self) -> &AttrValue {
+        &self.0
+    }
+
+    /// Get the attribute value
+    pub fn into_inner(self) -> AttrValue {
+        self.0
+    }
+}
+
+impl From<HashableAttrValue> for AttrValue {
+    fn from(value: HashableAttrValue) -> Self {
+        value.0
+    }
+}
+
+/// Hash a value with a canonical encoding, consistent with its `PartialEq` implementation
+trait CacheKey {
+    fn hash_key<H: Hasher>(&self, state: &mut H);
+
+    /// Returns whether the value contains a `NaN` float, which makes it not equal to itself
+    fn has_nan(&self) -> bool;
+}
+
+impl CacheKey for f64 {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        // NOTE: 0.0 == -0.0, so they must produce the same key
+        let value = if *self == 0.0 { 0.0 } else { *self };
+        value.to_bits().hash(state);
+    }
+
+    fn has_nan(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl CacheKey for f32 {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        let value = if *self == 0.0 { 0.0 } else { *self };
+        value.to_bits().hash(state);
+    }
+
+    fn has_nan(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl CacheKey for (f64, f64) {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_key(state);
+        self.1.hash_key(state);
+    }
+
+    fn has_nan(&self) -> bool {
+        self.0.has_nan() || self.1.has_nan()
+    }
+}
+
+impl CacheKey for AttrValue {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            AttrValue::Alignment(x) => x.hash(state),
+            AttrValue::Borders(x) => x.hash(state),
+            AttrValue::Color(x) => x.hash(state),
+            AttrValue::Dataset(x) => x.hash_key(state),
+            AttrValue::Direction(x) => x.hash(state),
+            AttrValue::Flag(x) => x.hash(state),
+            AttrValue::InputType(x) => x.hash_key(state),
+            AttrValue::Layout(x) => x.hash(state),
+            AttrValue::Length(x) => x.hash(state),
+            AttrValue::Number(x) => x.hash(state),
+            AttrValue::Palette(x) => x.hash(state),
+            AttrValue::Shape(x) => x.hash_key(state),
+            AttrValue::Size(x) => x.hash(state),
+            AttrValue::SizeSpec(x) => x.hash(state),
+            AttrValue::String(x) => x.hash(state),
+            AttrValue::Style(x) => x.hash(state),
+            AttrValue::Table(x) => x.hash(state),
+            AttrValue::TableSpec(x) => x.hash(state),
+            AttrValue::Text(x) => x.hash(state),
+            AttrValue::TextModifiers(x) => x.hash(state),
+            AttrValue::TextWrap(x) => x.hash(state),
+            AttrValue::Title(x) => x.hash(state),
+            AttrValue::Payload(x) => x.hash_key(state),
+            AttrValue::Unset => {}
+        }
+    }
+
+    fn has_nan(&self) -> bool {
+        match self {
+            AttrValue::Dataset(x) => x.has_nan(),
+            AttrValue::InputType(x) => x.has_nan(),
+            AttrValue::Shape(x) => x.has_nan(),
+            AttrValue::Payload(x) => x.has_nan(),
+            _ => false,
+        }
+    }
+}
+
+impl CacheKey for Dataset {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.marker.hash(state);
+        self.graph_type.hash(state);
+        self.style.hash(state);
+        self.get_data().len().hash(state);
+        self.get_data().iter().for_each(|x| x.hash_key(state));
+    }
+
+    fn has_nan(&self) -> bool {
+        self.get_data().iter().any(CacheKey::has_nan)
+    }
+}
+
+impl CacheKey for InputType {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            InputType::Password(ch) | InputType::CustomPassword(ch, _, _) => ch.hash(state),
+            _ => {}
+        }
+    }
+
+    fn has_nan(&self) -> bool {
+        false
+    }
+}
+
+impl CacheKey for Shape {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Shape::Label((x, y, text, color)) => {
+                x.hash_key(state);
+                y.hash_key(state);
+                text.hash(state);
+                color.hash(state);
+            }
+            Shape::Layer => {}
+            Shape::Line(line) => {
+                line.x1.hash_key(state);
+                line.x2.hash_key(state);
+                line.y1.hash_key(state);
+                line.y2.hash_key(state);
+                line.color.hash(state);
+            }
+            Shape::Map(map) => map.color.hash(state),
+            Shape::Points((points, color)) => {
+                points.len().hash(state);
+                points.iter().for_each(|x| x.hash_key(state));
+                color.hash(state);
+            }
+            Shape::Rectangle(rect) => {
+                rect.x.hash_key(state);
+                rect.y.hash_key(state);
+                rect.width.hash_key(state);
+                rect.height.hash_key(state);
+                rect.color.hash(state);
+            }
+        }
+    }
+
+    fn has_nan(&self) -> bool {
+        match self {
+            Shape::Label((x, y, _, _)) => x.has_nan() || y.has_nan(),
+            Shape::Layer | Shape::Map(_) => false,
+            Shape::Line(line) => [line.x1, line.x2, line.y1, line.y2]
+                .iter()
+                .any(CacheKey::has_nan),
+            Shape::Points((points, _)) => points.iter().any(CacheKey::has_nan),
+            Shape::Rectangle(rect) => [rect.x, rect.y, rect.width, rect.height]
+                .iter()
+                .any(CacheKey::has_nan),
+        }
+    }
+}
+
+impl CacheKey for PropPayload {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            PropPayload::One(x) => x.hash_key(state),
+            PropPayload::Tup2((a, b)) => {
+                a.hash_key(state);
+                b.hash_key(state);
+            }
+            PropPayload::Tup3((a, b, c)) => {
+                a.hash_key(state);
+                b.hash_key(state);
+                c.hash_key(state);
+            }
+            PropPayload::Tup4((a, b, c, d)) => {
+                a.hash_key(state);
+                b.hash_key(state);
+                c.hash_key(state);
+                d.hash_key(state);
+            }
+            PropPayload::Vec(values) => {
+                values.len().hash(state);
+                values.iter().for_each(|x| x.hash_key(state));
+            }
+            PropPayload::Map(map) => {
+                // NOTE: map iteration order is not deterministic, so entries are hashed sorted by key
+                let mut entries: Vec<(&String, &PropValue)> = map.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                entries.len().hash(state);
+                for (key, value) in entries {
+                    key.hash(state);
+                    value.hash_key(state);
+                }
+            }
+            PropPayload::Linked(list) => {
+                list.len().hash(state);
+                list.iter().for_each(|x| x.hash_key(state));
+            }
+            // NOTE: the value can't be hashed, but equal values have the same type
+            PropPayload::Any(x) => x.value_type_id().hash(state),
+            PropPayload::None => {}
+        }
+    }
+
+    fn has_nan(&self) -> bool {
+        match self {
+            PropPayload::One(x) => x.has_nan(),
+            PropPayload::Tup2((a, b)) => a.has_nan() || b.has_nan(),
+            PropPayload::Tup3((a, b, c)) => a.has_nan() || b.has_nan() || c.has_nan(),
+            PropPayload::Tup4((a, b, c, d)) => {
+                a.has_nan() || b.has_nan() || c.has_nan() || d.has_nan()
+            }
+            PropPayload::Vec(values) => values.iter().any(CacheKey::has_nan),
+            PropPayload::Map(map) => map.values().any(CacheKey::has_nan),
+            PropPayload::Linked(list) => list.iter().any(CacheKey::has_nan),
+            PropPayload::Any(_) | PropPayload::None => false,
+        }
+    }
+}
+
+impl CacheKey for PropValue {
+    fn hash_key<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            PropValue::Bool(x) => x.hash(state),
+            PropValue::U8(x) => x.hash(state),
+            PropValue::U16(x) => x.hash(state),
+            PropValue::U32(x) => x.hash(state),
+            PropValue::U64(x) => x.hash(state),
+            PropValue::U128(x) => x.hash(state),
+            PropValue::Usize(x) => x.hash(state),
+            PropValue::I8(x) => x.hash(state),
+            PropValue::I16(x) => x.hash(state),
+            PropValue::I32(x) => x.hash(state),
+            PropValue::I64(x) => x.hash(state),
+            PropValue::I128(x) => x.hash(state),
+            PropValue::Isize(x) => x.hash(state),
+            PropValue::F64(x) => x.hash_key(state),
+            PropValue::F32(x) => x.hash_key(state),
+            PropValue::Str(x) => x.hash(state),
+            PropValue::Alignment(x) => x.hash(state),
+            PropValue::Color(x) => x.hash(state),
+            PropValue::Dataset(x) => x.hash_key(state),
+            PropValue::InputType(x) => x.hash_key(state),
+            PropValue::Shape(x) => x.hash_key(state),
+            PropValue::Style(x) => x.hash(state),
+            PropValue::Table(x) => x.hash(state),
+            PropValue::TextSpan(x) => x.hash(state),
+        }
+    }
+
+    fn has_nan(&self) -> bool {
+        match self {
+            PropValue::F64(x) => x.has_nan(),
+            PropValue::F32(x) => x.has_nan(),
+            PropValue::Dataset(x) => x.has_nan(),
+            PropValue::Shape(x) => x.has_nan(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::props::{
+        Alignment, Attribute, Borders, Color, ColumnSpec, Palette, SizeSpec, Style, TableSpec,
+        TextModifiers, TextSpan, TextWrap,
+    };
+    use crate::ratatui::layout::Constraint;
+    use crate::ratatui::widgets::canvas::{Line, Map, MapResolution};
+
+    fn sample() -> Vec<AttrValue> {
+        vec![
+            AttrValue::Alignment(Alignment::Center),
+            AttrValue::Borders(Borders::default()),
+            AttrValue::Borders(Borders::default().color(Color::Red)),
+            AttrValue::Color(Color::Red),
+            AttrValue::Color(Color::Blue),
+            AttrValue::Dataset(Dataset::default().data(vec![(0.0, 1.0), (1.0, 2.0)])),
+            AttrValue::Dataset(Dataset::default().data(vec![(0.0, 1.0), (1.0, 2.5)])),
+            AttrValue::Flag(true),
+            AttrValue::Flag(false),
+            AttrValue::InputType(InputType::Password('*')),
+            AttrValue::InputType(InputType::Password('#')),
+            AttrValue::Length(1),
+            AttrValue::Number(1),
+            AttrValue::Palette(Palette::default().color("focus", Color::Red)),
+            AttrValue::Palette(Palette::default().color("focus", Color::Blue)),
+            AttrValue::Size(1),
+            AttrValue::SizeSpec(SizeSpec::Cells(1)),
+            AttrValue::SizeSpec(SizeSpec::Percent(1)),
+            AttrValue::Shape(Shape::Line(Line::new(0.0, 0.0, 1.0, 1.0, Color::Red))),
+            AttrValue::Shape(Shape::Line(Line::new(0.0, 0.0, 1.0, 2.0, Color::Red))),
+            AttrValue::String(String::from("omar")),
+            AttrValue::String(String::from("omaR")),
+            AttrValue::Style(Style::default()),
+            AttrValue::Style(Style::default().fg(Color::Yellow)),
+            AttrValue::Table(vec![vec![TextSpan::from("a")]]),
+            AttrValue::Table(vec![vec![TextSpan::from("a")], vec![]]),
+            AttrValue::TableSpec(TableSpec::new(
+                vec![ColumnSpec::new("a", Constraint::Length(1))],
+                vec![vec![TextSpan::from("a")]],
+            )),
+            AttrValue::TableSpec(TableSpec::new(
+                vec![ColumnSpec::new("a", Constraint::Length(2))],
+                vec![vec![TextSpan::from("a")]],
+            )),
+            AttrValue::Text(TextSpan::from("a")),
+            AttrValue::TextModifiers(TextModifiers::BOLD),
+            AttrValue::TextWrap(TextWrap::Word),
+            AttrValue::TextWrap(TextWrap::WordBreak),
+            AttrValue::Title((String::from("omar"), Alignment::Left)),
+            AttrValue::Payload(PropPayload::None),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(1.0))),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(1.5))),
+            AttrValue::Payload(PropPayload::One(PropValue::F32(1.0))),
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::U8(1), PropValue::U8(2)])),
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::U8(2), PropValue::U8(1)])),
+            AttrValue::any(1_u8),
+            AttrValue::any(String::from("omar")),
+            AttrValue::Unset,
+        ]
+    }
+
+    #[test]
+    fn equal_attr_values_should_have_equal_cache_keys() {
+        for value in sample() {
+            assert_eq!(value.cache_key(), value.clone().cache_key());
+        }
+        // signed zero
+        assert_eq!(
+            AttrValue::Payload(PropPayload::One(PropValue::F64(0.0))).cache_key(),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(-0.0))).cache_key()
+        );
+        // map order
+        let mut a = HashMap::new();
+        let mut b = HashMap::new();
+        for i in 0..32 {
+            a.insert(i.to_string(), PropValue::Usize(i));
+            b.insert((31 - i).to_string(), PropValue::Usize(31 - i));
+        }
+        assert_eq!(
+            AttrValue::Payload(PropPayload::Map(a)).cache_key(),
+            AttrValue::Payload(PropPayload::Map(b)).cache_key()
+        );
+        // values ignored by eq
+        assert_eq!(
+            AttrValue::Shape(Shape::Map(Map {
+                resolution: MapResolution::Low,
+                color: Color::Red
+            }))
+            .cache_key(),
+            AttrValue::Shape(Shape::Map(Map {
+                resolution: MapResolution::High,
+                color: Color::Red
+            }))
+            .cache_key()
+        );
+        assert_eq!(
+            AttrValue::InputType(InputType::Custom(|_| true, |_, _| true)).cache_key(),
+            AttrValue::InputType(InputType::Custom(|_| false, |_, _| false)).cache_key()
+        );
+    }
+
+    #[test]
+    fn hash_should_be_consistent_with_eq() {
+        fn hash(value: &impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let values = sample();
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(a == b, i == j);
+                assert_eq!(hash(a) == hash(b), i == j);
+            }
+            assert_eq!(hash(a), hash(&a.clone()));
+            let hashable = a.hashable().unwrap();
+            assert_eq!(hashable.get(), a);
+            assert_eq!(hash(&hashable), hash(a));
+        }
+        // signed zero
+        assert_eq!(
+            hash(&AttrValue::Payload(PropPayload::One(PropValue::F64(0.0)))),
+            hash(&AttrValue::Payload(PropPayload::One(PropValue::F64(-0.0))))
+        );
+    }
+
+    #[test]
+    fn attr_values_with_nan_should_not_be_hashable() {
+        assert!(
+            AttrValue::Payload(PropPayload::One(PropValue::F64(f64::NAN)))
+                .hashable()
+                .is_none()
+        );
+        assert!(AttrValue::Payload(PropPayload::Vec(vec![
+            PropValue::U8(1),
+            PropValue::F32(f32::NAN)
+        ]))
+        .hashable()
+        .is_none());
+        assert!(
+            AttrValue::Dataset(Dataset::default().data(vec![(0.0, f64::NAN)]))
+                .hashable()
+                .is_none()
+        );
+        assert!(
+            AttrValue::Shape(Shape::Line(Line::new(0.0, f64::NAN, 1.0, 1.0, Color::Red)))
+                .hashable()
+                .is_none()
+        );
+        // hashable values can be used as keys
+        let mut styles: HashMap<(Attribute, HashableAttrValue), Style> = HashMap::new();
+        let key = AttrValue::Payload(PropPayload::One(PropValue::F64(1.5)))
+            .hashable()
+            .unwrap();
+        styles.insert((Attribute::Value, key.clone()), Style::default());
+        assert!(styles.contains_key(&(Attribute::Value, key.clone())));
+        assert_eq!(
+            key.into_inner(),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(1.5)))
+        );
+    }
+
+    #[test]
+    fn different_attr_values_should_not_collide() {
+        let keys: Vec<u64> = sample().iter().map(AttrValue::cache_key).collect();
+        for (i, a) in keys.iter().enumerate() {
+            for b in keys.iter().skip(i + 1) {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}
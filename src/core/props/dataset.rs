@@ -2,18 +2,27 @@
 //!
 //! `Dataset` is a wrapper for tui dataset
 
+use std::collections::VecDeque;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
 use super::Style;
 use crate::ratatui::symbols::Marker;
 use crate::ratatui::widgets::{Dataset as TuiDataset, GraphType};
 
 /// Dataset describes a set of data for a chart
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub struct Dataset {
     pub name: String,
+    #[cfg_attr(feature = "serialize", serde(with = "super::remote::MarkerDef"))]
     pub marker: Marker,
+    #[cfg_attr(feature = "serialize", serde(with = "super::remote::GraphTypeDef"))]
     pub graph_type: GraphType,
     pub style: Style,
-    data: Vec<(f64, f64)>,
+    /// Records of the dataset; always kept contiguous, so that they can be borrowed as a slice
+    data: VecDeque<(f64, f64)>,
 }
 
 impl Default for Dataset {
@@ -23,7 +32,7 @@ impl Default for Dataset {
             marker: Marker::Dot,
             graph_type: GraphType::Scatter,
             style: Style::default(),
-            data: Vec::default(),
+            data: VecDeque::default(),
         }
     }
 }
@@ -55,30 +64,82 @@ impl Dataset {
 
     /// Set data for dataset; must be a vec of (f64, f64)
     pub fn data(mut self, data: Vec<(f64, f64)>) -> Self {
-        self.data = data;
+        self.data = VecDeque::from(data);
         self
     }
 
     /// Push a record to the back of dataset
     pub fn push(&mut self, point: (f64, f64)) {
-        self.data.push(point);
+        self.data.push_back(point);
+        self.data.make_contiguous();
+    }
+
+    /// Push a record to the back of dataset, dropping the records at the front so that
+    /// the dataset holds at most `max_len` records (e.g. to keep the last samples of a stream).
+    ///
+    /// The records are dropped in constant amortized time, so it can be called for each sample of a large stream
+    pub fn push_bounded(&mut self, point: (f64, f64), max_len: usize) {
+        if max_len == 0 {
+            self.data.clear();
+            return;
+        }
+        if self.data.len() >= max_len {
+            self.data.drain(..=self.data.len() - max_len);
+            // room for other `max_len` records, so that the records are moved back to the front
+            // at most once every `max_len` pushes
+            self.data.reserve(max_len);
+        }
+        self.push(point);
     }
 
     /// Pop last element of dataset
     pub fn pop(&mut self) {
-        self.data.pop();
+        self.data.pop_back();
     }
 
-    /// Pop last element of dataset
+    /// Pop first element of dataset
     pub fn pop_front(&mut self) {
-        if !self.data.is_empty() {
-            self.data.remove(0);
-        }
+        self.data.pop_front();
     }
 
     /// Get a reference to data
     pub fn get_data(&self) -> &[(f64, f64)] {
-        &self.data
+        // the records are always contiguous, so they're all in the first slice
+        self.data.as_slices().0
+    }
+
+    /// Get a reference to the last `n` records of data, or to all the records if there are less than `n`
+    pub fn last_n(&self, n: usize) -> &[(f64, f64)] {
+        let data = self.get_data();
+        &data[data.len().saturating_sub(n)..]
+    }
+
+    /// Get the bounds of the records, as `((min_x, max_x), (min_y, max_y))`, e.g. to scale the axes of a chart.
+    /// `NaN` coordinates are ignored.
+    ///
+    /// Returns `None` if the dataset is empty
+    pub fn min_max(&self) -> Option<((f64, f64), (f64, f64))> {
+        let (&(x, y), data) = self.get_data().split_first()?;
+        Some(data.iter().fold(
+            ((x, x), (y, y)),
+            |((min_x, max_x), (min_y, max_y)), &(x, y)| {
+                ((min_x.min(x), max_x.max(x)), (min_y.min(y), max_y.max(y)))
+            },
+        ))
+    }
+
+    /// Get a reference to `len` records of data, starting from the record at `start`.
+    /// The window is clamped to the data, so it may contain less than `len` records.
+    pub fn get_data_window(&self, start: usize, len: usize) -> &[(f64, f64)] {
+        let data = self.get_data();
+        let start = start.min(data.len());
+        let end = start.saturating_add(len).min(data.len());
+        &data[start..end]
+    }
+
+    /// Convert the dataset into a ratatui dataset with the records in the window returned by [`Dataset::get_data_window`]
+    pub fn to_tui_window(&self, start: usize, len: usize) -> TuiDataset<'_> {
+        TuiDataset::from(self).data(self.get_data_window(start, len))
     }
 }
 
@@ -105,7 +166,11 @@ mod test {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::ratatui::backend::TestBackend;
+    use crate::ratatui::buffer::Buffer;
     use crate::ratatui::style::Color;
+    use crate::ratatui::widgets::{Axis, Chart};
+    use crate::ratatui::Terminal;
 
     #[test]
     fn dataset() {
@@ -141,4 +206,119 @@ mod test {
         // From
         let _: TuiDataset = TuiDataset::from(&dataset);
     }
+
+    #[test]
+    fn should_get_data_window() {
+        let dataset = Dataset::default().data(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 9.0)]);
+        assert_eq!(dataset.get_data_window(1, 2), &[(1.0, 1.0), (2.0, 4.0)]);
+        assert_eq!(dataset.get_data_window(2, 10), &[(2.0, 4.0), (3.0, 9.0)]);
+        assert!(dataset.get_data_window(10, 2).is_empty());
+        assert!(dataset.get_data_window(1, 0).is_empty());
+    }
+
+    #[test]
+    fn should_push_bounded_records() {
+        let mut dataset = Dataset::default();
+        let mut expected: Vec<(f64, f64)> = Vec::new();
+        for i in 0..100 {
+            let point = (i as f64, (i * i) as f64);
+            dataset.push_bounded(point, 7);
+            expected.push(point);
+            if expected.len() > 7 {
+                expected.remove(0);
+            }
+            assert_eq!(dataset.get_data(), expected.as_slice());
+            // windows match the ones of the records in a vec
+            assert_eq!(
+                dataset.get_data_window(2, 3),
+                &expected[2.min(expected.len())..5.min(expected.len())]
+            );
+        }
+        // the records wrap around the buffer and are still compared and cloned as a sequence
+        assert_eq!(dataset, Dataset::default().data(expected.clone()));
+        assert_eq!(dataset.clone().get_data(), expected.as_slice());
+        // a smaller bound drops the exceeding records
+        dataset.push_bounded((100.0, 0.0), 3);
+        assert_eq!(
+            dataset.get_data(),
+            &[(98.0, 9604.0), (99.0, 9801.0), (100.0, 0.0)]
+        );
+        dataset.push_bounded((101.0, 0.0), 0);
+        assert!(dataset.get_data().is_empty());
+        // mixed with the other operations
+        let mut dataset = Dataset::default().data(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+        dataset.pop_front();
+        dataset.push((3.0, 3.0));
+        dataset.push_bounded((4.0, 4.0), 3);
+        dataset.pop();
+        assert_eq!(dataset.get_data(), &[(2.0, 2.0), (3.0, 3.0)]);
+    }
+
+    #[test]
+    fn should_get_last_records() {
+        let mut dataset = Dataset::default();
+        assert!(dataset.last_n(3).is_empty());
+        for i in 0..10 {
+            dataset.push_bounded((i as f64, 0.0), 5);
+        }
+        assert_eq!(dataset.last_n(2), &[(8.0, 0.0), (9.0, 0.0)]);
+        assert_eq!(dataset.last_n(10), dataset.get_data());
+        assert!(dataset.last_n(0).is_empty());
+    }
+
+    #[test]
+    fn should_get_bounds_of_records() {
+        assert_eq!(Dataset::default().min_max(), None);
+        let dataset = Dataset::default().data(vec![(1.0, 4.0)]);
+        assert_eq!(dataset.min_max(), Some(((1.0, 1.0), (4.0, 4.0))));
+        let mut dataset =
+            Dataset::default().data(vec![(2.0, -1.0), (0.0, 3.0), (f64::NAN, 7.0), (5.0, 2.0)]);
+        assert_eq!(dataset.min_max(), Some(((0.0, 5.0), (-1.0, 7.0))));
+        dataset.push_bounded((6.0, 0.0), 2);
+        assert_eq!(dataset.min_max(), Some(((5.0, 6.0), (0.0, 2.0))));
+    }
+
+    #[test]
+    fn should_render_dataset_as_tui_dataset() {
+        let dataset = Dataset::default()
+            .name("squares")
+            .graph_type(GraphType::Line)
+            .marker(Marker::Dot)
+            .style(Style::default().fg(Color::Cyan))
+            .data(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 9.0)]);
+        let expected = render(
+            TuiDataset::default()
+                .name("squares")
+                .graph_type(GraphType::Line)
+                .marker(Marker::Dot)
+                .style(Style::default().fg(Color::Cyan))
+                .data(&[(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 9.0)]),
+        );
+        assert_eq!(render(TuiDataset::from(&dataset)), expected);
+        // window
+        let expected = render(
+            TuiDataset::default()
+                .name("squares")
+                .graph_type(GraphType::Line)
+                .marker(Marker::Dot)
+                .style(Style::default().fg(Color::Cyan))
+                .data(&[(1.0, 1.0), (2.0, 4.0)]),
+        );
+        assert_eq!(render(dataset.to_tui_window(1, 2)), expected);
+    }
+
+    fn render(dataset: TuiDataset) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Chart::new(vec![dataset])
+                        .x_axis(Axis::default().bounds([0.0, 3.0]))
+                        .y_axis(Axis::default().bounds([0.0, 9.0])),
+                    frame.area(),
+                )
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
 }
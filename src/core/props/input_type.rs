@@ -5,15 +5,25 @@
 use std::fmt;
 use std::str::FromStr;
 
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
 use crate::utils::parser::{parse_color, parse_email, parse_phone_number};
 
 /// Input type for text inputs
+///
+/// When serialized, `Custom` and `CustomPassword` values can't be serialized and make the serialization fail.
 #[derive(Clone)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub enum InputType {
     /// Color input. Syntax can be css-color, `rgb(rrr, ggg, bbb)` or `#rrggbb`
     Color,
     /// Email input. Must contain a valid email address
     Email,
+    /// Float number, with an optional sign and at most one decimal point (e.g. `-4.5`)
+    Float,
+    /// Hexadecimal number, without prefix; both lowercase and uppercase digits are valid
+    Hex,
     /// Number. Can be signed and float
     Number,
     /// Text field; text will be shadowed by provided char
@@ -32,10 +42,12 @@ pub enum InputType {
     /// while the `char_valid()` callback is used to tell whether the input char is allowed to be pushed to input value
     /// (e.g. an email address is `valid` if contains the name, the '@' and the domain;
     /// but `char_valid` allows characters, numbers, symbol and up to one '@')
+    #[cfg_attr(feature = "serialize", serde(skip))]
     Custom(fn(&str) -> bool, fn(&str, char) -> bool),
     /// Custom validation password; text will be shadowed by provided char
     /// You must provide the function to call on `validate` and the function to call on `char_valid`.
     /// See `Custom` for callbacks meaning
+    #[cfg_attr(feature = "serialize", serde(skip))]
     CustomPassword(char, fn(&str) -> bool, fn(&str, char) -> bool),
 }
 
@@ -44,6 +56,8 @@ impl PartialEq for InputType {
         match (self, other) {
             (Self::Color, Self::Color) => true,
             (Self::Email, Self::Email) => true,
+            (Self::Float, Self::Float) => true,
+            (Self::Hex, Self::Hex) => true,
             (Self::Number, Self::Number) => true,
             (Self::Password(ch), Self::Password(ch2)) => ch == ch2,
             (Self::Telephone, Self::Telephone) => true,
@@ -64,6 +78,8 @@ impl fmt::Debug for InputType {
             Self::Custom(..) => write!(f, "InputType::Custom"),
             Self::CustomPassword(c, _, _) => write!(f, "InputType::CustomPassword({})", c),
             Self::Email => write!(f, "InputType::Email"),
+            Self::Float => write!(f, "InputType::Float"),
+            Self::Hex => write!(f, "InputType::Hex"),
             Self::Number => write!(f, "InputType::Number"),
             Self::Password(ch) => write!(f, "InputType::Password({})", ch),
             Self::SignedInteger => write!(f, "InputType::SignedInteger"),
@@ -80,6 +96,12 @@ impl InputType {
         match self {
             Self::Color => Self::char_valid_for_color(input, c),
             Self::Email => Self::char_valid_for_email(input, c),
+            Self::Float => {
+                c.is_ascii_digit()
+                    || (['+', '-'].contains(&c) && input.is_empty())
+                    || (c == '.' && !input.contains('.'))
+            }
+            Self::Hex => c.is_ascii_hexdigit(),
             Self::Number => {
                 c.is_ascii_digit() || (['+', '-'].contains(&c) && input.is_empty()) || c == '.'
             }
@@ -100,6 +122,13 @@ impl InputType {
         match self {
             Self::Color => parse_color(s).is_some(),
             Self::Email => parse_email(s).is_some(),
+            // NOTE: `f64::from_str` also accepts exponents, infinity and NaN
+            Self::Float => {
+                s.chars()
+                    .all(|c| c.is_ascii_digit() || ['+', '-', '.'].contains(&c))
+                    && f64::from_str(s).is_ok()
+            }
+            Self::Hex => !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit()),
             Self::Number => f64::from_str(s).is_ok(),
             Self::SignedInteger => isize::from_str(s).is_ok(),
             Self::UnsignedInteger => usize::from_str(s).is_ok(),
@@ -280,6 +309,64 @@ mod test {
         assert_eq!(InputType::UnsignedInteger.char_valid("24", '5'), true);
     }
 
+    #[test]
+    fn validate_float_and_hex() {
+        assert_eq!(InputType::Float.validate("-4.5"), true);
+        assert_eq!(InputType::Float.validate("+4"), true);
+        assert_eq!(InputType::Float.validate(".5"), true);
+        assert_eq!(InputType::Float.validate("4."), true);
+        assert_eq!(InputType::Float.validate("4.5.1"), false);
+        assert_eq!(InputType::Float.validate("--4"), false);
+        assert_eq!(InputType::Float.validate("4-"), false);
+        assert_eq!(InputType::Float.validate("1e5"), false);
+        assert_eq!(InputType::Float.validate("inf"), false);
+        assert_eq!(InputType::Float.validate("NaN"), false);
+        assert_eq!(InputType::Float.validate(""), false);
+        assert_eq!(InputType::Float.validate("-"), false);
+        assert_eq!(InputType::Float.char_valid("", '-'), true);
+        assert_eq!(InputType::Float.char_valid("-", '-'), false);
+        assert_eq!(InputType::Float.char_valid("-4", '.'), true);
+        assert_eq!(InputType::Float.char_valid("-4.5", '.'), false);
+        assert_eq!(InputType::Float.char_valid("-4.5", '1'), true);
+        assert_eq!(InputType::Float.char_valid("4", 'e'), false);
+        // hex
+        assert_eq!(InputType::Hex.validate("ff00bb"), true);
+        assert_eq!(InputType::Hex.validate("FF00BB"), true);
+        assert_eq!(InputType::Hex.validate("C0fFeE"), true);
+        assert_eq!(InputType::Hex.validate("0xff"), false);
+        assert_eq!(InputType::Hex.validate("fg"), false);
+        assert_eq!(InputType::Hex.validate("-ff"), false);
+        assert_eq!(InputType::Hex.validate(""), false);
+        assert_eq!(InputType::Hex.char_valid("", 'A'), true);
+        assert_eq!(InputType::Hex.char_valid("a", '9'), true);
+        assert_eq!(InputType::Hex.char_valid("a", 'g'), false);
+        assert_eq!(InputType::Hex.char_valid("", '#'), false);
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn should_serialize_input_types_without_callbacks() {
+        for input_type in [
+            InputType::Color,
+            InputType::Email,
+            InputType::Float,
+            InputType::Hex,
+            InputType::Number,
+            InputType::Password('*'),
+            InputType::SignedInteger,
+            InputType::Telephone,
+            InputType::Text,
+            InputType::UnsignedInteger,
+        ] {
+            let json = serde_json::to_string(&input_type).unwrap();
+            let deserialized: InputType = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, input_type);
+        }
+        assert!(
+            serde_json::to_string(&InputType::Custom(custom_valid, custom_char_valid)).is_err()
+        );
+    }
+
     #[test]
     fn validate_input_char_text() {
         assert_eq!(InputType::Text.char_valid("", 'a'), true);
@@ -2,14 +2,24 @@
 //!
 //! This module exposes the layout type
 
+use std::collections::HashMap;
+
+use super::SizeSpec;
 use crate::ratatui::layout::{Constraint, Direction, Layout as TuiLayout, Margin, Rect};
 
 /// Defines how a layout has to be rendered
-#[derive(Debug, PartialEq, Clone, Eq)]
+///
+/// Chunks can optionally be named, in order to be resolved with [`Layout::chunks_named`],
+/// and each chunk can be split further by a nested layout.
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub struct Layout {
     constraints: Vec<Constraint>,
     direction: Direction,
     margin: Margin,
+    /// Chunk names, indexed as constraints
+    names: Vec<Option<String>>,
+    /// Nested layouts, indexed as constraints
+    children: Vec<Option<Layout>>,
 }
 
 impl Default for Layout {
@@ -21,6 +31,8 @@ impl Default for Layout {
                 horizontal: 0,
                 vertical: 0,
             },
+            names: Vec::new(),
+            children: Vec::new(),
         }
     }
 }
@@ -28,8 +40,31 @@ impl Default for Layout {
 impl Layout {
     // -- constructors
 
+    /// Set the constraints of the layout.
+    /// The chunk names and the nested layouts previously set are cleared.
     pub fn constraints(mut self, constraints: &[Constraint]) -> Self {
         self.constraints = constraints.to_vec();
+        self.names = Vec::new();
+        self.children = Vec::new();
+        self
+    }
+
+    /// Set the constraints of the layout from the sizes of the chunks.
+    /// The chunk names and the nested layouts previously set are cleared.
+    ///
+    /// ```rust
+    /// use tuirealm::props::{Layout, SizeSpec};
+    /// use tuirealm::ratatui::layout::Rect;
+    ///
+    /// let chunks = Layout::default()
+    ///     .sizes(&[SizeSpec::Percent(25), SizeSpec::Min(0)])
+    ///     .chunks(Rect::new(0, 0, 80, 24));
+    /// assert_eq!(chunks[0].height, 6);
+    /// ```
+    pub fn sizes(mut self, sizes: &[SizeSpec]) -> Self {
+        self.constraints = sizes.iter().copied().map(Constraint::from).collect();
+        self.names = Vec::new();
+        self.children = Vec::new();
         self
     }
 
@@ -56,24 +91,95 @@ impl Layout {
         self
     }
 
+    /// Set the constraints of the layout, giving a name to each chunk
+    ///
+    /// ```rust
+    /// use tuirealm::props::Layout;
+    /// use tuirealm::ratatui::layout::{Constraint, Rect};
+    ///
+    /// let chunks = Layout::default()
+    ///     .children_named(vec![("header", Constraint::Length(3)), ("body", Constraint::Min(0))])
+    ///     .chunks_named(Rect::new(0, 0, 80, 24));
+    /// assert_eq!(chunks["body"], Rect::new(0, 3, 80, 21));
+    /// ```
+    pub fn children_named<S: Into<String>>(mut self, children: Vec<(S, Constraint)>) -> Self {
+        let (names, constraints): (Vec<Option<String>>, Vec<Constraint>) = children
+            .into_iter()
+            .map(|(name, constraint)| (Some(name.into()), constraint))
+            .unzip();
+        self.constraints = constraints;
+        self.names = names;
+        self.children = Vec::new();
+        self
+    }
+
+    /// Split the chunk named `name` with a nested `layout`.
+    ///
+    /// If there is no chunk named `name`, the nested layout is ignored;
+    /// so set the named chunks with [`Layout::children_named`] first.
+    pub fn nest<S: AsRef<str>>(mut self, name: S, layout: Layout) -> Self {
+        let name = name.as_ref();
+        let Some(index) = self.names.iter().position(|x| x.as_deref() == Some(name)) else {
+            return self;
+        };
+        if self.children.len() <= index {
+            self.children.resize(index + 1, None);
+        }
+        self.children[index] = Some(layout);
+        self
+    }
+
     // -- chunks
 
     /// Split an `Area` into chunks using the current layout configuration
     pub fn chunks(&self, area: Rect) -> Vec<Rect> {
+        TuiLayout::from(self).split(area).to_vec()
+    }
+
+    /// Split an `Area` into chunks using the current layout configuration, returning the area of each named chunk,
+    /// including the chunks of nested layouts.
+    ///
+    /// Names must be unique across nested layouts, otherwise the area of the innermost chunk is returned.
+    pub fn chunks_named(&self, area: Rect) -> HashMap<String, Rect> {
+        let mut named = HashMap::new();
+        self.resolve_named(area, &mut named);
+        named
+    }
+
+    fn resolve_named(&self, area: Rect, named: &mut HashMap<String, Rect>) {
+        for (i, chunk) in self.chunks(area).into_iter().enumerate() {
+            if let Some(Some(name)) = self.names.get(i) {
+                named.insert(name.clone(), chunk);
+            }
+            if let Some(Some(child)) = self.children.get(i) {
+                child.resolve_named(chunk, named);
+            }
+        }
+    }
+}
+
+impl From<&Layout> for TuiLayout {
+    /// Build the ratatui layout with the direction, the margin and the constraints of the layout.
+    /// Nested layouts are not part of the returned layout; use [`Layout::chunks_named`] to resolve them.
+    fn from(layout: &Layout) -> Self {
         TuiLayout::default()
-            .direction(self.direction)
-            .horizontal_margin(self.margin.horizontal)
-            .vertical_margin(self.margin.vertical)
-            .constraints::<&[Constraint]>(self.constraints.as_ref())
-            .split(area)
-            .to_vec()
+            .direction(layout.direction)
+            .horizontal_margin(layout.margin.horizontal)
+            .vertical_margin(layout.margin.vertical)
+            .constraints::<&[Constraint]>(layout.constraints.as_ref())
     }
 }
 
 #[cfg(test)]
 mod test {
 
+    use pretty_assertions::assert_eq;
+
     use super::*;
+    use crate::ratatui::backend::TestBackend;
+    use crate::ratatui::buffer::Buffer;
+    use crate::ratatui::widgets::Block;
+    use crate::ratatui::Terminal;
 
     #[test]
     fn should_build_a_layout() {
@@ -90,4 +196,149 @@ mod test {
             ]);
         assert_eq!(layout.chunks(area).len(), 3);
     }
+
+    #[test]
+    fn should_build_a_layout_from_sizes() {
+        let area = Rect::new(0, 0, 80, 24);
+        let layout = Layout::default().direction(Direction::Horizontal).sizes(&[
+            SizeSpec::Cells(10),
+            SizeSpec::Ratio(1, 0),
+            SizeSpec::Percent(25),
+        ]);
+        assert_eq!(
+            layout.chunks(area),
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(&[
+                    Constraint::Length(10),
+                    Constraint::Ratio(1, 1),
+                    Constraint::Percentage(25),
+                ])
+                .chunks(area)
+        );
+    }
+
+    #[test]
+    fn should_resolve_named_chunks() {
+        let area = Rect::new(0, 0, 80, 24);
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .children_named(vec![
+                ("sidebar", Constraint::Length(30)),
+                ("main", Constraint::Min(0)),
+            ]);
+        let named = layout.chunks_named(area);
+        let chunks = layout.chunks(area);
+        assert_eq!(named.len(), 2);
+        assert_eq!(named["sidebar"], chunks[0]);
+        assert_eq!(named["main"], chunks[1]);
+        // unnamed layouts have no named chunks
+        assert!(Layout::default()
+            .constraints(&[Constraint::Min(0)])
+            .chunks_named(area)
+            .is_empty());
+    }
+
+    #[test]
+    fn should_resolve_nested_layouts() {
+        let area = Rect::new(0, 0, 80, 24);
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .children_named(vec![
+                ("sidebar", Constraint::Length(30)),
+                ("main", Constraint::Min(0)),
+            ])
+            .nest(
+                "main",
+                Layout::default()
+                    .margin(1)
+                    .children_named(vec![
+                        ("header", Constraint::Length(3)),
+                        ("body", Constraint::Min(0)),
+                    ])
+                    .nest(
+                        "body",
+                        Layout::default()
+                            .direction(Direction::Horizontal)
+                            .children_named(vec![
+                                ("left", Constraint::Percentage(50)),
+                                ("right", Constraint::Percentage(50)),
+                            ]),
+                    ),
+            );
+        // compose manually
+        let outer = TuiLayout::horizontal([Constraint::Length(30), Constraint::Min(0)]).split(area);
+        let main = TuiLayout::vertical([Constraint::Length(3), Constraint::Min(0)])
+            .margin(1)
+            .split(outer[1]);
+        let body = TuiLayout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main[1]);
+        let named = layout.chunks_named(area);
+        assert_eq!(named.len(), 6);
+        assert_eq!(named["sidebar"], outer[0]);
+        assert_eq!(named["main"], outer[1]);
+        assert_eq!(named["header"], main[0]);
+        assert_eq!(named["body"], main[1]);
+        assert_eq!(named["left"], body[0]);
+        assert_eq!(named["right"], body[1]);
+    }
+
+    #[test]
+    fn should_render_with_tui_layout() {
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .horizontal_margin(1)
+            .vertical_margin(2)
+            .constraints(&[Constraint::Length(8), Constraint::Min(0)]);
+        let expected = render(
+            TuiLayout::horizontal([Constraint::Length(8), Constraint::Min(0)])
+                .horizontal_margin(1)
+                .vertical_margin(2),
+        );
+        assert_eq!(render(TuiLayout::from(&layout)), expected);
+        assert_eq!(
+            layout.chunks(Rect::new(0, 0, 30, 10)),
+            TuiLayout::from(&layout)
+                .split(Rect::new(0, 0, 30, 10))
+                .to_vec()
+        );
+    }
+
+    fn render(layout: TuiLayout) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(30, 10)).unwrap();
+        terminal
+            .draw(|frame| {
+                for chunk in layout.split(frame.area()).iter() {
+                    frame.render_widget(Block::bordered(), *chunk);
+                }
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    #[test]
+    fn should_ignore_nested_layout_of_unknown_chunk() {
+        let layout = Layout::default().children_named(vec![("main", Constraint::Min(0))]);
+        assert_eq!(
+            layout.clone().nest("sidebar", Layout::default().margin(1)),
+            layout
+        );
+    }
+
+    #[test]
+    fn should_clear_names_and_nested_layouts_when_setting_constraints() {
+        let area = Rect::new(0, 0, 80, 24);
+        let named = Layout::default()
+            .children_named(vec![("main", Constraint::Min(0))])
+            .nest(
+                "main",
+                Layout::default().children_named(vec![("body", Constraint::Min(0))]),
+            );
+        assert_eq!(named.chunks_named(area).len(), 2);
+        let layout = named.clone().constraints(&[Constraint::Min(0)]);
+        assert!(layout.chunks_named(area).is_empty());
+        assert_eq!(layout, Layout::default().constraints(&[Constraint::Min(0)]));
+        let layout = named.sizes(&[SizeSpec::Min(0)]);
+        assert!(layout.chunks_named(area).is_empty());
+    }
 }
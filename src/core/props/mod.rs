@@ -2,27 +2,38 @@
 //!
 //! the props module expose the Properties supported by the components and all the values they can get.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
 
 // -- modules
 mod borders;
+mod cache_key;
 mod dataset;
 mod direction;
 mod input_type;
 mod layout;
+mod palette;
+#[cfg(feature = "serialize")]
+mod remote;
 mod shape;
+mod size;
+mod text_wrap;
 mod texts;
 mod value;
 
 // -- exports
 pub use borders::{BorderSides, BorderType, Borders};
+pub use cache_key::HashableAttrValue;
 pub use dataset::Dataset;
 pub use direction::Direction;
 pub use input_type::InputType;
 pub use layout::Layout;
+pub use palette::Palette;
 pub use shape::Shape;
-pub use texts::{Table, TableBuilder, TextSpan};
-pub use value::{PropPayload, PropValue};
+pub use size::SizeSpec;
+pub use text_wrap::TextWrap;
+pub use texts::{table_to_lines, ColumnSpec, Table, TableBuilder, TableExt, TableSpec, TextSpan};
+pub use value::{AnyProp, AnyPropValue, PropPayload, PropValue};
 
 pub use crate::ratatui::layout::Alignment;
 pub use crate::ratatui::style::{Color, Modifier as TextModifiers, Style};
@@ -47,9 +58,42 @@ impl Props {
         self.get(query).unwrap_or(default)
     }
 
-    /// Set a new attribute into Properties
+    /// Set a new attribute into Properties.
+    /// If `value` is [`AttrValue::Unset`], the attribute is removed instead
     pub fn set(&mut self, query: Attribute, value: AttrValue) {
-        self.attrs.insert(query, value);
+        if value == AttrValue::Unset {
+            self.attrs.remove(&query);
+        } else {
+            self.attrs.insert(query, value);
+        }
+    }
+
+    /// Remove the attribute associated to the selector.
+    /// Returns the removed value, if any
+    pub fn remove(&mut self, query: Attribute) -> Option<AttrValue> {
+        self.attrs.remove(&query)
+    }
+
+    /// Returns whether an attribute is associated to the selector
+    pub fn has(&self, query: Attribute) -> bool {
+        self.attrs.contains_key(&query)
+    }
+
+    /// Get, if any, the attribute associated to the selector if it's an [`AnyProp`] of type `T`.
+    /// Returns `None` if the attribute is not set or if it has a different type
+    pub fn get_custom<T: std::any::Any>(&self, query: Attribute) -> Option<&T> {
+        self.attrs.get(&query).and_then(AttrValue::downcast_ref)
+    }
+
+    /// Get, if any, a mutable reference to the attribute associated to the selector if it's an [`AnyProp`] of type `T`.
+    /// Returns `None` if the attribute is not set or if it has a different type
+    pub fn get_custom_mut<T: std::any::Any>(&mut self, query: Attribute) -> Option<&mut T> {
+        self.attrs.get_mut(&query).and_then(AttrValue::downcast_mut)
+    }
+
+    /// Iterate over all the attributes set in Properties
+    pub fn iter(&self) -> impl Iterator<Item = (&Attribute, &AttrValue)> {
+        self.attrs.iter()
     }
 }
 
@@ -57,7 +101,9 @@ impl Props {
 /// The selector must identify uniquely an attribute in the properties.
 /// Check each attribute documentation to see how they're supposed to be used, but remember that
 /// when implementing a component, you're free to use each attribute as you prefer!
-#[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd, Hash)]
+///
+/// Attributes are ordered by declaration; [`Attribute::Custom`] attributes come last, ordered by name.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, PartialOrd, Ord, Hash)]
 pub enum Attribute {
     /// Layout alignment
     Alignment,
@@ -67,6 +113,10 @@ pub enum Attribute {
     Borders,
     /// Main color
     Color,
+    /// Whether the component consumes the keyboard input while it's focused (e.g. a text input).
+    /// When set to `AttrValue::Flag(true)` on the active component, keyboard events are not forwarded to the subscriptions,
+    /// so global key bindings don't fire while typing; the other events are still forwarded.
+    ConsumesInput,
     /// Component content. Generic purpose
     Content,
     /// Dataset of component; should be associated to `AttrValue::Dataset`
@@ -100,8 +150,13 @@ pub enum Attribute {
     InputType,
     /// Defines a layout
     Layout,
-    /// A map of colors for complex components
+    /// A map of colors for complex components; its value should be `AttrValue::Palette`
     Palette,
+    /// Whether the component receives the keyboard events as they're read, without the translation of the
+    /// application keymap (e.g. a text input or a key binding editor).
+    /// When set to `AttrValue::Flag(true)` on the active component, it receives the original keys, while the subscriptions
+    /// still receive the translated events.
+    RawInput,
     /// Intended to decide whether to rewind when reaching boundaries on list/tables
     Rewind,
     /// Intended to store a `AttrValue::Shape`
@@ -126,10 +181,38 @@ pub enum Attribute {
     Value,
     /// Component width; useful when using containers or layouts
     Width,
-    /// A user defined property
+    /// A user defined property.
+    /// Use [`Attribute::custom`] to create a custom attribute from a name known only at runtime.
     Custom(&'static str),
 }
 
+impl Attribute {
+    /// Create a [`Attribute::Custom`] from a name which may be known only at runtime (e.g. loaded from configuration).
+    ///
+    /// Names are interned, so the same name always produces the same attribute and the attribute
+    /// compares equal to `Attribute::Custom` created with a static string with the same name.
+    ///
+    /// > Each distinct name is allocated once and never freed, so don't use this with unbounded sets of names.
+    pub fn custom<S: AsRef<str>>(name: S) -> Self {
+        Self::Custom(intern_custom_attribute(name.as_ref()))
+    }
+}
+
+/// Intern a custom attribute name, returning a static reference to it
+fn intern_custom_attribute(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    names.insert(name);
+    name
+}
+
 // -- AttrValues
 
 /// Describes a single attribute in the component properties.
@@ -146,19 +229,50 @@ pub enum AttrValue {
     Layout(Layout),
     Length(usize),
     Number(isize),
+    Palette(Palette),
     Shape(Shape),
     Size(u16),
+    /// A size relative to the space available in the parent
+    SizeSpec(SizeSpec),
     String(String),
     Style(Style),
     Table(Table),
+    /// A table with the description of its columns
+    TableSpec(TableSpec),
     Text(TextSpan),
     TextModifiers(TextModifiers),
+    TextWrap(TextWrap),
     Title((String, Alignment)),
     /// User defined complex attribute value
     Payload(PropPayload),
+    /// Sentinel value used to remove an attribute.
+    /// When passed to [`Props::set`], the attribute is removed from properties.
+    /// Use `Application::remove_attr` to remove an attribute from a mounted component.
+    Unset,
 }
 
 impl AttrValue {
+    /// Create an [`AttrValue::Payload`] containing `value` as [`AnyProp`]
+    pub fn any<T: AnyPropValue>(value: T) -> Self {
+        AttrValue::Payload(PropPayload::any(value))
+    }
+
+    /// Get a reference to the value if it's a [`PropPayload::Any`] of type `T`, or None
+    pub fn downcast_ref<T: std::any::Any>(&self) -> Option<&T> {
+        match self {
+            AttrValue::Payload(payload) => payload.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Get a mutable reference to the value if it's a [`PropPayload::Any`] of type `T`, or None
+    pub fn downcast_mut<T: std::any::Any>(&mut self) -> Option<&mut T> {
+        match self {
+            AttrValue::Payload(payload) => payload.downcast_mut(),
+            _ => None,
+        }
+    }
+
     // -- unwrappers
 
     pub fn unwrap_alignment(self) -> Alignment {
@@ -231,6 +345,13 @@ impl AttrValue {
         }
     }
 
+    pub fn unwrap_palette(self) -> Palette {
+        match self {
+            AttrValue::Palette(x) => x,
+            _ => panic!("AttrValue is not Palette"),
+        }
+    }
+
     pub fn unwrap_shape(self) -> Shape {
         match self {
             AttrValue::Shape(x) => x,
@@ -245,6 +366,15 @@ impl AttrValue {
         }
     }
 
+    /// Unwrap a [`AttrValue::SizeSpec`]; a [`AttrValue::Size`] is unwrapped as [`SizeSpec::Cells`]
+    pub fn unwrap_size_spec(self) -> SizeSpec {
+        match self {
+            AttrValue::SizeSpec(x) => x,
+            AttrValue::Size(x) => SizeSpec::Cells(x),
+            _ => panic!("AttrValue is not SizeSpec"),
+        }
+    }
+
     pub fn unwrap_string(self) -> String {
         match self {
             AttrValue::String(x) => x,
@@ -266,6 +396,13 @@ impl AttrValue {
         }
     }
 
+    pub fn unwrap_table_spec(self) -> TableSpec {
+        match self {
+            AttrValue::TableSpec(x) => x,
+            _ => panic!("AttrValue is not TableSpec"),
+        }
+    }
+
     pub fn unwrap_text(self) -> TextSpan {
         match self {
             AttrValue::Text(x) => x,
@@ -280,6 +417,13 @@ impl AttrValue {
         }
     }
 
+    pub fn unwrap_text_wrap(self) -> TextWrap {
+        match self {
+            AttrValue::TextWrap(x) => x,
+            _ => panic!("AttrValue is not TextWrap"),
+        }
+    }
+
     pub fn unwrap_title(self) -> (String, Alignment) {
         match self {
             AttrValue::Title(x) => x,
@@ -298,6 +442,8 @@ impl AttrValue {
 #[cfg(test)]
 mod test {
 
+    use std::collections::BTreeMap;
+
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -332,8 +478,17 @@ mod test {
         );
         assert_eq!(AttrValue::Length(12).unwrap_length(), 12);
         assert_eq!(AttrValue::Number(-24).unwrap_number(), -24);
+        assert_eq!(
+            AttrValue::Palette(Palette::default().color("focus", Color::Red)).unwrap_palette(),
+            Palette::default().color("focus", Color::Red)
+        );
         assert_eq!(AttrValue::Shape(Shape::Layer).unwrap_shape(), Shape::Layer);
         assert_eq!(AttrValue::Size(12).unwrap_size(), 12);
+        assert_eq!(
+            AttrValue::SizeSpec(SizeSpec::Percent(30)).unwrap_size_spec(),
+            SizeSpec::Percent(30)
+        );
+        assert_eq!(AttrValue::Size(12).unwrap_size_spec(), SizeSpec::Cells(12));
         assert_eq!(
             AttrValue::String(String::from("pippo")).unwrap_string(),
             String::from("pippo")
@@ -346,6 +501,10 @@ mod test {
             AttrValue::Table(Table::default()).unwrap_table(),
             Table::default()
         );
+        assert_eq!(
+            AttrValue::TableSpec(TableSpec::default()).unwrap_table_spec(),
+            TableSpec::default()
+        );
         assert_eq!(
             AttrValue::Text(TextSpan::default()).unwrap_text(),
             TextSpan::default()
@@ -354,6 +513,10 @@ mod test {
             AttrValue::TextModifiers(TextModifiers::BOLD).unwrap_text_modifiers(),
             TextModifiers::BOLD
         );
+        assert_eq!(
+            AttrValue::TextWrap(TextWrap::Word).unwrap_text_wrap(),
+            TextWrap::Word
+        );
         assert_eq!(
             AttrValue::Title((String::from("pippo"), Alignment::Left)).unwrap_title(),
             (String::from("pippo"), Alignment::Left)
@@ -364,6 +527,82 @@ mod test {
         );
     }
 
+    #[test]
+    fn custom_attribute_should_be_created_at_runtime() {
+        let name = format!("column-{}-width", 3);
+        let attr = Attribute::custom(&name);
+        assert_eq!(attr, Attribute::Custom("column-3-width"));
+        assert_eq!(attr, Attribute::custom(name.clone()));
+        assert_ne!(attr, Attribute::custom("column-4-width"));
+        // names are interned
+        if let (Attribute::Custom(a), Attribute::Custom(b)) =
+            (Attribute::custom(&name), Attribute::custom(&name))
+        {
+            assert!(std::ptr::eq(a, b));
+        }
+        // lookup
+        let mut props = Props::default();
+        props.set(Attribute::custom(&name), AttrValue::Size(12));
+        assert_eq!(
+            props.get(Attribute::Custom("column-3-width")),
+            Some(AttrValue::Size(12))
+        );
+        let mut map = HashMap::new();
+        map.insert(Attribute::Custom("column-3-width"), 1);
+        assert_eq!(map.get(&Attribute::custom(name)), Some(&1));
+    }
+
+    #[test]
+    fn attributes_should_be_ordered() {
+        assert!(Attribute::Alignment < Attribute::Background);
+        assert!(Attribute::Width < Attribute::Custom("a"));
+        assert!(Attribute::Custom("a") < Attribute::custom("b"));
+        assert_eq!(
+            Attribute::custom("a").cmp(&Attribute::Custom("a")),
+            std::cmp::Ordering::Equal
+        );
+        let attrs: BTreeMap<Attribute, usize> = [
+            (Attribute::Custom("b"), 0),
+            (Attribute::Value, 1),
+            (Attribute::Custom("a"), 2),
+            (Attribute::Alignment, 3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            attrs.keys().copied().collect::<Vec<Attribute>>(),
+            vec![
+                Attribute::Alignment,
+                Attribute::Value,
+                Attribute::Custom("a"),
+                Attribute::Custom("b")
+            ]
+        );
+    }
+
+    #[test]
+    fn props_should_set_remove_and_iter_attributes() {
+        let mut props = Props::default();
+        props.set(Attribute::Display, AttrValue::Flag(false));
+        props.set(Attribute::Custom("error-highlight"), AttrValue::Flag(true));
+        assert!(props.has(Attribute::Display));
+        assert_eq!(props.iter().count(), 2);
+        // remove
+        assert_eq!(
+            props.remove(Attribute::Custom("error-highlight")),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(props.remove(Attribute::Custom("error-highlight")), None);
+        // unset
+        props.set(Attribute::Display, AttrValue::Unset);
+        assert_eq!(props.has(Attribute::Display), false);
+        assert_eq!(
+            props.get_or(Attribute::Display, AttrValue::Flag(true)),
+            AttrValue::Flag(true)
+        );
+        assert_eq!(props.iter().count(), 0);
+    }
+
     #[test]
     #[should_panic]
     fn unwrapping_alignment_should_panic_if_not_identity() {
@@ -424,6 +663,12 @@ mod test {
         AttrValue::Flag(true).unwrap_number();
     }
 
+    #[test]
+    #[should_panic]
+    fn unwrapping_palette_should_panic_if_not_identity() {
+        AttrValue::Flag(true).unwrap_palette();
+    }
+
     #[test]
     #[should_panic]
     fn unwrapping_shape_should_panic_if_not_identity() {
@@ -477,4 +722,45 @@ mod test {
     fn unwrapping_payload_should_panic_if_not_identity() {
         AttrValue::Flag(true).unwrap_payload();
     }
+
+    #[test]
+    fn should_get_custom_props() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Config {
+            max_len: usize,
+        }
+
+        let mut props = Props::default();
+        props.set(
+            Attribute::Custom("config"),
+            AttrValue::any(Config { max_len: 16 }),
+        );
+        props.set(Attribute::Text, AttrValue::String(String::from("omar")));
+        assert_eq!(
+            props.get_custom::<Config>(Attribute::Custom("config")),
+            Some(&Config { max_len: 16 })
+        );
+        // wrong type or not an any prop
+        assert!(props
+            .get_custom::<String>(Attribute::Custom("config"))
+            .is_none());
+        assert!(props.get_custom::<String>(Attribute::Text).is_none());
+        assert!(props.get_custom::<Config>(Attribute::Value).is_none());
+        // mutate
+        props
+            .get_custom_mut::<Config>(Attribute::Custom("config"))
+            .unwrap()
+            .max_len = 32;
+        assert_eq!(
+            props
+                .get(Attribute::Custom("config"))
+                .unwrap()
+                .downcast_ref::<Config>(),
+            Some(&Config { max_len: 32 })
+        );
+        let mut value = props.get(Attribute::Custom("config")).unwrap();
+        value.downcast_mut::<Config>().unwrap().max_len = 64;
+        assert_eq!(value, AttrValue::any(Config { max_len: 64 }));
+        assert_eq!(AttrValue::Flag(true).downcast_ref::<bool>(), None);
+    }
 }
@@ -0,0 +1,193 @@
+//! ## Palette
+//!
+//! `Palette` is the module which defines the palette property, a map of named colors
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::{Color, Style};
+
+/// A map of named colors (e.g. `highlight`, `focus`, `error`), used by complex components which need more colors
+/// than foreground and background.
+/// Should be used with `Attribute::Palette`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(transparent)
+)]
+pub struct Palette {
+    colors: HashMap<String, Color>,
+}
+
+impl Palette {
+    /// Set `color` for `key`
+    pub fn color<S: AsRef<str>>(mut self, key: S, color: Color) -> Self {
+        self.set(key, color);
+        self
+    }
+
+    /// Set `color` for `key`, replacing the previous color, if any
+    pub fn set<S: AsRef<str>>(&mut self, key: S, color: Color) {
+        self.colors.insert(key.as_ref().to_string(), color);
+    }
+
+    /// Get the color for `key`
+    pub fn get(&self, key: &str) -> Option<Color> {
+        self.colors.get(key).copied()
+    }
+
+    /// Get the color for `key` or `default` if the palette has no color for `key`
+    pub fn get_or(&self, key: &str, default: Color) -> Color {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Get a style with the color for `key` as foreground.
+    /// If the palette has no color for `key`, the style is empty
+    pub fn fg_of(&self, key: &str) -> Style {
+        match self.get(key) {
+            Some(color) => Style::default().fg(color),
+            None => Style::default(),
+        }
+    }
+
+    /// Get a style with the color for `key` as background.
+    /// If the palette has no color for `key`, the style is empty
+    pub fn bg_of(&self, key: &str) -> Style {
+        match self.get(key) {
+            Some(color) => Style::default().bg(color),
+            None => Style::default(),
+        }
+    }
+
+    /// Returns whether the palette has a color for `key`
+    pub fn has(&self, key: &str) -> bool {
+        self.colors.contains_key(key)
+    }
+
+    /// Remove the color for `key`, returning it
+    pub fn remove(&mut self, key: &str) -> Option<Color> {
+        self.colors.remove(key)
+    }
+
+    /// Iterate over the colors of the palette, in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.colors
+            .iter()
+            .map(|(key, color)| (key.as_str(), *color))
+    }
+
+    /// Returns the amount of colors in the palette
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns whether the palette has no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
+impl Hash for Palette {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // NOTE: map iteration order is not deterministic, so entries are hashed sorted by key
+        let mut entries: Vec<(&String, &Color)> = self.colors.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries.hash(state);
+    }
+}
+
+impl From<HashMap<&str, Color>> for Palette {
+    fn from(colors: HashMap<&str, Color>) -> Self {
+        colors.into_iter().collect()
+    }
+}
+
+impl From<HashMap<String, Color>> for Palette {
+    fn from(colors: HashMap<String, Color>) -> Self {
+        Self { colors }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, Color)> for Palette {
+    fn from_iter<T: IntoIterator<Item = (S, Color)>>(iter: T) -> Self {
+        Self {
+            colors: iter
+                .into_iter()
+                .map(|(key, color)| (key.as_ref().to_string(), color))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn palette() {
+        let mut palette = Palette::default()
+            .color("highlight", Color::Yellow)
+            .color("focus", Color::LightBlue);
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.get("highlight"), Some(Color::Yellow));
+        assert_eq!(palette.get("error"), None);
+        assert_eq!(palette.get_or("error", Color::Red), Color::Red);
+        assert_eq!(palette.get_or("focus", Color::Red), Color::LightBlue);
+        assert_eq!(
+            palette.fg_of("highlight"),
+            Style::default().fg(Color::Yellow)
+        );
+        assert_eq!(
+            palette.bg_of("focus"),
+            Style::default().bg(Color::LightBlue)
+        );
+        assert_eq!(palette.fg_of("error"), Style::default());
+        assert_eq!(palette.bg_of("error"), Style::default());
+        palette.set("highlight", Color::Cyan);
+        assert_eq!(palette.get("highlight"), Some(Color::Cyan));
+        assert!(palette.has("focus"));
+        assert_eq!(palette.remove("focus"), Some(Color::LightBlue));
+        assert_eq!(palette.has("focus"), false);
+        assert_eq!(
+            palette.iter().collect::<Vec<_>>(),
+            vec![("highlight", Color::Cyan)]
+        );
+        assert_eq!(Palette::default().is_empty(), true);
+    }
+
+    #[test]
+    fn palette_from_map() {
+        let palette = Palette::from(HashMap::from([
+            ("highlight", Color::Yellow),
+            ("focus", Color::LightBlue),
+        ]));
+        assert_eq!(
+            palette,
+            Palette::default()
+                .color("focus", Color::LightBlue)
+                .color("highlight", Color::Yellow)
+        );
+        assert_eq!(
+            Palette::from(HashMap::from([(String::from("focus"), Color::LightBlue)])),
+            Palette::default().color("focus", Color::LightBlue)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn should_serialize_palette() {
+        let palette = Palette::default()
+            .color("highlight", Color::Rgb(32, 32, 32))
+            .color("focus", Color::LightBlue);
+        let json = serde_json::to_string(&palette).unwrap();
+        let deserialized: Palette = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, palette);
+    }
+}
@@ -0,0 +1,46 @@
+//! ## Remote
+//!
+//! Serde definitions for the ratatui types used by the props which don't implement `Serialize` and `Deserialize`
+
+use serde::{Deserialize, Serialize};
+
+use crate::ratatui::layout::Alignment;
+use crate::ratatui::symbols::Marker;
+use crate::ratatui::widgets::{BorderType, GraphType};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Alignment")]
+pub(crate) enum AlignmentDef {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "BorderType")]
+pub(crate) enum BorderTypeDef {
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+    QuadrantInside,
+    QuadrantOutside,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "GraphType")]
+pub(crate) enum GraphTypeDef {
+    Scatter,
+    Line,
+    Bar,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Marker")]
+pub(crate) enum MarkerDef {
+    Dot,
+    Block,
+    Bar,
+    Braille,
+    HalfBlock,
+}
@@ -2,8 +2,9 @@
 //!
 //! This module exposes the shape attribute type
 
-use super::Color;
-use crate::ratatui::widgets::canvas::{Line, Map, Rectangle};
+use super::{Color, Style};
+use crate::ratatui::text::Span;
+use crate::ratatui::widgets::canvas::{Context, Line, Map, Points, Rectangle};
 
 /// Describes the shape to draw on the canvas
 #[derive(Clone, Debug)]
@@ -16,6 +17,32 @@ pub enum Shape {
     Rectangle(Rectangle),
 }
 
+impl Shape {
+    /// Draw the shape on the canvas `ctx`, e.g. in the painter of a [`crate::ratatui::widgets::canvas::Canvas`]:
+    ///
+    /// - a [`Shape::Label`] is printed with its color as foreground;
+    /// - a [`Shape::Layer`] saves the shapes drawn so far into a new layer.
+    pub fn draw(&self, ctx: &mut Context) {
+        match self {
+            Shape::Label((x, y, text, color)) => {
+                ctx.print(
+                    *x,
+                    *y,
+                    Span::styled(text.clone(), Style::default().fg(*color)),
+                );
+            }
+            Shape::Layer => ctx.layer(),
+            Shape::Line(line) => ctx.draw(line),
+            Shape::Map(map) => ctx.draw(map),
+            Shape::Points((coords, color)) => ctx.draw(&Points {
+                coords,
+                color: *color,
+            }),
+            Shape::Rectangle(rectangle) => ctx.draw(rectangle),
+        }
+    }
+}
+
 impl PartialEq for Shape {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -37,3 +64,72 @@ impl PartialEq for Shape {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ratatui::backend::TestBackend;
+    use crate::ratatui::buffer::Buffer;
+    use crate::ratatui::widgets::canvas::Canvas;
+    use crate::ratatui::Terminal;
+
+    #[test]
+    fn should_draw_shapes_on_canvas() {
+        let shapes = [
+            Shape::Line(Line::new(0.0, 0.0, 10.0, 10.0, Color::Red)),
+            Shape::Layer,
+            Shape::Rectangle(Rectangle {
+                x: 2.0,
+                y: 2.0,
+                width: 4.0,
+                height: 3.0,
+                color: Color::Green,
+            }),
+            Shape::Points((vec![(1.0, 8.0), (8.0, 1.0)], Color::Blue)),
+            Shape::Label((5.0, 9.0, String::from("hi"), Color::Yellow)),
+        ];
+        let expected = render(|ctx| {
+            ctx.draw(&Line::new(0.0, 0.0, 10.0, 10.0, Color::Red));
+            ctx.layer();
+            ctx.draw(&Rectangle {
+                x: 2.0,
+                y: 2.0,
+                width: 4.0,
+                height: 3.0,
+                color: Color::Green,
+            });
+            ctx.draw(&Points {
+                coords: &[(1.0, 8.0), (8.0, 1.0)],
+                color: Color::Blue,
+            });
+            ctx.print(
+                5.0,
+                9.0,
+                Span::styled("hi", Style::default().fg(Color::Yellow)),
+            );
+        });
+        assert_eq!(
+            render(|ctx| shapes.iter().for_each(|shape| shape.draw(ctx))),
+            expected
+        );
+    }
+
+    fn render<F: Fn(&mut Context)>(painter: F) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Canvas::default()
+                        .x_bounds([0.0, 10.0])
+                        .y_bounds([0.0, 10.0])
+                        .paint(painter),
+                    frame.area(),
+                )
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+}
@@ -0,0 +1,152 @@
+//! ## Size
+//!
+//! This module exposes the size specification, used to size the components relatively to their parent
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::ratatui::layout::Constraint;
+
+/// Describes the size of a component (e.g. its [`super::Attribute::Width`]) relatively to the space available
+/// in its parent.
+///
+/// Degenerate specifications never panic: percentages above 100 and ratios above 1 are clamped to the available space,
+/// and a ratio with a zero denominator takes the whole available space.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
+pub enum SizeSpec {
+    /// A fixed amount of cells
+    Cells(u16),
+    /// A percentage of the available space
+    Percent(u8),
+    /// A fraction of the available space (numerator, denominator)
+    Ratio(u16, u16),
+    /// At least the provided amount of cells; takes all the available space
+    Min(u16),
+    /// At most the provided amount of cells
+    Max(u16),
+}
+
+impl SizeSpec {
+    /// Resolve the amount of cells the size takes out of the `available` cells.
+    ///
+    /// The size never exceeds `available`, so [`SizeSpec::Min`] takes all the available space,
+    /// while [`SizeSpec::Cells`] and [`SizeSpec::Max`] take up to their amount of cells.
+    pub fn resolve(&self, available: u16) -> u16 {
+        match *self {
+            Self::Cells(cells) | Self::Max(cells) => cells.min(available),
+            Self::Percent(percent) => Self::fraction(available, u32::from(percent.min(100)), 100),
+            Self::Ratio(_, 0) | Self::Min(_) => available,
+            Self::Ratio(num, den) => {
+                Self::fraction(available, u32::from(num.min(den)), u32::from(den))
+            }
+        }
+    }
+
+    /// Returns `num / den` of `available`, rounded down
+    fn fraction(available: u16, num: u32, den: u32) -> u16 {
+        (u32::from(available) * num / den) as u16
+    }
+}
+
+impl From<u16> for SizeSpec {
+    fn from(cells: u16) -> Self {
+        Self::Cells(cells)
+    }
+}
+
+impl From<SizeSpec> for Constraint {
+    fn from(size: SizeSpec) -> Self {
+        match size {
+            SizeSpec::Cells(cells) => Constraint::Length(cells),
+            SizeSpec::Percent(percent) => Constraint::Percentage(u16::from(percent.min(100))),
+            SizeSpec::Ratio(_, 0) => Constraint::Ratio(1, 1),
+            SizeSpec::Ratio(num, den) => Constraint::Ratio(u32::from(num.min(den)), u32::from(den)),
+            SizeSpec::Min(cells) => Constraint::Min(cells),
+            SizeSpec::Max(cells) => Constraint::Max(cells),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_resolve_sizes() {
+        assert_eq!(SizeSpec::Cells(10).resolve(80), 10);
+        assert_eq!(SizeSpec::Cells(100).resolve(80), 80);
+        assert_eq!(SizeSpec::Percent(30).resolve(80), 24);
+        assert_eq!(SizeSpec::Percent(100).resolve(80), 80);
+        assert_eq!(SizeSpec::Ratio(1, 3).resolve(80), 26);
+        assert_eq!(SizeSpec::Ratio(2, 2).resolve(80), 80);
+        assert_eq!(SizeSpec::Min(10).resolve(80), 80);
+        assert_eq!(SizeSpec::Min(100).resolve(80), 80);
+        assert_eq!(SizeSpec::Max(10).resolve(80), 10);
+        assert_eq!(SizeSpec::Max(100).resolve(80), 80);
+        assert_eq!(SizeSpec::from(12), SizeSpec::Cells(12));
+        // no overflow
+        assert_eq!(SizeSpec::Percent(99).resolve(u16::MAX), 64879);
+        assert_eq!(
+            SizeSpec::Ratio(u16::MAX - 1, u16::MAX).resolve(u16::MAX),
+            u16::MAX - 1
+        );
+    }
+
+    #[test]
+    fn should_clamp_degenerate_sizes() {
+        assert_eq!(SizeSpec::Percent(0).resolve(80), 0);
+        assert_eq!(SizeSpec::Percent(250).resolve(80), 80);
+        assert_eq!(SizeSpec::Ratio(0, 3).resolve(80), 0);
+        assert_eq!(SizeSpec::Ratio(1, 0).resolve(80), 80);
+        assert_eq!(SizeSpec::Ratio(0, 0).resolve(80), 80);
+        assert_eq!(SizeSpec::Ratio(5, 2).resolve(80), 80);
+        assert_eq!(SizeSpec::Cells(0).resolve(80), 0);
+        for size in [
+            SizeSpec::Cells(10),
+            SizeSpec::Percent(30),
+            SizeSpec::Ratio(1, 0),
+            SizeSpec::Min(10),
+            SizeSpec::Max(10),
+        ] {
+            assert_eq!(size.resolve(0), 0);
+        }
+    }
+
+    #[test]
+    fn should_convert_sizes_into_constraints() {
+        assert_eq!(
+            Constraint::from(SizeSpec::Cells(10)),
+            Constraint::Length(10)
+        );
+        assert_eq!(
+            Constraint::from(SizeSpec::Percent(30)),
+            Constraint::Percentage(30)
+        );
+        assert_eq!(
+            Constraint::from(SizeSpec::Percent(250)),
+            Constraint::Percentage(100)
+        );
+        assert_eq!(
+            Constraint::from(SizeSpec::Ratio(1, 3)),
+            Constraint::Ratio(1, 3)
+        );
+        assert_eq!(
+            Constraint::from(SizeSpec::Ratio(1, 0)),
+            Constraint::Ratio(1, 1)
+        );
+        assert_eq!(
+            Constraint::from(SizeSpec::Ratio(5, 2)),
+            Constraint::Ratio(2, 2)
+        );
+        assert_eq!(Constraint::from(SizeSpec::Min(10)), Constraint::Min(10));
+        assert_eq!(Constraint::from(SizeSpec::Max(10)), Constraint::Max(10));
+    }
+}
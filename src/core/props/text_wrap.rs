@@ -0,0 +1,17 @@
+//! ## TextWrap
+//!
+//! Describes how text is wrapped when it doesn't fit into a line
+
+/// Defines how the text longer than the available width is wrapped into lines
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum TextWrap {
+    /// Text is not wrapped; only newlines start a new line
+    #[default]
+    None,
+    /// Lines are broken at the last char which fits into the line
+    Char,
+    /// Lines are broken at whitespaces; words longer than a line are kept whole
+    Word,
+    /// Lines are broken at whitespaces; words longer than a line are split
+    WordBreak,
+}
@@ -3,14 +3,21 @@
 //! `Texts` is the module which defines the texts properties for components.
 //! It also provides some helpers and builders to facilitate the use of builders.
 
-use crate::ratatui::style::{Color, Modifier};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::ratatui::layout::{Alignment, Constraint};
+use crate::ratatui::style::{Color, Modifier, Style};
+use crate::ratatui::text::{Line, Span};
+use crate::ratatui::widgets::{Cell, Row};
 
 // -- Text parts
 
 /// ### TextSpan
 ///
 /// TextSpan is a "cell" of text with its attributes
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub struct TextSpan {
     pub content: String,
     pub fg: Color,
@@ -29,6 +36,24 @@ impl TextSpan {
         }
     }
 
+    /// Instantiate a new `TextSpan` with the colors and the modifiers of `style`
+    pub fn styled<S: AsRef<str>>(text: S, style: Style) -> Self {
+        Self {
+            content: text.as_ref().to_string(),
+            fg: style.fg.unwrap_or(Color::Reset),
+            bg: style.bg.unwrap_or(Color::Reset),
+            modifiers: style.add_modifier - style.sub_modifier,
+        }
+    }
+
+    /// Get the style of the `TextSpan`
+    pub fn style(&self) -> Style {
+        Style::default()
+            .fg(self.fg)
+            .bg(self.bg)
+            .add_modifier(self.modifiers)
+    }
+
     pub fn fg(mut self, fg: Color) -> Self {
         self.fg = fg;
         self
@@ -97,15 +122,178 @@ where
     }
 }
 
+impl From<TextSpan> for Span<'static> {
+    fn from(span: TextSpan) -> Self {
+        let style = span.style();
+        Span::styled(span.content, style)
+    }
+}
+
+impl From<&TextSpan> for Span<'static> {
+    fn from(span: &TextSpan) -> Self {
+        Span::styled(span.content.clone(), span.style())
+    }
+}
+
 /// Table represents a list of rows with a list of columns of text spans
 pub type Table = Vec<Vec<TextSpan>>;
 
+/// Accessors for the rows and the cells of a [`Table`]
+pub trait TableExt {
+    /// Get the rows of the table
+    fn rows(&self) -> &[Vec<TextSpan>];
+
+    /// Get the cells of the row at `index`, if any
+    fn row(&self, index: usize) -> Option<&[TextSpan]>;
+
+    /// Get the dimensions of the table as `(rows, columns)`, where columns is the length of the longest row
+    fn dimensions(&self) -> (usize, usize);
+}
+
+impl TableExt for Table {
+    fn rows(&self) -> &[Vec<TextSpan>] {
+        self.as_slice()
+    }
+
+    fn row(&self, index: usize) -> Option<&[TextSpan]> {
+        self.get(index).map(Vec::as_slice)
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (
+            self.len(),
+            self.iter().map(Vec::len).max().unwrap_or_default(),
+        )
+    }
+}
+
+/// Convert each row of `table` into a [`Line`] made of the row spans
+pub fn table_to_lines(table: &Table) -> Vec<Line<'static>> {
+    table
+        .iter()
+        .map(|row| Line::from(row.iter().map(Span::from).collect::<Vec<Span>>()))
+        .collect()
+}
+
+/// Describes a column of a [`TableSpec`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColumnSpec {
+    /// Column title, displayed in the header
+    pub title: TextSpan,
+    /// Width hint for the column
+    pub width: Constraint,
+    /// Alignment of the cells in the column
+    pub alignment: Alignment,
+}
+
+impl ColumnSpec {
+    /// Instantiate a new left-aligned `ColumnSpec` with `title` and `width` hint
+    pub fn new<T: Into<TextSpan>>(title: T, width: Constraint) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Set the alignment of the cells in the column
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+/// A [`Table`] with the description of its columns.
+///
+/// Rows may have fewer cells than columns; the missing cells are rendered as empty cells.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TableSpec {
+    pub columns: Vec<ColumnSpec>,
+    pub rows: Table,
+}
+
+impl TableSpec {
+    /// Instantiate a new `TableSpec` with `columns` and `rows`
+    pub fn new(columns: Vec<ColumnSpec>, rows: Table) -> Self {
+        Self { columns, rows }
+    }
+
+    /// Get the cell at `row`, `col`, if any
+    pub fn cell(&self, row: usize, col: usize) -> Option<&TextSpan> {
+        self.rows.get(row).and_then(|r| r.get(col))
+    }
+
+    /// Get the width hints of the columns
+    pub fn widths(&self) -> Vec<Constraint> {
+        self.columns.iter().map(|c| c.width).collect()
+    }
+
+    /// Get the header row, made of the column titles
+    pub fn header(&self) -> Row<'static> {
+        Row::new(
+            self.columns
+                .iter()
+                .map(|c| Self::to_cell(&c.title, c.alignment)),
+        )
+    }
+
+    /// Convert the rows into ratatui [`Row`]s, aligning each cell as its column.
+    ///
+    /// Ragged rows are padded with empty cells, while cells exceeding the columns are kept left-aligned.
+    pub fn to_rows(&self) -> Vec<Row<'static>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let cells = (0..row.len().max(self.columns.len())).map(|col| {
+                    let alignment = self
+                        .columns
+                        .get(col)
+                        .map(|c| c.alignment)
+                        .unwrap_or(Alignment::Left);
+                    match row.get(col) {
+                        Some(span) => Self::to_cell(span, alignment),
+                        None => Cell::default(),
+                    }
+                });
+                Row::new(cells)
+            })
+            .collect()
+    }
+
+    fn to_cell(span: &TextSpan, alignment: Alignment) -> Cell<'static> {
+        Cell::from(Line::from(Span::from(span)).alignment(alignment))
+    }
+}
+
 /// Table builder is a helper to make it easier to build text tables
 pub struct TableBuilder {
     table: Option<Table>,
+    columns: Vec<ColumnSpec>,
+    /// Capacity of the rows created with [`TableBuilder::add_row`]
+    row_capacity: usize,
 }
 
 impl TableBuilder {
+    /// Create a new [`TableBuilder`] with room for `rows` rows of `cols` columns each.
+    ///
+    /// Use it when the size of the table is known in advance (e.g. the result of a query),
+    /// to avoid reallocating the table and its rows while adding the cells.
+    pub fn with_capacity(rows: usize, cols: usize) -> Self {
+        let mut table = Vec::with_capacity(rows.max(1));
+        table.push(Vec::with_capacity(cols));
+        Self {
+            table: Some(table),
+            columns: Vec::new(),
+            row_capacity: cols,
+        }
+    }
+
+    /// Describe a new column of the table; columns are used only by [`TableBuilder::build_spec`]
+    pub fn column(&mut self, column: ColumnSpec) -> &mut Self {
+        self.columns.push(column);
+        self
+    }
+
     /// Add a column to the last row
     pub fn add_col(&mut self, span: TextSpan) -> &mut Self {
         if let Some(table) = self.table.as_mut() {
@@ -116,10 +304,57 @@ impl TableBuilder {
         self
     }
 
+    /// Add a column with `text` and `style` to the last row
+    pub fn add_styled_col<S: AsRef<str>>(&mut self, text: S, style: Style) -> &mut Self {
+        self.add_col(TextSpan::styled(text, style))
+    }
+
+    /// Add a row made of `spans` to the table.
+    /// If the last row is empty, `spans` are added to it, otherwise a new row is created.
+    pub fn add_line(&mut self, spans: Vec<TextSpan>) -> &mut Self {
+        if let Some(table) = self.table.as_mut() {
+            Self::push_row(table, spans);
+        }
+        self
+    }
+
+    /// Add a row made of the spans yielded by `spans` to the table, as [`TableBuilder::add_line`].
+    pub fn add_row_from_iter<I>(&mut self, spans: I) -> &mut Self
+    where
+        I: IntoIterator<Item = TextSpan>,
+    {
+        if let Some(table) = self.table.as_mut() {
+            match table.last_mut() {
+                Some(row) if row.is_empty() => row.extend(spans),
+                _ => table.push(spans.into_iter().collect()),
+            }
+        }
+        self
+    }
+
+    /// Add all the `rows` to the table, as [`TableBuilder::add_line`] for each row.
+    ///
+    /// The table is grown once by the lower bound of the size hint of `rows`.
+    pub fn extend_rows<I>(&mut self, rows: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Vec<TextSpan>>,
+    {
+        if let Some(table) = self.table.as_mut() {
+            let rows = rows.into_iter();
+            // NOTE: the last row is replaced by the first one, if empty
+            let reused = table.last().is_some_and(Vec::is_empty);
+            table.reserve(rows.size_hint().0.saturating_sub(usize::from(reused)));
+            for row in rows {
+                Self::push_row(table, row);
+            }
+        }
+        self
+    }
+
     /// Add a new row to the table
     pub fn add_row(&mut self) -> &mut Self {
         if let Some(table) = self.table.as_mut() {
-            table.push(vec![]);
+            table.push(Vec::with_capacity(self.row_capacity));
         }
         self
     }
@@ -129,12 +364,30 @@ impl TableBuilder {
     pub fn build(&mut self) -> Table {
         self.table.take().unwrap()
     }
+
+    /// Take table out of builder, with the columns described with [`TableBuilder::column`]
+    /// Don't call this method twice for any reasons!
+    pub fn build_spec(&mut self) -> TableSpec {
+        TableSpec::new(std::mem::take(&mut self.columns), self.build())
+    }
+
+    /// Push `row` to `table`, replacing the last row if it's empty
+    fn push_row(table: &mut Table, row: Vec<TextSpan>) {
+        match table.last_mut() {
+            // NOTE: keep the last row if it has room for the spans, to reuse its allocation
+            Some(last) if last.is_empty() && last.capacity() >= row.len() => last.extend(row),
+            Some(last) if last.is_empty() => *last = row,
+            _ => table.push(row),
+        }
+    }
 }
 
 impl Default for TableBuilder {
     fn default() -> Self {
         TableBuilder {
             table: Some(vec![vec![]]),
+            columns: Vec::new(),
+            row_capacity: 0,
         }
     }
 }
@@ -163,10 +416,10 @@ mod test {
             .build();
         // Verify table
         assert_eq!(table.len(), 5); // 5 spans
-        assert_eq!(table.get(0).unwrap().len(), 2); // 2 cols
+        assert_eq!(table.first().unwrap().len(), 2); // 2 cols
         assert_eq!(table.get(1).unwrap().len(), 2); // 2 cols
         assert_eq!(
-            table.get(1).unwrap().get(0).unwrap().content.as_str(),
+            table.get(1).unwrap().first().unwrap().content.as_str(),
             "christian"
         ); // check content
         assert_eq!(table.get(2).unwrap().len(), 2); // 2 cols
@@ -174,6 +427,152 @@ mod test {
         assert_eq!(table.get(4).unwrap().len(), 1); // 1 cols
     }
 
+    #[test]
+    fn table_builder_should_add_styled_cols_and_lines() {
+        let style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        let table: Table = TableBuilder::default()
+            .add_line(vec![TextSpan::from("name"), TextSpan::from("age")])
+            .add_line(vec![TextSpan::from("omar")])
+            .add_styled_col("25", style)
+            .build();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table[0].len(), 2);
+        assert_eq!(table[1].len(), 2);
+        assert_eq!(table[1][1].fg, Color::Red);
+        assert_eq!(table[1][1].modifiers, Modifier::BOLD);
+        // to lines
+        let lines = table_to_lines(&table);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].spans.len(), 2);
+        assert_eq!(lines[1].spans[1].content, "25");
+        assert_eq!(lines[1].spans[1].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn table_builder_should_add_rows_in_bulk() {
+        let table: Table = TableBuilder::with_capacity(4, 2)
+            .add_row_from_iter(["name", "age"].into_iter().map(TextSpan::from))
+            .extend_rows(
+                (0..2).map(|i| vec![TextSpan::from(format!("user{i}")), TextSpan::from("30")]),
+            )
+            .add_row()
+            .add_col(TextSpan::from("omar"))
+            .build();
+        assert_eq!(table.dimensions(), (4, 2));
+        assert_eq!(table.rows().len(), 4);
+        assert_eq!(table.row(0).unwrap()[1].content, "age");
+        assert_eq!(table.row(2).unwrap()[0].content, "user1");
+        assert_eq!(table.row(3).unwrap().len(), 1);
+        assert!(table.row(4).is_none());
+        // the first empty row is filled
+        let table: Table = TableBuilder::default()
+            .extend_rows(vec![
+                vec![TextSpan::from("a")],
+                vec![],
+                vec![TextSpan::from("b")],
+            ])
+            .build();
+        assert_eq!(table.dimensions(), (2, 1));
+        assert_eq!(table.row(1).unwrap()[0].content, "b");
+        // empty table
+        let table: Table = Table::new();
+        assert_eq!(table.dimensions(), (0, 0));
+    }
+
+    fn spec_cell(text: &str, alignment: Alignment) -> Cell<'static> {
+        Cell::from(Line::from(Span::from(TextSpan::from(text))).alignment(alignment))
+    }
+
+    #[test]
+    fn table_spec_should_describe_columns() {
+        let spec = TableBuilder::default()
+            .column(ColumnSpec::new("name", Constraint::Percentage(70)))
+            .column(ColumnSpec::new("age", Constraint::Length(3)).alignment(Alignment::Right))
+            .add_line(vec![TextSpan::from("christian"), TextSpan::from("23")])
+            .add_line(vec![TextSpan::from("omar")])
+            .build_spec();
+        assert_eq!(spec.columns.len(), 2);
+        assert_eq!(spec.rows.len(), 2);
+        assert_eq!(
+            spec.widths(),
+            vec![Constraint::Percentage(70), Constraint::Length(3)]
+        );
+        assert_eq!(spec.cell(0, 1).unwrap().content, "23");
+        assert_eq!(spec.cell(1, 0).unwrap().content, "omar");
+        assert!(spec.cell(1, 1).is_none());
+        assert!(spec.cell(2, 0).is_none());
+        // convert
+        let header = spec.header();
+        assert_eq!(
+            header,
+            Row::new(vec![
+                spec_cell("name", Alignment::Left),
+                spec_cell("age", Alignment::Right),
+            ])
+        );
+        let rows = spec.to_rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            Row::new(vec![
+                spec_cell("christian", Alignment::Left),
+                spec_cell("23", Alignment::Right),
+            ])
+        );
+        // ragged row is padded
+        assert_eq!(
+            rows[1],
+            Row::new(vec![spec_cell("omar", Alignment::Left), Cell::default(),])
+        );
+    }
+
+    #[test]
+    fn table_spec_should_keep_cells_exceeding_columns() {
+        let spec = TableSpec::new(
+            vec![ColumnSpec::new("name", Constraint::Min(0)).alignment(Alignment::Center)],
+            vec![vec![TextSpan::from("omar"), TextSpan::from("25")]],
+        );
+        assert_eq!(spec.widths(), vec![Constraint::Min(0)]);
+        assert_eq!(spec.cell(0, 1).unwrap().content, "25");
+        assert_eq!(
+            spec.to_rows()[0],
+            Row::new(vec![
+                spec_cell("omar", Alignment::Center),
+                spec_cell("25", Alignment::Left),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_span_should_convert_to_ratatui_span() {
+        let style = Style::default()
+            .fg(Color::Yellow)
+            .bg(Color::Blue)
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC);
+        let span = TextSpan::styled("omar", style);
+        assert_eq!(span.fg, Color::Yellow);
+        assert_eq!(span.bg, Color::Blue);
+        assert_eq!(span.modifiers, Modifier::BOLD | Modifier::ITALIC);
+        assert_eq!(span.style(), style);
+        let tui_span = Span::from(&span);
+        assert_eq!(tui_span.content, "omar");
+        assert_eq!(tui_span.style, style);
+        // round trip
+        let back = TextSpan::styled(tui_span.content.as_ref(), tui_span.style);
+        assert_eq!(back, span);
+        let tui_span: Span = span.into();
+        assert_eq!(tui_span.style, style);
+        // sub modifiers are removed
+        let span = TextSpan::styled(
+            "pippo",
+            Style::default()
+                .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+                .remove_modifier(Modifier::ITALIC),
+        );
+        assert_eq!(span.modifiers, Modifier::BOLD);
+        assert_eq!(span.fg, Color::Reset);
+    }
+
     #[test]
     fn text_span() {
         // default
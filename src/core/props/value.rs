@@ -2,14 +2,26 @@
 //!
 //! This module exposes the prop values
 
+use std::any::{Any, TypeId};
 use std::collections::{HashMap, LinkedList};
+use std::fmt;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
 
 use super::{Alignment, Color, Dataset, InputType, Shape, Style, Table, TextSpan};
 
 // -- Prop value
 
-/// The payload contains the actual value for user defined properties
+/// The payload contains the actual value for user defined properties.
+///
+/// When serialized, `Any` values can't be serialized and make the serialization fail.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 pub enum PropPayload {
     One(PropValue),
     Tup2((PropValue, PropValue)),
@@ -18,11 +30,21 @@ pub enum PropPayload {
     Vec(Vec<PropValue>),
     Map(HashMap<String, PropValue>),
     Linked(LinkedList<PropPayload>),
+    /// A value of any type, e.g. a configuration struct of the component
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    Any(AnyProp),
     None,
 }
 
-/// Value describes the value contained in a `PropPayload`
+/// Value describes the value contained in a `PropPayload`.
+///
+/// When serialized, `Shape` values and custom `InputType` values can't be serialized and make the serialization fail.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 pub enum PropValue {
     Bool(bool),
     U8(u8),
@@ -41,17 +63,150 @@ pub enum PropValue {
     F32(f32),
     Str(String),
     // -- tui props
+    #[cfg_attr(feature = "serialize", serde(with = "super::remote::AlignmentDef"))]
     Alignment(Alignment),
     Color(Color),
     Dataset(Dataset),
     InputType(InputType),
+    #[cfg_attr(feature = "serialize", serde(skip))]
     Shape(Shape),
     Style(Style),
     Table(Table),
     TextSpan(TextSpan),
 }
 
+/// A value which can be stored in an [`AnyProp`].
+///
+/// It's implemented for all the types which implement `Debug`, `Clone` and `PartialEq`.
+pub trait AnyPropValue: Any + fmt::Debug {
+    /// Get the value as [`Any`], in order to downcast it
+    fn as_any(&self) -> &dyn Any;
+
+    /// Get the value as mutable [`Any`], in order to downcast it
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Clone the value into a new box
+    fn clone_box(&self) -> Box<dyn AnyPropValue>;
+
+    /// Returns whether the value is equal to `other`; values of different types are never equal
+    fn eq_any(&self, other: &dyn AnyPropValue) -> bool;
+}
+
+impl<T> AnyPropValue for T
+where
+    T: Any + fmt::Debug + Clone + PartialEq,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn AnyPropValue> {
+        Box::new(self.clone())
+    }
+
+    fn eq_any(&self, other: &dyn AnyPropValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+/// A user defined value of any type, stored in [`PropPayload::Any`].
+///
+/// This allows components to store arbitrary values, such as their configuration, in the properties:
+///
+/// ```rust
+/// use tuirealm::props::{AttrValue, Attribute, Props};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Config {
+///     max_len: usize,
+/// }
+///
+/// let mut props = Props::default();
+/// props.set(Attribute::Custom("config"), AttrValue::any(Config { max_len: 16 }));
+/// assert_eq!(props.get_custom::<Config>(Attribute::Custom("config")).unwrap().max_len, 16);
+/// // downcasting to the wrong type returns None
+/// assert!(props.get_custom::<String>(Attribute::Custom("config")).is_none());
+/// ```
+pub struct AnyProp(Box<dyn AnyPropValue>);
+
+impl AnyProp {
+    /// Create a new `AnyProp` containing `value`
+    pub fn new<T: AnyPropValue>(value: T) -> Self {
+        Self(Box::new(value))
+    }
+
+    /// Get the value as [`Any`]
+    pub fn as_any(&self) -> &dyn Any {
+        self.0.as_any()
+    }
+
+    /// Get the value as mutable [`Any`]
+    pub fn as_any_mut(&mut self) -> &mut dyn Any {
+        self.0.as_any_mut()
+    }
+
+    /// Returns whether the value is of type `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.as_any().is::<T>()
+    }
+
+    /// Get a reference to the value if it's of type `T`, or None
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Get a mutable reference to the value if it's of type `T`, or None
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
+
+    /// Get the [`TypeId`] of the value
+    pub(crate) fn value_type_id(&self) -> TypeId {
+        self.as_any().type_id()
+    }
+}
+
+impl Clone for AnyProp {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl PartialEq for AnyProp {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_any(other.0.as_ref())
+    }
+}
+
+impl fmt::Debug for AnyProp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyProp").field(&self.0).finish()
+    }
+}
+
 impl PropPayload {
+    /// Create an `Any` payload containing `value`
+    pub fn any<T: AnyPropValue>(value: T) -> Self {
+        PropPayload::Any(AnyProp::new(value))
+    }
+
+    /// Get a reference to the value of an `Any` payload if it's of type `T`, or None
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().and_then(AnyProp::downcast_ref)
+    }
+
+    /// Get a mutable reference to the value of an `Any` payload if it's of type `T`, or None
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        match self {
+            PropPayload::Any(v) => v.downcast_mut(),
+            _ => None,
+        }
+    }
+
     /// Unwrap a One value from PropPayload
     pub fn unwrap_one(self) -> PropValue {
         match self {
@@ -108,6 +263,14 @@ impl PropPayload {
         }
     }
 
+    /// Unwrap an Any value from PropPayload
+    pub fn unwrap_any(self) -> AnyProp {
+        match self {
+            PropPayload::Any(v) => v,
+            _ => panic!("Called `unwrap_any` on a bad value"),
+        }
+    }
+
     /// Get a One value from PropPayload, or None
     pub fn as_one(&self) -> Option<&PropValue> {
         match self {
@@ -163,6 +326,14 @@ impl PropPayload {
             _ => None,
         }
     }
+
+    /// Get an Any value from PropPayload, or None
+    pub fn as_any(&self) -> Option<&AnyProp> {
+        match self {
+            PropPayload::Any(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 impl PropValue {
@@ -567,20 +738,20 @@ mod tests {
 
     #[test]
     fn prop_values() {
-        PropPayload::One(PropValue::Usize(2));
-        PropPayload::Tup2((PropValue::Bool(true), PropValue::Usize(128)));
-        PropPayload::Tup3((
+        let _ = PropPayload::One(PropValue::Usize(2));
+        let _ = PropPayload::Tup2((PropValue::Bool(true), PropValue::Usize(128)));
+        let _ = PropPayload::Tup3((
             PropValue::Bool(true),
             PropValue::Usize(128),
             PropValue::Str(String::from("omar")),
         ));
-        PropPayload::Tup4((
+        let _ = PropPayload::Tup4((
             PropValue::Bool(true),
             PropValue::U8(128),
             PropValue::Str(String::from("pippo")),
             PropValue::Isize(-2),
         ));
-        PropPayload::Vec(vec![
+        let _ = PropPayload::Vec(vec![
             PropValue::U16(1),
             PropValue::U32(2),
             PropValue::U64(3),
@@ -650,14 +821,14 @@ mod tests {
             *map.get("u").unwrap(),
             PropValue::Alignment(Alignment::Center)
         );
-        PropPayload::Map(map);
+        let _ = PropPayload::Map(map);
         let mut link: LinkedList<PropPayload> = LinkedList::new();
         link.push_back(PropPayload::One(PropValue::Usize(1)));
         link.push_back(PropPayload::Tup2((
             PropValue::Usize(2),
             PropValue::Usize(4),
         )));
-        PropPayload::Linked(link);
+        let _ = PropPayload::Linked(link);
     }
 
     #[test]
@@ -666,7 +837,7 @@ mod tests {
             PropValue::Alignment(Alignment::Center).unwrap_alignment(),
             Alignment::Center
         );
-        assert_eq!(PropValue::Bool(true).unwrap_bool(), true);
+        assert!(PropValue::Bool(true).unwrap_bool());
         assert_eq!(
             PropValue::Dataset(Dataset::default()).unwrap_dataset(),
             Dataset::default()
@@ -796,12 +967,9 @@ mod tests {
 
     #[test]
     fn unwrap_prop_payloads() {
-        assert_eq!(
-            PropPayload::One(PropValue::Bool(false))
-                .unwrap_one()
-                .unwrap_bool(),
-            false
-        );
+        assert!(!PropPayload::One(PropValue::Bool(false))
+            .unwrap_one()
+            .unwrap_bool());
         assert_eq!(
             PropPayload::Tup2((PropValue::Bool(false), PropValue::Bool(false))).unwrap_tup2(),
             (PropValue::Bool(false), PropValue::Bool(false))
@@ -911,4 +1079,122 @@ mod tests {
         );
         assert_eq!(PropPayload::None.as_linked(), None);
     }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn should_serialize_nested_payloads() {
+        use crate::props::{Color, Dataset, Style, TableBuilder, TextModifiers, TextSpan};
+        use crate::ratatui::symbols::Marker;
+        use crate::ratatui::widgets::GraphType;
+
+        let payload = PropPayload::Linked(LinkedList::from([
+            PropPayload::Map(HashMap::from([
+                (String::from("width"), PropValue::U16(24)),
+                (
+                    String::from("tab"),
+                    PropValue::Str(String::from("downloads")),
+                ),
+                (String::from("bytes"), PropValue::U128(u128::MAX)),
+                (String::from("offset"), PropValue::I64(-8)),
+                (
+                    String::from("align"),
+                    PropValue::Alignment(Alignment::Center),
+                ),
+            ])),
+            PropPayload::Vec(vec![
+                PropValue::Color(Color::Rgb(255, 128, 0)),
+                PropValue::Style(
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(TextModifiers::BOLD | TextModifiers::ITALIC),
+                ),
+                PropValue::TextSpan(TextSpan::new("hello").fg(Color::Red).underlined()),
+                PropValue::Table(
+                    TableBuilder::default()
+                        .add_col(TextSpan::new("a"))
+                        .add_col(TextSpan::new("b").bold())
+                        .add_row()
+                        .add_col(TextSpan::new("c"))
+                        .build(),
+                ),
+                PropValue::Dataset(
+                    Dataset::default()
+                        .name("temperatures")
+                        .marker(Marker::Braille)
+                        .graph_type(GraphType::Line)
+                        .style(Style::default().fg(Color::Cyan))
+                        .data(vec![(0.0, 12.5), (1.0, 14.0)]),
+                ),
+            ]),
+            PropPayload::Linked(LinkedList::from([
+                PropPayload::Tup2((PropValue::Bool(true), PropValue::F64(0.5))),
+                PropPayload::None,
+            ])),
+        ]));
+        let json = serde_json::to_string(&payload).unwrap();
+        let deserialized: PropPayload = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, payload);
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn should_not_serialize_custom_input_types_and_shapes() {
+        let json = serde_json::to_string(&PropValue::InputType(InputType::Number)).unwrap();
+        let deserialized: PropValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, PropValue::InputType(InputType::Number));
+        assert!(
+            serde_json::to_string(&PropValue::InputType(InputType::Custom(
+                |_| true,
+                |_, _| true
+            )))
+            .is_err()
+        );
+        assert!(
+            serde_json::to_string(&PropPayload::Vec(vec![PropValue::Shape(Shape::Layer)])).is_err()
+        );
+        assert!(serde_json::to_string(&PropPayload::any(1_u8)).is_err());
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Config {
+        max_len: usize,
+    }
+
+    #[test]
+    fn should_downcast_any_prop() {
+        let mut payload = PropPayload::any(Config { max_len: 16 });
+        assert!(payload.as_any().unwrap().is::<Config>());
+        assert_eq!(
+            payload.downcast_ref::<Config>(),
+            Some(&Config { max_len: 16 })
+        );
+        // wrong type
+        assert!(payload.downcast_ref::<usize>().is_none());
+        assert!(payload.downcast_mut::<String>().is_none());
+        assert!(PropPayload::None.downcast_ref::<Config>().is_none());
+        // mutate
+        payload.downcast_mut::<Config>().unwrap().max_len = 32;
+        assert_eq!(
+            payload.unwrap_any().downcast_ref::<Config>(),
+            Some(&Config { max_len: 32 })
+        );
+    }
+
+    #[test]
+    fn any_props_should_be_compared_by_value() {
+        let payload = PropPayload::any(Config { max_len: 16 });
+        assert_eq!(payload.clone(), payload);
+        assert_ne!(payload, PropPayload::any(Config { max_len: 32 }));
+        assert_ne!(payload, PropPayload::any(16_usize));
+        assert_eq!(
+            format!("{payload:?}"),
+            "Any(AnyProp(Config { max_len: 16 }))"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrapping_any_should_panic_if_not_identity() {
+        PropPayload::None.unwrap_any();
+    }
 }
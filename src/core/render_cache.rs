@@ -0,0 +1,127 @@
+//! ## Render cache
+//!
+//! The render cache keeps the buffer drawn by each component the last time it has been rendered,
+//! in order to re-draw it without calling the component `view` method, as long as the component is not changed.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::ratatui::buffer::Buffer;
+use crate::ratatui::layout::Rect;
+
+/// Buffers rendered by the components, associated to the component id and to the area they've been rendered in
+pub(crate) struct RenderCache<ComponentId>
+where
+    ComponentId: Eq + PartialEq + Clone + Hash,
+{
+    renders: HashMap<ComponentId, (Rect, Buffer)>,
+}
+
+impl<K> Default for RenderCache<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    fn default() -> Self {
+        Self {
+            renders: HashMap::new(),
+        }
+    }
+}
+
+impl<K> RenderCache<K>
+where
+    K: Eq + PartialEq + Clone + Hash,
+{
+    /// Copy the render of component `id` into `buf`, if the component has been rendered in `area` last time.
+    /// Returns whether the render has been copied
+    pub fn restore(&self, id: &K, area: Rect, buf: &mut Buffer) -> bool {
+        match self.renders.get(id) {
+            Some((rendered_in, render))
+                if *rendered_in == area && render.area == area.intersection(buf.area) =>
+            {
+                copy_area(render, buf, render.area);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Store the content of `area` in `buf` as the render of component `id`
+    pub fn store(&mut self, id: K, area: Rect, buf: &Buffer) {
+        let clipped = area.intersection(buf.area);
+        let mut render = Buffer::empty(clipped);
+        copy_area(buf, &mut render, clipped);
+        self.renders.insert(id, (area, render));
+    }
+
+    /// Remove the render of component `id`
+    pub fn invalidate(&mut self, id: &K) {
+        self.renders.remove(id);
+    }
+
+    /// Remove all the renders
+    pub fn clear(&mut self) {
+        self.renders.clear();
+    }
+}
+
+/// Copy the cells in `area` from `src` to `dest`. `area` must be contained in both buffers
+fn copy_area(src: &Buffer, dest: &mut Buffer, area: Rect) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            dest[(x, y)] = src[(x, y)].clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::MockComponentId;
+    use crate::ratatui::style::{Color, Style};
+
+    #[test]
+    fn should_store_and_restore_renders() {
+        let mut cache: RenderCache<MockComponentId> = RenderCache::default();
+        let area = Rect::new(2, 1, 5, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        buf.set_string(2, 1, "hello", Style::default().fg(Color::Red));
+        buf.set_string(2, 2, "world", Style::default());
+        cache.store(MockComponentId::InputFoo, area, &buf);
+        // restore into a new frame
+        let mut frame = Buffer::empty(Rect::new(0, 0, 10, 4));
+        assert!(cache.restore(&MockComponentId::InputFoo, area, &mut frame));
+        assert_eq!(frame, buf);
+        // different area or id
+        assert!(!cache.restore(
+            &MockComponentId::InputFoo,
+            Rect::new(2, 1, 5, 3),
+            &mut frame
+        ));
+        assert!(!cache.restore(&MockComponentId::InputBar, area, &mut frame));
+        // invalidate
+        cache.invalidate(&MockComponentId::InputFoo);
+        assert!(!cache.restore(&MockComponentId::InputFoo, area, &mut frame));
+        cache.store(MockComponentId::InputFoo, area, &buf);
+        cache.clear();
+        assert!(!cache.restore(&MockComponentId::InputFoo, area, &mut frame));
+    }
+
+    #[test]
+    fn should_clip_renders_to_buffer_area() {
+        let mut cache: RenderCache<MockComponentId> = RenderCache::default();
+        let area = Rect::new(5, 2, 10, 10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        buf.set_string(5, 3, "abc", Style::default());
+        cache.store(MockComponentId::InputFoo, area, &buf);
+        let mut frame = Buffer::empty(Rect::new(0, 0, 8, 4));
+        assert!(cache.restore(&MockComponentId::InputFoo, area, &mut frame));
+        assert_eq!(frame, buf);
+        // the terminal has been resized
+        let mut frame = Buffer::empty(Rect::new(0, 0, 10, 5));
+        assert!(!cache.restore(&MockComponentId::InputFoo, area, &mut frame));
+    }
+}
@@ -3,12 +3,21 @@
 //! This module exposes the state type and values
 
 use std::collections::{HashMap, LinkedList};
+use std::time::Duration;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
 
 use crate::props::Color;
 use crate::utils::{Email, PhoneNumber};
 
 /// State describes a component state
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 pub enum State {
     One(StateValue),
     Tup2((StateValue, StateValue)),
@@ -17,11 +26,18 @@ pub enum State {
     Vec(Vec<StateValue>),
     Map(HashMap<String, StateValue>),
     Linked(LinkedList<State>),
+    /// Composite state made of named states; useful for components owning several children
+    Nested(HashMap<String, State>),
     None,
 }
 
 /// StateValue describes the value contained in a State
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 pub enum StateValue {
     None,
     Bool(bool),
@@ -39,6 +55,12 @@ pub enum StateValue {
     Isize(isize),
     F64(f64),
     String(String),
+    Duration(Duration),
+    /// A position (e.g. a cursor position)
+    Position {
+        x: u16,
+        y: u16,
+    },
     // -- input types
     Color(Color),
     Email(Email),
@@ -95,6 +117,14 @@ impl State {
         }
     }
 
+    /// Unwrap `Nested` as the map of the inner states, keyed by name
+    pub fn unwrap_nested(self) -> HashMap<String, State> {
+        match self {
+            Self::Nested(val) => val,
+            state => panic!("Could not unwrap {:?} as `Nested`", state),
+        }
+    }
+
     /// Returns whether `State` is `State::None`
     pub fn is_none(&self) -> bool {
         matches!(self, Self::None)
@@ -212,6 +242,21 @@ impl StateValue {
         }
     }
 
+    pub fn unwrap_duration(self) -> Duration {
+        match self {
+            Self::Duration(val) => val,
+            value => panic!("Could not unwrap {:?} as `Duration`", value),
+        }
+    }
+
+    /// Unwrap `Position` as `(x, y)`
+    pub fn unwrap_position(self) -> (u16, u16) {
+        match self {
+            Self::Position { x, y } => (x, y),
+            value => panic!("Could not unwrap {:?} as `Position`", value),
+        }
+    }
+
     pub fn unwrap_color(self) -> Color {
         match self {
             Self::Color(val) => val,
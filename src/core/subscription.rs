@@ -2,14 +2,25 @@
 //!
 //! This module defines the model for the Subscriptions
 
+use std::cmp::Reverse;
+use std::fmt::{self, Debug};
 use std::hash::Hash;
 use std::ops::Range;
 
-use crate::event::{KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::lifecycle::ErasedId;
+use crate::event::{
+    KeyEvent, KeyModifiers, Lifecycle, LifecycleKind, MouseEvent, MouseEventKind, TimerId,
+};
 use crate::{AttrValue, Attribute, Event, State};
 
 /// Public type to define a subscription.
-pub struct Sub<ComponentId, UserEvent>(EventClause<UserEvent>, SubClause<ComponentId>)
+///
+/// When many components are subscribed to the same event, the event is forwarded to the subscriptions with the highest
+/// priority first; subscriptions with the same priority are visited in subscription order. (Default priority: 0)
+pub struct Sub<ComponentId, UserEvent>(EventClause<UserEvent>, SubClause<ComponentId>, Option<i32>)
 where
     ComponentId: Eq + PartialEq + Clone + Hash,
     UserEvent: Eq + PartialEq + Clone + PartialOrd;
@@ -21,7 +32,246 @@ where
 {
     /// Creates a new `Sub`
     pub fn new(event_clause: EventClause<U>, sub_clause: SubClause<K>) -> Self {
-        Self(event_clause, sub_clause)
+        Self(event_clause, sub_clause, None)
+    }
+
+    /// Set the priority of the subscription. Subscriptions with higher priority receive the events first
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.2 = Some(priority);
+        self
+    }
+
+    /// Returns the priority of the subscription, if it has been set
+    pub(crate) fn explicit_priority(&self) -> Option<i32> {
+        self.2
+    }
+
+    /// Returns the subscription event clause
+    pub(crate) fn event(&self) -> &EventClause<U> {
+        &self.0
+    }
+
+    /// Start building a `Sub` for [`EventClause::Any`]
+    pub fn any() -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::Any)
+    }
+
+    /// Start building a `Sub` for [`EventClause::Keyboard`].
+    /// Modifiers can be added with [`SubBuilder::with_ctrl`], [`SubBuilder::with_alt`], [`SubBuilder::with_shift`]
+    pub fn keyboard<E: Into<KeyEvent>>(ev: E) -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::Keyboard(ev.into()))
+    }
+
+    /// Start building a `Sub` for [`EventClause::Mouse`]
+    pub fn mouse(clause: MouseEventClause) -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::Mouse(clause))
+    }
+
+    /// Start building a `Sub` for [`EventClause::WindowResize`]
+    pub fn window_resize() -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::WindowResize)
+    }
+
+    /// Start building a `Sub` for [`EventClause::FocusGained`]
+    pub fn focus_gained() -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::FocusGained)
+    }
+
+    /// Start building a `Sub` for [`EventClause::FocusLost`]
+    pub fn focus_lost() -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::FocusLost)
+    }
+
+    /// Start building a `Sub` for [`EventClause::Tick`]
+    pub fn tick() -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::Tick)
+    }
+
+    /// Start building a `Sub` for [`EventClause::Lifecycle`]
+    pub fn lifecycle(clause: LifecycleClause) -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::Lifecycle(clause))
+    }
+
+    /// Start building a `Sub` for [`EventClause::Timer`]
+    pub fn timer(id: TimerId) -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::Timer(id))
+    }
+
+    /// Start building a `Sub` for [`EventClause::User`]
+    pub fn user(ev: U) -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::User(ev))
+    }
+
+    /// Start building a `Sub` for [`EventClause::UserWhere`]
+    pub fn user_where(predicate: fn(&U) -> bool) -> SubBuilder<K, U> {
+        SubBuilder::new(EventClause::UserWhere(predicate))
+    }
+}
+
+/// A builder for [`Sub`], created with the `Sub` shortcuts (e.g. [`Sub::keyboard`]).
+///
+/// Clauses are chained from left to right, so that
+/// `Sub::tick().when_mounted(a).and_mounted(b).or_mounted(c).build()`
+/// is the same as
+/// `Sub::new(EventClause::Tick, SubClause::or(SubClause::and(IsMounted(a), IsMounted(b)), IsMounted(c)))`.
+///
+/// If no clause is provided, the subscription clause is [`SubClause::Always`].
+pub struct SubBuilder<ComponentId, UserEvent>
+where
+    ComponentId: Eq + PartialEq + Clone + Hash,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd,
+{
+    ev: EventClause<UserEvent>,
+    when: Option<SubClause<ComponentId>>,
+    priority: Option<i32>,
+}
+
+impl<K, U> SubBuilder<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    fn new(ev: EventClause<U>) -> Self {
+        Self {
+            ev,
+            when: None,
+            priority: None,
+        }
+    }
+
+    /// Set the priority of the subscription. See [`Sub::priority`]
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Add `modifiers` to the keyboard or mouse event clause.
+    /// It has no effect on the other event clauses.
+    pub fn with_modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        match &mut self.ev {
+            EventClause::Keyboard(ev) => ev.modifiers |= modifiers,
+            EventClause::Mouse(ev) => ev.modifiers |= modifiers,
+            _ => {}
+        }
+        self
+    }
+
+    /// Add [`KeyModifiers::CONTROL`] to the keyboard or mouse event clause
+    pub fn with_ctrl(self) -> Self {
+        self.with_modifiers(KeyModifiers::CONTROL)
+    }
+
+    /// Add [`KeyModifiers::ALT`] to the keyboard or mouse event clause
+    pub fn with_alt(self) -> Self {
+        self.with_modifiers(KeyModifiers::ALT)
+    }
+
+    /// Add [`KeyModifiers::SHIFT`] to the keyboard or mouse event clause
+    pub fn with_shift(self) -> Self {
+        self.with_modifiers(KeyModifiers::SHIFT)
+    }
+
+    /// Set the first subscription clause.
+    /// If a clause has already been set, it behaves like [`SubBuilder::and`]
+    pub fn when(self, clause: SubClause<K>) -> Self {
+        self.and(clause)
+    }
+
+    /// Shortcut for `when(SubClause::IsMounted(id))`
+    pub fn when_mounted(self, id: K) -> Self {
+        self.when(SubClause::IsMounted(id))
+    }
+
+    /// Shortcut for `when(SubClause::IsVisible(id))`
+    pub fn when_visible(self, id: K) -> Self {
+        self.when(SubClause::IsVisible(id))
+    }
+
+    /// Shortcut for `when(SubClause::HasAttrValue(id, attr, value))`
+    pub fn when_has_attr(self, id: K, attr: Attribute, value: AttrValue) -> Self {
+        self.when(SubClause::HasAttrValue(id, attr, value))
+    }
+
+    /// Shortcut for `when(SubClause::HasState(id, state))`
+    pub fn when_has_state(self, id: K, state: State) -> Self {
+        self.when(SubClause::HasState(id, state))
+    }
+
+    /// Combine the current clause with `clause` using [`SubClause::And`]
+    pub fn and(mut self, clause: SubClause<K>) -> Self {
+        self.when = Some(match self.when.take() {
+            Some(when) => SubClause::and(when, clause),
+            None => clause,
+        });
+        self
+    }
+
+    /// Shortcut for `and(SubClause::IsMounted(id))`
+    pub fn and_mounted(self, id: K) -> Self {
+        self.and(SubClause::IsMounted(id))
+    }
+
+    /// Shortcut for `and(SubClause::IsVisible(id))`
+    pub fn and_visible(self, id: K) -> Self {
+        self.and(SubClause::IsVisible(id))
+    }
+
+    /// Shortcut for `and(SubClause::HasAttrValue(id, attr, value))`
+    pub fn and_has_attr(self, id: K, attr: Attribute, value: AttrValue) -> Self {
+        self.and(SubClause::HasAttrValue(id, attr, value))
+    }
+
+    /// Shortcut for `and(SubClause::HasState(id, state))`
+    pub fn and_has_state(self, id: K, state: State) -> Self {
+        self.and(SubClause::HasState(id, state))
+    }
+
+    /// Combine the current clause with `clause` using [`SubClause::Or`]
+    pub fn or(mut self, clause: SubClause<K>) -> Self {
+        self.when = Some(match self.when.take() {
+            Some(when) => SubClause::or(when, clause),
+            None => clause,
+        });
+        self
+    }
+
+    /// Shortcut for `or(SubClause::IsMounted(id))`
+    pub fn or_mounted(self, id: K) -> Self {
+        self.or(SubClause::IsMounted(id))
+    }
+
+    /// Shortcut for `or(SubClause::IsVisible(id))`
+    pub fn or_visible(self, id: K) -> Self {
+        self.or(SubClause::IsVisible(id))
+    }
+
+    /// Shortcut for `or(SubClause::HasAttrValue(id, attr, value))`
+    pub fn or_has_attr(self, id: K, attr: Attribute, value: AttrValue) -> Self {
+        self.or(SubClause::HasAttrValue(id, attr, value))
+    }
+
+    /// Shortcut for `or(SubClause::HasState(id, state))`
+    pub fn or_has_state(self, id: K, state: State) -> Self {
+        self.or(SubClause::HasState(id, state))
+    }
+
+    /// Build the [`Sub`]
+    pub fn build(self) -> Sub<K, U> {
+        Sub(
+            self.ev,
+            self.when.unwrap_or(SubClause::Always),
+            self.priority,
+        )
+    }
+}
+
+impl<K, U> From<SubBuilder<K, U>> for Sub<K, U>
+where
+    K: Eq + PartialEq + Clone + Hash,
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    fn from(builder: SubBuilder<K, U>) -> Self {
+        builder.build()
     }
 }
 
@@ -37,7 +287,9 @@ where
 ///     - ev: the event it listens for
 ///     - when: a clause that must be satisfied to forward the event to the component.
 ///
+/// A subscription can also be paused; a paused subscription keeps its clauses, but never forwards events.
 ///
+/// Subscriptions are visited by priority (descending), then by sequence number (ascending).
 pub(crate) struct Subscription<ComponentId, UserEvent>
 where
     ComponentId: Eq + PartialEq + Clone + Hash,
@@ -49,6 +301,12 @@ where
     ev: EventClause<UserEvent>,
     /// Restrict forwarding clauses
     when: SubClause<ComponentId>,
+    /// Whether the subscription is active; paused subscriptions never forward events
+    active: bool,
+    /// Delivery priority; higher priority subscriptions are visited first
+    priority: i32,
+    /// Sequence number, which orders subscriptions with the same priority
+    seq: usize,
 }
 
 impl<K, U> Subscription<K, U>
@@ -62,9 +320,18 @@ where
             target,
             ev: sub.0,
             when: sub.1,
+            active: true,
+            priority: sub.2.unwrap_or_default(),
+            seq: 0,
         }
     }
 
+    /// Set the sequence number of the subscription
+    pub(crate) fn with_seq(mut self, seq: usize) -> Self {
+        self.seq = seq;
+        self
+    }
+
     /// Returns sub target
     pub(crate) fn target(&self) -> &K {
         &self.target
@@ -75,6 +342,36 @@ where
         &self.ev
     }
 
+    /// Returns the subscription priority
+    pub(crate) fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns the subscription sequence number
+    pub(crate) fn seq(&self) -> usize {
+        self.seq
+    }
+
+    /// Returns whether this subscription must be visited before `other`
+    pub(crate) fn precedes(&self, other: &Self) -> bool {
+        (Reverse(self.priority), self.seq) < (Reverse(other.priority), other.seq)
+    }
+
+    /// Returns the information about this subscription
+    pub(crate) fn info(&self) -> SubInfo<K, U> {
+        SubInfo {
+            event: self.ev.clone(),
+            when: self.when.clone(),
+            priority: self.priority,
+            active: self.active,
+        }
+    }
+
+    /// Set whether the subscription is active
+    pub(crate) fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
     /// Returns whether to forward event to component
     pub(crate) fn forward<HasAttrFn, GetStateFn, MountedFn>(
         &self,
@@ -88,12 +385,33 @@ where
         GetStateFn: Fn(&K) -> Option<State>,
         MountedFn: Fn(&K) -> bool,
     {
-        self.ev.forward(ev) && self.when.forward(has_attr_fn, get_state_fn, mounted_fn)
+        self.active
+            && self.ev.forward(ev)
+            && self.when.forward(has_attr_fn, get_state_fn, mounted_fn)
     }
 }
 
+/// Information about a subscription of a component, returned by `Application::subscriptions_of`
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct SubInfo<ComponentId, UserEvent>
+where
+    ComponentId: Eq + PartialEq + Clone + Hash,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Event the subscription listens for
+    pub event: EventClause<UserEvent>,
+    /// Clause which must be satisfied to forward the event
+    pub when: SubClause<ComponentId>,
+    /// Delivery priority
+    pub priority: i32,
+    /// Whether the subscription is active or paused
+    pub active: bool,
+}
+
 /// A event clause for [`MouseEvent`]s
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub struct MouseEventClause {
     /// The kind of mouse event that was caused
     pub kind: MouseEventKind,
@@ -111,14 +429,155 @@ impl MouseEventClause {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// A event clause for [`Lifecycle`] events.
+///
+/// The clause can match the events of a certain kind, the events of a certain component, or both.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LifecycleClause {
+    kind: Option<LifecycleKind>,
+    id: Option<ErasedId>,
+}
+
+impl LifecycleClause {
+    /// Match any lifecycle event
+    pub fn any() -> Self {
+        Self {
+            kind: None,
+            id: None,
+        }
+    }
+
+    /// Match the lifecycle events of `kind` of any component
+    pub fn any_of_kind(kind: LifecycleKind) -> Self {
+        Self {
+            kind: Some(kind),
+            id: None,
+        }
+    }
+
+    /// Match any lifecycle event of component `id`
+    pub fn of<K>(id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self {
+            kind: None,
+            id: Some(ErasedId::new(id)),
+        }
+    }
+
+    /// Match the lifecycle events of `kind` of component `id`
+    pub fn kind_of<K>(kind: LifecycleKind, id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self {
+            kind: Some(kind),
+            id: Some(ErasedId::new(id)),
+        }
+    }
+
+    /// Shortcut for `any_of_kind(LifecycleKind::Mounted)`
+    pub fn any_mounted() -> Self {
+        Self::any_of_kind(LifecycleKind::Mounted)
+    }
+
+    /// Shortcut for `any_of_kind(LifecycleKind::Umounted)`
+    pub fn any_umounted() -> Self {
+        Self::any_of_kind(LifecycleKind::Umounted)
+    }
+
+    /// Shortcut for `any_of_kind(LifecycleKind::FocusGained)`
+    pub fn any_focus_gained() -> Self {
+        Self::any_of_kind(LifecycleKind::FocusGained)
+    }
+
+    /// Shortcut for `any_of_kind(LifecycleKind::FocusLost)`
+    pub fn any_focus_lost() -> Self {
+        Self::any_of_kind(LifecycleKind::FocusLost)
+    }
+
+    /// Shortcut for `kind_of(LifecycleKind::Mounted, id)`
+    pub fn mounted_of<K>(id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self::kind_of(LifecycleKind::Mounted, id)
+    }
+
+    /// Shortcut for `kind_of(LifecycleKind::Umounted, id)`
+    pub fn umounted_of<K>(id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self::kind_of(LifecycleKind::Umounted, id)
+    }
+
+    /// Shortcut for `kind_of(LifecycleKind::FocusGained, id)`
+    pub fn focus_gained_of<K>(id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self::kind_of(LifecycleKind::FocusGained, id)
+    }
+
+    /// Shortcut for `kind_of(LifecycleKind::FocusLost, id)`
+    pub fn focus_lost_of<K>(id: K) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+    {
+        Self::kind_of(LifecycleKind::FocusLost, id)
+    }
+
+    /// Returns whether `ev` satisfies the clause
+    fn matches(&self, ev: &Lifecycle) -> bool {
+        self.kind.map(|kind| kind == ev.kind()).unwrap_or(true)
+            && self
+                .id
+                .as_ref()
+                .map(|id| id == ev.erased_id())
+                .unwrap_or(true)
+    }
+}
+
+/// The clauses of a component can't be serialized, since the component id is type-erased
+#[cfg(feature = "serialize")]
+impl Serialize for LifecycleClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.id.is_some() {
+            return Err(serde::ser::Error::custom(
+                "the lifecycle clause of a component can't be serialized",
+            ));
+        }
+        self.kind.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for LifecycleClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<LifecycleKind>::deserialize(deserializer).map(|kind| Self { kind, id: None })
+    }
+}
 
 /// An event clause indicates on which kind of event the event must be forwarded to the `target` component.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 pub enum EventClause<UserEvent>
 where
     UserEvent: Eq + PartialEq + Clone + PartialOrd,
 {
-    /// Forward, no matter what kind of event
+    /// Forward, no matter what kind of event, except for [`Event::Lifecycle`] and [`Event::Timer`] events
     Any,
     /// Check whether a certain key has been pressed
     Keyboard(KeyEvent),
@@ -126,11 +585,30 @@ where
     Mouse(MouseEventClause),
     /// Check whether window has been resized
     WindowResize,
+    /// Check whether the terminal window has gained focus
+    FocusGained,
+    /// Check whether the terminal window has lost focus
+    FocusLost,
     /// The event will be forwarded on a tick
     Tick,
     /// Event will be forwarded on this specific user event.
     /// The way user event is matched, depends on its [`PartialEq`] implementation
     User(UserEvent),
+    /// Event will be forwarded on any user event which satisfies the provided predicate
+    /// (e.g. `|ev| matches!(ev, MyEvent::DownloadProgress(_))`).
+    ///
+    /// A `UserWhere` clause is never equal to any clause, not even to itself, since functions have no stable address:
+    /// its subscriptions are never detected as duplicated and they can't be unsubscribed by value with
+    /// `Application::unsubscribe`, which returns `ApplicationError::PredicateClause`;
+    /// use `Application::unsubscribe_where` instead.
+    ///
+    /// The predicate can't be serialized.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    UserWhere(fn(&UserEvent) -> bool),
+    /// Event will be forwarded on the lifecycle events which satisfy the clause
+    Lifecycle(LifecycleClause),
+    /// Event will be forwarded when a timer with this id expires, whichever component owns the timer
+    Timer(TimerId),
 }
 
 impl<U> EventClause<U>
@@ -145,17 +623,92 @@ where
     /// - Keyboard: everything must match
     /// - Mouse: everything must match, column and row need to be within range
     /// - WindowResize: matches only event type, not sizes
+    /// - FocusGained / FocusLost: matches the terminal focus events
     /// - Tick: matches tick event
     /// - None: matches None event
     /// - UserEvent: depends on UserEvent [`PartialEq`]
-    fn forward(&self, ev: &Event<U>) -> bool {
+    /// - UserWhere: the user event must satisfy the predicate
+    /// - Lifecycle: the lifecycle event must satisfy the lifecycle clause
+    /// - Timer: the timer id must match
+    pub(crate) fn forward(&self, ev: &Event<U>) -> bool {
         match self {
-            EventClause::Any => true,
+            EventClause::Any => ev.is_lifecycle().is_none() && ev.is_timer().is_none(),
             EventClause::Keyboard(k) => Some(k) == ev.is_keyboard(),
             EventClause::Mouse(m) => ev.is_mouse().map(|ev| m.is_in_range(ev)).unwrap_or(false),
             EventClause::WindowResize => ev.is_window_resize(),
+            EventClause::FocusGained => matches!(ev, Event::FocusGained),
+            EventClause::FocusLost => matches!(ev, Event::FocusLost),
             EventClause::Tick => ev.is_tick(),
             EventClause::User(u) => Some(u) == ev.is_user(),
+            EventClause::UserWhere(predicate) => ev.is_user().map(predicate).unwrap_or(false),
+            EventClause::Lifecycle(clause) => ev
+                .is_lifecycle()
+                .map(|ev| clause.matches(ev))
+                .unwrap_or(false),
+            EventClause::Timer(id) => Some(id) == ev.is_timer(),
+        }
+    }
+}
+
+impl<U> PartialEq for EventClause<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Any, Self::Any)
+            | (Self::WindowResize, Self::WindowResize)
+            | (Self::FocusGained, Self::FocusGained)
+            | (Self::FocusLost, Self::FocusLost)
+            | (Self::Tick, Self::Tick) => true,
+            (Self::Keyboard(a), Self::Keyboard(b)) => a == b,
+            (Self::Mouse(a), Self::Mouse(b)) => a == b,
+            (Self::User(a), Self::User(b)) => a == b,
+            // NOTE: function addresses are neither unique nor stable, so predicates can't be compared
+            (Self::UserWhere(_), _) => false,
+            (Self::Lifecycle(a), Self::Lifecycle(b)) => a == b,
+            (Self::Timer(a), Self::Timer(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<U> fmt::Display for EventClause<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Debug,
+{
+    /// Describe the clause in a human-readable way, e.g. `key CTRL+s` or `user event Foo`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventClause::Any => write!(f, "any event"),
+            EventClause::Keyboard(key) => write!(f, "key {key}"),
+            EventClause::Mouse(clause) => write!(
+                f,
+                "mouse {:?} at columns {}..{}, rows {}..{}",
+                clause.kind,
+                clause.column.start,
+                clause.column.end,
+                clause.row.start,
+                clause.row.end
+            ),
+            EventClause::WindowResize => write!(f, "window resize"),
+            EventClause::FocusGained => write!(f, "focus gained"),
+            EventClause::FocusLost => write!(f, "focus lost"),
+            EventClause::Tick => write!(f, "tick"),
+            EventClause::User(ev) => write!(f, "user event {ev:?}"),
+            EventClause::UserWhere(_) => write!(f, "user event satisfying predicate"),
+            EventClause::Lifecycle(clause) => {
+                match clause.kind {
+                    Some(kind) => write!(f, "lifecycle {kind:?}")?,
+                    None => write!(f, "any lifecycle")?,
+                }
+                // NOTE: the component id is type-erased, so it can't be displayed
+                match clause.id {
+                    Some(_) => write!(f, " of a component"),
+                    None => write!(f, " of any component"),
+                }
+            }
+            EventClause::Timer(id) => write!(f, "timer {}", id.0),
         }
     }
 }
@@ -166,7 +719,12 @@ where
 /// - [`SubClause::Not`]: Negates inner condition
 /// - [`SubClause::And`]: the AND of the two clauses must be `true`
 /// - [`SubClause::Or`]: the OR of the two clauses must be `true`
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Deserialize, Serialize),
+    serde(tag = "type", content = "args")
+)]
 #[allow(clippy::large_enum_variant)]
 pub enum SubClause<Id>
 where
@@ -176,11 +734,30 @@ where
     Always,
     /// Forward event if target component has provided attribute with the provided value
     /// If the attribute doesn't exist on component, result is always `false`.
+    ///
+    /// The attribute clauses can't be serialized.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     HasAttrValue(Id, Attribute, AttrValue),
+    /// Forward event if target component has one of the provided attribute values.
+    /// If the attribute doesn't exist on component or the values are empty, result is always `false`.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    HasAttrValueIn(Id, Attribute, Vec<AttrValue>),
     /// Forward event if target component has provided state
     HasState(Id, State),
+    /// Forward event if target component has one of the provided states.
+    /// If the values are empty, result is always `false`.
+    HasStateIn(Id, Vec<State>),
+    /// Forward event if the state of target component satisfies the provided predicate.
+    ///
+    /// A `StateSatisfies` clause is never equal to any clause, not even to itself, since functions have no stable address.
+    /// The predicate can't be serialized.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    StateSatisfies(Id, fn(&State) -> bool),
     /// Forward event if target component is mounted
     IsMounted(Id),
+    /// Forward event if target component is mounted and visible.
+    /// A component is visible unless its [`Attribute::Display`] is set to `AttrValue::Flag(false)`.
+    IsVisible(Id),
     /// Forward event if the inner clause is `false`
     Not(Box<SubClause<Id>>),
     /// Forward event if both the inner clauses are `true`
@@ -189,6 +766,33 @@ where
     Or(Box<SubClause<Id>>, Box<SubClause<Id>>),
 }
 
+impl<Id> PartialEq for SubClause<Id>
+where
+    Id: Eq + PartialEq + Clone + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Always, Self::Always) => true,
+            (Self::HasAttrValue(a, qa, va), Self::HasAttrValue(b, qb, vb)) => {
+                a == b && qa == qb && va == vb
+            }
+            (Self::HasAttrValueIn(a, qa, va), Self::HasAttrValueIn(b, qb, vb)) => {
+                a == b && qa == qb && va == vb
+            }
+            (Self::HasState(a, sa), Self::HasState(b, sb)) => a == b && sa == sb,
+            (Self::HasStateIn(a, sa), Self::HasStateIn(b, sb)) => a == b && sa == sb,
+            // NOTE: function addresses are neither unique nor stable, so predicates can't be compared
+            (Self::StateSatisfies(..), _) => false,
+            (Self::IsMounted(a), Self::IsMounted(b)) => a == b,
+            (Self::IsVisible(a), Self::IsVisible(b)) => a == b,
+            (Self::Not(a), Self::Not(b)) => a == b,
+            (Self::And(a1, a2), Self::And(b1, b2)) => a1 == b1 && a2 == b2,
+            (Self::Or(a1, a2), Self::Or(b1, b2)) => a1 == b1 && a2 == b2,
+            _ => false,
+        }
+    }
+}
+
 impl<Id> SubClause<Id>
 where
     Id: Eq + PartialEq + Clone + Hash,
@@ -242,14 +846,31 @@ where
                 let (fwd, has_attr_fn) = Self::has_attribute(id, query, value, has_attr_fn);
                 (fwd, has_attr_fn, get_state_fn, mounted_fn)
             }
+            Self::HasAttrValueIn(id, query, values) => {
+                let (fwd, has_attr_fn) = Self::has_attribute_in(id, query, values, has_attr_fn);
+                (fwd, has_attr_fn, get_state_fn, mounted_fn)
+            }
             Self::HasState(id, state) => {
                 let (fwd, get_state_fn) = Self::has_state(id, state, get_state_fn);
                 (fwd, has_attr_fn, get_state_fn, mounted_fn)
             }
+            Self::HasStateIn(id, states) => {
+                let (fwd, get_state_fn) =
+                    Self::state_satisfies(id, |s| states.contains(s), get_state_fn);
+                (fwd, has_attr_fn, get_state_fn, mounted_fn)
+            }
+            Self::StateSatisfies(id, predicate) => {
+                let (fwd, get_state_fn) = Self::state_satisfies(id, predicate, get_state_fn);
+                (fwd, has_attr_fn, get_state_fn, mounted_fn)
+            }
             Self::IsMounted(id) => {
                 let (fwd, mounted_fn) = Self::is_mounted(id, mounted_fn);
                 (fwd, has_attr_fn, get_state_fn, mounted_fn)
             }
+            Self::IsVisible(id) => {
+                let (fwd, has_attr_fn, mounted_fn) = Self::is_visible(id, has_attr_fn, mounted_fn);
+                (fwd, has_attr_fn, get_state_fn, mounted_fn)
+            }
             Self::Not(clause) => {
                 let (fwd, has_attr_fn, get_state_fn, mounted_fn) =
                     clause.check_forwarding(has_attr_fn, get_state_fn, mounted_fn);
@@ -292,6 +913,42 @@ where
         )
     }
 
+    fn has_attribute_in<HasAttrFn>(
+        id: &Id,
+        query: &Attribute,
+        values: &[AttrValue],
+        has_attr_fn: HasAttrFn,
+    ) -> (bool, HasAttrFn)
+    where
+        HasAttrFn: Fn(&Id, Attribute) -> Option<AttrValue>,
+    {
+        (
+            match has_attr_fn(id, *query) {
+                None => false,
+                Some(v) => values.contains(&v),
+            },
+            has_attr_fn,
+        )
+    }
+
+    fn state_satisfies<GetStateFn, P>(
+        id: &Id,
+        predicate: P,
+        get_state_fn: GetStateFn,
+    ) -> (bool, GetStateFn)
+    where
+        GetStateFn: Fn(&Id) -> Option<State>,
+        P: Fn(&State) -> bool,
+    {
+        (
+            match get_state_fn(id) {
+                Some(s) => predicate(&s),
+                None => false,
+            },
+            get_state_fn,
+        )
+    }
+
     fn has_state<GetStateFn>(id: &Id, state: &State, get_state_fn: GetStateFn) -> (bool, GetStateFn)
     where
         GetStateFn: Fn(&Id) -> Option<State>,
@@ -311,18 +968,82 @@ where
     {
         (mounted_fn(id), mounted_fn)
     }
+
+    fn is_visible<HasAttrFn, MountedFn>(
+        id: &Id,
+        has_attr_fn: HasAttrFn,
+        mounted_fn: MountedFn,
+    ) -> (bool, HasAttrFn, MountedFn)
+    where
+        HasAttrFn: Fn(&Id, Attribute) -> Option<AttrValue>,
+        MountedFn: Fn(&Id) -> bool,
+    {
+        (
+            mounted_fn(id) && has_attr_fn(id, Attribute::Display) != Some(AttrValue::Flag(false)),
+            has_attr_fn,
+            mounted_fn,
+        )
+    }
+}
+
+impl<Id> SubClause<Id>
+where
+    Id: Eq + PartialEq + Clone + Hash + Debug,
+{
+    /// Write the clause, wrapped in parentheses if it's an `And` or an `Or`
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::And(..) | Self::Or(..) => write!(f, "({self})"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl<Id> fmt::Display for SubClause<Id>
+where
+    Id: Eq + PartialEq + Clone + Hash + Debug,
+{
+    /// Describe the clause tree in a human-readable way, e.g. `InputFoo is mounted and not (state of InputBar is Empty)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => write!(f, "always"),
+            Self::HasAttrValue(id, attr, value) => write!(f, "{attr:?} of {id:?} is {value:?}"),
+            Self::HasAttrValueIn(id, attr, values) => {
+                write!(f, "{attr:?} of {id:?} is one of {values:?}")
+            }
+            Self::HasState(id, state) => write!(f, "state of {id:?} is {state:?}"),
+            Self::HasStateIn(id, states) => write!(f, "state of {id:?} is one of {states:?}"),
+            Self::StateSatisfies(id, _) => write!(f, "state of {id:?} satisfies predicate"),
+            Self::IsMounted(id) => write!(f, "{id:?} is mounted"),
+            Self::IsVisible(id) => write!(f, "{id:?} is visible"),
+            Self::Not(clause) => write!(f, "not ({clause})"),
+            Self::And(a, b) => {
+                a.fmt_operand(f)?;
+                write!(f, " and ")?;
+                b.fmt_operand(f)
+            }
+            Self::Or(a, b) => {
+                a.fmt_operand(f)?;
+                write!(f, " or ")?;
+                b.fmt_operand(f)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
 
+    use std::collections::HashMap;
+    use std::time::Duration;
+
     use pretty_assertions::assert_eq;
 
     use super::*;
     use crate::command::Cmd;
     use crate::event::{Key, KeyModifiers, MouseEventKind};
-    use crate::mock::{MockComponentId, MockEvent, MockFooInput};
-    use crate::{MockComponent, StateValue};
+    use crate::mock::{MockComponentId, MockEvent, MockFooInput, MockIdlePoll, MockMsg};
+    use crate::{Application, EventListenerCfg, MockComponent, StateValue};
 
     #[test]
     fn subscription_should_forward() {
@@ -338,6 +1059,7 @@ mod test {
                     Attribute::Focus,
                     AttrValue::Flag(true),
                 ),
+                None,
             ),
         );
         assert_eq!(sub.target(), &MockComponentId::InputFoo);
@@ -392,9 +1114,92 @@ mod test {
         );
     }
 
+    #[test]
+    fn subscriptions_should_be_ordered_by_priority_then_seq() {
+        let sub = |priority: i32, seq: usize| {
+            Subscription::new(
+                MockComponentId::InputFoo,
+                Sub::<MockComponentId, MockEvent>::tick()
+                    .priority(priority)
+                    .build(),
+            )
+            .with_seq(seq)
+        };
+        assert!(sub(1, 5).precedes(&sub(0, 0)));
+        assert!(sub(0, 0).precedes(&sub(0, 1)));
+        assert!(!sub(0, 1).precedes(&sub(0, 0)));
+        assert!(!sub(-1, 0).precedes(&sub(0, 1)));
+        assert!(!sub(0, 0).precedes(&sub(0, 0)));
+        let info = sub(3, 0).info();
+        assert_eq!(info.event, EventClause::Tick);
+        assert_eq!(info.when, SubClause::Always);
+        assert_eq!(info.priority, 3);
+        assert_eq!(info.active, true);
+    }
+
+    #[test]
+    fn paused_subscription_should_not_forward() {
+        let mut sub = Subscription::new(
+            MockComponentId::InputFoo,
+            Sub::<MockComponentId, MockEvent>::tick().build(),
+        );
+        let ev = Event::Tick(Duration::ZERO);
+        assert!(sub.active);
+        assert!(sub.forward(&ev, |_, _| None, |_| None, |_| true));
+        sub.set_active(false);
+        assert!(!sub.active);
+        assert!(!sub.forward(&ev, |_, _| None, |_| None, |_| true));
+        sub.set_active(true);
+        assert!(sub.forward(&ev, |_, _| None, |_| None, |_| true));
+    }
+
     #[test]
     fn event_clause_any_should_forward() {
-        assert!(EventClause::<MockEvent>::Any.forward(&Event::Tick));
+        assert!(EventClause::<MockEvent>::Any.forward(&Event::Tick(Duration::ZERO)));
+    }
+
+    #[test]
+    fn event_clause_any_should_not_forward_lifecycle_events() {
+        assert!(
+            !EventClause::<MockEvent>::Any.forward(&Event::Lifecycle(Lifecycle::new(
+                LifecycleKind::Mounted,
+                MockComponentId::InputFoo
+            )))
+        );
+    }
+
+    #[test]
+    fn event_clause_lifecycle_should_forward() {
+        let mounted_foo = Event::<MockEvent>::Lifecycle(Lifecycle::new(
+            LifecycleKind::Mounted,
+            MockComponentId::InputFoo,
+        ));
+        let focus_bar = Event::<MockEvent>::Lifecycle(Lifecycle::new(
+            LifecycleKind::FocusGained,
+            MockComponentId::InputBar,
+        ));
+        let clause = EventClause::<MockEvent>::Lifecycle(LifecycleClause::any());
+        assert_eq!(clause.forward(&mounted_foo), true);
+        assert_eq!(clause.forward(&focus_bar), true);
+        assert_eq!(clause.forward(&Event::Tick(Duration::ZERO)), false);
+        let clause = EventClause::<MockEvent>::Lifecycle(LifecycleClause::any_mounted());
+        assert_eq!(clause.forward(&mounted_foo), true);
+        assert_eq!(clause.forward(&focus_bar), false);
+        let clause =
+            EventClause::<MockEvent>::Lifecycle(LifecycleClause::of(MockComponentId::InputBar));
+        assert_eq!(clause.forward(&mounted_foo), false);
+        assert_eq!(clause.forward(&focus_bar), true);
+        let clause = EventClause::<MockEvent>::Lifecycle(LifecycleClause::focus_lost_of(
+            MockComponentId::InputBar,
+        ));
+        assert_eq!(clause.forward(&focus_bar), false);
+        let clause = EventClause::<MockEvent>::Lifecycle(LifecycleClause::mounted_of(
+            MockComponentId::InputFoo,
+        ));
+        assert_eq!(clause.forward(&mounted_foo), true);
+        // id of a different type
+        let clause = EventClause::<MockEvent>::Lifecycle(LifecycleClause::mounted_of(0u8));
+        assert_eq!(clause.forward(&mounted_foo), false);
     }
 
     #[test]
@@ -410,7 +1215,8 @@ mod test {
             false
         );
         assert_eq!(
-            EventClause::<MockEvent>::Keyboard(KeyEvent::from(Key::Enter)).forward(&Event::Tick),
+            EventClause::<MockEvent>::Keyboard(KeyEvent::from(Key::Enter))
+                .forward(&Event::Tick(Duration::ZERO)),
             false
         );
         assert_eq!(
@@ -475,7 +1281,7 @@ mod test {
                 column: 0..10,
                 row: 0..10
             })
-            .forward(&Event::Tick),
+            .forward(&Event::Tick(Duration::ZERO)),
             false
         );
     }
@@ -487,20 +1293,56 @@ mod test {
             true
         );
         assert_eq!(
-            EventClause::<MockEvent>::WindowResize.forward(&Event::Tick),
+            EventClause::<MockEvent>::WindowResize.forward(&Event::Tick(Duration::ZERO)),
+            false
+        );
+    }
+
+    #[test]
+    fn event_clause_focus_should_forward() {
+        assert_eq!(
+            EventClause::<MockEvent>::FocusGained.forward(&Event::FocusGained),
+            true
+        );
+        assert_eq!(
+            EventClause::<MockEvent>::FocusGained.forward(&Event::FocusLost),
+            false
+        );
+        assert_eq!(
+            EventClause::<MockEvent>::FocusLost.forward(&Event::FocusLost),
+            true
+        );
+        assert_eq!(
+            EventClause::<MockEvent>::FocusLost.forward(&Event::FocusGained),
             false
         );
     }
 
     #[test]
     fn event_clause_tick_should_forward() {
-        assert_eq!(EventClause::<MockEvent>::Tick.forward(&Event::Tick), true);
+        assert_eq!(
+            EventClause::<MockEvent>::Tick.forward(&Event::Tick(Duration::ZERO)),
+            true
+        );
         assert_eq!(
             EventClause::<MockEvent>::Tick.forward(&Event::WindowResize(0, 0)),
             false
         );
     }
 
+    #[test]
+    fn event_clause_timer_should_forward() {
+        let clause = EventClause::<MockEvent>::Timer(TimerId(1));
+        assert_eq!(clause.forward(&Event::Timer(TimerId(1))), true);
+        assert_eq!(clause.forward(&Event::Timer(TimerId(2))), false);
+        assert_eq!(clause.forward(&Event::Tick(Duration::ZERO)), false);
+        // timers are not forwarded to `Any`
+        assert_eq!(
+            EventClause::<MockEvent>::Any.forward(&Event::Timer(TimerId(1))),
+            false
+        );
+    }
+
     #[test]
     fn event_clause_user_should_forward() {
         assert_eq!(
@@ -508,11 +1350,26 @@ mod test {
             true
         );
         assert_eq!(
-            EventClause::<MockEvent>::User(MockEvent::Foo).forward(&Event::Tick),
+            EventClause::<MockEvent>::User(MockEvent::Foo).forward(&Event::Tick(Duration::ZERO)),
             false
         );
     }
 
+    #[test]
+    fn event_clause_user_where_should_forward() {
+        let clause = EventClause::<MockEvent>::UserWhere(|ev| matches!(ev, MockEvent::Hello(_)));
+        assert_eq!(
+            clause.forward(&Event::User(MockEvent::Hello(String::from("omar")))),
+            true
+        );
+        assert_eq!(
+            clause.forward(&Event::User(MockEvent::Hello(String::from("pippo")))),
+            true
+        );
+        assert_eq!(clause.forward(&Event::User(MockEvent::Foo)), false);
+        assert_eq!(clause.forward(&Event::Tick(Duration::ZERO)), false);
+    }
+
     #[test]
     fn clause_always_should_forward() {
         let component = MockFooInput::default();
@@ -580,6 +1437,158 @@ mod test {
         ); // Has state 'a'
     }
 
+    #[test]
+    fn clause_has_attribute_in_should_forward() {
+        let mut component = MockFooInput::default();
+        let clause = SubClause::HasAttrValueIn(
+            MockComponentId::InputBar,
+            Attribute::Focus,
+            vec![AttrValue::Flag(true), AttrValue::Flag(false)],
+        );
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            false
+        ); // Has no focus attribute
+        component.attr(Attribute::Focus, AttrValue::Flag(false));
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            true
+        );
+        // not mounted
+        assert_eq!(clause.forward(|_, _| None, |_| None, |_| false), false);
+        // empty
+        let clause = SubClause::HasAttrValueIn(MockComponentId::InputBar, Attribute::Focus, vec![]);
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            false
+        );
+    }
+
+    #[test]
+    fn clause_has_state_in_should_forward() {
+        let mut component = MockFooInput::default();
+        let clause = SubClause::HasStateIn(
+            MockComponentId::InputBar,
+            vec![
+                State::One(StateValue::String(String::from("a"))),
+                State::One(StateValue::String(String::from("ab"))),
+            ],
+        );
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            false
+        );
+        component.perform(Cmd::Type('a'));
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            true
+        );
+        component.perform(Cmd::Type('b'));
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            true
+        );
+        component.perform(Cmd::Type('c'));
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            false
+        );
+        // not mounted
+        assert_eq!(clause.forward(|_, _| None, |_| None, |_| false), false);
+        // empty
+        let clause = SubClause::HasStateIn(MockComponentId::InputBar, vec![]);
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            false
+        );
+    }
+
+    #[test]
+    fn clause_state_satisfies_should_forward() {
+        let mut component = MockFooInput::default();
+        let clause = SubClause::StateSatisfies(
+            MockComponentId::InputBar,
+            |state| matches!(state, State::One(StateValue::String(s)) if s.len() > 1),
+        );
+        component.perform(Cmd::Type('a'));
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            false
+        );
+        component.perform(Cmd::Type('b'));
+        assert_eq!(
+            clause.forward(
+                |_, q| component.query(q),
+                |_| Some(component.state()),
+                |_| true
+            ),
+            true
+        );
+        // not mounted
+        assert_eq!(clause.forward(|_, _| None, |_| None, |_| false), false);
+    }
+
+    #[test]
+    fn clause_has_nested_state_should_forward() {
+        let state = |cursor: u16| {
+            State::Nested(HashMap::from([
+                (
+                    String::from("cursor"),
+                    State::One(StateValue::Position { x: cursor, y: 0 }),
+                ),
+                (
+                    String::from("elapsed"),
+                    State::One(StateValue::Duration(Duration::from_secs(1))),
+                ),
+            ]))
+        };
+        let clause = SubClause::HasState(MockComponentId::InputBar, state(2));
+        assert_eq!(
+            clause.forward(|_, _| None, |_| Some(state(2)), |_| true),
+            true
+        );
+        assert_eq!(
+            clause.forward(|_, _| None, |_| Some(state(3)), |_| true),
+            false
+        );
+    }
+
     #[test]
     fn clause_is_mounted_should_forward() {
         let component = MockFooInput::default();
@@ -602,6 +1611,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn clause_is_visible_should_forward() {
+        let mut component = MockFooInput::default();
+        let clause = SubClause::IsVisible(MockComponentId::InputBar);
+        // Display not set
+        assert_eq!(
+            clause.forward(|_, q| component.query(q), |_| None, |_| true),
+            true
+        );
+        component.attr(Attribute::Display, AttrValue::Flag(true));
+        assert_eq!(
+            clause.forward(|_, q| component.query(q), |_| None, |_| true),
+            true
+        );
+        component.attr(Attribute::Display, AttrValue::Flag(false));
+        assert_eq!(
+            clause.forward(|_, q| component.query(q), |_| None, |_| true),
+            false
+        );
+        // Not mounted
+        component.attr(Attribute::Display, AttrValue::Flag(true));
+        assert_eq!(
+            clause.forward(|_, q| component.query(q), |_| None, |_| false),
+            false
+        );
+    }
+
     #[test]
     fn clause_not_should_forward() {
         let mut component = MockFooInput::default();
@@ -735,8 +1771,220 @@ mod test {
     fn should_create_a_sub() {
         let actual: Sub<MockComponentId, MockEvent> =
             Sub::new(EventClause::Tick, SubClause::Always);
-        let expected: Sub<MockComponentId, MockEvent> = Sub(EventClause::Tick, SubClause::Always);
+        let expected: Sub<MockComponentId, MockEvent> =
+            Sub(EventClause::Tick, SubClause::Always, None);
         assert_eq!(actual.0, expected.0);
         assert_eq!(actual.1, expected.1);
+        assert_eq!(actual.2, expected.2);
+        assert_eq!(actual.priority(-3).2, Some(-3));
+    }
+
+    #[test]
+    fn should_build_a_sub() {
+        // no clause
+        let built: Sub<MockComponentId, MockEvent> = Sub::tick().build();
+        let manual: Sub<MockComponentId, MockEvent> =
+            Sub::new(EventClause::Tick, SubClause::Always);
+        assert_eq!(built.0, manual.0);
+        assert_eq!(built.1, manual.1);
+        // keyboard with modifiers and nested clauses
+        let built: Sub<MockComponentId, MockEvent> = Sub::keyboard(Key::Char('h'))
+            .with_ctrl()
+            .with_alt()
+            .when_mounted(MockComponentId::InputFoo)
+            .and_has_attr(
+                MockComponentId::InputFoo,
+                Attribute::Display,
+                AttrValue::Flag(true),
+            )
+            .or_has_state(MockComponentId::InputBar, State::None)
+            .build();
+        let manual: Sub<MockComponentId, MockEvent> = Sub::new(
+            EventClause::Keyboard(KeyEvent::new(
+                Key::Char('h'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            )),
+            SubClause::or(
+                SubClause::and(
+                    SubClause::IsMounted(MockComponentId::InputFoo),
+                    SubClause::HasAttrValue(
+                        MockComponentId::InputFoo,
+                        Attribute::Display,
+                        AttrValue::Flag(true),
+                    ),
+                ),
+                SubClause::HasState(MockComponentId::InputBar, State::None),
+            ),
+        );
+        assert_eq!(built.0, manual.0);
+        assert_eq!(built.1, manual.1);
+        // modifiers are ignored for other events
+        let built: Sub<MockComponentId, MockEvent> = Sub::user(MockEvent::Foo)
+            .with_shift()
+            .when(SubClause::Always)
+            .into();
+        assert_eq!(built.0, EventClause::User(MockEvent::Foo));
+        assert_eq!(built.1, SubClause::Always);
+        let built: Sub<MockComponentId, MockEvent> = Sub::any().build();
+        assert_eq!(built.0, EventClause::Any);
+        let built: Sub<MockComponentId, MockEvent> = Sub::window_resize().build();
+        assert_eq!(built.0, EventClause::WindowResize);
+        assert_eq!(built.2, None);
+        let built: Sub<MockComponentId, MockEvent> = Sub::focus_gained().build();
+        assert_eq!(built.0, EventClause::FocusGained);
+        let built: Sub<MockComponentId, MockEvent> = Sub::focus_lost().build();
+        assert_eq!(built.0, EventClause::FocusLost);
+        let built: Sub<MockComponentId, MockEvent> = Sub::tick().priority(10).build();
+        assert_eq!(built.2, Some(10));
+    }
+
+    #[test]
+    fn built_sub_should_be_detected_as_duplicated() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![Sub::new(
+                    EventClause::Keyboard(KeyEvent::new(Key::Esc, KeyModifiers::CONTROL)),
+                    SubClause::Always
+                )]
+            )
+            .is_ok());
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputFoo,
+                Sub::keyboard(Key::Esc).with_ctrl().build()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn user_where_sub_should_never_be_equal() {
+        fn is_hello(ev: &MockEvent) -> bool {
+            matches!(ev, MockEvent::Hello(_))
+        }
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(EventListenerCfg::default().add_port(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            ));
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![Sub::user_where(is_hello).build()]
+            )
+            .is_ok());
+        // predicates can't be compared, so the subscription is not a duplicate
+        assert!(application
+            .subscribe(
+                &MockComponentId::InputFoo,
+                Sub::new(EventClause::UserWhere(is_hello), SubClause::Always)
+            )
+            .is_ok());
+        assert_ne!(
+            EventClause::<MockEvent>::UserWhere(is_hello),
+            EventClause::UserWhere(is_hello)
+        );
+        assert_ne!(
+            SubClause::StateSatisfies(MockComponentId::InputFoo, |_| true),
+            SubClause::StateSatisfies(MockComponentId::InputFoo, |_| true)
+        );
+        assert_eq!(
+            SubClause::not(SubClause::IsMounted(MockComponentId::InputFoo)),
+            SubClause::not(SubClause::IsMounted(MockComponentId::InputFoo))
+        );
+        // nor unsubscribed by value
+        assert!(application
+            .unsubscribe(&MockComponentId::InputFoo, EventClause::UserWhere(is_hello))
+            .is_err());
+        assert_eq!(
+            application.unsubscribe_where(|_, ev| matches!(ev, EventClause::UserWhere(_))),
+            2
+        );
+    }
+
+    #[test]
+    fn should_display_event_clauses() {
+        let display = |clause: EventClause<MockEvent>| clause.to_string();
+        assert_eq!(display(EventClause::Any), "any event");
+        assert_eq!(
+            display(EventClause::Keyboard(KeyEvent::new(
+                Key::Char('s'),
+                KeyModifiers::CONTROL
+            ))),
+            "key CTRL+s"
+        );
+        assert_eq!(
+            display(EventClause::Mouse(MouseEventClause {
+                kind: MouseEventKind::Moved,
+                modifiers: KeyModifiers::NONE,
+                column: 0..10,
+                row: 2..4,
+            })),
+            "mouse Moved at columns 0..10, rows 2..4"
+        );
+        assert_eq!(display(EventClause::Tick), "tick");
+        assert_eq!(
+            display(EventClause::User(MockEvent::Hello(String::from("omar")))),
+            "user event Hello(\"omar\")"
+        );
+        assert_eq!(
+            display(EventClause::UserWhere(|_| true)),
+            "user event satisfying predicate"
+        );
+        assert_eq!(
+            display(EventClause::Lifecycle(LifecycleClause::any())),
+            "any lifecycle of any component"
+        );
+        assert_eq!(
+            display(EventClause::Lifecycle(LifecycleClause::mounted_of(
+                MockComponentId::InputFoo
+            ))),
+            "lifecycle Mounted of a component"
+        );
+        assert_eq!(display(EventClause::Timer(TimerId(3))), "timer 3");
+    }
+
+    #[test]
+    fn should_display_nested_sub_clause() {
+        let clause = SubClause::and(
+            SubClause::IsMounted(MockComponentId::InputFoo),
+            SubClause::or(
+                SubClause::not(SubClause::HasState(
+                    MockComponentId::InputBar,
+                    State::One(StateValue::String(String::from("bar"))),
+                )),
+                SubClause::and(
+                    SubClause::IsVisible(MockComponentId::InputOmar),
+                    SubClause::HasAttrValueIn(
+                        MockComponentId::InputOmar,
+                        Attribute::Focus,
+                        vec![AttrValue::Flag(true)],
+                    ),
+                ),
+            ),
+        );
+        assert_eq!(
+            clause.to_string(),
+            "InputFoo is mounted and (not (state of InputBar is One(String(\"bar\"))) or \
+             (InputOmar is visible and Focus of InputOmar is one of [Flag(true)]))"
+        );
+        assert_eq!(SubClause::<MockComponentId>::Always.to_string(), "always");
+        assert_eq!(
+            SubClause::not(SubClause::or(
+                SubClause::IsMounted(MockComponentId::InputFoo),
+                SubClause::StateSatisfies(MockComponentId::InputBar, |_| true)
+            ))
+            .to_string(),
+            "not (InputFoo is mounted or state of InputBar satisfies predicate)"
+        );
     }
 }
@@ -4,12 +4,15 @@
 
 // -- ext
 use std::collections::HashMap;
+use std::fmt::Debug;
 use std::hash::Hash;
 
 use ratatui::Frame;
 use thiserror::Error;
 
+use super::render_cache::RenderCache;
 use crate::ratatui::layout::Rect;
+use crate::utils::text::edit_distance;
 use crate::{AttrValue, Attribute, Component, Event, Injector, State};
 
 /// A boxed component. Shorthand for View components map
@@ -19,15 +22,21 @@ pub(crate) type WrappedComponent<Msg, UserEvent> = Box<dyn Component<Msg, UserEv
 /// Returns a variable Ok and a ViewError in case of error.
 pub type ViewResult<T> = Result<T, ViewError>;
 
-/// An error returned by the view
+/// An error returned by the view.
+///
+/// Component ids are reported with their [`Debug`] representation.
 #[derive(Debug, Error)]
 pub enum ViewError {
-    #[error("component already mounted")]
-    ComponentAlreadyMounted,
-    #[error("component not found")]
-    ComponentNotFound,
-    #[error("there's no component to blur")]
-    NoComponentToBlur,
+    #[error("component already mounted: {0}")]
+    ComponentAlreadyMounted(String),
+    /// The component `id` is not mounted. `suggestion` is the mounted id most similar to `id`, if any is similar enough
+    #[error("component not found: {id}{}", .suggestion.as_ref().map(|s| format!(" (did you mean {s}?)")).unwrap_or_default())]
+    ComponentNotFound {
+        id: String,
+        suggestion: Option<String>,
+    },
+    #[error("there's no component to blur (mounted components: [{}])", .mounted.join(", "))]
+    NoComponentToBlur { mounted: Vec<String> },
 }
 
 /// View is the wrapper and manager for all the components.
@@ -35,50 +44,58 @@ pub enum ViewError {
 /// Each View can have only one focused component at the time. At least one component must be always focused
 pub struct View<ComponentId, Msg, UserEvent>
 where
-    ComponentId: Eq + PartialEq + Clone + Hash,
-    Msg: PartialEq,
+    ComponentId: Eq + PartialEq + Clone + Hash + Debug,
     UserEvent: Eq + PartialEq + Clone + PartialOrd,
 {
     /// Components Mounted onto View
     components: HashMap<ComponentId, WrappedComponent<Msg, UserEvent>>,
+    /// Ids of the mounted components, in mount order
+    mount_order: Vec<ComponentId>,
     /// Current active component
     focus: Option<ComponentId>,
     /// Focus stack; used to determine which component should hold focus in case the current element is blurred
     focus_stack: Vec<ComponentId>,
     /// Property injectors
     injectors: Vec<Box<dyn Injector<ComponentId>>>,
+    /// Area where the active component has been rendered last time
+    focus_area: Option<(ComponentId, Rect)>,
+    /// Renders of the components; `None` if the render cache is disabled. (Default: None)
+    render_cache: Option<RenderCache<ComponentId>>,
 }
 
 impl<K, Msg, UserEvent> Default for View<K, Msg, UserEvent>
 where
-    K: Eq + PartialEq + Clone + Hash,
-    Msg: PartialEq,
+    K: Eq + PartialEq + Clone + Hash + Debug,
     UserEvent: Eq + PartialEq + Clone + PartialOrd,
 {
     fn default() -> Self {
         Self {
             components: HashMap::new(),
+            mount_order: Vec::new(),
             focus: None,
             focus_stack: Vec::new(),
             injectors: Vec::new(),
+            focus_area: None,
+            render_cache: None,
         }
     }
 }
 
 impl<K, Msg, UserEvent> View<K, Msg, UserEvent>
 where
-    K: Eq + PartialEq + Clone + Hash,
-    Msg: PartialEq,
+    K: Eq + PartialEq + Clone + Hash + Debug,
     UserEvent: Eq + PartialEq + Clone + PartialOrd,
 {
     /// Mount component on View.
     /// Returns error if the component is already mounted
     pub fn mount(&mut self, id: K, component: WrappedComponent<Msg, UserEvent>) -> ViewResult<()> {
         if self.mounted(&id) {
-            Err(ViewError::ComponentAlreadyMounted)
+            Err(ViewError::ComponentAlreadyMounted(format!("{id:?}")))
         } else {
             // Insert
+            self.invalidate_render_cache(&id);
             self.components.insert(id.clone(), component);
+            self.mount_order.push(id.clone());
             // Inject properties
             self.inject(&id)
         }
@@ -87,7 +104,7 @@ where
     /// Umount component from View
     pub fn umount(&mut self, id: &K) -> ViewResult<()> {
         if !self.mounted(id) {
-            return Err(ViewError::ComponentNotFound);
+            return Err(self.not_found(id));
         }
         if self.has_focus(id) {
             let _ = self.blur();
@@ -96,10 +113,13 @@ where
         self.pop_from_stack(id);
         // Umount
         self.components.remove(id);
+        self.mount_order.retain(|x| x != id);
+        self.invalidate_render_cache(id);
         Ok(())
     }
 
-    /// Remount component. This method WON'T change the focus stack
+    /// Remount component. This method WON'T change the focus stack.
+    /// If the component was already mounted, it keeps its position in the mount order
     pub fn remount(
         &mut self,
         id: K,
@@ -109,8 +129,11 @@ where
         let had_focus = self.has_focus(&id);
         if self.mounted(&id) {
             self.components.remove(&id);
+        } else {
+            self.mount_order.push(id.clone());
         }
         // remount
+        self.invalidate_render_cache(&id);
         self.components.insert(id.clone(), component);
         // Inject properties
         self.inject(&id)?;
@@ -125,8 +148,12 @@ where
     /// Umount all components in the view and clear focus stack and state
     pub fn umount_all(&mut self) {
         self.components.clear();
+        self.mount_order.clear();
         self.focus_stack.clear();
         self.focus = None;
+        if let Some(cache) = self.render_cache.as_mut() {
+            cache.clear();
+        }
     }
 
     /// Returns whether component `id` is mounted
@@ -134,33 +161,100 @@ where
         self.components.contains_key(id)
     }
 
+    /// Returns the ids of the mounted components, in mount order
+    pub fn mounted_ids(&self) -> Vec<&K> {
+        self.mount_order.iter().collect()
+    }
+
+    /// Move `id` right before `other` in the mount order; nothing happens if either is not mounted
+    pub(crate) fn move_before(&mut self, id: &K, other: &K) {
+        let Some(from) = self.mount_order.iter().position(|x| x == id) else {
+            return;
+        };
+        let id = self.mount_order.remove(from);
+        match self.mount_order.iter().position(|x| x == other) {
+            Some(to) => self.mount_order.insert(to, id),
+            None => self.mount_order.insert(from, id),
+        }
+    }
+
+    /// Returns the ids of the mounted components which satisfy `filter`, in mount order
+    pub fn components_with<F>(&self, filter: F) -> Vec<&K>
+    where
+        F: Fn(&K) -> bool,
+    {
+        self.mount_order.iter().filter(|id| filter(id)).collect()
+    }
+
     /// Returns current active element (if any)
     pub(crate) fn focus(&self) -> Option<&K> {
         self.focus.as_ref()
     }
 
     /// Render component called `id`
+    ///
+    /// If the render cache is enabled and the component hasn't changed since it has been rendered in `area` last time,
+    /// the previous render is copied into the frame, without calling the component `view` method.
     pub fn view(&mut self, id: &K, f: &mut Frame, area: Rect) {
         if let Some(c) = self.components.get_mut(id) {
-            c.view(f, area);
+            match self.render_cache.as_mut() {
+                Some(cache) if cache.restore(id, area, f.buffer_mut()) => {}
+                Some(cache) => {
+                    c.view(f, area);
+                    cache.store(id.clone(), area, f.buffer_mut());
+                }
+                None => c.view(f, area),
+            }
+            if self.focus.as_ref() == Some(id) {
+                self.focus_area = Some((id.clone(), area));
+            }
+        }
+    }
+
+    /// Returns the cursor position requested by the active component in the area where it has been
+    /// rendered last time.
+    /// Returns [`None`] if there's no active component or if it hasn't been rendered since it got focus.
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        let (id, area) = self.focus_area.as_ref()?;
+        if self.focus.as_ref() != Some(id) {
+            return None;
         }
+        self.components.get(id)?.cursor(*area)
     }
 
     /// Forward `event` (call `on()`) on component `id` and return a `Msg` if any.
     /// Returns error if the component doesn't exist
     pub(crate) fn forward(&mut self, id: &K, event: Event<UserEvent>) -> ViewResult<Option<Msg>> {
+        self.invalidate_render_cache(id);
         match self.components.get_mut(id) {
-            None => Err(ViewError::ComponentNotFound),
+            None => Err(self.not_found(id)),
             Some(c) => Ok(c.on(event)),
         }
     }
 
+    /// Forward `event` to each mounted component which satisfies `filter`, in mount order,
+    /// and return the messages they produced
+    pub(crate) fn forward_all<F>(&mut self, event: &Event<UserEvent>, filter: F) -> Vec<Msg>
+    where
+        F: Fn(&K) -> bool,
+    {
+        let ids: Vec<K> = self
+            .mount_order
+            .iter()
+            .filter(|id| filter(id))
+            .cloned()
+            .collect();
+        ids.iter()
+            .filter_map(|id| self.forward(id, event.clone()).ok().flatten())
+            .collect()
+    }
+
     /// Query view component for a certain `AttrValue`
     /// Returns error if the component doesn't exist
     /// Returns None if the attribute doesn't exist.
     pub fn query(&self, id: &K, query: Attribute) -> ViewResult<Option<AttrValue>> {
         match self.components.get(id) {
-            None => Err(ViewError::ComponentNotFound),
+            None => Err(self.not_found(id)),
             Some(c) => Ok(c.query(query)),
         }
     }
@@ -168,21 +262,51 @@ where
     /// Set attribute for component `id`
     /// Returns error if the component doesn't exist
     pub fn attr(&mut self, id: &K, attr: Attribute, value: AttrValue) -> ViewResult<()> {
+        self.invalidate_render_cache(id);
         if let Some(c) = self.components.get_mut(id) {
             c.attr(attr, value);
             Ok(())
         } else {
-            Err(ViewError::ComponentNotFound)
+            Err(self.not_found(id))
         }
     }
 
+    /// Remove attribute `attr` from component `id`.
+    /// Shorthand for `attr(id, attr, AttrValue::Unset)`.
+    /// Returns error if the component doesn't exist
+    pub fn remove_attr(&mut self, id: &K, attr: Attribute) -> ViewResult<()> {
+        self.attr(id, attr, AttrValue::Unset)
+    }
+
     /// Get state for component `id`.
     /// Returns `Err` if component doesn't exist
     pub fn state(&self, id: &K) -> ViewResult<State> {
         self.components
             .get(id)
             .map(|c| c.state())
-            .ok_or(ViewError::ComponentNotFound)
+            .ok_or_else(|| self.not_found(id))
+    }
+
+    /// Get the state of all the mounted components, except for the components whose state is [`State::None`]
+    pub fn state_all(&self) -> HashMap<K, State> {
+        self.components
+            .iter()
+            .map(|(id, c)| (id.clone(), c.state()))
+            .filter(|(_, state)| *state != State::None)
+            .collect()
+    }
+
+    /// Restore `state` on component `id`, calling [`crate::MockComponent::restore`].
+    /// Returns `Err` if component doesn't exist
+    pub fn restore(&mut self, id: &K, state: State) -> ViewResult<()> {
+        self.invalidate_render_cache(id);
+        match self.components.get_mut(id) {
+            None => Err(self.not_found(id)),
+            Some(c) => {
+                c.restore(state);
+                Ok(())
+            }
+        }
     }
 
     // -- shorthands
@@ -199,6 +323,20 @@ where
         Ok(())
     }
 
+    /// Give focus to component `id`, like [`View::active`], but the previous active component, if any,
+    /// WON'T BE PUSHED to the STACK.
+    /// Returns error: if component doesn't exist. Use `mounted()` to check if component exists
+    pub fn active_no_push(&mut self, id: &K) -> ViewResult<()> {
+        self.set_focus(id, true)?;
+        if let Some(focus) = self.focus.take() {
+            if &focus != id {
+                let _ = self.set_focus(&focus, false);
+            }
+        }
+        self.change_focus(id);
+        Ok(())
+    }
+
     /// Blur selected element AND DON'T PUSH CURRENT ACTIVE ELEMENT INTO THE STACK
     /// Shorthand for `attr(id, Attribute::Focus(AttrValue::Flag(false)))`.
     /// It also unset the current focus and give it to the first element in stack.
@@ -211,7 +349,61 @@ where
             self.focus_to_last();
             Ok(())
         } else {
-            Err(ViewError::NoComponentToBlur)
+            Err(ViewError::NoComponentToBlur {
+                mounted: self.mounted_names(),
+            })
+        }
+    }
+
+    /// Returns the focus stack, from the first to the last component which will get focus on blur
+    pub fn focus_stack(&self) -> Vec<&K> {
+        self.focus_stack.iter().collect()
+    }
+
+    /// Clear the focus stack. The current active component, if any, keeps focus
+    pub fn clear_focus_stack(&mut self) {
+        self.focus_stack.clear();
+    }
+
+    /// Replace the focus stack with the components of `stack` which are mounted and don't have focus
+    pub(crate) fn set_focus_stack(&mut self, stack: Vec<K>) {
+        let stack = stack
+            .into_iter()
+            .filter(|id| self.mounted(id) && !self.has_focus(id))
+            .collect();
+        self.focus_stack = stack;
+    }
+
+    // -- render cache
+
+    /// Enable or disable the render cache. Disabling the render cache drops all the cached renders.
+    ///
+    /// When the render cache is enabled, the buffer drawn by each component is kept, and as long as the component
+    /// is not changed and it is rendered in the same area, the cached buffer is drawn instead of calling
+    /// the component `view` method.
+    /// A component is changed when an event is forwarded to it, when an attribute is set, when its focus changes
+    /// or when it is remounted.
+    ///
+    /// > The render cache must be used only with components which render depends only on their properties and states
+    /// > and which don't overlap with other components; if the render of a component changes for any other reason
+    /// > (e.g. time), call [`View::invalidate_render_cache`] to redraw it.
+    /// >
+    /// > A cached render overwrites every cell of the area of the component, including the cells the component doesn't draw:
+    /// > these cells keep what was drawn below the component when it has been cached. So if the component is drawn
+    /// > over another one (e.g. a popup), the cells of the component below are stale until the render of the component
+    /// > on top is invalidated with [`View::invalidate_render_cache`].
+    pub fn enable_render_cache(&mut self, enable: bool) {
+        match (enable, self.render_cache.is_some()) {
+            (true, false) => self.render_cache = Some(RenderCache::default()),
+            (false, true) => self.render_cache = None,
+            _ => {}
+        }
+    }
+
+    /// Drop the cached render of component `id`, so that it will be redrawn on the next call to [`View::view`]
+    pub fn invalidate_render_cache(&mut self, id: &K) {
+        if let Some(cache) = self.render_cache.as_mut() {
+            cache.invalidate(id);
         }
     }
 
@@ -233,7 +425,7 @@ where
     }
 
     /// Pop component `id` from focus stack
-    fn pop_from_stack(&mut self, id: &K) {
+    pub(crate) fn pop_from_stack(&mut self, id: &K) {
         self.focus_stack.retain(|x| x != id);
     }
 
@@ -262,10 +454,14 @@ where
         self.focus = Some(key.clone());
     }
 
-    /// Give focus to the last component in the stack
+    /// Give focus to the last mounted component in the stack.
+    /// Components which are not mounted anymore are removed from the stack
     fn focus_to_last(&mut self) {
-        if let Some(focus) = self.take_last_from_stack() {
-            let _ = self.active(&focus);
+        while let Some(focus) = self.take_last_from_stack() {
+            if self.mounted(&focus) {
+                let _ = self.active(&focus);
+                break;
+            }
         }
     }
 
@@ -274,13 +470,37 @@ where
         self.focus_stack.pop()
     }
 
+    /// Returns the error for the component `id` which is not mounted,
+    /// suggesting the mounted id most similar to `id`, if any is similar enough
+    pub(crate) fn not_found(&self, id: &K) -> ViewError {
+        let id = format!("{id:?}");
+        // NOTE: allow a typo every three chars
+        let max_distance = (id.chars().count() / 3).max(1);
+        let suggestion = self
+            .mounted_names()
+            .into_iter()
+            .map(|mounted| (edit_distance(&id, &mounted), mounted))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min()
+            .map(|(_, mounted)| mounted);
+        ViewError::ComponentNotFound { id, suggestion }
+    }
+
+    /// Returns the names of the mounted components, sorted
+    fn mounted_names(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.components.keys().map(|id| format!("{id:?}")).collect();
+        ids.sort();
+        ids
+    }
+
     /// Set focus value for component
     fn set_focus(&mut self, id: &K, value: bool) -> ViewResult<()> {
+        self.invalidate_render_cache(id);
         if let Some(c) = self.components.get_mut(id) {
             c.attr(Attribute::Focus, AttrValue::Flag(value));
             Ok(())
         } else {
-            Err(ViewError::ComponentNotFound)
+            Err(self.not_found(id))
         }
     }
 
@@ -301,6 +521,8 @@ where
 #[cfg(test)]
 mod test {
 
+    use std::time::Duration;
+
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -308,7 +530,89 @@ mod test {
     use crate::mock::{
         MockBarInput, MockComponentId, MockEvent, MockFooInput, MockInjector, MockMsg,
     };
-    use crate::StateValue;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::command::{Cmd, CmdResult};
+    use crate::event::KeyModifiers;
+    use crate::props::Props;
+    use crate::ratatui::backend::TestBackend;
+    use crate::ratatui::style::Style;
+    use crate::ratatui::Terminal;
+    use crate::{MockComponent, StateValue};
+
+    /// A component rendering a table with many rows, which counts how many times it has been rendered
+    struct MockHeavyTable {
+        props: Props,
+        renders: Arc<AtomicUsize>,
+    }
+
+    impl MockHeavyTable {
+        fn new(renders: Arc<AtomicUsize>) -> Self {
+            Self {
+                props: Props::default(),
+                renders,
+            }
+        }
+    }
+
+    impl MockComponent for MockHeavyTable {
+        fn view(&mut self, frame: &mut Frame, area: Rect) {
+            self.renders.fetch_add(1, Ordering::SeqCst);
+            let title = self
+                .props
+                .get_or(Attribute::Text, AttrValue::String(String::default()))
+                .unwrap_string();
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let rows: Vec<String> = (0..2000)
+                .map(|i| format!("{:04} {} {} {}", i, title, focus, area.width))
+                .collect();
+            let buf = frame.buffer_mut();
+            for (y, row) in (area.top()..area.bottom()).zip(rows.iter()) {
+                buf.set_stringn(area.x, y, row, area.width as usize, Style::default());
+            }
+        }
+
+        fn query(&self, attr: Attribute) -> Option<AttrValue> {
+            self.props.get(attr)
+        }
+
+        fn attr(&mut self, attr: Attribute, value: AttrValue) {
+            self.props.set(attr, value);
+        }
+
+        fn state(&self) -> State {
+            State::None
+        }
+
+        fn perform(&mut self, _: Cmd) -> CmdResult {
+            CmdResult::None
+        }
+    }
+
+    impl Component<MockMsg, MockEvent> for MockHeavyTable {
+        fn on(&mut self, ev: Event<MockEvent>) -> Option<MockMsg> {
+            if let Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) = ev
+            {
+                let mut title = self
+                    .props
+                    .get_or(Attribute::Text, AttrValue::String(String::default()))
+                    .unwrap_string();
+                title.push(ch);
+                self.attr(Attribute::Text, AttrValue::String(title.clone()));
+                Some(MockMsg::FooInputChanged(title))
+            } else {
+                None
+            }
+        }
+    }
 
     #[test]
     fn default_view_should_be_empty() {
@@ -318,6 +622,73 @@ mod test {
         assert!(view.focus_stack.is_empty());
     }
 
+    #[test]
+    fn view_should_report_mount_order() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        for id in [
+            MockComponentId::InputOmar,
+            MockComponentId::InputFoo,
+            MockComponentId::InputBar,
+        ] {
+            assert!(view.mount(id, Box::new(MockFooInput::default())).is_ok());
+        }
+        assert_eq!(
+            view.mounted_ids(),
+            vec![
+                &MockComponentId::InputOmar,
+                &MockComponentId::InputFoo,
+                &MockComponentId::InputBar
+            ]
+        );
+        // remount keeps the position
+        assert!(view
+            .remount(MockComponentId::InputFoo, Box::new(MockBarInput::default()))
+            .is_ok());
+        assert_eq!(
+            view.mounted_ids(),
+            vec![
+                &MockComponentId::InputOmar,
+                &MockComponentId::InputFoo,
+                &MockComponentId::InputBar
+            ]
+        );
+        // umount and mount again moves to the end
+        assert!(view.umount(&MockComponentId::InputOmar).is_ok());
+        assert!(view
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok());
+        assert_eq!(
+            view.mounted_ids(),
+            vec![
+                &MockComponentId::InputFoo,
+                &MockComponentId::InputBar,
+                &MockComponentId::InputOmar
+            ]
+        );
+        // remounting a component which is not mounted appends it
+        assert!(view
+            .remount(
+                MockComponentId::Dyn(String::from("a")),
+                Box::new(MockFooInput::default())
+            )
+            .is_ok());
+        assert_eq!(
+            view.components_with(|id| matches!(
+                id,
+                MockComponentId::Dyn(_) | MockComponentId::InputFoo
+            )),
+            vec![
+                &MockComponentId::InputFoo,
+                &MockComponentId::Dyn(String::from("a"))
+            ]
+        );
+        view.umount_all();
+        assert!(view.mounted_ids().is_empty());
+    }
+
     #[test]
     fn view_should_mount_and_umount_components() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -421,6 +792,48 @@ mod test {
         names.iter().for_each(|x| assert!(view.mounted(x)));
     }
 
+    #[test]
+    fn view_should_report_the_id_in_errors() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert_eq!(
+            view.blur().unwrap_err().to_string(),
+            "there's no component to blur (mounted components: [])"
+        );
+        assert!(view
+            .mount(
+                MockComponentId::Dyn(String::from("input-name")),
+                Box::new(MockBarInput::default())
+            )
+            .is_ok());
+        assert!(view
+            .mount(MockComponentId::InputFoo, Box::new(MockFooInput::default()))
+            .is_ok());
+        assert_eq!(
+            view.mount(MockComponentId::InputFoo, Box::new(MockFooInput::default()))
+                .unwrap_err()
+                .to_string(),
+            "component already mounted: InputFoo"
+        );
+        // typo
+        assert_eq!(
+            view.state(&MockComponentId::Dyn(String::from("input-nmae")))
+                .unwrap_err()
+                .to_string(),
+            r#"component not found: Dyn("input-nmae") (did you mean Dyn("input-name")?)"#
+        );
+        // no similar id
+        assert_eq!(
+            view.umount(&MockComponentId::InputOmar)
+                .unwrap_err()
+                .to_string(),
+            "component not found: InputOmar"
+        );
+        assert_eq!(
+            view.blur().unwrap_err().to_string(),
+            r#"there's no component to blur (mounted components: [Dyn("input-name"), InputFoo])"#
+        );
+    }
+
     #[test]
     fn view_should_handle_focus() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -505,6 +918,78 @@ mod test {
         assert!(view.blur().is_err());
     }
 
+    #[test]
+    fn view_should_inspect_and_manipulate_focus_stack() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(view
+            .mount(MockComponentId::InputFoo, Box::new(MockFooInput::default()))
+            .is_ok());
+        assert!(view
+            .mount(MockComponentId::InputBar, Box::new(MockBarInput::default()))
+            .is_ok());
+        assert!(view
+            .mount(
+                MockComponentId::InputOmar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok());
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        assert_eq!(view.focus_stack(), vec![&MockComponentId::InputFoo]);
+        // Replace BAR with OMAR
+        assert!(view.active_no_push(&MockComponentId::InputOmar).is_ok());
+        assert!(view.has_focus(&MockComponentId::InputOmar));
+        assert_eq!(
+            view.query(&MockComponentId::InputBar, Attribute::Focus)
+                .ok()
+                .unwrap()
+                .unwrap(),
+            AttrValue::Flag(false)
+        );
+        assert_eq!(view.focus_stack(), vec![&MockComponentId::InputFoo]);
+        assert!(view.active_no_push(&MockComponentId::InputBar).is_ok());
+        assert!(view.active_no_push(&MockComponentId::InputBar).is_ok());
+        assert!(view.has_focus(&MockComponentId::InputBar));
+        assert_eq!(view.focus_stack(), vec![&MockComponentId::InputFoo]);
+        // Blur should give focus back to FOO
+        assert!(view.blur().is_ok());
+        assert!(view.has_focus(&MockComponentId::InputFoo));
+        assert!(view
+            .active_no_push(&MockComponentId::Dyn(String::from("pippo")))
+            .is_err());
+        // Clear stack
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        assert!(view.active(&MockComponentId::InputOmar).is_ok());
+        assert_eq!(
+            view.focus_stack(),
+            vec![&MockComponentId::InputFoo, &MockComponentId::InputBar]
+        );
+        view.clear_focus_stack();
+        assert!(view.focus_stack().is_empty());
+        assert!(view.has_focus(&MockComponentId::InputOmar));
+        assert!(view.blur().is_ok());
+        assert!(view.focus().is_none());
+    }
+
+    #[test]
+    fn view_should_skip_unmounted_components_in_stack_on_blur() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(view
+            .mount(MockComponentId::InputFoo, Box::new(MockFooInput::default()))
+            .is_ok());
+        assert!(view
+            .mount(MockComponentId::InputBar, Box::new(MockBarInput::default()))
+            .is_ok());
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        // Put an unmounted component in the stack
+        view.focus_stack
+            .push(MockComponentId::Dyn(String::from("help")));
+        assert!(view.blur().is_ok());
+        assert!(view.has_focus(&MockComponentId::InputFoo));
+        assert!(view.focus_stack().is_empty());
+    }
+
     #[test]
     fn view_should_forward_events() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -521,10 +1006,60 @@ mod test {
         );
         // To non-existing component
         assert!(view
-            .forward(&MockComponentId::InputBar, Event::Tick)
+            .forward(&MockComponentId::InputBar, Event::Tick(Duration::ZERO))
             .is_err());
     }
 
+    #[test]
+    fn view_should_track_cursor_of_active_component() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+        assert!(view
+            .mount(MockComponentId::InputFoo, Box::new(MockFooInput::default()))
+            .is_ok());
+        assert!(view
+            .mount(MockComponentId::InputBar, Box::new(MockFooInput::default()))
+            .is_ok());
+        // not active
+        let foo_area = Rect::new(2, 3, 20, 1);
+        let bar_area = Rect::new(2, 6, 20, 1);
+        assert!(terminal
+            .draw(|f| {
+                view.view(&MockComponentId::InputFoo, f, foo_area);
+                view.view(&MockComponentId::InputBar, f, bar_area);
+            })
+            .is_ok());
+        assert_eq!(view.cursor(), None);
+        // active, but not rendered yet
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(view.cursor(), None);
+        // rendered; order doesn't matter
+        assert!(terminal
+            .draw(|f| {
+                view.view(&MockComponentId::InputFoo, f, foo_area);
+                view.view(&MockComponentId::InputBar, f, bar_area);
+            })
+            .is_ok());
+        assert_eq!(view.cursor(), Some((2, 3)));
+        assert!(view
+            .forward(
+                &MockComponentId::InputFoo,
+                Event::Keyboard(KeyEvent::from(Key::Right))
+            )
+            .is_ok());
+        assert_eq!(view.cursor(), Some((3, 3)));
+        // focus changes
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        assert_eq!(view.cursor(), None);
+        assert!(terminal
+            .draw(|f| view.view(&MockComponentId::InputBar, f, bar_area))
+            .is_ok());
+        assert_eq!(view.cursor(), Some((2, 6)));
+        // focus is given back to foo, which must be rendered again
+        assert!(view.blur().is_ok());
+        assert_eq!(view.cursor(), None);
+    }
+
     #[test]
     fn view_should_read_and_write_attributes() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -553,6 +1088,19 @@ mod test {
                 .unwrap(),
             Some(AttrValue::Flag(true))
         );
+        // Remove attribute
+        assert!(view
+            .remove_attr(&MockComponentId::InputFoo, Attribute::Focus)
+            .is_ok());
+        assert_eq!(
+            view.query(&MockComponentId::InputFoo, Attribute::Focus)
+                .ok()
+                .unwrap(),
+            None
+        );
+        assert!(view
+            .remove_attr(&MockComponentId::InputBar, Attribute::Focus)
+            .is_err());
     }
 
     #[test]
@@ -571,7 +1119,7 @@ mod test {
     #[test]
     fn view_should_inject_properties() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
-        view.add_injector(Box::new(MockInjector::default()));
+        view.add_injector(Box::new(MockInjector));
         assert!(view
             .mount(MockComponentId::InputBar, Box::new(MockBarInput::default()))
             .is_ok());
@@ -584,4 +1132,166 @@ mod test {
             AttrValue::String(String::from("hello, world!"))
         );
     }
+
+    #[test]
+    fn render_cache_should_render_the_same_output() {
+        let cached_renders = Arc::new(AtomicUsize::new(0));
+        let renders = Arc::new(AtomicUsize::new(0));
+        let mut cached: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        cached.enable_render_cache(true);
+        assert!(cached.render_cache.is_some());
+        let mut uncached: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(uncached.render_cache.is_none());
+        let mut cached_terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        for (view, renders) in [(&mut cached, &cached_renders), (&mut uncached, &renders)] {
+            assert!(view
+                .mount(
+                    MockComponentId::InputFoo,
+                    Box::new(MockHeavyTable::new(renders.clone()))
+                )
+                .is_ok());
+            assert!(view
+                .mount(
+                    MockComponentId::InputBar,
+                    Box::new(MockHeavyTable::new(Arc::new(AtomicUsize::new(0))))
+                )
+                .is_ok());
+        }
+        // draw both views and check the output is the same
+        type MockView = View<MockComponentId, MockMsg, MockEvent>;
+        type MockTerminal = Terminal<TestBackend>;
+        fn draw_views(views: [(&mut MockView, &mut MockTerminal); 2]) {
+            for (view, terminal) in views {
+                assert!(terminal
+                    .draw(|f| {
+                        let area = f.area();
+                        let (top, bottom) = (
+                            Rect::new(0, 0, area.width, area.height / 2),
+                            Rect::new(0, area.height / 2, area.width, area.height / 2),
+                        );
+                        view.view(&MockComponentId::InputFoo, f, top);
+                        view.view(&MockComponentId::InputBar, f, bottom);
+                    })
+                    .is_ok());
+            }
+        }
+        macro_rules! draw {
+            () => {
+                draw_views([
+                    (&mut cached, &mut cached_terminal),
+                    (&mut uncached, &mut terminal),
+                ]);
+                assert_eq!(
+                    cached_terminal.backend().buffer(),
+                    terminal.backend().buffer()
+                );
+            };
+        }
+        // unchanged frames
+        for _ in 0..30 {
+            draw!();
+        }
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 1);
+        assert_eq!(renders.load(Ordering::SeqCst), 30);
+        // event
+        for view in [&mut cached, &mut uncached] {
+            assert_eq!(
+                view.forward(
+                    &MockComponentId::InputFoo,
+                    Event::Keyboard(KeyEvent::from(Key::Char('a')))
+                )
+                .unwrap(),
+                Some(MockMsg::FooInputChanged(String::from("a")))
+            );
+        }
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 2);
+        // attr
+        for view in [&mut cached, &mut uncached] {
+            assert!(view
+                .attr(
+                    &MockComponentId::InputFoo,
+                    Attribute::Text,
+                    AttrValue::String(String::from("omar"))
+                )
+                .is_ok());
+        }
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 3);
+        // focus
+        for view in [&mut cached, &mut uncached] {
+            assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        }
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 4);
+        for view in [&mut cached, &mut uncached] {
+            assert!(view.active(&MockComponentId::InputBar).is_ok());
+        }
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 5);
+        // resize
+        cached_terminal.backend_mut().resize(40, 10);
+        terminal.backend_mut().resize(40, 10);
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 6);
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 6);
+        // invalidate
+        cached.invalidate_render_cache(&MockComponentId::InputFoo);
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 7);
+        // disable
+        cached.enable_render_cache(false);
+        draw!();
+        assert_eq!(cached_renders.load(Ordering::SeqCst), 8);
+    }
+
+    #[test]
+    fn render_cache_should_be_invalidated_on_remount() {
+        let renders = Arc::new(AtomicUsize::new(0));
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        view.enable_render_cache(true);
+        let mut terminal = Terminal::new(TestBackend::new(20, 4)).unwrap();
+        assert!(view
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockHeavyTable::new(renders.clone()))
+            )
+            .is_ok());
+        let mut draw = |view: &mut View<MockComponentId, MockMsg, MockEvent>| {
+            assert!(terminal
+                .draw(|f| view.view(&MockComponentId::InputFoo, f, f.area()))
+                .is_ok());
+        };
+        draw(&mut view);
+        draw(&mut view);
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+        assert!(view
+            .remount(
+                MockComponentId::InputFoo,
+                Box::new(MockHeavyTable::new(renders.clone()))
+            )
+            .is_ok());
+        draw(&mut view);
+        assert_eq!(renders.load(Ordering::SeqCst), 2);
+        assert!(view.umount(&MockComponentId::InputFoo).is_ok());
+        assert!(view
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockHeavyTable::new(renders.clone()))
+            )
+            .is_ok());
+        draw(&mut view);
+        assert_eq!(renders.load(Ordering::SeqCst), 3);
+        view.umount_all();
+        assert!(view
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockHeavyTable::new(renders.clone()))
+            )
+            .is_ok());
+        draw(&mut view);
+        assert_eq!(renders.load(Ordering::SeqCst), 4);
+    }
 }
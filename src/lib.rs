@@ -35,9 +35,17 @@
 //! Supported features are:
 //!
 //! - `derive` (*default*): add the `#[derive(MockComponent)]` proc macro to automatically implement `MockComponent` for `Component`. [Read more](https://github.com/veeso/tuirealm_derive).
-//! - `serialize`: add the serialize/deserialize trait implementation for `KeyEvent` and `Key`.
+//! - `serialize`: add the serialize/deserialize trait implementation for `KeyEvent`, `Key` and `Event` and enables event recording and replay and the serialization of the layout snapshots.
 //! - `crossterm`: use the [crossterm](https://github.com/crossterm-rs/crossterm) terminal backend
 //! - `termion`: use the [termion](https://github.com/redox-os/termion) terminal backend
+//! - `tracing`: instrument the application and the event listener with [tracing](https://docs.rs/tracing) events
+//!
+//! ### Using ratatui 🐀
+//!
+//! tui-realm re-exports the whole ratatui library under `tuirealm::ratatui`, and the commonly used types
+//! (e.g. `Rect`, `Style`, `Constraint`, `Buffer` and `TestBackend`) under `tuirealm::ratatui_prelude`.
+//! Use these paths instead of depending on ratatui directly, so that the ratatui types used by your application
+//! are always the same used by tui-realm.
 //!
 //! ### Create a tui-realm application 🪂
 //!
@@ -70,24 +78,38 @@ extern crate self as tuirealm;
 #[macro_use]
 extern crate tuirealm_derive;
 
+#[macro_use]
+mod trace;
+
 mod core;
 pub mod listener;
 pub mod macros;
 #[cfg(test)]
 pub mod mock;
 pub mod ratatui;
+pub mod ratatui_prelude;
 pub mod terminal;
 pub mod utils;
-pub use listener::{EventListenerCfg, ListenerError};
+pub use listener::{
+    EventListenerCfg, EventSender, ListenerError, MissedIntervalPolicy, QueueOverflowPolicy,
+    TickAlignment,
+};
 // -- derive
 #[cfg(feature = "derive")]
 #[doc(hidden)]
 pub use tuirealm_derive::*;
 
-pub use self::core::application::{self, Application, ApplicationError, PollStrategy};
+pub use self::core::application::{
+    self, Application, ApplicationBuildError, ApplicationBuilder, ApplicationError, FocusRing,
+    FrameScheduler, LayoutSnapshot, ModalHandle, PollStrategy, RestartPolicy, TickResult,
+};
 pub use self::core::event::{self, Event, NoUserEvent};
 pub use self::core::injector::Injector;
 pub use self::core::props::{self, AttrValue, Attribute, Props};
-pub use self::core::subscription::{EventClause as SubEventClause, Sub, SubClause};
-pub use self::core::{command, Component, MockComponent, State, StateValue, Update, ViewError};
+pub use self::core::subscription::{
+    EventClause as SubEventClause, LifecycleClause, Sub, SubBuilder, SubClause, SubInfo,
+};
+pub use self::core::{
+    command, Children, Component, MapUserEvent, MockComponent, State, StateValue, Update, ViewError,
+};
 pub use self::ratatui::Frame;
@@ -2,7 +2,137 @@
 //!
 //! This module exposes the EventListenerCfg which is used to build the event listener
 
-use super::{Duration, EventListener, Poll, Port};
+use std::sync::Arc;
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+use super::ListenerResult;
+use super::{
+    Duration, EventListener, EventRecorder, ListenerMetrics, MissedIntervalPolicy, Poll, Port,
+    QueueOverflowPolicy, TickAlignment, WorkerOptions,
+};
+use crate::event::KeyEventKind;
+use crate::Event;
+
+/// A function which creates a new [`Poll`] each time the event listener is (re)started
+type PollFactory<U> = Arc<dyn Fn() -> Box<dyn Poll<U>> + Send + Sync>;
+
+/// A transform of the events of a port, which is applied again to the port rebuilt by a [`PortFactory`]
+type SharedTransform<U> = Arc<dyn Fn(Event<U>) -> Option<Event<U>> + Send + Sync>;
+
+/// Describes how to build a [`Port`] with a [`PollFactory`]
+struct PortFactory<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    factory: PollFactory<U>,
+    interval: Duration,
+    max_poll: usize,
+    transforms: Vec<SharedTransform<U>>,
+    /// Whether the port is polled on a dedicated thread
+    blocking: bool,
+    /// Bounds of the intervals hinted by the poll
+    interval_bounds: Option<(Duration, Duration)>,
+}
+
+impl<U> PortFactory<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    /// Build a new [`Port`]
+    fn build(&self) -> Port<U> {
+        let port = self.transforms.iter().fold(
+            Port::new((self.factory)(), self.interval, self.max_poll),
+            |port, transform| {
+                let transform = Arc::clone(transform);
+                port.filter_map(move |ev| transform(ev))
+            },
+        );
+        let port = match self.interval_bounds {
+            Some((min, max)) => port.interval_bounds(min, max),
+            None => port,
+        };
+        if self.blocking {
+            port.blocking()
+        } else {
+            port
+        }
+    }
+}
+
+impl<U> Clone for PortFactory<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    fn clone(&self) -> Self {
+        Self {
+            factory: Arc::clone(&self.factory),
+            interval: self.interval,
+            max_poll: self.max_poll,
+            transforms: self.transforms.clone(),
+            blocking: self.blocking,
+            interval_bounds: self.interval_bounds,
+        }
+    }
+}
+
+/// An input listener whose [`Poll`] is built when the event listener is started,
+/// so that the options of the input listeners are applied whatever the order they've been set in
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+enum InputPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    #[cfg(feature = "crossterm")]
+    Crossterm {
+        interval: Duration,
+        poll_timeout: Option<Duration>,
+    },
+    #[cfg(feature = "crossterm")]
+    CrosstermSource(crate::terminal::CrosstermInputListener<U>),
+    #[cfg(feature = "termion")]
+    Termion { interval: Duration },
+    #[cfg(feature = "termion")]
+    TermionSource(crate::terminal::TermionInputListener<U>),
+}
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+impl<U> InputPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    /// Returns a copy of the input port, unless it reads from a source which can't be rebuilt
+    fn rebuildable(&self) -> Option<Self> {
+        match self {
+            #[cfg(feature = "crossterm")]
+            Self::Crossterm {
+                interval,
+                poll_timeout,
+            } => Some(Self::Crossterm {
+                interval: *interval,
+                poll_timeout: *poll_timeout,
+            }),
+            #[cfg(feature = "termion")]
+            Self::Termion { interval } => Some(Self::Termion {
+                interval: *interval,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Placeholder of the [`Poll`] of an [`InputPort`] until the event listener is started
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+struct PendingInput;
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+impl<U> Poll<U> for PendingInput
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
+        Ok(None)
+    }
+}
 
 /// The event listener configurator is used to setup an event listener.
 /// Once you're done with configuration just call `EventListenerCfg::start` and the event listener will start and the listener
@@ -12,8 +142,30 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
 {
     ports: Vec<Port<U>>,
+    /// Factories of the ports which can be rebuilt on restart
+    factories: Vec<PortFactory<U>>,
+    /// Whether the last port added has been built by the last factory
+    last_port_factory: bool,
     tick_interval: Option<Duration>,
+    tick_alignment: TickAlignment,
     poll_timeout: Duration,
+    recorder: Option<EventRecorder<U>>,
+    key_event_kinds: Vec<KeyEventKind>,
+    queue_capacity: usize,
+    overflow_policy: QueueOverflowPolicy,
+    metrics: Option<Arc<ListenerMetrics>>,
+    /// User events emitted at a fixed interval
+    interval_events: Vec<(U, Duration)>,
+    missed_interval_policy: MissedIntervalPolicy,
+    /// Input listeners built on start, with the index of their port
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    input_ports: Vec<(usize, InputPort<U>)>,
+    #[cfg(feature = "crossterm")]
+    crossterm_raw_hook: Option<crate::terminal::CrosstermRawHook<U>>,
+    #[cfg(feature = "crossterm")]
+    crossterm_focus_change: bool,
+    #[cfg(feature = "termion")]
+    termion_raw_hook: Option<crate::terminal::TermionRawHook<U>>,
 }
 
 impl<U> Default for EventListenerCfg<U>
@@ -23,8 +175,26 @@ where
     fn default() -> Self {
         Self {
             ports: Vec::default(),
+            factories: Vec::default(),
+            last_port_factory: false,
             poll_timeout: Duration::from_millis(10),
             tick_interval: None,
+            tick_alignment: TickAlignment::default(),
+            recorder: None,
+            key_event_kinds: vec![KeyEventKind::Press],
+            queue_capacity: 1024,
+            overflow_policy: QueueOverflowPolicy::default(),
+            metrics: None,
+            interval_events: Vec::new(),
+            missed_interval_policy: MissedIntervalPolicy::default(),
+            #[cfg(any(feature = "crossterm", feature = "termion"))]
+            input_ports: Vec::new(),
+            #[cfg(feature = "crossterm")]
+            crossterm_raw_hook: None,
+            #[cfg(feature = "crossterm")]
+            crossterm_focus_change: false,
+            #[cfg(feature = "termion")]
+            termion_raw_hook: None,
         }
     }
 }
@@ -34,8 +204,169 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     /// Create the event listener with the parameters provided and start the workers
-    pub(crate) fn start(self) -> EventListener<U> {
-        EventListener::start(self.ports, self.poll_timeout, self.tick_interval)
+    #[cfg_attr(not(any(feature = "crossterm", feature = "termion")), allow(unused_mut))]
+    pub(crate) fn start(mut self) -> EventListener<U> {
+        #[cfg(any(feature = "crossterm", feature = "termion"))]
+        self.build_input_ports();
+        EventListener::start(
+            self.ports,
+            self.poll_timeout,
+            self.queue_capacity,
+            WorkerOptions {
+                tick_interval: self.tick_interval,
+                tick_alignment: self.tick_alignment,
+                recorder: self.recorder,
+                key_event_kinds: self.key_event_kinds,
+                overflow_policy: self.overflow_policy,
+                metrics: self.metrics,
+                interval_events: self.interval_events,
+                missed_interval_policy: self.missed_interval_policy,
+            },
+        )
+    }
+
+    /// Replace the placeholder of each input port with its input listener, built with the current options
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    fn build_input_ports(&mut self) {
+        for (index, port) in std::mem::take(&mut self.input_ports) {
+            let poll: Box<dyn Poll<U>> = match port {
+                #[cfg(feature = "crossterm")]
+                InputPort::Crossterm {
+                    interval,
+                    poll_timeout,
+                } => {
+                    let listener = crate::terminal::CrosstermInputListener::new(interval);
+                    Box::new(self.crossterm_options(match poll_timeout {
+                        Some(timeout) => listener.with_poll_timeout(timeout),
+                        None => listener,
+                    }))
+                }
+                #[cfg(feature = "crossterm")]
+                InputPort::CrosstermSource(listener) => Box::new(self.crossterm_options(listener)),
+                #[cfg(feature = "termion")]
+                InputPort::Termion { interval } => Box::new(
+                    self.termion_options(crate::terminal::TermionInputListener::new(interval)),
+                ),
+                #[cfg(feature = "termion")]
+                InputPort::TermionSource(listener) => Box::new(self.termion_options(listener)),
+            };
+            self.ports[index].replace_poll(poll);
+        }
+    }
+
+    /// Apply the options of the crossterm input listeners to `listener`
+    #[cfg(feature = "crossterm")]
+    fn crossterm_options(
+        &self,
+        listener: crate::terminal::CrosstermInputListener<U>,
+    ) -> crate::terminal::CrosstermInputListener<U> {
+        let listener = match self.crossterm_raw_hook {
+            Some(hook) => listener.with_raw_hook(hook),
+            None => listener,
+        };
+        if self.crossterm_focus_change {
+            listener.with_focus_change()
+        } else {
+            listener
+        }
+    }
+
+    /// Apply the options of the termion input listeners to `listener`
+    #[cfg(feature = "termion")]
+    fn termion_options(
+        &self,
+        listener: crate::terminal::TermionInputListener<U>,
+    ) -> crate::terminal::TermionInputListener<U> {
+        match self.termion_raw_hook {
+            Some(hook) => listener.with_raw_hook(hook),
+            None => listener,
+        }
+    }
+
+    /// Add an input port, whose input listener is built on start
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    fn input_port(self, port: InputPort<U>, interval: Duration, max_poll: usize) -> Self {
+        let index = self.ports.len();
+        let mut cfg = match port.rebuildable() {
+            Some(_) => self.add_port_factory(|| Box::new(PendingInput), interval, max_poll),
+            None => self.add_port(Box::new(PendingInput), interval, max_poll),
+        };
+        cfg.input_ports.push((index, port));
+        cfg
+    }
+
+    /// Create an [`EventListener`] without worker, which returns only the events injected with an [`super::EventSender`].
+    /// Ports and tick are ignored.
+    pub(crate) fn start_headless(self) -> EventListener<U> {
+        EventListener::headless(self.poll_timeout, self.queue_capacity)
+    }
+
+    /// Returns a copy of this configuration which can be used to rebuild the event listener with [`Self::rebuild`].
+    ///
+    /// Returns [`None`] if any port has been added without a factory, since it can't be rebuilt.
+    /// The recorder is not kept in the copy.
+    pub(crate) fn restart_template(&self) -> Option<Self> {
+        if self.ports.len() != self.factories.len() {
+            return None;
+        }
+        Some(Self {
+            ports: Vec::default(),
+            factories: self.factories.clone(),
+            last_port_factory: self.last_port_factory,
+            tick_interval: self.tick_interval,
+            tick_alignment: self.tick_alignment,
+            poll_timeout: self.poll_timeout,
+            recorder: None,
+            key_event_kinds: self.key_event_kinds.clone(),
+            queue_capacity: self.queue_capacity,
+            overflow_policy: self.overflow_policy,
+            metrics: self.metrics.clone(),
+            interval_events: self.interval_events.clone(),
+            missed_interval_policy: self.missed_interval_policy,
+            #[cfg(any(feature = "crossterm", feature = "termion"))]
+            input_ports: self
+                .input_ports
+                .iter()
+                .filter_map(|(index, port)| port.rebuildable().map(|port| (*index, port)))
+                .collect(),
+            #[cfg(feature = "crossterm")]
+            crossterm_raw_hook: self.crossterm_raw_hook,
+            #[cfg(feature = "crossterm")]
+            crossterm_focus_change: self.crossterm_focus_change,
+            #[cfg(feature = "termion")]
+            termion_raw_hook: self.termion_raw_hook,
+        })
+    }
+
+    /// Build a new configuration with new ports created from the port factories
+    pub(crate) fn rebuild(&self) -> Self {
+        Self {
+            ports: self.factories.iter().map(PortFactory::build).collect(),
+            factories: self.factories.clone(),
+            last_port_factory: self.last_port_factory,
+            tick_interval: self.tick_interval,
+            tick_alignment: self.tick_alignment,
+            poll_timeout: self.poll_timeout,
+            recorder: None,
+            key_event_kinds: self.key_event_kinds.clone(),
+            queue_capacity: self.queue_capacity,
+            overflow_policy: self.overflow_policy,
+            metrics: self.metrics.clone(),
+            interval_events: self.interval_events.clone(),
+            missed_interval_policy: self.missed_interval_policy,
+            #[cfg(any(feature = "crossterm", feature = "termion"))]
+            input_ports: self
+                .input_ports
+                .iter()
+                .filter_map(|(index, port)| port.rebuildable().map(|port| (*index, port)))
+                .collect(),
+            #[cfg(feature = "crossterm")]
+            crossterm_raw_hook: self.crossterm_raw_hook,
+            #[cfg(feature = "crossterm")]
+            crossterm_focus_change: self.crossterm_focus_change,
+            #[cfg(feature = "termion")]
+            termion_raw_hook: self.termion_raw_hook,
+        }
     }
 
     /// Set poll timeout.
@@ -59,10 +390,88 @@ where
         self
     }
 
+    /// Defines the tick interval for the event listener, like [`Self::tick_interval`], and align the ticks
+    /// to the multiples of the interval since the Unix epoch (see [`TickAlignment::UnixEpoch`]).
+    ///
+    /// The alignment can then be changed with [`Self::tick_alignment`].
+    pub fn tick_interval_aligned(self, interval: Duration) -> Self {
+        self.tick_interval(interval)
+            .tick_alignment(TickAlignment::UnixEpoch)
+    }
+
+    /// Set when the ticks are scheduled. (Default: [`TickAlignment::Unaligned`])
+    pub fn tick_alignment(mut self, alignment: TickAlignment) -> Self {
+        self.tick_alignment = alignment;
+        self
+    }
+
+    /// Emit `Event::User(event)` every `every`, e.g. to refresh some data periodically without a dedicated port.
+    /// Can be called multiple times to emit several events, each with its own interval.
+    ///
+    /// The events are scheduled by the worker alongside the ticks: each deadline is one interval after the previous one,
+    /// so the events don't drift, and the first event is emitted one interval after the listener has started.
+    /// While the ports are locked no event is emitted; the deadlines missed meanwhile are handled according to the
+    /// [`MissedIntervalPolicy`] set with [`Self::missed_interval_policy`].
+    ///
+    /// > Panics if `every` is 0
+    pub fn interval_event(mut self, event: U, every: Duration) -> Self {
+        if every == Duration::ZERO {
+            panic!("interval cannot be 0")
+        }
+        self.interval_events.push((event, every));
+        self
+    }
+
+    /// Set what the worker does with the missed deadlines of the interval events. (Default: [`MissedIntervalPolicy::Skip`])
+    pub fn missed_interval_policy(mut self, policy: MissedIntervalPolicy) -> Self {
+        self.missed_interval_policy = policy;
+        self
+    }
+
+    /// Set the maximum amount of events which can wait to be polled by the application. (Default: 1024)
+    ///
+    /// When the queue is full, the worker applies the [`QueueOverflowPolicy`] set with [`Self::queue_overflow_policy`].
+    ///
+    /// > Panics if capacity is 0
+    pub fn queue_capacity(mut self, capacity: usize) -> Self {
+        if capacity == 0 {
+            panic!("queue capacity cannot be 0")
+        }
+        self.queue_capacity = capacity;
+        self
+    }
+
+    /// Set what the worker does when the event queue is full. (Default: [`QueueOverflowPolicy::DropTicks`])
+    pub fn queue_overflow_policy(mut self, policy: QueueOverflowPolicy) -> Self {
+        self.overflow_policy = policy;
+        self
+    }
+
+    /// Count the events emitted by the event listener for each event kind.
+    ///
+    /// The counters are reported by [`crate::Application::metrics`] once metrics are enabled on the application
+    /// with [`crate::Application::enable_metrics`]. (Default: disabled)
+    pub fn with_event_metrics(mut self) -> Self {
+        self.metrics = Some(Arc::default());
+        self
+    }
+
+    /// Set the kinds of keyboard events emitted by the event listener.
+    /// Keyboard events of the other kinds are discarded. (Default: only [`KeyEventKind::Press`])
+    ///
+    /// > Set also [`KeyEventKind::Release`] and [`KeyEventKind::Repeat`] if your application needs to track the keys held down
+    /// > (e.g. for gaming-style controls). Keep in mind that components will then receive more than one event for each keystroke.
+    pub fn with_key_event_kinds(mut self, kinds: &[KeyEventKind]) -> Self {
+        self.key_event_kinds = kinds.to_vec();
+        self
+    }
+
     /// Add a new [`Port`] (Poll, Interval) to the the event listener.
     ///
     /// The interval is the amount of time between each [`Poll::poll`] call.
     /// The max_poll is the maximum amount of times the port should be polled in a single poll.
+    ///
+    /// To emit the events produced by a thread of yours, add a [`super::ChannelPort`].
     pub fn add_port(self, poll: Box<dyn Poll<U>>, interval: Duration, max_poll: usize) -> Self {
         self.port(Port::new(poll, interval, max_poll))
     }
@@ -72,33 +481,254 @@ where
     /// The [`Port`] needs to be manually constructed, unlike [`Self::add_port`]
     pub fn port(mut self, port: Port<U>) -> Self {
         self.ports.push(port);
+        self.last_port_factory = false;
         self
     }
 
+    /// Add a new [`Port`] whose [`Poll`] is created by `factory`.
+    ///
+    /// Unlike [`Self::add_port`], the port can be rebuilt when the event listener is restarted
+    /// by the [`crate::application::RestartPolicy`].
+    ///
+    /// The interval is the amount of time between each [`Poll::poll`] call.
+    /// The max_poll is the maximum amount of times the port should be polled in a single poll.
+    pub fn add_port_factory<F>(mut self, factory: F, interval: Duration, max_poll: usize) -> Self
+    where
+        F: Fn() -> Box<dyn Poll<U>> + Send + Sync + 'static,
+    {
+        let factory = PortFactory {
+            factory: Arc::new(factory),
+            interval,
+            max_poll,
+            transforms: Vec::new(),
+            blocking: false,
+            interval_bounds: None,
+        };
+        self.ports.push(factory.build());
+        self.factories.push(factory);
+        self.last_port_factory = true;
+        self
+    }
+
+    /// Replace each event emitted by the last port added with the event returned by `f` (see [`Port::map`]).
+    ///
+    /// > Panics if no port has been added
+    pub fn map_port<F>(self, f: F) -> Self
+    where
+        F: Fn(Event<U>) -> Event<U> + Send + Sync + 'static,
+    {
+        self.filter_map_port(move |ev| Some(f(ev)))
+    }
+
+    /// Discard the events emitted by the last port added which don't satisfy `f` (see [`Port::filter`]).
+    ///
+    /// > Panics if no port has been added
+    pub fn filter_port<F>(self, f: F) -> Self
+    where
+        F: Fn(&Event<U>) -> bool + Send + Sync + 'static,
+    {
+        self.filter_map_port(move |ev| f(&ev).then_some(ev))
+    }
+
+    /// Replace each event emitted by the last port added with the event returned by `f`,
+    /// discarding it if `f` returns [`None`] (see [`Port::filter_map`]).
+    ///
+    /// If the port has been added with a factory, the transform is applied also when the port is rebuilt.
+    ///
+    /// > Panics if no port has been added
+    pub fn filter_map_port<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Event<U>) -> Option<Event<U>> + Send + Sync + 'static,
+    {
+        let transform: SharedTransform<U> = Arc::new(f);
+        let port = self.ports.pop().expect("no port has been added");
+        if self.last_port_factory {
+            if let Some(factory) = self.factories.last_mut() {
+                factory.transforms.push(Arc::clone(&transform));
+            }
+        }
+        self.ports.push(port.filter_map(move |ev| transform(ev)));
+        self
+    }
+
+    /// Poll the last port added on a dedicated thread (see [`Port::blocking`]).
+    ///
+    /// If the port has been added with a factory, the port rebuilt by the factory is blocking as well.
+    ///
+    /// > Panics if no port has been added
+    pub fn blocking_port(mut self) -> Self {
+        let port = self.ports.pop().expect("no port has been added");
+        if self.last_port_factory {
+            if let Some(factory) = self.factories.last_mut() {
+                factory.blocking = true;
+            }
+        }
+        self.ports.push(port.blocking());
+        self
+    }
+
+    /// Clamp the intervals hinted by the last port added between `min` and `max` (see [`Port::interval_bounds`]).
+    ///
+    /// If the port has been added with a factory, the port rebuilt by the factory has the same bounds.
+    ///
+    /// > Panics if no port has been added or if `min` is greater than `max`
+    pub fn port_interval_bounds(mut self, min: Duration, max: Duration) -> Self {
+        let port = self.ports.pop().expect("no port has been added");
+        if self.last_port_factory {
+            if let Some(factory) = self.factories.last_mut() {
+                factory.interval_bounds = Some((min, max));
+            }
+        }
+        self.ports.push(port.interval_bounds(min, max));
+        self
+    }
+
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    /// Record every event emitted by the event listener to `writer`.
+    ///
+    /// Each event is written as a JSON line, together with the milliseconds elapsed since the listener
+    /// has been configured. The recording can be reproduced with [`EventListenerCfg::replay`].
+    pub fn with_recorder<W>(mut self, writer: W) -> Self
+    where
+        W: std::io::Write + Send + 'static,
+        U: serde::Serialize,
+    {
+        self.recorder = Some(EventRecorder::json_lines(writer));
+        self
+    }
+
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    /// Add a [`super::ReplayPort`] which re-emits the events recorded at `path` with their original timing.
+    ///
+    /// Use [`EventListenerCfg::port`] with a manually constructed [`super::ReplayPort`] to change the timing.
+    pub fn replay<P>(self, path: P) -> std::io::Result<Self>
+    where
+        P: AsRef<std::path::Path>,
+        U: serde::de::DeserializeOwned,
+    {
+        let port = super::ReplayPort::open(path)?;
+        Ok(self.add_port(Box::new(port), Duration::from_millis(1), usize::MAX))
+    }
+
     #[cfg(feature = "crossterm")]
     /// Add to the event listener the default crossterm input listener [`crate::terminal::CrosstermInputListener`]
     ///
     /// The interval is the amount of time between each [`Poll::poll`] call.
     /// The max_poll is the maximum amount of times the port should be polled in a single poll.
     pub fn crossterm_input_listener(self, interval: Duration, max_poll: usize) -> Self {
-        self.add_port(
-            Box::new(crate::terminal::CrosstermInputListener::<U>::new(interval)),
+        self.input_port(
+            InputPort::Crossterm {
+                interval,
+                poll_timeout: None,
+            },
             interval,
             max_poll,
         )
     }
 
+    #[cfg(feature = "crossterm")]
+    /// Add to the event listener the default crossterm input listener, polled on a dedicated thread
+    /// (see [`Self::blocking_port`]).
+    ///
+    /// The listener waits up to `timeout` for an event, so the keys are emitted as soon as they're pressed,
+    /// while the worker wakes up at most once per `timeout` when the terminal is idle.
+    /// The timeout bounds the time the listener takes to notice the event listener has been paused or stopped.
+    ///
+    /// The max_poll is the maximum amount of times the port should be polled in a single poll.
+    pub fn crossterm_input_listener_blocking(self, timeout: Duration, max_poll: usize) -> Self {
+        self.input_port(
+            InputPort::Crossterm {
+                interval: timeout,
+                poll_timeout: Some(timeout),
+            },
+            timeout,
+            max_poll,
+        )
+        .blocking_port()
+    }
+
+    #[cfg(feature = "crossterm")]
+    /// Add to the event listener a crossterm input listener [`crate::terminal::CrosstermInputListener`]
+    /// which reads the events from `source` instead of the terminal of the process.
+    ///
+    /// The interval is the amount of time between each [`Poll::poll`] call.
+    /// The max_poll is the maximum amount of times the port should be polled in a single poll.
+    ///
+    /// > The source can't be rebuilt, so the listener can't be restarted by the [`crate::application::RestartPolicy`]
+    pub fn crossterm_input_listener_with_source<S>(
+        self,
+        source: S,
+        interval: Duration,
+        max_poll: usize,
+    ) -> Self
+    where
+        S: crate::terminal::CrosstermEventSource + 'static,
+    {
+        let listener = crate::terminal::CrosstermInputListener::from_source(source, interval);
+        self.input_port(InputPort::CrosstermSource(listener), interval, max_poll)
+    }
+
+    #[cfg(feature = "crossterm")]
+    /// Set the hook used by the crossterm input listener to convert the crossterm events.
+    /// The hook is called before the built-in conversion and if it returns [`None`], the built-in conversion is used.
+    ///
+    /// The hook applies to all the crossterm input listeners, whether they've been added before or after calling this method.
+    pub fn crossterm_raw_hook(mut self, hook: crate::terminal::CrosstermRawHook<U>) -> Self {
+        self.crossterm_raw_hook = Some(hook);
+        self
+    }
+
+    #[cfg(feature = "crossterm")]
+    /// Set whether the crossterm input listener must enable the reporting of the terminal focus changes,
+    /// so that [`crate::Event::FocusGained`] and [`crate::Event::FocusLost`] are emitted
+    /// (see [`crate::terminal::CrosstermInputListener::with_focus_change`]).
+    ///
+    /// The option applies to all the crossterm input listeners, whether they've been added before or after calling this method.
+    pub fn crossterm_focus_change(mut self, enable: bool) -> Self {
+        self.crossterm_focus_change = enable;
+        self
+    }
+
     #[cfg(feature = "termion")]
     /// Add to the event listener the default termion input listener [`crate::terminal::TermionInputListener`]
     ///
     /// The interval is the amount of time between each [`Poll::poll`] call.
     /// The max_poll is the maximum amount of times the port should be polled in a single poll.
     pub fn termion_input_listener(self, interval: Duration, max_poll: usize) -> Self {
-        self.add_port(
-            Box::new(crate::terminal::TermionInputListener::<U>::new(interval)),
-            interval,
-            max_poll,
-        )
+        self.input_port(InputPort::Termion { interval }, interval, max_poll)
+    }
+
+    #[cfg(feature = "termion")]
+    /// Add to the event listener a termion input listener [`crate::terminal::TermionInputListener`]
+    /// which reads the events from `events` instead of stdin.
+    ///
+    /// The interval is the amount of time between each [`Poll::poll`] call.
+    /// The max_poll is the maximum amount of times the port should be polled in a single poll.
+    ///
+    /// > The events can't be rebuilt, so the listener can't be restarted by the [`crate::application::RestartPolicy`]
+    pub fn termion_input_listener_with_source<I>(
+        self,
+        events: I,
+        interval: Duration,
+        max_poll: usize,
+    ) -> Self
+    where
+        I: Iterator<Item = std::io::Result<termion::event::Event>> + Send + 'static,
+    {
+        let listener = crate::terminal::TermionInputListener::from_events(events);
+        self.input_port(InputPort::TermionSource(listener), interval, max_poll)
+    }
+
+    #[cfg(feature = "termion")]
+    /// Set the hook used by the termion input listener to convert the termion events.
+    /// The hook is called before the built-in conversion and if it returns [`None`], the built-in conversion is used.
+    ///
+    /// The hook applies to all the termion input listeners, whether they've been added before or after calling this method.
+    pub fn termion_raw_hook(mut self, hook: crate::terminal::TermionRawHook<U>) -> Self {
+        self.termion_raw_hook = Some(hook);
+        self
     }
 }
 
@@ -121,6 +751,11 @@ mod test {
         assert_eq!(builder.tick_interval.unwrap(), Duration::from_secs(10));
         let builder = builder.poll_timeout(Duration::from_millis(50));
         assert_eq!(builder.poll_timeout, Duration::from_millis(50));
+        let builder = builder.crossterm_raw_hook(|_| None);
+        assert!(builder.crossterm_raw_hook.is_some());
+        assert_eq!(builder.crossterm_focus_change, false);
+        let builder = builder.crossterm_focus_change(true);
+        assert_eq!(builder.crossterm_focus_change, true);
         let builder = builder
             .crossterm_input_listener(Duration::from_millis(200), 1)
             .add_port(Box::new(MockPoll::default()), Duration::from_secs(300), 1);
@@ -129,6 +764,21 @@ mod test {
         assert!(listener.stop().is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "crossterm")]
+    fn should_start_and_stop_blocking_crossterm_input_listener() {
+        let builder = EventListenerCfg::<MockEvent>::default()
+            .crossterm_input_listener_blocking(Duration::from_millis(100), 16);
+        assert_eq!(builder.ports.len(), 1);
+        assert!(builder.ports[0].is_blocking());
+        assert!(builder.restart_template().unwrap().rebuild().ports[0].is_blocking());
+        let mut listener = builder.start();
+        std::thread::sleep(Duration::from_millis(50));
+        let stopping = std::time::Instant::now();
+        assert!(listener.stop().is_ok());
+        assert!(stopping.elapsed() < Duration::from_secs(1));
+    }
+
     #[test]
     #[cfg(feature = "termion")]
     fn should_configure_and_start_event_listener_termion() {
@@ -140,6 +790,8 @@ mod test {
         assert_eq!(builder.tick_interval.unwrap(), Duration::from_secs(10));
         let builder = builder.poll_timeout(Duration::from_millis(50));
         assert_eq!(builder.poll_timeout, Duration::from_millis(50));
+        let builder = builder.termion_raw_hook(|_| None);
+        assert!(builder.termion_raw_hook.is_some());
         let builder = builder
             .termion_input_listener(Duration::from_millis(200), 1)
             .add_port(Box::new(MockPoll::default()), Duration::from_secs(300), 1);
@@ -148,6 +800,52 @@ mod test {
         assert!(listener.stop().is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "termion")]
+    fn should_add_termion_input_listener_with_source() {
+        use termion::event::{Event as TonEvent, Key as TonKey};
+
+        use crate::event::{Key, KeyEvent};
+        use crate::Event;
+
+        let mut listener = EventListenerCfg::<MockEvent>::default()
+            .poll_timeout(Duration::from_millis(100))
+            .termion_input_listener_with_source(
+                std::iter::once(Ok(TonEvent::Key(TonKey::Char('a')))),
+                Duration::from_millis(10),
+                1,
+            )
+            .start();
+        assert_eq!(
+            listener.poll().unwrap(),
+            Some(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+        );
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "termion")]
+    fn should_apply_termion_raw_hook_set_after_input_listener() {
+        use termion::event::{Event as TonEvent, Key as TonKey};
+
+        use crate::Event;
+
+        let mut listener = EventListenerCfg::<MockEvent>::default()
+            .poll_timeout(Duration::from_millis(100))
+            .termion_input_listener_with_source(
+                std::iter::once(Ok(TonEvent::Key(TonKey::Char('a')))),
+                Duration::from_millis(10),
+                1,
+            )
+            .termion_raw_hook(|ev| match ev {
+                TonEvent::Key(TonKey::Char('a')) => Some(Event::User(MockEvent::Foo)),
+                _ => None,
+            })
+            .start();
+        assert_eq!(listener.poll().unwrap(), Some(Event::User(MockEvent::Foo)));
+        assert!(listener.stop().is_ok());
+    }
+
     #[test]
     #[should_panic]
     fn event_listener_cfg_should_panic_with_poll_timeout_zero() {
@@ -167,4 +865,142 @@ mod test {
         ));
         assert_eq!(builder.ports.len(), 1);
     }
+
+    #[test]
+    fn should_rebuild_ports_from_factories() {
+        let builder = EventListenerCfg::<MockEvent>::default()
+            .tick_interval(Duration::from_secs(1))
+            .add_port_factory(
+                || Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            );
+        assert_eq!(builder.ports.len(), 1);
+        let template = builder.restart_template().unwrap();
+        assert!(template.ports.is_empty());
+        let rebuilt = template.rebuild();
+        assert_eq!(rebuilt.ports.len(), 1);
+        assert_eq!(rebuilt.tick_interval, Some(Duration::from_secs(1)));
+        // ports without factory can't be rebuilt
+        assert!(builder
+            .add_port(Box::new(MockPoll::default()), Duration::from_millis(1), 1)
+            .restart_template()
+            .is_none());
+    }
+
+    #[test]
+    fn should_transform_the_events_of_the_last_port() {
+        let mut builder = EventListenerCfg::<MockEvent>::default()
+            .add_port(Box::new(MockPoll::default()), Duration::from_millis(1), 1)
+            .filter_port(|ev| !matches!(ev, Event::Keyboard(_)))
+            .add_port_factory(
+                || Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )
+            .map_port(|ev| match ev {
+                Event::Keyboard(_) => Event::User(MockEvent::Foo),
+                ev => ev,
+            });
+        let ev = builder.ports[0].poll().unwrap().unwrap();
+        assert_eq!(builder.ports[0].process(ev), None);
+        let ev = builder.ports[1].poll().unwrap().unwrap();
+        assert_eq!(
+            builder.ports[1].process(ev),
+            Some(Event::User(MockEvent::Foo))
+        );
+        // the transform is applied to the rebuilt port
+        let mut rebuilt = EventListenerCfg::<MockEvent>::default()
+            .add_port_factory(
+                || Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )
+            .map_port(|_| Event::User(MockEvent::Bar))
+            .restart_template()
+            .unwrap()
+            .rebuild();
+        let ev = rebuilt.ports[0].poll().unwrap().unwrap();
+        assert_eq!(
+            rebuilt.ports[0].process(ev),
+            Some(Event::User(MockEvent::Bar))
+        );
+    }
+
+    #[test]
+    fn should_make_the_last_port_blocking() {
+        let builder = EventListenerCfg::<MockEvent>::default()
+            .add_port(Box::new(MockPoll::default()), Duration::from_millis(1), 1)
+            .add_port_factory(
+                || Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )
+            .blocking_port();
+        assert!(!builder.ports[0].is_blocking());
+        assert!(builder.ports[1].is_blocking());
+        // the rebuilt port is blocking as well
+        let rebuilt = EventListenerCfg::<MockEvent>::default()
+            .add_port_factory(
+                || Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )
+            .blocking_port()
+            .restart_template()
+            .unwrap()
+            .rebuild();
+        assert!(rebuilt.ports[0].is_blocking());
+    }
+
+    #[test]
+    fn should_bound_the_intervals_of_the_last_port() {
+        let rebuilt = EventListenerCfg::<MockEvent>::default()
+            .add_port_factory(
+                || Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )
+            .port_interval_bounds(Duration::from_millis(100), Duration::from_secs(5))
+            .restart_template()
+            .unwrap()
+            .rebuild();
+        assert_eq!(
+            rebuilt.factories[0].interval_bounds,
+            Some((Duration::from_millis(100), Duration::from_secs(5)))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_transforming_events_without_ports() {
+        let _ = EventListenerCfg::<MockEvent>::default().map_port(|ev| ev);
+    }
+
+    #[test]
+    fn should_keep_interval_events_on_rebuild() {
+        let config = EventListenerCfg::<MockEvent>::default()
+            .interval_event(MockEvent::Foo, Duration::from_secs(1))
+            .interval_event(MockEvent::Bar, Duration::from_secs(5))
+            .missed_interval_policy(MissedIntervalPolicy::CatchUp);
+        let rebuilt = config.restart_template().unwrap().rebuild();
+        assert_eq!(
+            rebuilt.interval_events,
+            vec![
+                (MockEvent::Foo, Duration::from_secs(1)),
+                (MockEvent::Bar, Duration::from_secs(5))
+            ]
+        );
+        assert_eq!(
+            rebuilt.missed_interval_policy,
+            MissedIntervalPolicy::CatchUp
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_zero_interval_event() {
+        let _ =
+            EventListenerCfg::<MockEvent>::default().interval_event(MockEvent::Foo, Duration::ZERO);
+    }
 }
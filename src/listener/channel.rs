@@ -0,0 +1,166 @@
+//! ## Channel
+//!
+//! This module exposes the channel port, which emits the user events sent from other threads,
+//! e.g. the progress of a long-running background job.
+
+use std::sync::mpsc;
+
+use super::{ListenerError, ListenerResult, Poll};
+use crate::Event;
+
+/// A [`Poll`] implementation which emits as [`Event::User`] the user events sent with its [`ChannelSender`]s.
+///
+/// This is the easiest way to report the progress of a job running on another thread:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use tuirealm::listener::ChannelPort;
+/// use tuirealm::EventListenerCfg;
+///
+/// #[derive(Debug, Eq, PartialEq, Clone, PartialOrd)]
+/// enum UserEvent {
+///     Progress(u8),
+///     Done,
+/// }
+///
+/// let (sender, port) = ChannelPort::new();
+/// let listener = EventListenerCfg::default().add_port(Box::new(port), Duration::from_millis(10), 16);
+/// std::thread::spawn(move || {
+///     for progress in 0..=100 {
+///         let _ = sender.send(UserEvent::Progress(progress));
+///     }
+///     let _ = sender.send(UserEvent::Done);
+/// });
+/// ```
+///
+/// The events sent before the event listener is started are kept until the port is polled.
+/// When all the senders have been dropped, the port simply stops emitting events.
+pub struct ChannelPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    receiver: mpsc::Receiver<U>,
+}
+
+impl<U> ChannelPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    /// Create a new [`ChannelPort`] and the [`ChannelSender`] used to send it the user events
+    pub fn new() -> (ChannelSender<U>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        (ChannelSender { sender }, Self { receiver })
+    }
+}
+
+impl<U> Poll<U> for ChannelPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
+        match self.receiver.try_recv() {
+            Ok(ev) => Ok(Some(Event::User(ev))),
+            // NOTE: once all the senders have been dropped, there's nothing to poll anymore
+            Err(mpsc::TryRecvError::Empty) | Err(mpsc::TryRecvError::Disconnected) => Ok(None),
+        }
+    }
+}
+
+/// A cloneable handle to send user events to a [`ChannelPort`]. The sender can be moved to other threads.
+pub struct ChannelSender<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    sender: mpsc::Sender<U>,
+}
+
+impl<U> ChannelSender<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    /// Send `event` to the port; the event is emitted as [`Event::User`] the next time the port is polled.
+    ///
+    /// Returns [`ListenerError::ListenerDied`] if the port has been dropped (e.g. the event listener has been stopped).
+    pub fn send(&self, event: U) -> ListenerResult<()> {
+        self.sender
+            .send(event)
+            .map_err(|_| ListenerError::ListenerDied)
+    }
+}
+
+impl<U> Clone for ChannelSender<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::MockEvent;
+    use crate::EventListenerCfg;
+
+    #[test]
+    fn should_poll_sent_events() {
+        let (sender, mut port) = ChannelPort::<MockEvent>::new();
+        assert_eq!(port.poll().unwrap(), None);
+        assert!(sender.send(MockEvent::Foo).is_ok());
+        assert!(sender.clone().send(MockEvent::Bar).is_ok());
+        assert_eq!(port.poll().unwrap(), Some(Event::User(MockEvent::Foo)));
+        assert_eq!(port.poll().unwrap(), Some(Event::User(MockEvent::Bar)));
+        assert_eq!(port.poll().unwrap(), None);
+        drop(port);
+        assert!(matches!(
+            sender.send(MockEvent::Foo),
+            Err(ListenerError::ListenerDied)
+        ));
+    }
+
+    #[test]
+    fn should_deliver_events_sent_before_and_after_listener_start() {
+        let (sender, port) = ChannelPort::<MockEvent>::new();
+        assert!(sender.send(MockEvent::Foo).is_ok());
+        let mut listener = EventListenerCfg::default()
+            .add_port(Box::new(port), Duration::from_millis(10), 1)
+            .start();
+        assert_eq!(
+            listener.poll_for(Duration::from_secs(1)).unwrap(),
+            Some(Event::User(MockEvent::Foo))
+        );
+        let worker_sender = sender.clone();
+        std::thread::spawn(move || worker_sender.send(MockEvent::Bar))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            listener.poll_for(Duration::from_secs(1)).unwrap(),
+            Some(Event::User(MockEvent::Bar))
+        );
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn should_keep_listener_alive_when_senders_are_dropped() {
+        let (sender, port) = ChannelPort::<MockEvent>::new();
+        let mut listener = EventListenerCfg::default()
+            .add_port(Box::new(port), Duration::from_millis(10), 1)
+            .start();
+        drop(sender);
+        // NOTE: `ListenerDied` would be returned if the worker had terminated
+        for _ in 0..5 {
+            assert_eq!(listener.poll_for(Duration::from_millis(20)).unwrap(), None);
+        }
+        assert!(listener.stop().is_ok());
+    }
+}
@@ -0,0 +1,109 @@
+//! ## Metrics
+//!
+//! This module exposes the event counters collected by the event listener worker
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::Event;
+
+/// Names of the event kinds, as returned by [`Event::kind`]
+const EVENT_KINDS: [&str; 10] = [
+    "Keyboard",
+    "Mouse",
+    "WindowResize",
+    "FocusGained",
+    "FocusLost",
+    "Paste",
+    "Tick",
+    "ListenerRestarted",
+    "None",
+    "User",
+];
+
+/// Counters of the events emitted by the event listener worker, for each event kind.
+///
+/// The counters are shared between the worker and the application.
+#[derive(Debug, Default)]
+pub(crate) struct ListenerMetrics {
+    events: [AtomicU64; EVENT_KINDS.len()],
+}
+
+impl ListenerMetrics {
+    /// Count an emitted `event`
+    pub fn count_event<U>(&self, event: &Event<U>)
+    where
+        U: Eq + PartialEq + Clone + PartialOrd,
+    {
+        let kind = event.kind();
+        if let Some(i) = EVENT_KINDS.iter().position(|x| *x == kind) {
+            self.events[i].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Get the amount of emitted events for each kind; kinds without events are omitted
+    pub fn events(&self) -> HashMap<&'static str, u64> {
+        EVENT_KINDS
+            .iter()
+            .zip(self.events.iter())
+            .map(|(kind, count)| (*kind, count.load(Ordering::Relaxed)))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
+    /// Reset all counters
+    pub fn reset(&self) {
+        self.events
+            .iter()
+            .for_each(|count| count.store(0, Ordering::Relaxed));
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::event::{Key, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+    use crate::mock::MockEvent;
+
+    #[test]
+    fn should_count_events_by_kind() {
+        let metrics = ListenerMetrics::default();
+        let events: Vec<Event<MockEvent>> = vec![
+            Event::Keyboard(KeyEvent::from(Key::Enter)),
+            Event::Keyboard(KeyEvent::from(Key::Esc)),
+            Event::Tick(Duration::from_secs(1)),
+            Event::User(MockEvent::Foo),
+            Event::ListenerRestarted,
+        ];
+        events.iter().for_each(|ev| metrics.count_event(ev));
+        let counts = metrics.events();
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts.get("Keyboard"), Some(&2));
+        assert_eq!(counts.get("Tick"), Some(&1));
+        assert_eq!(counts.get("User"), Some(&1));
+        assert_eq!(counts.get("ListenerRestarted"), Some(&1));
+        // every kind is counted
+        let events: [Event<MockEvent>; 6] = [
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Moved,
+                modifiers: KeyModifiers::NONE,
+                column: 0,
+                row: 0,
+            }),
+            Event::WindowResize(1, 1),
+            Event::FocusGained,
+            Event::FocusLost,
+            Event::Paste(String::from("a")),
+            Event::None,
+        ];
+        events.iter().for_each(|ev| metrics.count_event(ev));
+        assert_eq!(metrics.events().len(), EVENT_KINDS.len());
+        metrics.reset();
+        assert!(metrics.events().is_empty());
+    }
+}
@@ -5,7 +5,10 @@
 
 // -- modules
 mod builder;
+mod channel;
+mod metrics;
 mod port;
+mod recorder;
 mod worker;
 
 use std::sync::atomic::AtomicBool;
@@ -15,12 +18,19 @@ use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
 pub use builder::EventListenerCfg;
+pub use channel::{ChannelPort, ChannelSender};
+pub(crate) use metrics::ListenerMetrics;
 pub use port::Port;
+use recorder::EventRecorder;
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub use recorder::{RecordedEvent, ReplayPort, ReplayTiming};
 use thiserror::Error;
 use worker::EventListenerWorker;
 
 // -- internal
 use super::Event;
+use crate::event::KeyEventKind;
 
 /// Result returned by `EventListener`. [`Ok`] value depends on the method, while the
 /// Err value is always [`ListenerError`].
@@ -36,6 +46,50 @@ pub enum ListenerError {
     ListenerDied,
     #[error("poll() call returned error")]
     PollFailed,
+    /// The poll of a port has panicked, so the port is not polled anymore.
+    /// The port is identified by its position in the order the ports have been added
+    #[error("the port #{0} has panicked and it has been disabled")]
+    PortPanicked(usize),
+    #[error("the event listener queue is full")]
+    QueueFull,
+}
+
+/// Defines what the event listener worker does when the queue of the events waiting to be polled
+/// by the application is full.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum QueueOverflowPolicy {
+    /// [`Event::Tick`] events are discarded, while the other events wait for the queue to have room.
+    /// This keeps input events responsive when the application is busy.
+    #[default]
+    DropTicks,
+    /// New events are discarded until the queue has room
+    DropNewest,
+    /// The worker waits for the queue to have room
+    Block,
+}
+
+/// Defines when the event listener worker schedules the [`Event::Tick`] events.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TickAlignment {
+    /// Each tick is sent one interval after the previous one; the ticks drift by the time spent sending them.
+    #[default]
+    Unaligned,
+    /// Ticks are sent at the multiples of the interval since the Unix epoch; e.g. with a 1 second interval
+    /// the ticks are sent when the wall clock second changes, which is useful to display a clock.
+    UnixEpoch,
+    /// Ticks are sent at the multiples of the interval since the event listener has been started, so they don't drift.
+    ListenerStart,
+}
+
+/// Defines what the event listener worker does with the interval events (see [`EventListenerCfg::interval_event`])
+/// whose deadlines have been missed, e.g. while the listener was paused or the queue was full.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MissedIntervalPolicy {
+    /// The event is emitted once and the missed deadlines are skipped
+    #[default]
+    Skip,
+    /// The event is emitted once for each missed deadline
+    CatchUp,
 }
 
 /// The poll trait defines the function [`Poll::poll`], which will be called by the event listener
@@ -45,12 +99,25 @@ where
     UserEvent: Eq + PartialEq + Clone + PartialOrd + 'static,
 {
     /// Poll for an event from user or from another source (e.g. Network).
-    /// This function mustn't be blocking, and will be called within the configured interval of the event listener.
+    /// This function mustn't be blocking, and will be called within the configured interval of the event listener,
+    /// unless the port has been registered with [`Port::blocking`]: then it's called on a dedicated thread, as soon as
+    /// the previous poll has returned, and it should wait up to a short timeout for an event before returning [`None`].
+    /// The timeout bounds the time the port takes to notice the event listener has been paused or stopped.
     /// It may return Error in case something went wrong.
     /// If it was possible to poll for event, `Ok` must be returned.
     /// If an event was read, then [`Some`] must be returned, otherwise [`None`].
     /// The event must be converted to `Event` using the `adapters`.
     fn poll(&mut self) -> ListenerResult<Option<Event<UserEvent>>>;
+
+    /// Returns the interval to wait before polling this port again, if it should change.
+    /// It is called after each poll, so a port can adjust its own cadence
+    /// (e.g. poll often while a request is in flight and seldom while idle).
+    ///
+    /// The hint is clamped to the bounds set with [`Port::interval_bounds`] and it is kept until the next hint.
+    /// The default implementation returns [`None`], which keeps the current interval.
+    fn interval_hint(&self) -> Option<Duration> {
+        None
+    }
 }
 
 /// The event listener...
@@ -66,8 +133,16 @@ where
     running: Arc<AtomicBool>,
     /// Msg receiver from worker
     recv: mpsc::Receiver<ListenerMsg<U>>,
+    /// Msg sender, used to inject events with [`EventSender`]
+    sender: mpsc::SyncSender<ListenerMsg<U>>,
     /// Join handle for worker
     thread: Option<JoinHandle<()>>,
+    /// Event counters updated by the worker, if enabled
+    metrics: Option<Arc<ListenerMetrics>>,
+    /// Amount of ports the worker has been started with
+    port_count: usize,
+    /// Whether the worker produces the tick events
+    has_tick: bool,
 }
 
 impl<U> EventListener<U>
@@ -75,31 +150,55 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     /// Create a new [`EventListener`] and start it.
-    /// - `poll` is the trait object which polls for input events
-    /// - `poll_interval` is the interval to poll for input events. It should always be at least a poll time used by `poll`
-    /// - `tick_interval` is the interval used to send the `Tick` event. If `None`, no tick will be sent.
-    ///     Tick should be used only when you need to handle the tick in the interface through the Subscriptions.
-    ///     The tick should have in this case, the same value (or less) of the refresh rate of the TUI.
+    /// - `ports` are the ports polled by the worker
+    /// - `poll_timeout` is the maximum time to wait for an event on [`EventListener::poll`]
+    /// - `queue_capacity` is the maximum amount of events waiting to be polled
+    /// - `options` configure the worker (see [`WorkerOptions`])
     ///
     /// > Panics if `poll_timeout` is 0
     pub(self) fn start(
         ports: Vec<Port<U>>,
         poll_timeout: Duration,
-        tick_interval: Option<Duration>,
+        queue_capacity: usize,
+        options: WorkerOptions<U>,
     ) -> Self {
         if poll_timeout == Duration::ZERO {
             panic!(
                 "poll timeout cannot be 0 (see <https://github.com/rust-lang/rust/issues/39364>)"
             )
         }
+        let port_count = ports.len();
+        let has_tick = options.tick_interval.is_some();
+        let metrics = options.metrics.clone();
         // Prepare channel and running state
-        let config = Self::setup_thread(ports, tick_interval);
+        let config = Self::setup_thread(ports, queue_capacity, options);
         Self {
             paused: config.paused,
             running: config.running,
             poll_timeout,
             recv: config.rx,
+            sender: config.tx,
             thread: Some(config.thread),
+            metrics,
+            port_count,
+            has_tick,
+        }
+    }
+
+    /// Create a new [`EventListener`] without worker thread.
+    /// The listener returns only the events injected with [`EventSender`].
+    pub(self) fn headless(poll_timeout: Duration, queue_capacity: usize) -> Self {
+        let (sender, recv) = mpsc::sync_channel(queue_capacity);
+        Self {
+            paused: Arc::new(AtomicBool::new(false)),
+            running: Arc::new(AtomicBool::new(false)),
+            poll_timeout,
+            recv,
+            sender,
+            thread: None,
+            metrics: None,
+            port_count: 0,
+            has_tick: false,
         }
     }
 
@@ -120,6 +219,7 @@ where
     pub fn pause(&mut self) -> ListenerResult<()> {
         self.paused
             .store(true, std::sync::atomic::Ordering::Relaxed);
+        debug!("event listener paused");
         Ok(())
     }
 
@@ -127,30 +227,87 @@ where
     pub fn unpause(&mut self) -> ListenerResult<()> {
         self.paused
             .store(false, std::sync::atomic::Ordering::Relaxed);
+        debug!("event listener resumed");
         Ok(())
     }
 
+    /// Returns whether the event listener worker is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Get the amount of ports the event listener has been started with
+    pub fn port_count(&self) -> usize {
+        self.port_count
+    }
+
+    /// Returns whether the event listener produces the tick events
+    pub fn has_tick(&self) -> bool {
+        self.has_tick
+    }
+
     /// Checks whether there are new events available from event
     pub fn poll(&self) -> ListenerResult<Option<Event<U>>> {
-        match self.recv.recv_timeout(self.poll_timeout) {
+        self.poll_for(self.poll_timeout)
+    }
+
+    /// Checks whether there are new events available from event, waiting up to `timeout`
+    pub fn poll_for(&self, timeout: Duration) -> ListenerResult<Option<Event<U>>> {
+        match self.recv.recv_timeout(timeout) {
             Ok(msg) => ListenerResult::from(msg),
+            // NOTE: the channel is never disconnected since we hold a sender, so check the worker
+            Err(mpsc::RecvTimeoutError::Timeout) if self.worker_died() => {
+                Err(ListenerError::ListenerDied)
+            }
             Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
-            Err(_) => Err(ListenerError::PollFailed),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(ListenerError::ListenerDied),
+        }
+    }
+
+    /// Get the event counters updated by the worker, if enabled
+    pub fn metrics(&self) -> Option<&ListenerMetrics> {
+        self.metrics.as_deref()
+    }
+
+    /// Get a new [`EventSender`] to inject events into the listener
+    pub fn sender(&self) -> EventSender<U> {
+        EventSender {
+            sender: self.sender.clone(),
         }
     }
 
+    /// Returns whether the worker has terminated without being stopped
+    fn worker_died(&self) -> bool {
+        self.thread
+            .as_ref()
+            .map(|x| x.is_finished())
+            .unwrap_or(false)
+    }
+
     /// Setup the thread and returns the structs necessary to interact with it
-    fn setup_thread(ports: Vec<Port<U>>, tick_interval: Option<Duration>) -> ThreadConfig<U> {
-        let (sender, recv) = mpsc::channel();
+    fn setup_thread(
+        ports: Vec<Port<U>>,
+        queue_capacity: usize,
+        options: WorkerOptions<U>,
+    ) -> ThreadConfig<U> {
+        let (sender, recv) = mpsc::sync_channel(queue_capacity);
+        let tx = sender.clone();
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
         let running_t = Arc::clone(&running);
         // Start thread
         let thread = thread::spawn(move || {
-            EventListenerWorker::new(ports, sender, paused_t, running_t, tick_interval).run();
+            EventListenerWorker::new(ports, sender, paused_t, running_t, options.tick_interval)
+                .tick_alignment(options.tick_alignment)
+                .recorder(options.recorder)
+                .key_event_kinds(options.key_event_kinds)
+                .overflow_policy(options.overflow_policy)
+                .metrics(options.metrics)
+                .interval_events(options.interval_events, options.missed_interval_policy)
+                .run();
         });
-        ThreadConfig::new(recv, paused, running, thread)
+        ThreadConfig::new(recv, tx, paused, running, thread)
     }
 }
 
@@ -163,6 +320,51 @@ where
     }
 }
 
+// -- worker options
+
+/// Options of the worker of the [`EventListener`], set with the [`EventListenerCfg`]
+struct WorkerOptions<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    /// Interval used to send the `Tick` event. If `None`, no tick will be sent.
+    /// Tick should be used only when you need to handle the tick in the interface through the Subscriptions.
+    /// The tick should have in this case, the same value (or less) of the refresh rate of the TUI.
+    tick_interval: Option<Duration>,
+    /// When the ticks are scheduled
+    tick_alignment: TickAlignment,
+    /// Called for each event emitted by the worker
+    recorder: Option<EventRecorder<U>>,
+    /// Kinds of keyboard events emitted by the worker
+    key_event_kinds: Vec<KeyEventKind>,
+    /// What the worker does when the queue is full
+    overflow_policy: QueueOverflowPolicy,
+    /// Event counters updated by the worker
+    metrics: Option<Arc<ListenerMetrics>>,
+    /// User events emitted at a fixed interval
+    interval_events: Vec<(U, Duration)>,
+    /// What the worker does with the missed deadlines of the interval events
+    missed_interval_policy: MissedIntervalPolicy,
+}
+
+impl<U> Default for WorkerOptions<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn default() -> Self {
+        Self {
+            tick_interval: None,
+            tick_alignment: TickAlignment::default(),
+            recorder: None,
+            key_event_kinds: vec![KeyEventKind::Press],
+            overflow_policy: QueueOverflowPolicy::default(),
+            metrics: None,
+            interval_events: Vec::new(),
+            missed_interval_policy: MissedIntervalPolicy::default(),
+        }
+    }
+}
+
 // -- thread config
 
 /// Config returned by thread setup
@@ -171,6 +373,7 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     rx: mpsc::Receiver<ListenerMsg<U>>,
+    tx: mpsc::SyncSender<ListenerMsg<U>>,
     paused: Arc<AtomicBool>,
     running: Arc<AtomicBool>,
     thread: JoinHandle<()>,
@@ -182,12 +385,14 @@ where
 {
     pub fn new(
         rx: mpsc::Receiver<ListenerMsg<U>>,
+        tx: mpsc::SyncSender<ListenerMsg<U>>,
         paused: Arc<AtomicBool>,
         running: Arc<AtomicBool>,
         thread: JoinHandle<()>,
     ) -> Self {
         Self {
             rx,
+            tx,
             paused,
             running,
             thread,
@@ -195,6 +400,50 @@ where
     }
 }
 
+// -- event sender
+
+/// A cloneable handle to inject events into the event listener.
+///
+/// The injected events are returned by the listener like the events polled from the ports,
+/// so they are forwarded to the active component and to the subscriptions on [`crate::Application::tick`].
+/// The sender can be moved to other threads.
+pub struct EventSender<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    sender: mpsc::SyncSender<ListenerMsg<U>>,
+}
+
+impl<U> EventSender<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    /// Send `event` to the event listener.
+    ///
+    /// Returns [`ListenerError::ListenerDied`] if the event listener has been dropped
+    /// (e.g. after the listener has been restarted a new sender must be obtained).
+    /// Returns [`ListenerError::QueueFull`] if the queue of the event listener is full; the event is discarded.
+    pub fn send(&self, event: Event<U>) -> ListenerResult<()> {
+        self.sender
+            .try_send(ListenerMsg::User(event))
+            .map_err(|err| match err {
+                mpsc::TrySendError::Full(_) => ListenerError::QueueFull,
+                mpsc::TrySendError::Disconnected(_) => ListenerError::ListenerDied,
+            })
+    }
+}
+
+impl<U> Clone for EventSender<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
 // -- listener thread
 
 /// Listener message is returned by the listener thread
@@ -203,7 +452,7 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
 {
     Error(ListenerError),
-    Tick,
+    Tick(Duration),
     User(Event<U>),
 }
 
@@ -214,7 +463,7 @@ where
     fn from(msg: ListenerMsg<U>) -> Self {
         match msg {
             ListenerMsg::Error(err) => Err(err),
-            ListenerMsg::Tick => Ok(Some(Event::Tick)),
+            ListenerMsg::Tick(elapsed) => Ok(Some(Event::Tick(elapsed))),
             ListenerMsg::User(ev) => Ok(Some(ev)),
         }
     }
@@ -227,7 +476,7 @@ mod test {
 
     use super::*;
     use crate::core::event::{Key, KeyEvent};
-    use crate::mock::{MockEvent, MockPoll};
+    use crate::mock::{MockEvent, MockIdlePoll, MockPoll};
 
     #[test]
     fn worker_should_run_thread() {
@@ -238,8 +487,13 @@ mod test {
                 1,
             )],
             Duration::from_millis(10),
-            Some(Duration::from_secs(3)),
+            1024,
+            WorkerOptions {
+                tick_interval: Some(Duration::from_secs(3)),
+                ..WorkerOptions::default()
+            },
         );
+        assert_eq!(listener.port_count(), 1);
         // Wait 1 second
         thread::sleep(Duration::from_secs(1));
         // Poll (event)
@@ -248,35 +502,70 @@ mod test {
             Event::Keyboard(KeyEvent::from(Key::Enter))
         );
         // Poll (tick)
-        assert_eq!(listener.poll().ok().unwrap().unwrap(), Event::Tick);
+        assert!(listener.poll().ok().unwrap().unwrap().is_tick());
         // Poll (None)
         assert!(listener.poll().ok().unwrap().is_none());
         // Wait 3 seconds
         thread::sleep(Duration::from_secs(3));
         // New tick
-        assert_eq!(listener.poll().ok().unwrap().unwrap(), Event::Tick);
+        assert!(listener.poll().ok().unwrap().unwrap().is_tick());
         // Stop
         assert!(listener.stop().is_ok());
     }
 
+    #[test]
+    fn listener_should_receive_events_from_sender() {
+        let mut listener = EventListener::<MockEvent>::start(
+            vec![Port::new(
+                Box::new(MockIdlePoll),
+                Duration::from_millis(10),
+                1,
+            )],
+            Duration::from_millis(10),
+            1024,
+            WorkerOptions::default(),
+        );
+        let sender = listener.sender();
+        let handle = thread::spawn(move || sender.send(Event::User(MockEvent::Foo)));
+        assert!(handle.join().unwrap().is_ok());
+        assert_eq!(
+            listener.poll().ok().unwrap().unwrap(),
+            Event::User(MockEvent::Foo)
+        );
+        assert!(listener.poll().ok().unwrap().is_none());
+        let sender = listener.sender();
+        assert!(listener.stop().is_ok());
+        drop(listener);
+        assert!(sender.send(Event::User(MockEvent::Bar)).is_err());
+    }
+
     #[test]
     fn worker_should_be_paused() {
         let mut listener = EventListener::<MockEvent>::start(
             vec![],
             Duration::from_millis(10),
-            Some(Duration::from_millis(750)),
+            1024,
+            WorkerOptions {
+                tick_interval: Some(Duration::from_millis(750)),
+                ..WorkerOptions::default()
+            },
         );
+        assert_eq!(listener.port_count(), 0);
+        assert_eq!(listener.has_tick(), true);
         thread::sleep(Duration::from_millis(100));
+        assert!(!listener.is_paused());
         assert!(listener.pause().is_ok());
+        assert!(listener.is_paused());
         // Should be some
-        assert_eq!(listener.poll().ok().unwrap().unwrap(), Event::Tick);
+        assert!(listener.poll().ok().unwrap().unwrap().is_tick());
         // Wait tick time
         thread::sleep(Duration::from_secs(1));
         assert_eq!(listener.poll().ok().unwrap(), None);
         // Unpause
         assert!(listener.unpause().is_ok());
+        assert!(!listener.is_paused());
         thread::sleep(Duration::from_millis(300));
-        assert_eq!(listener.poll().ok().unwrap().unwrap(), Event::Tick);
+        assert!(listener.poll().ok().unwrap().unwrap().is_tick());
         // Stop
         assert!(listener.stop().is_ok());
     }
@@ -287,7 +576,11 @@ mod test {
         EventListener::<MockEvent>::start(
             vec![],
             Duration::from_millis(0),
-            Some(Duration::from_secs(3)),
+            1024,
+            WorkerOptions {
+                tick_interval: Some(Duration::from_secs(3)),
+                ..WorkerOptions::default()
+            },
         );
     }
 }
@@ -7,9 +7,17 @@ use std::time::{Duration, Instant};
 
 use super::{Event, ListenerResult, Poll};
 
+/// A function applied to the events emitted by a [`Port`]; the event is discarded if [`None`] is returned
+pub(crate) type PortTransform<U> = Box<dyn FnMut(Event<U>) -> Option<Event<U>> + Send>;
+
 /// A port is a wrapper around the poll trait object, which also defines an interval, which defines
 /// the amount of time between each [`Poll::poll`] call.
-/// Its purpose is to listen for incoming events of a user-defined type
+/// Its purpose is to listen for incoming events of a user-defined type.
+///
+/// The events emitted by a port can be transformed with [`Port::map`], [`Port::filter`] and [`Port::filter_map`]
+/// and rate limited with [`Port::debounce`] and [`Port::throttle`].
+///
+/// A port whose poll can wait for an event can be polled on a dedicated thread with [`Port::blocking`].
 pub struct Port<U>
 where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
@@ -18,6 +26,22 @@ where
     interval: Duration,
     next_poll: Instant,
     max_poll: usize,
+    /// Transforms applied in order to each event polled
+    transforms: Vec<PortTransform<U>>,
+    debounce: Option<Duration>,
+    /// Events emitted within the debounce window, with the time they've been emitted
+    debounced: Vec<(Event<U>, Instant)>,
+    throttle: Option<Duration>,
+    /// Latest event received within the throttle window, waiting to be emitted
+    throttled: Option<Event<U>>,
+    /// Time of the last event emitted while throttling
+    last_emit: Option<Instant>,
+    /// Whether the port is polled on a dedicated thread
+    blocking: bool,
+    /// Bounds of the intervals hinted by the poll
+    interval_bounds: Option<(Duration, Duration)>,
+    /// Whether the port is not polled anymore, since its poll has panicked
+    disabled: bool,
 }
 
 impl<U> Port<U>
@@ -30,16 +54,115 @@ where
     ///
     /// * `poll` - The poll trait object
     /// * `interval` - The interval between each poll
-    /// * `max_poll` - The maximum amount of times the port should be polled in a single poll
+    /// * `max_poll` - The maximum amount of times the port should be polled in a single poll.
+    ///   The worker drains up to `max_poll` events from the port before moving on to the next one;
+    ///   the port is always polled at least once.
     pub fn new(poll: Box<dyn Poll<U>>, interval: Duration, max_poll: usize) -> Self {
         Self {
             poll,
             interval,
             next_poll: Instant::now(),
             max_poll,
+            transforms: Vec::new(),
+            debounce: None,
+            debounced: Vec::new(),
+            throttle: None,
+            throttled: None,
+            last_emit: None,
+            blocking: false,
+            interval_bounds: None,
+            disabled: false,
         }
     }
 
+    /// Replace each event emitted by this port with the event returned by `f`
+    /// (e.g. to tag the events of the port or to convert keys into user events).
+    ///
+    /// Transforms are applied in the order they're added, before debounce and throttle.
+    pub fn map<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(Event<U>) -> Event<U> + Send + 'static,
+    {
+        self.filter_map(move |ev| Some(f(ev)))
+    }
+
+    /// Discard the events emitted by this port which don't satisfy `f`.
+    ///
+    /// Transforms are applied in the order they're added, before debounce and throttle.
+    pub fn filter<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(&Event<U>) -> bool + Send + 'static,
+    {
+        self.filter_map(move |ev| f(&ev).then_some(ev))
+    }
+
+    /// Replace each event emitted by this port with the event returned by `f`, discarding it if `f` returns [`None`].
+    ///
+    /// Transforms are applied in the order they're added, before debounce and throttle.
+    pub fn filter_map<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(Event<U>) -> Option<Event<U>> + Send + 'static,
+    {
+        self.transforms.push(Box::new(f));
+        self
+    }
+
+    /// Suppress the events equal to an event emitted by this port less than `window` ago
+    pub fn debounce(mut self, window: Duration) -> Self {
+        self.debounce = Some(window);
+        self
+    }
+
+    /// Emit at most one event every `window`.
+    ///
+    /// The events received within the window are discarded, except for the latest one, which is emitted
+    /// on the first poll after the window has expired.
+    pub fn throttle(mut self, window: Duration) -> Self {
+        self.throttle = Some(window);
+        self
+    }
+
+    /// Poll this port on a dedicated thread, which polls it again as soon as the previous poll has returned,
+    /// and wake up the worker as soon as an event is read.
+    ///
+    /// This is meant for ports whose [`Poll::poll`] waits up to a timeout for an event
+    /// (e.g. with `crossterm::event::poll(timeout)`): the events are emitted as soon as they're read,
+    /// while the worker doesn't wake up on each interval to poll the port.
+    /// The timeout bounds the time the port takes to notice the event listener has been paused or stopped.
+    /// Up to `max_poll` events are queued for the worker: once the queue is full, the port is not polled
+    /// until the worker catches up.
+    ///
+    /// > The poll must wait for the events: a poll which returns immediately keeps the thread busy
+    pub fn blocking(mut self) -> Self {
+        self.blocking = true;
+        self
+    }
+
+    /// Clamp the intervals hinted by [`Poll::interval_hint`] between `min` and `max`.
+    /// If no bounds are set, the hinted intervals are used as they are.
+    ///
+    /// > Panics if `min` is greater than `max`
+    pub fn interval_bounds(mut self, min: Duration, max: Duration) -> Self {
+        assert!(min <= max, "min interval is greater than max interval");
+        self.interval_bounds = Some((min, max));
+        self
+    }
+
+    /// Returns whether the port is polled on a dedicated thread (see [`Port::blocking`])
+    pub fn is_blocking(&self) -> bool {
+        self.blocking
+    }
+
+    /// Returns whether the port is not polled anymore, since its poll has panicked
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Stop polling the port
+    pub(crate) fn disable(&mut self) {
+        self.disabled = true;
+    }
+
     /// Get how often a port should get polled in a single poll
     pub fn max_poll(&self) -> usize {
         self.max_poll
@@ -65,10 +188,75 @@ where
         self.poll.poll()
     }
 
-    /// Calculate the next poll (t_now + interval)
+    /// Replace the inner [`Poll`] with `poll`, returning the previous one
+    pub(crate) fn replace_poll(&mut self, poll: Box<dyn Poll<U>>) -> Box<dyn Poll<U>> {
+        std::mem::replace(&mut self.poll, poll)
+    }
+
+    /// Calculate the next poll (t_now + interval).
+    /// The interval is updated first, if the poll hints a new one (see [`Poll::interval_hint`])
     pub fn calc_next_poll(&mut self) {
+        if let Some(hint) = self.poll.interval_hint() {
+            self.interval = match self.interval_bounds {
+                Some((min, max)) => hint.clamp(min, max),
+                None => hint,
+            };
+        }
         self.next_poll = Instant::now().add(self.interval);
     }
+
+    /// Apply the transforms, debounce and throttle to a polled `event`.
+    /// Returns the event, if it must be emitted now
+    pub(crate) fn process(&mut self, event: Event<U>) -> Option<Event<U>> {
+        let event = self
+            .transforms
+            .iter_mut()
+            .try_fold(event, |event, transform| transform(event))?;
+        let now = Instant::now();
+        if let Some(window) = self.debounce {
+            self.debounced
+                .retain(|(_, emitted)| now.duration_since(*emitted) < window);
+            if self.debounced.iter().any(|(ev, _)| *ev == event) {
+                return None;
+            }
+        }
+        if let (Some(window), Some(last_emit)) = (self.throttle, self.last_emit) {
+            if now.duration_since(last_emit) < window {
+                self.throttled = Some(event);
+                return None;
+            }
+        }
+        self.emitted(&event, now);
+        Some(event)
+    }
+
+    /// Returns the latest event held by the throttle, if the throttle window has expired
+    pub(crate) fn take_throttled(&mut self) -> Option<Event<U>> {
+        let now = Instant::now();
+        match (self.throttle, self.last_emit) {
+            (Some(window), Some(last_emit)) if now.duration_since(last_emit) >= window => {
+                let event = self.throttled.take()?;
+                self.emitted(&event, now);
+                Some(event)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the throttle holds an event, waiting for its window to expire
+    pub(crate) fn has_throttled(&self) -> bool {
+        self.throttled.is_some()
+    }
+
+    /// Keep track of `event` emitted at `now`
+    fn emitted(&mut self, event: &Event<U>, now: Instant) {
+        if self.debounce.is_some() {
+            self.debounced.push((event.clone(), now));
+        }
+        if self.throttle.is_some() {
+            self.last_emit = Some(now);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +265,7 @@ mod test {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::event::{Key, KeyEvent};
     use crate::mock::{MockEvent, MockPoll};
 
     #[test]
@@ -90,4 +279,114 @@ mod test {
         assert_eq!(listener.should_poll(), false);
         assert_eq!(*listener.interval(), Duration::from_secs(5));
     }
+
+    /// A poll which hints the interval set by the test
+    struct HintPoll {
+        hint: Option<Duration>,
+    }
+
+    impl Poll<MockEvent> for HintPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            Ok(None)
+        }
+
+        fn interval_hint(&self) -> Option<Duration> {
+            self.hint
+        }
+    }
+
+    #[test]
+    fn should_update_interval_with_clamped_hint() {
+        let mut port = Port::<MockEvent>::new(
+            Box::new(HintPoll {
+                hint: Some(Duration::from_millis(250)),
+            }),
+            Duration::from_secs(5),
+            1,
+        );
+        port.calc_next_poll();
+        assert_eq!(*port.interval(), Duration::from_millis(250));
+        // no hint keeps the interval
+        port.replace_poll(Box::new(HintPoll { hint: None }));
+        port.calc_next_poll();
+        assert_eq!(*port.interval(), Duration::from_millis(250));
+        // clamped
+        let mut port = port.interval_bounds(Duration::from_millis(100), Duration::from_secs(1));
+        port.replace_poll(Box::new(HintPoll {
+            hint: Some(Duration::from_millis(10)),
+        }));
+        port.calc_next_poll();
+        assert_eq!(*port.interval(), Duration::from_millis(100));
+        port.replace_poll(Box::new(HintPoll {
+            hint: Some(Duration::from_secs(5)),
+        }));
+        port.calc_next_poll();
+        assert_eq!(*port.interval(), Duration::from_secs(1));
+        assert!(port.next_poll() <= Instant::now() + Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_accept_inverted_interval_bounds() {
+        let _ = Port::<MockEvent>::new(Box::new(MockPoll::default()), Duration::from_secs(5), 1)
+            .interval_bounds(Duration::from_secs(2), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn should_debounce_events_within_window() {
+        let mut port =
+            Port::<MockEvent>::new(Box::new(MockPoll::default()), Duration::from_secs(5), 1)
+                .debounce(Duration::from_millis(50));
+        let ev = Event::User(MockEvent::Foo);
+        assert_eq!(port.process(ev.clone()), Some(ev.clone()));
+        assert_eq!(port.process(ev.clone()), None);
+        assert_eq!(
+            port.process(Event::User(MockEvent::Bar)),
+            Some(Event::User(MockEvent::Bar))
+        );
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(port.process(ev.clone()), Some(ev));
+        // nothing to emit if not throttled
+        assert_eq!(port.take_throttled(), None);
+    }
+
+    #[test]
+    fn should_filter_events() {
+        // drop the noise of a chatty port
+        let mut port =
+            Port::<MockEvent>::new(Box::new(MockPoll::default()), Duration::from_secs(5), 1)
+                .filter(|ev| !matches!(ev, Event::Tick(_) | Event::None));
+        assert_eq!(port.process(Event::Tick(Duration::ZERO)), None);
+        assert_eq!(port.process(Event::None), None);
+        assert_eq!(
+            port.process(Event::User(MockEvent::Foo)),
+            Some(Event::User(MockEvent::Foo))
+        );
+    }
+
+    #[test]
+    fn should_map_events() {
+        let mut port =
+            Port::<MockEvent>::new(Box::new(MockPoll::default()), Duration::from_secs(5), 1)
+                .map(|ev| match ev {
+                    Event::Keyboard(KeyEvent {
+                        code: Key::Char('q'),
+                        ..
+                    }) => Event::User(MockEvent::Hello(String::from("quit"))),
+                    ev => ev,
+                })
+                .filter_map(|ev| match ev {
+                    Event::User(MockEvent::Bar) => None,
+                    ev => Some(ev),
+                });
+        assert_eq!(
+            port.process(Event::Keyboard(KeyEvent::from(Key::Char('q')))),
+            Some(Event::User(MockEvent::Hello(String::from("quit"))))
+        );
+        assert_eq!(
+            port.process(Event::Keyboard(KeyEvent::from(Key::Char('a')))),
+            Some(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+        );
+        assert_eq!(port.process(Event::User(MockEvent::Bar)), None);
+    }
 }
@@ -0,0 +1,264 @@
+//! ## Recorder
+//!
+//! This module exposes the event recorder and the replay port, which can be used to record a session
+//! and to reproduce it later.
+
+#[cfg(feature = "serialize")]
+use std::collections::VecDeque;
+#[cfg(feature = "serialize")]
+use std::io::{BufRead, Write};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "serialize")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serialize")]
+use super::{ListenerResult, Poll};
+use crate::Event;
+
+/// Function called by the [`EventRecorder`] with the elapsed time and the emitted event
+type RecordFn<U> = Box<dyn FnMut(Duration, &Event<U>) + Send>;
+
+/// The event recorder is called by the event listener worker for each event emitted by the listener.
+pub(crate) struct EventRecorder<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    started: Instant,
+    record: RecordFn<U>,
+}
+
+impl<U> EventRecorder<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    /// Create a new recorder which calls `record` with the time elapsed since the recorder was created
+    /// and the emitted event.
+    #[cfg_attr(not(feature = "serialize"), allow(dead_code))]
+    pub fn new(record: RecordFn<U>) -> Self {
+        Self {
+            started: Instant::now(),
+            record,
+        }
+    }
+
+    /// Record `event`
+    pub fn record(&mut self, event: &Event<U>) {
+        (self.record)(self.started.elapsed(), event);
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<U> EventRecorder<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + Serialize,
+{
+    /// Create a recorder which writes each event as a JSON line to `writer`.
+    ///
+    /// Write errors are ignored, since recording must never make the listener fail.
+    pub fn json_lines<W>(mut writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::new(Box::new(move |elapsed, event| {
+            let record = RecordedEvent {
+                elapsed: elapsed.as_millis() as u64,
+                event: event.clone(),
+            };
+            if let Ok(mut line) = serde_json::to_vec(&record) {
+                line.push(b'\n');
+                let _ = writer.write_all(&line);
+                let _ = writer.flush();
+            }
+        }))
+    }
+}
+
+/// A single event in a recording.
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd,
+{
+    /// Milliseconds elapsed since the recording started
+    pub elapsed: u64,
+    /// The recorded event
+    pub event: Event<U>,
+}
+
+/// Defines the timing used by the [`ReplayPort`] to re-emit the recorded events.
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayTiming {
+    /// Events are emitted with the original timing
+    Original,
+    /// Events are emitted with the original timing divided by the provided factor
+    /// (e.g. `Accelerated(2.0)` replays the session twice as fast)
+    Accelerated(f64),
+    /// Events are emitted as soon as possible
+    Immediate,
+}
+
+/// A [`Poll`] implementation which re-emits the events recorded with [`crate::EventListenerCfg::with_recorder`].
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub struct ReplayPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    events: VecDeque<RecordedEvent<U>>,
+    timing: ReplayTiming,
+    started: Option<Instant>,
+}
+
+#[cfg(feature = "serialize")]
+impl<U> ReplayPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + DeserializeOwned,
+{
+    /// Create a new [`ReplayPort`] reading the recording from the JSON-lines file at `path`
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Self::from_reader(std::io::BufReader::new(file))
+    }
+
+    /// Create a new [`ReplayPort`] reading the recording from a JSON-lines `reader`
+    pub fn from_reader<R: BufRead>(reader: R) -> std::io::Result<Self> {
+        let mut events = VecDeque::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: RecordedEvent<U> = serde_json::from_str(&line)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            events.push_back(record);
+        }
+        Ok(Self::new(events.into()))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<U> ReplayPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    /// Create a new [`ReplayPort`] from the recorded `events`
+    pub fn new(events: Vec<RecordedEvent<U>>) -> Self {
+        Self {
+            events: events.into(),
+            timing: ReplayTiming::Original,
+            started: None,
+        }
+    }
+
+    /// Set the replay timing. (Default: [`ReplayTiming::Original`])
+    pub fn timing(mut self, timing: ReplayTiming) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Returns whether all the recorded events have been emitted
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns whether the event recorded at `elapsed` ms should be emitted
+    fn is_due(&self, started: Instant, elapsed: u64) -> bool {
+        let at = Duration::from_millis(elapsed);
+        match self.timing {
+            ReplayTiming::Immediate => true,
+            ReplayTiming::Original => started.elapsed() >= at,
+            ReplayTiming::Accelerated(factor) if factor > 0.0 => {
+                started.elapsed() >= at.div_f64(factor)
+            }
+            ReplayTiming::Accelerated(_) => true,
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<U> Poll<U> for ReplayPort<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        let due = self
+            .events
+            .front()
+            .map(|x| self.is_due(started, x.elapsed))
+            .unwrap_or(false);
+        if due {
+            Ok(self.events.pop_front().map(|x| x.event))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serialize")]
+mod test {
+
+    use std::io::{Read, Seek};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::event::{Key, KeyEvent};
+    use crate::NoUserEvent;
+
+    #[test]
+    fn should_record_and_replay_events() {
+        let mut file = tempfile::tempfile().unwrap();
+        let mut recorder = EventRecorder::<NoUserEvent>::json_lines(file.try_clone().unwrap());
+        recorder.record(&Event::Keyboard(KeyEvent::from(Key::Enter)));
+        recorder.record(&Event::Tick(Duration::from_millis(100)));
+        recorder.record(&Event::WindowResize(80, 24));
+        // read
+        file.rewind().unwrap();
+        let mut data = String::new();
+        file.read_to_string(&mut data).unwrap();
+        assert_eq!(data.lines().count(), 3);
+        let mut port = ReplayPort::<NoUserEvent>::from_reader(data.as_bytes())
+            .unwrap()
+            .timing(ReplayTiming::Immediate);
+        assert_eq!(
+            port.poll().unwrap().unwrap(),
+            Event::Keyboard(KeyEvent::from(Key::Enter))
+        );
+        assert_eq!(
+            port.poll().unwrap().unwrap(),
+            Event::Tick(Duration::from_millis(100))
+        );
+        assert_eq!(port.poll().unwrap().unwrap(), Event::WindowResize(80, 24));
+        assert!(port.is_finished());
+        assert!(port.poll().unwrap().is_none());
+    }
+
+    #[test]
+    fn should_replay_with_original_timing() {
+        let mut port = ReplayPort::<NoUserEvent>::new(vec![
+            RecordedEvent {
+                elapsed: 0,
+                event: Event::Tick(Duration::from_millis(100)),
+            },
+            RecordedEvent {
+                elapsed: 60_000,
+                event: Event::Tick(Duration::from_millis(100)),
+            },
+        ]);
+        assert_eq!(
+            port.poll().unwrap().unwrap(),
+            Event::Tick(Duration::from_millis(100))
+        );
+        assert!(port.poll().unwrap().is_none());
+        assert_eq!(port.is_finished(), false);
+    }
+}
@@ -2,13 +2,193 @@
 //!
 //! This module implements the worker thread for the event listener
 
-use std::ops::{Add, Sub};
+use std::ops::Add;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::AtomicBool;
+#[cfg(test)]
+use std::sync::atomic::AtomicUsize;
 use std::sync::{mpsc, Arc};
-use std::thread;
-use std::time::{Duration, Instant};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::{
+    EventRecorder, ListenerError, ListenerMetrics, ListenerMsg, ListenerResult,
+    MissedIntervalPolicy, Poll, Port, QueueOverflowPolicy, TickAlignment,
+};
+use crate::event::KeyEventKind;
+use crate::Event;
+
+// -- scheduling
+
+/// Time the worker sleeps before checking again whether it's been resumed, while paused
+const PAUSED_SLEEP: Duration = Duration::from_millis(25);
+/// Time the worker sleeps when there are neither ports nor ticks to schedule
+const IDLE_SLEEP: Duration = Duration::from_secs(60);
+
+/// The schedule of a port, as seen by the worker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PortSchedule {
+    /// Time of the next poll of the port
+    pub next_poll: Instant,
+}
+
+/// The schedule of the tick, as seen by the worker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct TickSchedule {
+    /// Time of the next tick
+    pub next_tick: Instant,
+}
+
+/// Returns the time at which the worker must wake up: the earliest between the next poll of the ports and the next tick.
+/// The returned time is not after `now` if a port poll or the tick is already due.
+///
+/// If there's nothing to schedule, the worker wakes up after [`IDLE_SLEEP`].
+pub(super) fn next_wakeup(
+    now: Instant,
+    ports: &[PortSchedule],
+    tick: Option<TickSchedule>,
+) -> Instant {
+    ports
+        .iter()
+        .map(|x| x.next_poll)
+        .chain(tick.map(|x| x.next_tick))
+        .min()
+        .unwrap_or_else(|| now.add(IDLE_SLEEP))
+}
+
+/// Returns the time left from `elapsed`, the time elapsed since the alignment origin, to the next multiple of `interval`.
+///
+/// If `elapsed` is exactly a multiple of `interval`, the next multiple is one whole interval later.
+pub(super) fn until_next_boundary(elapsed: Duration, interval: Duration) -> Duration {
+    let interval_nanos = interval.as_nanos();
+    if interval_nanos == 0 {
+        return Duration::ZERO;
+    }
+    let remainder = elapsed.as_nanos() % interval_nanos;
+    // NOTE: the result is at most `interval`, so it fits into a `Duration`
+    Duration::from_nanos((interval_nanos - remainder) as u64)
+}
+
+// -- interval events
+
+/// A user event emitted by the worker at a fixed interval
+pub(super) struct IntervalEvent<U> {
+    event: U,
+    every: Duration,
+    /// Deadline of the next emission
+    next: Instant,
+}
 
-use super::{ListenerMsg, Port};
+impl<U> IntervalEvent<U> {
+    /// Create a new [`IntervalEvent`], whose first deadline is one interval after `now`
+    pub(super) fn new(event: U, every: Duration, now: Instant) -> Self {
+        Self {
+            event,
+            every,
+            next: now.add(every),
+        }
+    }
+
+    /// Returns how many times the event must be emitted at `now`, according to `policy`, and schedules the next deadline.
+    ///
+    /// The next deadline is calculated from the previous deadline, not from `now`, so the emissions don't drift.
+    pub(super) fn due(&mut self, now: Instant, policy: MissedIntervalPolicy) -> usize {
+        if self.next > now {
+            return 0;
+        }
+        let late = now.duration_since(self.next).as_nanos() / self.every.as_nanos();
+        let missed = usize::try_from(late).unwrap_or(usize::MAX);
+        self.next = u32::try_from(missed.saturating_add(1))
+            .ok()
+            .and_then(|n| self.every.checked_mul(n))
+            .and_then(|delay| self.next.checked_add(delay))
+            .unwrap_or_else(|| now.add(self.every));
+        match policy {
+            MissedIntervalPolicy::Skip => 1,
+            MissedIntervalPolicy::CatchUp => missed.saturating_add(1),
+        }
+    }
+}
+
+// -- blocking ports
+
+/// The result of a poll of a blocking port
+type PollResult<U> = ListenerResult<Option<Event<U>>>;
+
+/// The [`Poll`] which replaces the poll of a blocking port in the worker: it returns the results
+/// read by the reader thread of the port
+struct ReaderPoll<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send,
+{
+    results: mpsc::Receiver<PollResult<U>>,
+}
+
+impl<U> Poll<U> for ReaderPoll<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn poll(&mut self) -> PollResult<U> {
+        self.results.try_recv().unwrap_or(Ok(None))
+    }
+}
+
+/// The [`Poll`] which replaces the [`ReaderPoll`] of a blocking port once its reader has been stopped
+struct StoppedPoll;
+
+impl<U> Poll<U> for StoppedPoll
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn poll(&mut self) -> PollResult<U> {
+        Ok(None)
+    }
+}
+
+/// Spawn the reader thread of a blocking port, which polls `poll` until the worker stops
+/// and sends the results to the worker, waking it up.
+/// The results channel is bounded, so the reader blocks, without polling, while the worker is behind.
+///
+/// While the worker is paused, the port is not polled. After an error, the port is polled again after `interval`.
+/// If the poll panics, the reader sends [`ListenerError::PortPanicked`] with the `index` of the port and terminates.
+fn spawn_reader<U>(
+    index: usize,
+    mut poll: Box<dyn Poll<U>>,
+    interval: Duration,
+    results: mpsc::SyncSender<PollResult<U>>,
+    wake: mpsc::Sender<()>,
+    paused: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+) -> JoinHandle<()>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    thread::spawn(move || {
+        while running.load(std::sync::atomic::Ordering::Relaxed) {
+            if paused.load(std::sync::atomic::Ordering::Relaxed) {
+                thread::sleep(PAUSED_SLEEP);
+                continue;
+            }
+            let Ok(result) = panic::catch_unwind(AssertUnwindSafe(|| poll.poll())) else {
+                // NOTE: the port is not polled anymore, but the worker keeps running
+                let _ = results.send(Err(ListenerError::PortPanicked(index)));
+                break;
+            };
+            let failed = result.is_err();
+            if matches!(result, Ok(None)) {
+                continue;
+            }
+            if results.send(result).is_err() || wake.send(()).is_err() {
+                break;
+            }
+            if failed {
+                thread::sleep(interval);
+            }
+        }
+        // NOTE: wake up the worker, which may be waiting for the next event, so that it notices it's been stopped
+        let _ = wake.send(());
+    })
+}
 
 // -- worker
 
@@ -18,11 +198,38 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
 {
     ports: Vec<Port<U>>,
-    sender: mpsc::Sender<ListenerMsg<U>>,
+    sender: mpsc::SyncSender<ListenerMsg<U>>,
     paused: Arc<AtomicBool>,
     running: Arc<AtomicBool>,
     next_tick: Instant,
+    /// Time of the previous tick, used to calculate the elapsed time between ticks
+    last_tick: Instant,
     tick_interval: Option<Duration>,
+    /// When the ticks are scheduled
+    tick_alignment: TickAlignment,
+    /// Time the worker has been created at, which is the origin of [`TickAlignment::ListenerStart`]
+    started: Instant,
+    recorder: Option<EventRecorder<U>>,
+    /// Kinds of keyboard events to emit; the other keyboard events are discarded
+    key_event_kinds: Vec<KeyEventKind>,
+    /// What to do when the queue is full
+    overflow_policy: QueueOverflowPolicy,
+    /// Counters of the emitted events, if enabled
+    metrics: Option<Arc<ListenerMetrics>>,
+    /// User events emitted at a fixed interval
+    interval_events: Vec<IntervalEvent<U>>,
+    /// What to do with the missed deadlines of the interval events
+    missed_interval_policy: MissedIntervalPolicy,
+    /// Wakes up the worker while it's waiting for the next event; cloned by the reader threads of the blocking ports
+    wake_tx: mpsc::Sender<()>,
+    wake_rx: mpsc::Receiver<()>,
+    /// Reader threads of the blocking ports
+    readers: Vec<JoinHandle<()>>,
+    /// Whether a reader thread has woken up the worker, so the blocking ports have results to drain
+    woken: bool,
+    /// Counter of the iterations of the run loop
+    #[cfg(test)]
+    iterations: Option<Arc<AtomicUsize>>,
 }
 
 impl<U> EventListenerWorker<U>
@@ -31,49 +238,190 @@ where
 {
     pub(super) fn new(
         ports: Vec<Port<U>>,
-        sender: mpsc::Sender<ListenerMsg<U>>,
+        sender: mpsc::SyncSender<ListenerMsg<U>>,
         paused: Arc<AtomicBool>,
         running: Arc<AtomicBool>,
         tick_interval: Option<Duration>,
     ) -> Self {
+        let (wake_tx, wake_rx) = mpsc::channel();
         Self {
             ports,
             sender,
             paused,
             running,
             next_tick: Instant::now(),
+            last_tick: Instant::now(),
             tick_interval,
+            tick_alignment: TickAlignment::default(),
+            started: Instant::now(),
+            recorder: None,
+            key_event_kinds: vec![KeyEventKind::Press],
+            overflow_policy: QueueOverflowPolicy::default(),
+            metrics: None,
+            interval_events: Vec::new(),
+            missed_interval_policy: MissedIntervalPolicy::default(),
+            wake_tx,
+            wake_rx,
+            readers: Vec::new(),
+            woken: false,
+            #[cfg(test)]
+            iterations: None,
+        }
+    }
+
+    /// Set when the ticks are scheduled
+    pub(super) fn tick_alignment(mut self, alignment: TickAlignment) -> Self {
+        self.tick_alignment = alignment;
+        self
+    }
+
+    /// Set the recorder to call for each emitted event
+    pub(super) fn recorder(mut self, recorder: Option<EventRecorder<U>>) -> Self {
+        self.recorder = recorder;
+        self
+    }
+
+    /// Set the kinds of keyboard events to emit
+    pub(super) fn key_event_kinds(mut self, kinds: Vec<KeyEventKind>) -> Self {
+        self.key_event_kinds = kinds;
+        self
+    }
+
+    /// Set the policy to apply when the queue is full
+    pub(super) fn overflow_policy(mut self, policy: QueueOverflowPolicy) -> Self {
+        self.overflow_policy = policy;
+        self
+    }
+
+    /// Set the counters to update for each emitted event
+    pub(super) fn metrics(mut self, metrics: Option<Arc<ListenerMetrics>>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Set the user events to emit at a fixed interval and what to do with their missed deadlines
+    pub(super) fn interval_events(
+        mut self,
+        events: Vec<(U, Duration)>,
+        policy: MissedIntervalPolicy,
+    ) -> Self {
+        let now = Instant::now();
+        self.interval_events = events
+            .into_iter()
+            .map(|(event, every)| IntervalEvent::new(event, every, now))
+            .collect();
+        self.missed_interval_policy = policy;
+        self
+    }
+
+    /// Set the counter to increment on each iteration of the run loop
+    #[cfg(test)]
+    fn iterations(mut self, counter: Arc<AtomicUsize>) -> Self {
+        self.iterations = Some(counter);
+        self
+    }
+
+    /// Send `msg` to the listener, applying the overflow `policy` if the queue is full.
+    /// Returns whether the message has been queued.
+    ///
+    /// While waiting for the queue to have room, the message is discarded if the worker is stopped.
+    fn send(
+        sender: &mpsc::SyncSender<ListenerMsg<U>>,
+        policy: QueueOverflowPolicy,
+        running: &AtomicBool,
+        mut msg: ListenerMsg<U>,
+    ) -> Result<bool, mpsc::SendError<ListenerMsg<U>>> {
+        let discard_on_full = match policy {
+            QueueOverflowPolicy::DropTicks => matches!(msg, ListenerMsg::Tick(_)),
+            QueueOverflowPolicy::DropNewest => true,
+            QueueOverflowPolicy::Block => false,
+        };
+        loop {
+            match sender.try_send(msg) {
+                Ok(()) => return Ok(true),
+                Err(mpsc::TrySendError::Disconnected(msg)) => return Err(mpsc::SendError(msg)),
+                Err(mpsc::TrySendError::Full(_)) if discard_on_full => return Ok(false),
+                Err(mpsc::TrySendError::Full(_))
+                    if !running.load(std::sync::atomic::Ordering::Relaxed) =>
+                {
+                    return Ok(false)
+                }
+                Err(mpsc::TrySendError::Full(m)) => {
+                    msg = m;
+                    thread::sleep(Duration::from_millis(1));
+                }
+            }
         }
     }
 
-    /// Calculate next tick time.
+    /// Returns whether `event` must be discarded, because it's a keyboard event of a kind which is not in `kinds`
+    fn discard(kinds: &[KeyEventKind], event: &Event<U>) -> bool {
+        match event {
+            Event::Keyboard(ev) => !kinds.contains(&ev.kind),
+            _ => false,
+        }
+    }
+
+    /// Record `event` if a recorder is set and count it if metrics are enabled
+    fn record(&mut self, event: &Event<U>) {
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(event);
+        }
+        if let Some(metrics) = self.metrics.as_ref() {
+            metrics.count_event(event);
+        }
+    }
+
+    /// Calculate next tick time, according to the tick alignment.
     /// If tick is None, panics.
     fn calc_next_tick(&mut self) {
-        self.next_tick = Instant::now().add(self.tick_interval.unwrap());
+        let interval = self.tick_interval.unwrap();
+        let now = Instant::now();
+        self.next_tick = match self.tick_alignment {
+            TickAlignment::Unaligned => now.add(interval),
+            TickAlignment::UnixEpoch => {
+                // NOTE: a system clock set before the epoch is treated as the epoch itself
+                let since_epoch = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                now.add(until_next_boundary(since_epoch, interval))
+            }
+            TickAlignment::ListenerStart => now.add(until_next_boundary(
+                now.duration_since(self.started),
+                interval,
+            )),
+        };
     }
 
-    /// Calc the distance in time between now and the first upcoming event
+    /// Calc the distance in time between now and the first upcoming event.
+    /// While paused, it's always [`PAUSED_SLEEP`]
+    ///
+    /// Blocking ports are not scheduled, since their readers wake up the worker, unless their throttle holds an event.
     fn next_event(&self) -> Duration {
+        if self.paused() {
+            return PAUSED_SLEEP;
+        }
+        if self.woken {
+            return Duration::ZERO;
+        }
         let now = Instant::now();
-        let fallback_time = now.add(Duration::from_secs(60));
-        // Get first upcoming event from ports
-        let min_listener_event = self
+        let ports: Vec<PortSchedule> = self
             .ports
             .iter()
-            .map(|x| x.next_poll())
-            .min()
-            .unwrap_or(fallback_time);
-        let next_tick = match self.tick_interval.is_some() {
-            false => fallback_time,
-            true => self.next_tick,
-        };
-        let min_time = std::cmp::min(min_listener_event, next_tick);
-        // If min time is > now, returns diff, otherwise return 0
-        if min_time > now {
-            min_time.sub(now)
-        } else {
-            Duration::ZERO
-        }
+            .filter(|x| !x.is_disabled())
+            .filter(|x| !x.is_blocking() || x.has_throttled())
+            .map(|x| PortSchedule {
+                next_poll: x.next_poll(),
+            })
+            .collect();
+        let tick = self.tick_interval.map(|_| TickSchedule {
+            next_tick: self.next_tick,
+        });
+        self.interval_events
+            .iter()
+            .map(|x| x.next)
+            .fold(next_wakeup(now, &ports, tick), Instant::min)
+            .saturating_duration_since(now)
     }
 
     /// Returns whether should keep running
@@ -97,60 +445,177 @@ where
 
     /// Send tick to listener and calc next tick
     fn send_tick(&mut self) -> Result<(), mpsc::SendError<ListenerMsg<U>>> {
-        // Send tick
-        match self.sender.send(ListenerMsg::Tick) {
-            // Terminate thread on send failed
-            Err(err) => Err(err),
-            Ok(_) => {
-                // Calc next tick
-                self.calc_next_tick();
-                Ok(())
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_tick);
+        // Send tick; terminate thread on send failed
+        let queued = Self::send(
+            &self.sender,
+            self.overflow_policy,
+            &self.running,
+            ListenerMsg::Tick(elapsed),
+        )?;
+        // NOTE: if the tick has been discarded, the elapsed time is reported by the next tick
+        if queued {
+            self.last_tick = now;
+            self.record(&Event::Tick(elapsed));
+        }
+        // Calc next tick
+        self.calc_next_tick();
+        Ok(())
+    }
+
+    /// Send the interval events whose deadline has expired
+    fn send_interval_events(&mut self) -> Result<(), mpsc::SendError<ListenerMsg<U>>> {
+        let now = Instant::now();
+        for i in 0..self.interval_events.len() {
+            let times = self.interval_events[i].due(now, self.missed_interval_policy);
+            for _ in 0..times {
+                let event = self.interval_events[i].event.clone();
+                self.emit(ListenerMsg::User(Event::User(event)))?;
             }
         }
+        Ok(())
     }
 
     /// Poll and send poll to listener. Calc next poll.
     /// Returns only the messages, while the None returned by poll are discarded
-    #[allow(clippy::needless_collect)]
     fn poll(&mut self) -> Result<(), mpsc::SendError<ListenerMsg<U>>> {
-        let port_iter = self.ports.iter_mut().filter(|port| port.should_poll());
-
-        for port in port_iter {
-            let mut times_remaining = port.max_poll();
+        let woken = std::mem::take(&mut self.woken);
+        for i in 0..self.ports.len() {
+            if self.ports[i].is_disabled() {
+                continue;
+            }
+            // NOTE: blocking ports are drained only when their readers wake up the worker, or to emit a throttled event
+            let should_poll = match self.ports[i].is_blocking() {
+                true => woken || (self.ports[i].has_throttled() && self.ports[i].should_poll()),
+                false => self.ports[i].should_poll(),
+            };
+            if !should_poll {
+                continue;
+            }
+            // emit the event held by the throttle, if its window has expired
+            if let Some(ev) = self.ports[i].take_throttled() {
+                self.emit(ListenerMsg::User(ev))?;
+            }
+            let mut times_remaining = self.ports[i].max_poll();
             // poll a port until it has nothing anymore
             loop {
-                let msg = match port.poll() {
-                    Ok(Some(ev)) => ListenerMsg::User(ev),
+                let port = &mut self.ports[i];
+                // NOTE: a port which panics is disabled, instead of killing the worker with the other ports
+                let Ok(result) = panic::catch_unwind(AssertUnwindSafe(|| port.poll())) else {
+                    port.disable();
+                    debug!(port = i, "port panicked; disabled");
+                    self.emit(ListenerMsg::Error(ListenerError::PortPanicked(i)))?;
+                    break;
+                };
+                let msg = match result {
+                    Ok(Some(ev)) if Self::discard(&self.key_event_kinds, &ev) => None,
+                    Ok(Some(ev)) => port.process(ev).map(ListenerMsg::User),
                     Ok(None) => break,
-                    Err(err) => ListenerMsg::Error(err),
+                    Err(err) => Some(ListenerMsg::Error(err)),
                 };
 
-                self.sender.send(msg)?;
+                if let Some(msg) = msg {
+                    self.emit(msg)?;
+                }
 
                 // do this at the end to at least call it once
                 times_remaining = times_remaining.saturating_sub(1);
 
                 if times_remaining == 0 {
+                    // NOTE: the results left by the reader are drained on the next iteration
+                    self.woken |= self.ports[i].is_blocking();
                     break;
                 }
             }
             // Update next poll
-            port.calc_next_poll();
+            if !self.ports[i].is_disabled() {
+                self.ports[i].calc_next_poll();
+            }
         }
 
         Ok(())
     }
 
+    /// Send `msg` to the listener; the event is recorded once queued
+    fn emit(&mut self, msg: ListenerMsg<U>) -> Result<(), mpsc::SendError<ListenerMsg<U>>> {
+        // NOTE: keep a copy of the event to record it once queued
+        let event = match (&msg, self.recorder.is_some() || self.metrics.is_some()) {
+            (ListenerMsg::User(ev), true) => Some(ev.clone()),
+            _ => None,
+        };
+        let queued = Self::send(&self.sender, self.overflow_policy, &self.running, msg)?;
+        if let (true, Some(ev)) = (queued, event) {
+            self.record(&ev);
+        }
+        Ok(())
+    }
+
+    /// Start the reader threads of the blocking ports, which replace their polls
+    fn spawn_readers(&mut self) {
+        for (index, port) in self
+            .ports
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, port)| port.is_blocking())
+        {
+            // NOTE: the worker drains up to `max_poll` results at once
+            let (results_tx, results) = mpsc::sync_channel(port.max_poll().max(1));
+            let poll = port.replace_poll(Box::new(ReaderPoll { results }));
+            self.readers.push(spawn_reader(
+                index,
+                poll,
+                *port.interval(),
+                results_tx,
+                self.wake_tx.clone(),
+                Arc::clone(&self.paused),
+                Arc::clone(&self.running),
+            ));
+        }
+    }
+
+    /// Stop the reader threads of the blocking ports and wait for them to terminate
+    fn join_readers(&mut self) {
+        if self.readers.is_empty() {
+            return;
+        }
+        // NOTE: the worker has stopped, so must its readers
+        self.running
+            .store(false, std::sync::atomic::Ordering::Relaxed);
+        // NOTE: drop the receivers of the results, so that the readers blocked on a full channel terminate
+        for port in self.ports.iter_mut().filter(|port| port.is_blocking()) {
+            port.replace_poll(Box::new(StoppedPoll));
+        }
+        for reader in self.readers.drain(..) {
+            let _ = reader.join();
+        }
+    }
+
+    /// Wait up to `timeout`, or until a blocking port has read an event
+    fn wait(&mut self, timeout: Duration) {
+        // NOTE: the channel is never disconnected since we hold a sender
+        if self.wake_rx.recv_timeout(timeout).is_ok() {
+            self.woken = true;
+        }
+        while self.wake_rx.try_recv().is_ok() {}
+    }
+
     /// thread run method
     pub(super) fn run(&mut self) {
+        debug!(ports = self.ports.len(), "event listener worker started");
+        self.spawn_readers();
         loop {
+            #[cfg(test)]
+            if let Some(counter) = self.iterations.as_ref() {
+                counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
             // Check if running or send_error has occurred
             if !self.running() {
                 break;
             }
             // If paused, wait and resume cycle
             if self.paused() {
-                thread::sleep(Duration::from_millis(25));
+                thread::sleep(PAUSED_SLEEP);
                 continue;
             }
             // Iter ports and Send messages
@@ -161,9 +626,16 @@ where
             if self.should_tick() && self.send_tick().is_err() {
                 break;
             }
+            // Interval events
+            if self.send_interval_events().is_err() {
+                break;
+            }
             // Sleep till next event
-            thread::sleep(self.next_event());
+            let timeout = self.next_event();
+            self.wait(timeout);
         }
+        self.join_readers();
+        debug!("event listener worker stopped");
     }
 }
 
@@ -172,15 +644,15 @@ mod test {
 
     use pretty_assertions::assert_eq;
 
-    use super::super::ListenerResult;
+    use super::super::{EventListener, ListenerResult, Poll};
     use super::*;
     use crate::core::event::{Key, KeyEvent};
     use crate::mock::{MockEvent, MockPoll};
-    use crate::Event;
+    use crate::{Event, EventListenerCfg};
 
     #[test]
     fn worker_should_poll_multiple_times() {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = mpsc::sync_channel(1024);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
@@ -201,9 +673,104 @@ mod test {
         assert_eq!(recieved.len(), 10);
     }
 
+    #[test]
+    fn worker_should_drain_ports_without_starving_others() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let paused_t = Arc::clone(&paused);
+        let running = Arc::new(AtomicBool::new(true));
+        let running_t = Arc::clone(&running);
+
+        let busy_port = Port::new(Box::new(MockPoll::default()), Duration::from_secs(5), 10);
+        let user_port = Port::new(Box::new(MockUserPoll), Duration::from_secs(5), 1);
+
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![busy_port, user_port],
+            tx,
+            paused_t,
+            running_t,
+            None,
+        );
+        assert!(worker.poll().is_ok());
+        let mut recieved = Vec::new();
+
+        while let Ok(msg) = rx.try_recv() {
+            recieved.push(ListenerResult::from(msg).ok().unwrap().unwrap());
+        }
+
+        assert_eq!(recieved.len(), 11);
+        assert_eq!(
+            recieved
+                .iter()
+                .filter(|x| **x == Event::Keyboard(KeyEvent::from(Key::Enter)))
+                .count(),
+            10
+        );
+        assert_eq!(recieved.last().unwrap(), &Event::User(MockEvent::Foo));
+    }
+
+    #[test]
+    fn worker_should_filter_key_event_kinds() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        // default: only press
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockKeyKindsPoll::default()),
+                Duration::from_secs(5),
+                10,
+            )],
+            tx.clone(),
+            Arc::clone(&paused),
+            Arc::clone(&running),
+            None,
+        );
+        assert!(worker.poll().is_ok());
+        let recieved: Vec<Event<MockEvent>> = rx
+            .try_iter()
+            .map(|msg| ListenerResult::from(msg).ok().unwrap().unwrap())
+            .collect();
+        assert_eq!(
+            recieved,
+            vec![Event::Keyboard(KeyEvent::from(Key::Char('a')))]
+        );
+        // all kinds
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockKeyKindsPoll::default()),
+                Duration::from_secs(5),
+                10,
+            )],
+            tx,
+            paused,
+            running,
+            None,
+        )
+        .key_event_kinds(vec![
+            KeyEventKind::Press,
+            KeyEventKind::Repeat,
+            KeyEventKind::Release,
+        ]);
+        assert!(worker.poll().is_ok());
+        let recieved: Vec<KeyEventKind> = rx
+            .try_iter()
+            .map(|msg| ListenerResult::from(msg).ok().unwrap().unwrap())
+            .filter_map(|ev| ev.is_keyboard().map(|x| x.kind))
+            .collect();
+        assert_eq!(
+            recieved,
+            vec![
+                KeyEventKind::Press,
+                KeyEventKind::Repeat,
+                KeyEventKind::Release
+            ]
+        );
+    }
+
     #[test]
     fn worker_should_send_poll() {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = mpsc::sync_channel(1024);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
@@ -230,7 +797,7 @@ mod test {
 
     #[test]
     fn worker_should_send_tick() {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = mpsc::sync_channel(1024);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
@@ -249,15 +816,16 @@ mod test {
         assert!(worker.send_tick().is_ok());
         assert!(worker.next_tick > Instant::now());
         // Receive
-        assert_eq!(
-            ListenerResult::from(rx.recv().ok().unwrap()).ok().unwrap(),
-            Some(Event::Tick)
-        );
+        assert!(ListenerResult::from(rx.recv().ok().unwrap())
+            .ok()
+            .unwrap()
+            .unwrap()
+            .is_tick());
     }
 
     #[test]
     fn worker_should_calc_times_correctly_with_tick() {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = mpsc::sync_channel(1024);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
@@ -294,7 +862,7 @@ mod test {
 
     #[test]
     fn worker_should_calc_times_correctly_without_tick() {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = mpsc::sync_channel(1024);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
@@ -328,7 +896,7 @@ mod test {
     #[test]
     #[should_panic]
     fn worker_should_panic_when_trying_next_tick_without_it() {
-        let (tx, _) = mpsc::channel();
+        let (tx, _) = mpsc::sync_channel(1024);
         let paused = Arc::new(AtomicBool::new(false));
         let paused_t = Arc::clone(&paused);
         let running = Arc::new(AtomicBool::new(true));
@@ -337,4 +905,823 @@ mod test {
             EventListenerWorker::<MockEvent>::new(vec![], tx, paused_t, running_t, None);
         worker.calc_next_tick();
     }
+
+    #[test]
+    fn worker_should_drop_newest_events_when_queue_is_full() {
+        let (tx, rx) = mpsc::sync_channel(4);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(10),
+                1024,
+            )],
+            tx,
+            paused,
+            running,
+            None,
+        )
+        .overflow_policy(QueueOverflowPolicy::DropNewest);
+        assert!(worker.poll().is_ok());
+        // queue must be bounded
+        assert_eq!(rx.try_iter().count(), 4);
+    }
+
+    #[test]
+    fn worker_should_drop_ticks_but_keep_events_when_queue_is_full() {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(10),
+                8,
+            )],
+            tx.clone(),
+            paused,
+            running,
+            Some(Duration::from_secs(1)),
+        );
+        // fill queue with an event; the tick must be dropped
+        assert!(tx
+            .try_send(ListenerMsg::User(Event::User(MockEvent::Bar)))
+            .is_ok());
+        assert!(worker.send_tick().is_ok());
+        assert!(matches!(rx.try_recv(), Ok(ListenerMsg::User(_))));
+        assert!(rx.try_recv().is_err());
+        // keyboard events must wait for room
+        let consumer = thread::spawn(move || {
+            let mut received = 0;
+            while received < 8 {
+                if let Ok(msg) = rx.recv_timeout(Duration::from_secs(5)) {
+                    assert!(matches!(msg, ListenerMsg::User(_)));
+                    received += 1;
+                } else {
+                    break;
+                }
+            }
+            received
+        });
+        assert!(worker.poll().is_ok());
+        assert_eq!(consumer.join().unwrap(), 8);
+    }
+
+    #[test]
+    fn worker_should_not_block_on_full_queue_when_stopped() {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(false));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(10),
+                16,
+            )],
+            tx,
+            paused,
+            running,
+            None,
+        )
+        .overflow_policy(QueueOverflowPolicy::Block);
+        assert!(worker.poll().is_ok());
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn worker_should_emit_events_of_blocking_ports_as_soon_as_read() {
+        let (sender, events) = mpsc::channel();
+        let dropped = Arc::new(AtomicBool::new(false));
+        // NOTE: the interval is much longer than the time the event takes to be emitted
+        let mut listener = EventListenerCfg::default()
+            .port(
+                Port::new(
+                    Box::new(MockBlockingPoll {
+                        events,
+                        timeout: Duration::from_millis(50),
+                        dropped: Arc::clone(&dropped),
+                    }),
+                    Duration::from_secs(10),
+                    16,
+                )
+                .blocking(),
+            )
+            .start();
+        thread::sleep(Duration::from_millis(100));
+        let sent = Instant::now();
+        sender.send(MockEvent::Foo).unwrap();
+        assert_eq!(
+            listener.poll_for(Duration::from_secs(5)).unwrap(),
+            Some(Event::User(MockEvent::Foo))
+        );
+        assert!(sent.elapsed() < Duration::from_secs(1));
+        // the reader stops within the poll timeout and wakes up the worker
+        let stopping = Instant::now();
+        assert!(listener.stop().is_ok());
+        assert!(stopping.elapsed() < Duration::from_secs(1));
+        assert!(dropped.load(std::sync::atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    fn worker_should_poll_ports_with_the_hinted_intervals() {
+        let polls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let hints = [Duration::from_millis(20), Duration::from_millis(150)];
+        let mut listener = EventListenerCfg::default()
+            .add_port(
+                Box::new(MockHintPoll {
+                    hints: hints.to_vec(),
+                    polls: Arc::clone(&polls),
+                }),
+                Duration::from_millis(10),
+                1,
+            )
+            .start();
+        thread::sleep(Duration::from_millis(700));
+        assert!(listener.stop().is_ok());
+        let polls = polls.lock().unwrap();
+        assert!(polls.len() >= 5, "{} polls", polls.len());
+        // the interval after the n-th poll is the hint returned after it
+        for (n, gap) in polls.windows(2).map(|w| w[1] - w[0]).enumerate() {
+            let hint = hints[(n + 1) % hints.len()];
+            assert!(gap >= hint, "gap {n}: {gap:?} < {hint:?}");
+            if hint == hints[0] {
+                assert!(gap < hints[1], "gap {n}: {gap:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn worker_should_disable_panicking_ports_and_keep_polling_the_others() {
+        let mut listener = EventListenerCfg::default()
+            .add_port(
+                Box::new(MockPanicPoll::default()),
+                Duration::from_millis(5),
+                1,
+            )
+            .add_port(Box::new(MockPoll::default()), Duration::from_millis(5), 1)
+            .start();
+        let mut panicked = false;
+        let mut keys_after_panic = 0;
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while Instant::now() < deadline && keys_after_panic < 3 {
+            match listener.poll_for(Duration::from_millis(100)) {
+                Err(ListenerError::PortPanicked(0)) => panicked = true,
+                Ok(Some(Event::Keyboard(_))) if panicked => keys_after_panic += 1,
+                // the panicking port doesn't emit events anymore
+                Ok(Some(Event::User(_))) => assert!(!panicked),
+                Ok(_) => {}
+                Err(err) => panic!("unexpected error: {err}"),
+            }
+        }
+        assert!(panicked);
+        assert_eq!(keys_after_panic, 3);
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn worker_should_report_panics_of_blocking_ports() {
+        let mut listener = EventListenerCfg::default()
+            .add_port(Box::new(MockPoll::default()), Duration::from_millis(5), 1)
+            .add_port(
+                Box::new(MockPanicPoll::default()),
+                Duration::from_millis(5),
+                1,
+            )
+            .blocking_port()
+            .start();
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let mut panicked = false;
+        while Instant::now() < deadline && !panicked {
+            panicked = matches!(
+                listener.poll_for(Duration::from_millis(100)),
+                Err(ListenerError::PortPanicked(1))
+            );
+        }
+        assert!(panicked);
+        // the other ports keep working
+        assert!(matches!(
+            listener.poll_for(Duration::from_secs(1)),
+            Ok(Some(Event::Keyboard(_)))
+        ));
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn worker_should_not_emit_events_of_blocking_ports_while_paused() {
+        let (sender, events) = mpsc::channel();
+        let mut listener = EventListenerCfg::default()
+            .port(
+                Port::new(
+                    Box::new(MockBlockingPoll {
+                        events,
+                        timeout: Duration::from_millis(20),
+                        dropped: Arc::default(),
+                    }),
+                    Duration::from_secs(10),
+                    16,
+                )
+                .blocking(),
+            )
+            .start();
+        assert!(listener.pause().is_ok());
+        sender.send(MockEvent::Bar).unwrap();
+        assert_eq!(listener.poll_for(Duration::from_millis(200)).unwrap(), None);
+        assert!(listener.unpause().is_ok());
+        assert_eq!(
+            listener.poll_for(Duration::from_secs(5)).unwrap(),
+            Some(Event::User(MockEvent::Bar))
+        );
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn worker_should_debounce_equal_events() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![
+                Port::new(Box::new(MockUserPoll), Duration::ZERO, 100)
+                    .debounce(Duration::from_secs(60)),
+                Port::new(
+                    Box::new(MockScriptedPoll::new(vec![
+                        MockEvent::Foo,
+                        MockEvent::Foo,
+                        MockEvent::Bar,
+                        MockEvent::Foo,
+                        MockEvent::Bar,
+                        MockEvent::None,
+                    ])),
+                    Duration::ZERO,
+                    100,
+                )
+                .debounce(Duration::from_secs(60)),
+            ],
+            tx,
+            paused,
+            running,
+            None,
+        );
+        assert!(worker.poll().is_ok());
+        assert!(worker.poll().is_ok());
+        let recieved: Vec<Event<MockEvent>> = rx
+            .try_iter()
+            .map(|msg| ListenerResult::from(msg).ok().unwrap().unwrap())
+            .collect();
+        assert_eq!(
+            recieved,
+            vec![
+                Event::User(MockEvent::Foo),
+                Event::User(MockEvent::Foo),
+                Event::User(MockEvent::Bar),
+                Event::User(MockEvent::None),
+            ]
+        );
+    }
+
+    #[test]
+    fn worker_should_throttle_events_keeping_the_latest() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let events = (0..10).map(|i| MockEvent::Hello(i.to_string())).collect();
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![
+                Port::new(Box::new(MockScriptedPoll::new(events)), Duration::ZERO, 100)
+                    .throttle(Duration::from_millis(100)),
+            ],
+            tx,
+            paused,
+            running,
+            None,
+        );
+        assert!(worker.poll().is_ok());
+        assert!(worker.poll().is_ok());
+        assert_eq!(
+            ListenerResult::from(rx.try_recv().unwrap()).ok().unwrap(),
+            Some(Event::User(MockEvent::Hello(String::from("0"))))
+        );
+        assert!(rx.try_recv().is_err());
+        // the latest event is emitted once the window has expired
+        thread::sleep(Duration::from_millis(150));
+        assert!(worker.poll().is_ok());
+        assert_eq!(
+            ListenerResult::from(rx.try_recv().unwrap()).ok().unwrap(),
+            Some(Event::User(MockEvent::Hello(String::from("9"))))
+        );
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn worker_should_limit_the_rate_of_throttled_ports() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(Box::new(MockUserPoll), Duration::ZERO, 100)
+                .throttle(Duration::from_millis(50))],
+            tx,
+            paused,
+            running,
+            None,
+        );
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_millis(300) {
+            assert!(worker.poll().is_ok());
+            thread::sleep(Duration::from_millis(5));
+        }
+        // at most one event every 50ms
+        let emitted = rx.try_iter().count();
+        assert!((2..=7).contains(&emitted), "emitted {emitted} events");
+    }
+
+    #[test]
+    fn next_wakeup_should_wait_for_long_ports() {
+        let now = Instant::now();
+        let ports = [
+            PortSchedule {
+                next_poll: now + Duration::from_secs(10),
+            },
+            PortSchedule {
+                next_poll: now + Duration::from_secs(30),
+            },
+        ];
+        assert_eq!(
+            next_wakeup(now, &ports, None),
+            now + Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn next_wakeup_should_pick_the_earliest_port() {
+        let now = Instant::now();
+        let ports = [
+            PortSchedule {
+                next_poll: now + Duration::from_secs(10),
+            },
+            PortSchedule {
+                next_poll: now + Duration::from_millis(20),
+            },
+        ];
+        assert_eq!(
+            next_wakeup(now, &ports, None),
+            now + Duration::from_millis(20)
+        );
+        // a port which is already due
+        let ports = [
+            PortSchedule {
+                next_poll: now + Duration::from_secs(10),
+            },
+            PortSchedule { next_poll: now },
+        ];
+        assert_eq!(next_wakeup(now, &ports, None), now);
+    }
+
+    #[test]
+    fn next_wakeup_should_pick_tick_shorter_than_ports() {
+        let now = Instant::now();
+        let ports = [PortSchedule {
+            next_poll: now + Duration::from_secs(10),
+        }];
+        let tick = TickSchedule {
+            next_tick: now + Duration::from_millis(250),
+        };
+        assert_eq!(
+            next_wakeup(now, &ports, Some(tick)),
+            now + Duration::from_millis(250)
+        );
+        assert_eq!(
+            next_wakeup(now, &[], Some(tick)),
+            now + Duration::from_millis(250)
+        );
+    }
+
+    #[test]
+    fn next_wakeup_should_idle_without_schedule() {
+        let now = Instant::now();
+        assert_eq!(next_wakeup(now, &[], None), now + IDLE_SLEEP);
+    }
+
+    #[test]
+    fn until_next_boundary_should_align_to_interval_multiples() {
+        let second = Duration::from_secs(1);
+        assert_eq!(
+            until_next_boundary(Duration::from_millis(1_250), second),
+            Duration::from_millis(750)
+        );
+        assert_eq!(
+            until_next_boundary(Duration::from_millis(999), second),
+            Duration::from_millis(1)
+        );
+        // exactly on a boundary: the next one is a whole interval later
+        assert_eq!(until_next_boundary(Duration::from_secs(3), second), second);
+        assert_eq!(until_next_boundary(Duration::ZERO, second), second);
+        // a large time since the epoch
+        assert_eq!(
+            until_next_boundary(
+                Duration::new(1_700_000_000, 400_000_000),
+                Duration::from_millis(250)
+            ),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            until_next_boundary(Duration::from_secs(5), Duration::ZERO),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn interval_event_should_be_scheduled_from_the_previous_deadline() {
+        let start = Instant::now();
+        let every = Duration::from_millis(100);
+        let mut interval = IntervalEvent::new(MockEvent::Foo, every, start);
+        // checked late, every 7ms, over a simulated second
+        let emitted: usize = (0..=1001)
+            .step_by(7)
+            .map(|t| interval.due(start + Duration::from_millis(t), MissedIntervalPolicy::Skip))
+            .sum();
+        assert_eq!(emitted, 10);
+        // the schedule doesn't drift
+        assert_eq!(interval.next, start + every * 11);
+    }
+
+    #[test]
+    fn interval_event_should_skip_or_catch_up_missed_deadlines() {
+        let start = Instant::now();
+        let every = Duration::from_millis(100);
+        let mut skip = IntervalEvent::new(MockEvent::Foo, every, start);
+        let mut catch_up = IntervalEvent::new(MockEvent::Foo, every, start);
+        let now = start + Duration::from_millis(550);
+        assert_eq!(skip.due(now, MissedIntervalPolicy::Skip), 1);
+        assert_eq!(catch_up.due(now, MissedIntervalPolicy::CatchUp), 5);
+        assert_eq!(skip.next, start + every * 6);
+        assert_eq!(catch_up.next, start + every * 6);
+        assert_eq!(skip.due(now, MissedIntervalPolicy::Skip), 0);
+    }
+
+    /// Count the `Foo` events polled from `listener` for `period`
+    fn count_interval_events(listener: &EventListener<MockEvent>, period: Duration) -> usize {
+        let deadline = Instant::now() + period;
+        let mut count = 0;
+        while let Some(timeout) = deadline.checked_duration_since(Instant::now()) {
+            if let Ok(Some(Event::User(MockEvent::Foo))) = listener.poll_for(timeout) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn worker_should_emit_interval_events() {
+        let mut listener = EventListenerCfg::default()
+            .interval_event(MockEvent::Foo, Duration::from_millis(50))
+            .start();
+        let count = count_interval_events(&listener, Duration::from_millis(520));
+        assert!((9..=10).contains(&count), "{count} events");
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn worker_should_skip_interval_events_missed_while_paused() {
+        let mut listener = EventListenerCfg::default()
+            .interval_event(MockEvent::Foo, Duration::from_millis(100))
+            .start();
+        assert!(listener.pause().is_ok());
+        assert_eq!(
+            count_interval_events(&listener, Duration::from_millis(530)),
+            0
+        );
+        // the deadlines at 100..=500ms have been missed; the next one is at 600ms
+        assert!(listener.unpause().is_ok());
+        assert_eq!(
+            count_interval_events(&listener, Duration::from_millis(40)),
+            1
+        );
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn worker_should_catch_up_interval_events_missed_while_paused() {
+        let mut listener = EventListenerCfg::default()
+            .interval_event(MockEvent::Foo, Duration::from_millis(100))
+            .missed_interval_policy(MissedIntervalPolicy::CatchUp)
+            .start();
+        assert!(listener.pause().is_ok());
+        thread::sleep(Duration::from_millis(530));
+        assert!(listener.unpause().is_ok());
+        assert_eq!(
+            count_interval_events(&listener, Duration::from_millis(40)),
+            5
+        );
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn worker_should_align_ticks_to_listener_start() {
+        let (tx, _rx) = mpsc::sync_channel(1024);
+        let interval = Duration::from_millis(300);
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![],
+            tx,
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(AtomicBool::new(true)),
+            Some(interval),
+        )
+        .tick_alignment(TickAlignment::ListenerStart);
+        thread::sleep(Duration::from_millis(50));
+        worker.calc_next_tick();
+        let since_start = worker.next_tick.duration_since(worker.started);
+        assert_eq!(since_start, interval);
+        thread::sleep(Duration::from_millis(300));
+        worker.calc_next_tick();
+        let since_start = worker.next_tick.duration_since(worker.started);
+        assert_eq!(since_start.as_nanos() % interval.as_nanos(), 0);
+        assert!(since_start >= interval * 2);
+    }
+
+    #[test]
+    fn worker_should_sleep_fixed_time_while_paused() {
+        let (tx, _rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(true));
+        let running = Arc::new(AtomicBool::new(true));
+        let worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )],
+            tx,
+            paused,
+            running,
+            Some(Duration::from_millis(1)),
+        );
+        assert_eq!(worker.next_event(), PAUSED_SLEEP);
+    }
+
+    #[test]
+    fn worker_should_not_spin_while_paused() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(true));
+        let running = Arc::new(AtomicBool::new(true));
+        let iterations = Arc::new(AtomicUsize::new(0));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(1),
+                1,
+            )],
+            tx,
+            Arc::clone(&paused),
+            Arc::clone(&running),
+            Some(Duration::from_millis(1)),
+        )
+        .iterations(Arc::clone(&iterations));
+        let handle = thread::spawn(move || worker.run());
+        thread::sleep(Duration::from_millis(200));
+        running.store(false, std::sync::atomic::Ordering::Relaxed);
+        assert!(handle.join().is_ok());
+        // one iteration every `PAUSED_SLEEP`
+        let iterations = iterations.load(std::sync::atomic::Ordering::Relaxed);
+        assert!(iterations <= 12, "{iterations} iterations");
+        assert_eq!(rx.try_iter().count(), 0);
+    }
+
+    #[test]
+    fn worker_should_not_spin_with_long_ports() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let iterations = Arc::new(AtomicUsize::new(0));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockPoll::default()),
+                Duration::from_millis(500),
+                1,
+            )],
+            tx,
+            paused,
+            Arc::clone(&running),
+            None,
+        )
+        .iterations(Arc::clone(&iterations));
+        let handle = thread::spawn(move || worker.run());
+        thread::sleep(Duration::from_millis(200));
+        running.store(false, std::sync::atomic::Ordering::Relaxed);
+        // the worker polls the port once, then sleeps until the next poll
+        assert_eq!(iterations.load(std::sync::atomic::Ordering::Relaxed), 1);
+        assert!(handle.join().is_ok());
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn worker_should_not_wake_up_for_idle_blocking_ports() {
+        let (tx, rx) = mpsc::sync_channel(1024);
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+        let iterations = Arc::new(AtomicUsize::new(0));
+        let (sender, events) = mpsc::channel();
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(
+                Box::new(MockBlockingPoll {
+                    events,
+                    timeout: Duration::from_millis(20),
+                    dropped: Arc::default(),
+                }),
+                Duration::from_millis(1),
+                16,
+            )
+            .blocking()],
+            tx,
+            paused,
+            Arc::clone(&running),
+            None,
+        )
+        .iterations(Arc::clone(&iterations));
+        let handle = thread::spawn(move || worker.run());
+        thread::sleep(Duration::from_millis(200));
+        // the worker has been running once, then it's been waiting for the reader
+        assert_eq!(iterations.load(std::sync::atomic::Ordering::Relaxed), 1);
+        sender.send(MockEvent::Foo).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(iterations.load(std::sync::atomic::Ordering::Relaxed), 2);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(ListenerMsg::User(Event::User(MockEvent::Foo)))
+        ));
+        running.store(false, std::sync::atomic::Ordering::Relaxed);
+        assert!(handle.join().is_ok());
+    }
+
+    #[test]
+    fn reader_should_not_poll_while_the_worker_is_behind() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let running = Arc::new(AtomicBool::new(true));
+        let (results_tx, results) = mpsc::sync_channel(2);
+        let (wake_tx, _wake_rx) = mpsc::channel();
+        let reader = spawn_reader(
+            0,
+            Box::new(MockCountingPoll {
+                polls: Arc::clone(&polls),
+            }),
+            Duration::ZERO,
+            results_tx,
+            wake_tx,
+            Arc::new(AtomicBool::new(false)),
+            Arc::clone(&running),
+        );
+        thread::sleep(Duration::from_millis(100));
+        // two results are queued and the third one is waiting to be sent
+        assert_eq!(polls.load(std::sync::atomic::Ordering::Relaxed), 3);
+        assert!(results.try_recv().is_ok());
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(polls.load(std::sync::atomic::Ordering::Relaxed), 4);
+        running.store(false, std::sync::atomic::Ordering::Relaxed);
+        drop(results);
+        assert!(reader.join().is_ok());
+    }
+
+    #[test]
+    fn worker_should_stop_readers_blocked_on_a_full_channel() {
+        let (tx, _rx) = mpsc::sync_channel(1);
+        let running = Arc::new(AtomicBool::new(true));
+        let mut worker = EventListenerWorker::<MockEvent>::new(
+            vec![Port::new(Box::new(MockUserPoll), Duration::from_millis(1), 2).blocking()],
+            tx,
+            Arc::new(AtomicBool::new(false)),
+            Arc::clone(&running),
+            None,
+        );
+        let handle = thread::spawn(move || worker.run());
+        thread::sleep(Duration::from_millis(100));
+        running.store(false, std::sync::atomic::Ordering::Relaxed);
+        assert!(handle.join().is_ok());
+    }
+
+    /// Poll which always returns a user event
+    struct MockUserPoll;
+
+    impl Poll<MockEvent> for MockUserPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            Ok(Some(Event::User(MockEvent::Foo)))
+        }
+    }
+
+    /// Poll which always returns a user event and counts how many times it's been polled
+    struct MockCountingPoll {
+        polls: Arc<AtomicUsize>,
+    }
+
+    impl Poll<MockEvent> for MockCountingPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            self.polls
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(Some(Event::User(MockEvent::Foo)))
+        }
+    }
+
+    /// Poll which returns a press, a repeat and a release event for the same key
+    struct MockKeyKindsPoll {
+        events: Vec<KeyEventKind>,
+    }
+
+    impl Default for MockKeyKindsPoll {
+        fn default() -> Self {
+            Self {
+                events: vec![
+                    KeyEventKind::Release,
+                    KeyEventKind::Repeat,
+                    KeyEventKind::Press,
+                ],
+            }
+        }
+    }
+
+    impl Poll<MockEvent> for MockKeyKindsPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            Ok(self
+                .events
+                .pop()
+                .map(|kind| Event::Keyboard(KeyEvent::from(Key::Char('a')).with_kind(kind))))
+        }
+    }
+
+    /// Poll which returns the provided user events, then nothing
+    struct MockScriptedPoll {
+        events: Vec<MockEvent>,
+    }
+
+    impl MockScriptedPoll {
+        fn new(mut events: Vec<MockEvent>) -> Self {
+            events.reverse();
+            Self { events }
+        }
+    }
+
+    impl Poll<MockEvent> for MockScriptedPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            Ok(self.events.pop().map(Event::User))
+        }
+    }
+
+    /// Poll which returns a user event, and panics on the third poll
+    #[derive(Default)]
+    struct MockPanicPoll {
+        polls: usize,
+    }
+
+    impl Poll<MockEvent> for MockPanicPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            self.polls += 1;
+            if self.polls == 3 {
+                panic!("poll failed badly");
+            }
+            Ok(Some(Event::User(MockEvent::Foo)))
+        }
+    }
+
+    /// Poll which records the time of each poll and hints the intervals in `hints` in turn
+    struct MockHintPoll {
+        hints: Vec<Duration>,
+        polls: Arc<std::sync::Mutex<Vec<Instant>>>,
+    }
+
+    impl Poll<MockEvent> for MockHintPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            self.polls.lock().unwrap().push(Instant::now());
+            Ok(None)
+        }
+
+        fn interval_hint(&self) -> Option<Duration> {
+            let polls = self.polls.lock().unwrap().len();
+            Some(self.hints[polls % self.hints.len()])
+        }
+    }
+
+    /// Poll which waits up to `timeout` for the events sent to it; sets `dropped` when dropped
+    struct MockBlockingPoll {
+        events: mpsc::Receiver<MockEvent>,
+        timeout: Duration,
+        dropped: Arc<AtomicBool>,
+    }
+
+    impl Poll<MockEvent> for MockBlockingPoll {
+        fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+            match self.events.recv_timeout(self.timeout) {
+                Ok(ev) => Ok(Some(Event::User(ev))),
+                Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    thread::sleep(self.timeout);
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    impl Drop for MockBlockingPoll {
+        fn drop(&mut self) {
+            self.dropped
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
 }
@@ -141,3 +141,386 @@ macro_rules! subclause_or {
         )
     };
 }
+
+/// A macro to implement [`crate::MockComponent`] for a wrapper component, forwarding all the methods
+/// to the inner component stored in the provided field.
+///
+/// Unlike `#[derive(MockComponent)]`, the inner field can have any name and methods can be overridden:
+/// pass the overriding methods in a block and all the other ones will still be forwarded to the inner component.
+///
+/// ### example
+///
+/// ```rust
+/// use tuirealm::command::{Cmd, CmdResult};
+/// use tuirealm::ratatui::layout::Rect;
+/// use tuirealm::{delegate_mock_component, AttrValue, Attribute, Frame, MockComponent, Props, State};
+///
+/// #[derive(Default)]
+/// struct Label {
+///     props: Props,
+/// }
+///
+/// # impl MockComponent for Label {
+/// #     fn view(&mut self, _frame: &mut Frame, _area: Rect) {}
+/// #     fn query(&self, attr: Attribute) -> Option<AttrValue> {
+/// #         self.props.get(attr)
+/// #     }
+/// #     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+/// #         self.props.set(attr, value);
+/// #     }
+/// #     fn state(&self) -> State {
+/// #         State::None
+/// #     }
+/// #     fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+/// #         CmdResult::None
+/// #     }
+/// # }
+/// #
+/// /// Forwards everything to the label
+/// #[derive(Default)]
+/// struct Title {
+///     label: Label,
+/// }
+///
+/// delegate_mock_component!(Title, label);
+///
+/// /// Forwards everything to the label, but the text is always uppercase
+/// #[derive(Default)]
+/// struct UpperTitle {
+///     title: Title,
+/// }
+///
+/// delegate_mock_component!(UpperTitle, title, {
+///     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+///         match (attr, value) {
+///             (Attribute::Text, AttrValue::String(text)) => self
+///                 .title
+///                 .attr(Attribute::Text, AttrValue::String(text.to_uppercase())),
+///             (attr, value) => self.title.attr(attr, value),
+///         }
+///     }
+/// });
+///
+/// let mut title = UpperTitle::default();
+/// title.attr(Attribute::Text, AttrValue::String(String::from("omar")));
+/// assert_eq!(
+///     title.query(Attribute::Text),
+///     Some(AttrValue::String(String::from("OMAR")))
+/// );
+/// assert_eq!(title.state(), State::None);
+/// ```
+///
+#[macro_export]
+macro_rules! delegate_mock_component {
+    ($name:ty, $field:ident) => {
+        $crate::delegate_mock_component!($name, $field, {});
+    };
+    ($name:ty, $field:ident, { $($overrides:tt)* }) => {
+        impl $crate::MockComponent for $name {
+            $($overrides)*
+            $crate::delegate_mock_component!(@view $field, $($overrides)*);
+            $crate::delegate_mock_component!(@query $field, $($overrides)*);
+            $crate::delegate_mock_component!(@attr $field, $($overrides)*);
+            $crate::delegate_mock_component!(@state $field, $($overrides)*);
+            $crate::delegate_mock_component!(@perform $field, $($overrides)*);
+            $crate::delegate_mock_component!(@perform_custom $field, $($overrides)*);
+            $crate::delegate_mock_component!(@cursor $field, $($overrides)*);
+            $crate::delegate_mock_component!(@restore $field, $($overrides)*);
+        }
+    };
+    // -- view
+    (@view $field:ident, fn view $($rest:tt)*) => {};
+    (@view $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@view $field, $($rest)*);
+    };
+    (@view $field:ident, ) => {
+        fn view(&mut self, frame: &mut $crate::Frame, area: $crate::ratatui::layout::Rect) {
+            self.$field.view(frame, area);
+        }
+    };
+    // -- query
+    (@query $field:ident, fn query $($rest:tt)*) => {};
+    (@query $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@query $field, $($rest)*);
+    };
+    (@query $field:ident, ) => {
+        fn query(&self, attr: $crate::Attribute) -> Option<$crate::AttrValue> {
+            self.$field.query(attr)
+        }
+    };
+    // -- attr
+    (@attr $field:ident, fn attr $($rest:tt)*) => {};
+    (@attr $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@attr $field, $($rest)*);
+    };
+    (@attr $field:ident, ) => {
+        fn attr(&mut self, attr: $crate::Attribute, value: $crate::AttrValue) {
+            self.$field.attr(attr, value)
+        }
+    };
+    // -- state
+    (@state $field:ident, fn state $($rest:tt)*) => {};
+    (@state $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@state $field, $($rest)*);
+    };
+    (@state $field:ident, ) => {
+        fn state(&self) -> $crate::State {
+            self.$field.state()
+        }
+    };
+    // -- perform
+    (@perform $field:ident, fn perform $($rest:tt)*) => {};
+    (@perform $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@perform $field, $($rest)*);
+    };
+    (@perform $field:ident, ) => {
+        fn perform(&mut self, cmd: $crate::command::Cmd) -> $crate::command::CmdResult {
+            self.$field.perform(cmd)
+        }
+    };
+    // -- perform_custom
+    (@perform_custom $field:ident, fn perform_custom $($rest:tt)*) => {};
+    (@perform_custom $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@perform_custom $field, $($rest)*);
+    };
+    (@perform_custom $field:ident, ) => {
+        fn perform_custom(
+            &mut self,
+            name: &'static str,
+            payload: $crate::props::PropPayload,
+        ) -> $crate::command::CmdResult {
+            self.$field.perform_custom(name, payload)
+        }
+    };
+    // -- cursor
+    (@cursor $field:ident, fn cursor $($rest:tt)*) => {};
+    (@cursor $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@cursor $field, $($rest)*);
+    };
+    (@cursor $field:ident, ) => {
+        fn cursor(&self, area: $crate::ratatui::layout::Rect) -> Option<(u16, u16)> {
+            self.$field.cursor(area)
+        }
+    };
+    // -- restore
+    (@restore $field:ident, fn restore $($rest:tt)*) => {};
+    (@restore $field:ident, $head:tt $($rest:tt)*) => {
+        $crate::delegate_mock_component!(@restore $field, $($rest)*);
+    };
+    (@restore $field:ident, ) => {
+        fn restore(&mut self, state: $crate::State) {
+            self.$field.restore(state)
+        }
+    };
+}
+
+/// A macro to mount many components into an [`crate::Application`] at once.
+///
+/// Each entry is terminated by `;` and has the syntax `id => component` or `id => component, subs: [sub, ...]`;
+/// components are boxed by the macro.
+/// The macro evaluates to [`crate::application::ApplicationResult<()>`]: components are mounted in order and
+/// the first error is returned, so the following components are not mounted.
+///
+/// ### example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// use tuirealm::command::{Cmd, CmdResult};
+/// use tuirealm::listener::{ListenerResult, Poll};
+/// use tuirealm::ratatui::layout::Rect;
+/// use tuirealm::{
+///     attr_many, mount_all, Application, AttrValue, Attribute, Component, Event, EventListenerCfg,
+///     Frame, MockComponent, NoUserEvent, Props, State, Sub, SubClause, SubEventClause,
+/// };
+///
+/// #[derive(Debug, Eq, PartialEq, Clone, Hash)]
+/// pub enum Id {
+///     Clock,
+///     Label,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum Msg {}
+///
+/// #[derive(Default)]
+/// struct Label {
+///     props: Props,
+/// }
+///
+/// # impl MockComponent for Label {
+/// #     fn view(&mut self, _frame: &mut Frame, _area: Rect) {}
+/// #     fn query(&self, attr: Attribute) -> Option<AttrValue> {
+/// #         self.props.get(attr)
+/// #     }
+/// #     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+/// #         self.props.set(attr, value);
+/// #     }
+/// #     fn state(&self) -> State {
+/// #         State::None
+/// #     }
+/// #     fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+/// #         CmdResult::None
+/// #     }
+/// # }
+/// #
+/// impl Component<Msg, NoUserEvent> for Label {
+///     fn on(&mut self, _ev: Event<NoUserEvent>) -> Option<Msg> {
+///         None
+///     }
+/// }
+/// #
+/// # struct IdlePoll;
+/// #
+/// # impl Poll<NoUserEvent> for IdlePoll {
+/// #     fn poll(&mut self) -> ListenerResult<Option<Event<NoUserEvent>>> {
+/// #         Ok(None)
+/// #     }
+/// # }
+///
+/// let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+///     EventListenerCfg::default()
+/// #       .add_port(Box::new(IdlePoll), Duration::from_millis(10), 1)
+/// );
+/// mount_all!(app, {
+///     Id::Clock => Label::default(), subs: [Sub::new(SubEventClause::Tick, SubClause::Always)];
+///     Id::Label => Label::default();
+/// })
+/// .expect("failed to mount components");
+/// assert!(app.mounted(&Id::Clock));
+/// assert!(app.mounted(&Id::Label));
+///
+/// // mounting an already mounted component fails
+/// assert!(mount_all!(app, { Id::Label => Label::default(); }).is_err());
+///
+/// attr_many!(app, Id::Label, {
+///     Attribute::Text => AttrValue::String(String::from("omar")),
+///     Attribute::Display => AttrValue::Flag(true),
+/// })
+/// .expect("failed to set attributes");
+/// assert_eq!(
+///     app.query(&Id::Label, Attribute::Text).unwrap(),
+///     Some(AttrValue::String(String::from("omar")))
+/// );
+/// ```
+///
+/// Components must implement [`crate::Component`]:
+///
+/// ```compile_fail
+/// use tuirealm::{mount_all, Application, EventListenerCfg, NoUserEvent};
+///
+/// let mut app: Application<u8, (), NoUserEvent> = Application::init(EventListenerCfg::default());
+/// // String is not a component
+/// mount_all!(app, { 0 => String::new(); }).unwrap();
+/// ```
+///
+#[macro_export]
+macro_rules! mount_all {
+    ($app:expr, { $($id:expr => $component:expr $(, subs: [$($sub:expr),* $(,)?])?);+ $(;)? }) => {
+        (|| -> $crate::application::ApplicationResult<()> {
+            $(
+                $app.mount(
+                    $id,
+                    ::std::boxed::Box::new($component),
+                    ::std::vec![$($($sub),*)?],
+                )?;
+            )+
+            Ok(())
+        })()
+    };
+}
+
+/// A macro to set many attributes on the component `id` of an [`crate::Application`] at once.
+///
+/// The macro evaluates to [`crate::application::ApplicationResult<()>`]: attributes are set in order and
+/// the first error is returned.
+///
+/// See [`mount_all!`] for an example.
+#[macro_export]
+macro_rules! attr_many {
+    ($app:expr, $id:expr, { $($attr:expr => $value:expr),+ $(,)? }) => {
+        (|| -> $crate::application::ApplicationResult<()> {
+            let id = &$id;
+            $(
+                $app.attr(id, $attr, $value)?;
+            )+
+            Ok(())
+        })()
+    };
+}
+
+/// A macro to build a [`crate::event::KeyEvent`] of kind [`crate::event::KeyEventKind::Press`].
+///
+/// Modifiers (`ctrl`, `alt`, `shift`, `super`, `hyper`, `meta`) are prepended to the key with `+`; the key can be either a char literal
+/// or a [`crate::event::Key`].
+/// The macro can be used as a pattern too, if at most one modifier is set.
+///
+/// ### example
+///
+/// ```rust
+/// use tuirealm::event::{Key, KeyEvent, KeyModifiers};
+/// use tuirealm::{key, Event, NoUserEvent};
+///
+/// assert_eq!(key!(ctrl + 'q'), KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL));
+/// assert_eq!(key!(super + 'k'), KeyEvent::new(Key::Char('k'), KeyModifiers::SUPER));
+/// assert_eq!(
+///     key!(ctrl + shift + Key::Left),
+///     KeyEvent::new(Key::Left, KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+/// );
+///
+/// let ev: Event<NoUserEvent> = Event::Keyboard(KeyEvent::from(Key::Char('a')));
+/// match ev {
+///     Event::Keyboard(key!(Key::Esc)) => unreachable!(),
+///     Event::Keyboard(key!(Key::Char(ch))) => assert_eq!(ch, 'a'),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! key {
+    (@mods [$($mods:ident)*] ctrl + $($rest:tt)+) => {
+        $crate::key!(@mods [$($mods)* CONTROL] $($rest)+)
+    };
+    (@mods [$($mods:ident)*] alt + $($rest:tt)+) => {
+        $crate::key!(@mods [$($mods)* ALT] $($rest)+)
+    };
+    (@mods [$($mods:ident)*] shift + $($rest:tt)+) => {
+        $crate::key!(@mods [$($mods)* SHIFT] $($rest)+)
+    };
+    (@mods [$($mods:ident)*] super + $($rest:tt)+) => {
+        $crate::key!(@mods [$($mods)* SUPER] $($rest)+)
+    };
+    (@mods [$($mods:ident)*] hyper + $($rest:tt)+) => {
+        $crate::key!(@mods [$($mods)* HYPER] $($rest)+)
+    };
+    (@mods [$($mods:ident)*] meta + $($rest:tt)+) => {
+        $crate::key!(@mods [$($mods)* META] $($rest)+)
+    };
+    (@mods [$($mods:ident)*] $ch:literal) => {
+        $crate::key!(@key [$($mods)*] $crate::event::Key::Char($ch))
+    };
+    (@mods [$($mods:ident)*] $($key:tt)+) => {
+        $crate::key!(@key [$($mods)*] $($key)+)
+    };
+    (@key [] $($key:tt)+) => {
+        $crate::key!(@event [$crate::event::KeyModifiers::NONE] $($key)+)
+    };
+    (@key [$mod:ident] $($key:tt)+) => {
+        $crate::key!(@event [$crate::event::KeyModifiers::$mod] $($key)+)
+    };
+    (@key [$mod:ident $($more:ident)+] $($key:tt)+) => {
+        $crate::key!(
+            @event [$crate::event::KeyModifiers::$mod$(.union($crate::event::KeyModifiers::$more))+]
+            $($key)+
+        )
+    };
+    (@event [$($mods:tt)+] $($key:tt)+) => {
+        $crate::event::KeyEvent {
+            code: $($key)+,
+            modifiers: $($mods)+,
+            kind: $crate::event::KeyEventKind::Press,
+        }
+    };
+    ($($rest:tt)+) => {
+        $crate::key!(@mods [] $($rest)+)
+    };
+}
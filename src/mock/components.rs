@@ -5,10 +5,14 @@
 use ratatui::Frame;
 
 use super::{MockEvent, MockMsg};
-use crate::command::{Cmd, CmdResult, Direction};
+use crate::command::{Cmd, CmdResult, Direction, Position};
 use crate::event::{Event, Key, KeyEvent, KeyModifiers};
+use crate::props::{PropPayload, PropValue};
 use crate::{AttrValue, Attribute, Component, MockComponent, Props, State, StateValue};
 
+/// Custom command which sets the text of [`MockInput`]; the payload is the text
+pub const MOCK_CMD_SET_TEXT: &str = "set-text";
+
 /// Mocked component implementing `MockComponent`
 pub struct MockInput {
     props: Props,
@@ -41,6 +45,7 @@ impl MockComponent for MockInput {
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
         match cmd {
+            Cmd::Move(Direction::Left) if self.states.cursor == 0 => CmdResult::None,
             Cmd::Move(Direction::Left) => {
                 self.states.left();
                 CmdResult::Changed(State::One(StateValue::Usize(self.states.cursor)))
@@ -50,12 +55,44 @@ impl MockComponent for MockInput {
                 CmdResult::Changed(State::One(StateValue::Usize(self.states.cursor)))
             }
             Cmd::Type(ch) => {
+                if let Some(AttrValue::InputType(input_type)) = self.props.get(Attribute::InputType)
+                {
+                    if !input_type.char_valid(&self.states.text, ch) {
+                        return CmdResult::Invalid(cmd);
+                    }
+                }
                 self.states.input(ch);
                 CmdResult::Changed(self.state())
             }
+            Cmd::GoTo(position) => {
+                self.states.go_to(position);
+                CmdResult::Changed(State::One(StateValue::Usize(self.states.cursor)))
+            }
+            _ => CmdResult::None,
+        }
+    }
+
+    fn perform_custom(&mut self, name: &'static str, payload: PropPayload) -> CmdResult {
+        match (name, payload) {
+            (MOCK_CMD_SET_TEXT, PropPayload::One(PropValue::Str(text))) => {
+                self.restore(State::One(StateValue::String(text)));
+                CmdResult::Custom(MOCK_CMD_SET_TEXT, self.state())
+            }
             _ => CmdResult::None,
         }
     }
+
+    fn cursor(&self, area: crate::ratatui::layout::Rect) -> Option<(u16, u16)> {
+        let column = crate::utils::text::cursor_column(&self.states.text, self.states.cursor);
+        Some((area.x + column as u16, area.y))
+    }
+
+    fn restore(&mut self, state: State) {
+        if let State::One(StateValue::String(text)) = state {
+            self.states.cursor = text.chars().count();
+            self.states.text = text;
+        }
+    }
 }
 
 // -- component states
@@ -88,22 +125,24 @@ impl MockInputStates {
     fn right(&mut self) {
         self.cursor += 1;
     }
+
+    fn go_to(&mut self, position: Position) {
+        self.cursor = match position {
+            Position::Begin => 0,
+            Position::End => self.text.chars().count(),
+            Position::At(at) => at.min(self.text.chars().count()),
+        };
+    }
 }
 
 // -- component impl
 
-#[derive(MockComponent)]
+#[derive(Default)]
 pub struct MockFooInput {
     component: MockInput,
 }
 
-impl Default for MockFooInput {
-    fn default() -> Self {
-        Self {
-            component: MockInput::default(),
-        }
-    }
-}
+crate::delegate_mock_component!(MockFooInput, component);
 
 impl Component<MockMsg, MockEvent> for MockFooInput {
     fn on(&mut self, ev: Event<MockEvent>) -> Option<MockMsg> {
@@ -111,22 +150,26 @@ impl Component<MockMsg, MockEvent> for MockFooInput {
             Event::Keyboard(KeyEvent {
                 code: Key::Left,
                 modifiers: _,
+                ..
             }) => Cmd::Move(Direction::Left),
             Event::Keyboard(KeyEvent {
                 code: Key::Right,
                 modifiers: _,
+                ..
             }) => Cmd::Move(Direction::Right),
             Event::Keyboard(KeyEvent {
                 code: Key::Char(ch),
                 modifiers: KeyModifiers::NONE,
+                ..
             }) => Cmd::Type(ch),
             Event::Keyboard(KeyEvent {
                 code: Key::Enter,
                 modifiers: KeyModifiers::NONE,
+                ..
             }) => return Some(MockMsg::FooSubmit(self.component.states.text.clone())),
             _ => Cmd::None,
         };
-        match self.component.perform(cmd) {
+        match self.component.perform_diff(cmd) {
             CmdResult::Changed(State::One(StateValue::String(s))) => {
                 Some(MockMsg::FooInputChanged(s))
             }
@@ -135,42 +178,40 @@ impl Component<MockMsg, MockEvent> for MockFooInput {
     }
 }
 
-#[derive(MockComponent)]
+#[derive(Default, MockComponent)]
 pub struct MockBarInput {
     component: MockInput,
 }
 
-impl Default for MockBarInput {
-    fn default() -> Self {
-        Self {
-            component: MockInput::default(),
-        }
-    }
-}
-
 impl Component<MockMsg, MockEvent> for MockBarInput {
     fn on(&mut self, ev: Event<MockEvent>) -> Option<MockMsg> {
         let cmd = match ev {
             Event::Keyboard(KeyEvent {
                 code: Key::Left,
                 modifiers: _,
+                ..
             }) => Cmd::Move(Direction::Left),
             Event::Keyboard(KeyEvent {
                 code: Key::Right,
                 modifiers: _,
+                ..
             }) => Cmd::Move(Direction::Right),
             Event::Keyboard(KeyEvent {
                 code: Key::Char(ch),
                 modifiers: KeyModifiers::NONE,
+                ..
             }) => Cmd::Type(ch),
             Event::Keyboard(KeyEvent {
                 code: Key::Enter,
                 modifiers: KeyModifiers::NONE,
+                ..
             }) => return Some(MockMsg::BarSubmit(self.component.states.text.clone())),
-            Event::Tick => return Some(MockMsg::BarTick),
+            Event::Tick(_) => return Some(MockMsg::BarTick),
+            Event::User(MockEvent::Hello(name)) => return Some(MockMsg::BarHello(name)),
+            Event::Timer(id) => return Some(MockMsg::BarTimer(id)),
             _ => Cmd::None,
         };
-        match self.component.perform(cmd) {
+        match self.component.perform_diff(cmd) {
             CmdResult::Changed(State::One(StateValue::String(s))) => {
                 Some(MockMsg::BarInputChanged(s))
             }
@@ -178,3 +219,58 @@ impl Component<MockMsg, MockEvent> for MockBarInput {
         }
     }
 }
+
+/// Mocked list component; `Up` and `Down` move the selection, which is returned in [`MockMsg::ListSelected`]
+#[derive(Default)]
+pub struct MockList {
+    props: Props,
+    selected: usize,
+}
+
+impl MockComponent for MockList {
+    fn view(&mut self, _: &mut Frame, _: crate::ratatui::layout::Rect) {}
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, query: Attribute, attr: AttrValue) {
+        self.props.set(query, attr);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.selected))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Move(Direction::Up) if self.selected > 0 => self.selected -= 1,
+            Cmd::Move(Direction::Down) => self.selected += 1,
+            _ => return CmdResult::None,
+        }
+        CmdResult::Changed(self.state())
+    }
+}
+
+impl Component<MockMsg, MockEvent> for MockList {
+    fn on(&mut self, ev: Event<MockEvent>) -> Option<MockMsg> {
+        let cmd = match ev {
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => Cmd::Move(Direction::Up),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => Cmd::Move(Direction::Down),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                modifiers: KeyModifiers::NONE,
+                ..
+            }) => return Some(MockMsg::ListKey(ch)),
+            _ => Cmd::None,
+        };
+        match self.perform(cmd) {
+            CmdResult::Changed(State::One(StateValue::Usize(selected))) => {
+                Some(MockMsg::ListSelected(selected))
+            }
+            _ => None,
+        }
+    }
+}
@@ -4,18 +4,19 @@
 
 use std::marker::PhantomData;
 
-use crate::event::{Event, Key, KeyEvent};
-use crate::listener::{ListenerResult, Poll};
+use crate::event::{Event, Key, KeyEvent, TimerId};
+use crate::listener::{ListenerError, ListenerResult, Poll};
 use crate::{AttrValue, Attribute, Injector};
 
 // -- modules
 mod components;
-pub use components::{MockBarInput, MockFooInput, MockInput};
+pub use components::{MockBarInput, MockFooInput, MockInput, MockList, MOCK_CMD_SET_TEXT};
 
 // -- event
 
 /// Mock UserEvent type
 #[derive(Debug, Eq, PartialEq, Clone, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
 pub enum MockEvent {
     None,
     Foo,
@@ -25,6 +26,7 @@ pub enum MockEvent {
 
 /// Mock component id type
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
 pub enum MockComponentId {
     InputBar,
     InputFoo,
@@ -51,6 +53,63 @@ impl<U: Eq + PartialEq + Clone + PartialOrd + Send + 'static> Poll<U> for MockPo
     }
 }
 
+/// Mock poll implementation which never returns events
+#[derive(Default)]
+pub struct MockIdlePoll;
+
+impl<U: Eq + PartialEq + Clone + PartialOrd + Send + 'static> Poll<U> for MockIdlePoll {
+    fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
+        Ok(None)
+    }
+}
+
+/// Mock poll implementation which panics when its interval hint is read after the first poll.
+/// Since only the panics of the poll are caught, this kills the event listener worker
+#[derive(Default)]
+pub struct MockDyingPoll;
+
+impl<U: Eq + PartialEq + Clone + PartialOrd + Send + 'static> Poll<U> for MockDyingPoll {
+    fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
+        Ok(None)
+    }
+
+    fn interval_hint(&self) -> Option<std::time::Duration> {
+        panic!("mock poll panicked")
+    }
+}
+
+/// Mock poll implementation which alternates an event and an error
+#[derive(Default)]
+pub struct MockFlakyPoll {
+    fail: bool,
+}
+
+impl<U: Eq + PartialEq + Clone + PartialOrd + Send + 'static> Poll<U> for MockFlakyPoll {
+    fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
+        self.fail = !self.fail;
+        if self.fail {
+            Ok(Some(Event::Keyboard(KeyEvent::from(Key::Enter))))
+        } else {
+            Err(ListenerError::PollFailed)
+        }
+    }
+}
+
+/// Mock poll implementation which floods the listener with numbered [`MockEvent::Hello`] events
+#[derive(Default)]
+pub struct MockFloodPoll {
+    next: usize,
+}
+
+impl Poll<MockEvent> for MockFloodPoll {
+    fn poll(&mut self) -> ListenerResult<Option<Event<MockEvent>>> {
+        self.next += 1;
+        Ok(Some(Event::User(MockEvent::Hello(
+            (self.next - 1).to_string(),
+        ))))
+    }
+}
+
 // -- msg
 
 /// Mocked Msg for components and view
@@ -61,6 +120,10 @@ pub enum MockMsg {
     BarInputChanged(String),
     BarSubmit(String),
     BarTick,
+    BarHello(String),
+    BarTimer(TimerId),
+    ListSelected(usize),
+    ListKey(char),
 }
 
 // -- injector
@@ -1,5 +1,9 @@
 //! ## ratatui
 //!
-//! `ratatui` just exposes the ratatui modules, in order to include the entire library inside realm
+//! `ratatui` just exposes the ratatui modules, in order to include the entire library inside realm.
+//!
+//! This is the supported way to access ratatui from applications and component libraries: using the types
+//! re-exported here guarantees they are the same types tui-realm uses, without depending on the same version of
+//! ratatui. See also [`crate::ratatui_prelude`] for the commonly used types.
 
 pub use ratatui::*;
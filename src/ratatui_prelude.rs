@@ -0,0 +1,14 @@
+//! ## ratatui prelude
+//!
+//! `ratatui_prelude` exposes the ratatui types which are commonly needed to implement components, so that
+//! they can be imported at once with `use tuirealm::ratatui_prelude::*;`.
+//!
+//! The types not listed here are available under [`crate::ratatui`], which re-exports the whole library.
+
+pub use ratatui::backend::TestBackend;
+pub use ratatui::prelude::*;
+pub use ratatui::widgets::canvas::{self, Canvas};
+pub use ratatui::widgets::{
+    self, Block, Cell, Clear, Gauge, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+    Tabs, Wrap,
+};
@@ -3,7 +3,12 @@
 //! Cross platform Terminal helper
 
 mod adapter;
+mod builder;
 mod event_listener;
+mod string;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once};
 
 use ratatui::{CompletedFrame, Frame};
 use thiserror::Error;
@@ -11,16 +16,23 @@ use thiserror::Error;
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 pub use self::adapter::CrosstermTerminalAdapter;
-pub use self::adapter::TerminalAdapter;
 #[cfg(feature = "termion")]
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
 pub use self::adapter::TermionTerminalAdapter;
+pub use self::adapter::{RestoreFn, TerminalAdapter};
+pub use self::adapter::{TestTerminalAdapter, TestTerminalModes};
+pub use self::builder::TerminalBridgeBuilder;
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
-pub use self::event_listener::CrosstermInputListener;
+pub use self::event_listener::{
+    CrosstermEventSource, CrosstermInputListener, CrosstermRawHook, CrosstermTerminalEventSource,
+};
 #[cfg(feature = "termion")]
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
-pub use self::event_listener::TermionInputListener;
+pub use self::event_listener::{TermionInputListener, TermionRawHook};
+pub use self::string::{
+    buffer_to_ansi_string, buffer_to_string, render_to_string, StringBackendBridge,
+};
 
 /// TerminalResult is a type alias for a Result that uses [`TerminalError`] as the error type.
 pub type TerminalResult<T> = Result<T, TerminalError>;
@@ -43,6 +55,16 @@ pub enum TerminalError {
     Unsupported,
     #[error("cannot activate / deactivate mouse capture")]
     CannotToggleMouseCapture,
+    #[error("cannot get terminal size")]
+    CannotGetSize,
+    #[error("cannot get cursor position")]
+    CannotGetCursorPosition,
+    #[error("cannot show / hide cursor")]
+    CannotToggleCursor,
+    #[error("cannot set terminal title")]
+    CannotSetTitle,
+    #[error("cannot enable / disable focus change reporting")]
+    CannotToggleFocusChange,
 }
 
 /// An helper around [`crate::ratatui::Terminal`] to quickly setup and perform on terminal.
@@ -50,7 +72,8 @@ pub enum TerminalError {
 /// Anyway this structure is 100% cross-backend compatible and is really easy to use, so I suggest you to use it.
 /// If you need more advance terminal command, you can get a reference to it using the `raw()` and `raw_mut()` methods.
 ///
-/// To quickly setup a terminal with default settings, you can use the [`TerminalBridge::init()`] method.
+/// To quickly setup a terminal with default settings, you can use the [`TerminalBridge::init()`] method,
+/// while [`TerminalBridge::builder()`] allows to choose the terminal modes (e.g. to render inline, without the alternate screen).
 ///
 /// ```rust
 /// use tuirealm::terminal::TerminalBridge;
@@ -60,11 +83,30 @@ pub enum TerminalError {
 /// #[cfg(feature = "termion")]
 /// let mut terminal = TerminalBridge::init_termion().unwrap();
 /// ```
+///
+/// When the bridge is dropped, the terminal modes enabled through the bridge are restored on a best-effort basis
+/// (see [`TerminalBridge::install_panic_hook`]).
 pub struct TerminalBridge<T>
 where
     T: TerminalAdapter,
 {
     terminal: T,
+    /// Modes enabled through the bridge, which are disabled when the terminal is restored
+    enabled: EnabledModes,
+    /// Whether the terminal has already been restored by [`TerminalBridge::restore`], the panic hook or on drop
+    restored: Arc<AtomicBool>,
+    /// Guards the installation of the panic hook
+    panic_hook: Once,
+}
+
+/// The terminal modes enabled through a [`TerminalBridge`]
+#[derive(Debug, Default, Clone, Copy)]
+struct EnabledModes {
+    raw_mode: bool,
+    alternate_screen: bool,
+    mouse_capture: bool,
+    focus_change: bool,
+    cursor_hidden: bool,
 }
 
 impl<T> TerminalBridge<T>
@@ -73,7 +115,12 @@ where
 {
     /// Instantiates a new Terminal bridge from a [`TerminalAdapter`]
     pub fn new(terminal: T) -> Self {
-        Self { terminal }
+        Self {
+            terminal,
+            enabled: EnabledModes::default(),
+            restored: Arc::new(AtomicBool::new(false)),
+            panic_hook: Once::new(),
+        }
     }
 
     /// Initialize a terminal with reasonable defaults for most applications.
@@ -86,29 +133,94 @@ where
     ///   is called after any other panic hooks that may be installed to ensure that the terminal is
     ///   restored before those hooks are called.
     ///
-    /// For more control over the terminal initialization, use [`TerminalBridge::new`].
+    /// For more control over the terminal initialization, use [`TerminalBridge::builder`] or [`TerminalBridge::new`].
     pub fn init(terminal: T) -> TerminalResult<Self> {
         let mut terminal = Self::new(terminal);
         terminal.enable_raw_mode()?;
         terminal.enter_alternate_screen()?;
-        Self::set_panic_hook();
+        terminal.install_panic_hook();
 
         Ok(terminal)
     }
 
+    /// Returns a [`TerminalBridgeBuilder`], to choose which terminal modes are set up
+    /// (raw mode, alternate screen, mouse capture and viewport).
+    pub fn builder() -> TerminalBridgeBuilder<T> {
+        TerminalBridgeBuilder::default()
+    }
+
     /// Restore the terminal to its original state.
     ///
-    /// This function will attempt to restore the terminal to its original state by leaving the alternate screen
-    /// and disabling raw mode. If either of these operations fail, the error will be returned.
+    /// This function will attempt to restore the terminal to its original state by undoing the modes enabled
+    /// through the bridge: leaving the alternate screen, disabling mouse capture, focus change reporting and raw mode
+    /// and showing the cursor. All the modes are restored, then the first error, if any, is returned.
+    ///
+    /// Once the terminal has been restored, it's not restored again when the bridge is dropped.
     pub fn restore(&mut self) -> TerminalResult<()> {
-        self.leave_alternate_screen()?;
-        self.disable_raw_mode()
+        let mut result = Ok(());
+        if self.enabled.alternate_screen {
+            result = result.and(self.leave_alternate_screen());
+        }
+        if self.enabled.mouse_capture {
+            result = result.and(self.disable_mouse_capture());
+        }
+        if self.enabled.focus_change {
+            result = result.and(self.disable_focus_change());
+        }
+        if self.enabled.raw_mode {
+            result = result.and(self.disable_raw_mode());
+        }
+        if self.enabled.cursor_hidden {
+            result = result.and(self.show_cursor());
+        }
+        if result.is_ok() {
+            self.restored.store(true, Ordering::SeqCst);
+        }
+        result
+    }
+
+    /// Installs a panic hook that restores the terminal before panicking.
+    ///
+    /// The installed hook leaves the alternate screen, disables raw mode and mouse capture and shows the cursor,
+    /// using [`TerminalAdapter::restore_fn`], then calls the previously installed hook, which prints the panic message.
+    ///
+    /// The terminal is restored only once, even if the bridge is then dropped while unwinding.
+    /// The hook is installed at most once for each bridge, so calling this method again has no effect.
+    ///
+    /// > Unlike [`TerminalBridge::restore`], the hook restores all the modes, even the ones which haven't been
+    /// > enabled through the bridge (e.g. it leaves the alternate screen of an inline viewport).
+    ///
+    /// ```rust
+    /// use tuirealm::terminal::TerminalBridge;
+    ///
+    /// let mut terminal = TerminalBridge::new_test(80, 24);
+    /// terminal.enable_raw_mode().unwrap();
+    /// terminal.enter_alternate_screen().unwrap();
+    /// terminal.install_panic_hook();
+    /// ```
+    ///
+    /// With termion raw mode can't be disabled by the hook, since it's restored only when the adapter is dropped,
+    /// so the panic message may be misaligned.
+    pub fn install_panic_hook(&self) {
+        self.panic_hook.call_once(|| {
+            let restore = self.terminal.restore_fn();
+            let restored = self.restored.clone();
+            let hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                if !restored.swap(true, Ordering::SeqCst) {
+                    restore();
+                }
+                hook(info);
+            }));
+        });
     }
 
     /// Sets a panic hook that restores the terminal before panicking.
     ///
     /// Replaces the panic hook with a one that will restore the terminal state before calling the
     /// original panic hook. This ensures that the terminal is left in a good state when a panic occurs.
+    ///
+    /// This only supports crossterm; prefer [`TerminalBridge::install_panic_hook`].
     pub fn set_panic_hook() {
         let hook = std::panic::take_hook();
         std::panic::set_hook(Box::new(move |info| {
@@ -127,12 +239,17 @@ where
 
     /// Enter in alternate screen using the terminal adapter
     pub fn enter_alternate_screen(&mut self) -> TerminalResult<()> {
-        self.terminal.enter_alternate_screen()
+        self.terminal.enter_alternate_screen()?;
+        self.enabled.alternate_screen = true;
+        self.restored.store(false, Ordering::SeqCst);
+        Ok(())
     }
 
     /// Leave the alternate screen using the terminal adapter
     pub fn leave_alternate_screen(&mut self) -> TerminalResult<()> {
-        self.terminal.leave_alternate_screen()
+        self.terminal.leave_alternate_screen()?;
+        self.enabled.alternate_screen = false;
+        Ok(())
     }
 
     /// Clear the screen
@@ -142,22 +259,80 @@ where
 
     /// Enable terminal raw mode
     pub fn enable_raw_mode(&mut self) -> TerminalResult<()> {
-        self.terminal.enable_raw_mode()
+        self.terminal.enable_raw_mode()?;
+        self.enabled.raw_mode = true;
+        self.restored.store(false, Ordering::SeqCst);
+        Ok(())
     }
 
     /// Disable terminal raw mode
     pub fn disable_raw_mode(&mut self) -> TerminalResult<()> {
-        self.terminal.disable_raw_mode()
+        self.terminal.disable_raw_mode()?;
+        self.enabled.raw_mode = false;
+        Ok(())
     }
 
     /// Enable mouse-event capture, if the backend supports it
     pub fn enable_mouse_capture(&mut self) -> TerminalResult<()> {
-        self.terminal.enable_mouse_capture()
+        self.terminal.enable_mouse_capture()?;
+        self.enabled.mouse_capture = true;
+        self.restored.store(false, Ordering::SeqCst);
+        Ok(())
     }
 
     /// Disable mouse-event capture, if the backend supports it
     pub fn disable_mouse_capture(&mut self) -> TerminalResult<()> {
-        self.terminal.disable_mouse_capture()
+        self.terminal.disable_mouse_capture()?;
+        self.enabled.mouse_capture = false;
+        Ok(())
+    }
+
+    /// Enable the reporting of the terminal focus changes, if the backend supports it.
+    ///
+    /// Once enabled, the input listener emits [`crate::Event::FocusGained`] and [`crate::Event::FocusLost`]
+    /// when the terminal window gains or loses focus.
+    pub fn enable_focus_change(&mut self) -> TerminalResult<()> {
+        self.terminal.enable_focus_change()?;
+        self.enabled.focus_change = true;
+        self.restored.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Disable the reporting of the terminal focus changes, if the backend supports it
+    pub fn disable_focus_change(&mut self) -> TerminalResult<()> {
+        self.terminal.disable_focus_change()?;
+        self.enabled.focus_change = false;
+        Ok(())
+    }
+
+    /// Get the terminal size as (width, height)
+    pub fn size(&self) -> TerminalResult<(u16, u16)> {
+        self.terminal.size()
+    }
+
+    /// Get the cursor position as (x, y)
+    pub fn cursor_position(&mut self) -> TerminalResult<(u16, u16)> {
+        self.terminal.cursor_position()
+    }
+
+    /// Hide the cursor
+    pub fn hide_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal.hide_cursor()?;
+        self.enabled.cursor_hidden = true;
+        self.restored.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Show the cursor
+    pub fn show_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal.show_cursor()?;
+        self.enabled.cursor_hidden = false;
+        Ok(())
+    }
+
+    /// Set the terminal window title
+    pub fn set_title(&mut self, title: &str) -> TerminalResult<()> {
+        self.terminal.set_title(title)
     }
 
     /// Draws a single frame to the terminal.
@@ -180,7 +355,7 @@ where
     /// previous frame to determine what has changed, and only the changes are written to the
     /// terminal. If the render callback does not fully render the frame, the terminal will not be
     /// in a consistent state.
-    pub fn draw<F>(&mut self, render_callback: F) -> TerminalResult<CompletedFrame>
+    pub fn draw<F>(&mut self, render_callback: F) -> TerminalResult<CompletedFrame<'_>>
     where
         F: FnOnce(&mut Frame<'_>),
     {
@@ -188,6 +363,18 @@ where
     }
 }
 
+impl<T> Drop for TerminalBridge<T>
+where
+    T: TerminalAdapter,
+{
+    fn drop(&mut self) {
+        // best-effort restore, unless the terminal has already been restored
+        if !self.restored.swap(true, Ordering::SeqCst) {
+            let _ = self.restore();
+        }
+    }
+}
+
 #[cfg(feature = "crossterm")]
 impl TerminalBridge<adapter::CrosstermTerminalAdapter> {
     /// Create a new instance of the [`TerminalBridge`] using [`crossterm`] as backend
@@ -242,3 +429,141 @@ impl TerminalBridge<adapter::TermionTerminalAdapter> {
         self.terminal.raw_mut()
     }
 }
+
+impl TerminalBridge<adapter::TestTerminalAdapter> {
+    /// Create a new instance of the [`TerminalBridge`] rendering on an in-memory test backend
+    /// with a `width` x `height` size
+    pub fn new_test(width: u16, height: u16) -> Self {
+        Self::new(adapter::TestTerminalAdapter::new(width, height))
+    }
+
+    /// Get the buffer rendered on the last [`TerminalBridge::draw`]
+    pub fn buffer(&self) -> &crate::ratatui::buffer::Buffer {
+        self.terminal.buffer()
+    }
+
+    /// Get the buffer rendered on the last [`TerminalBridge::draw`] as plain text, one line for each row.
+    ///
+    /// See [`buffer_to_string`]
+    pub fn plain_text(&self) -> String {
+        buffer_to_string(self.buffer())
+    }
+
+    /// Get the buffer rendered on the last [`TerminalBridge::draw`] as text with ANSI escape sequences
+    /// for the styles, one line for each row.
+    ///
+    /// See [`buffer_to_ansi_string`]
+    pub fn ansi_text(&self) -> String {
+        buffer_to_ansi_string(self.buffer())
+    }
+
+    /// Get the terminal modes set on the test adapter
+    pub fn modes(&self) -> adapter::TestTerminalModes {
+        self.terminal.modes()
+    }
+
+    /// Returns a reference to the underlying [`crate::ratatui::Terminal`]
+    pub fn raw(&self) -> &crate::ratatui::Terminal<crate::ratatui::backend::TestBackend> {
+        self.terminal.raw()
+    }
+
+    /// Returns a mutable reference the underlying [`crate::ratatui::Terminal`]
+    pub fn raw_mut(
+        &mut self,
+    ) -> &mut crate::ratatui::Terminal<crate::ratatui::backend::TestBackend> {
+        self.terminal.raw_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ratatui::style::Style;
+
+    #[test]
+    fn terminal_bridge_should_query_terminal() {
+        let mut terminal = TerminalBridge::new_test(80, 24);
+        assert_eq!(terminal.size().unwrap(), (80, 24));
+        assert!(terminal.hide_cursor().is_ok());
+        assert!(terminal.show_cursor().is_ok());
+        assert!(terminal.clear_screen().is_ok());
+        assert!(terminal.draw(|f| f.set_cursor_position((4, 2))).is_ok());
+        assert_eq!(terminal.cursor_position().unwrap(), (4, 2));
+        assert!(terminal
+            .draw(|f| f.buffer_mut().set_string(0, 0, "omar", Style::default()))
+            .is_ok());
+        assert_eq!(terminal.buffer().cell((1, 0)).unwrap().symbol(), "m");
+        // not supported by the adapter
+        assert!(matches!(
+            terminal.set_title("tui-realm"),
+            Err(TerminalError::Unsupported)
+        ));
+    }
+
+    #[test]
+    fn terminal_bridge_should_restore_terminal_on_drop() {
+        let mut terminal = TerminalBridge::new_test(80, 24);
+        let modes = terminal.modes();
+        assert!(terminal.enable_raw_mode().is_ok());
+        assert!(terminal.enter_alternate_screen().is_ok());
+        assert!(terminal.enable_mouse_capture().is_ok());
+        assert!(terminal.hide_cursor().is_ok());
+        assert!(terminal.enable_focus_change().is_ok());
+        assert!(modes.is_raw_mode());
+        assert!(modes.is_alternate_screen());
+        assert!(modes.is_mouse_capture());
+        assert!(modes.is_cursor_hidden());
+        assert!(modes.is_focus_change());
+        drop(terminal);
+        assert_eq!(modes.is_dirty(), false);
+    }
+
+    #[test]
+    fn terminal_bridge_should_restore_only_the_modes_it_enabled() {
+        let mut terminal = TerminalBridge::new_test(80, 24);
+        let modes = terminal.modes();
+        // modes set on the adapter aren't restored by the bridge
+        assert!(terminal.terminal.enter_alternate_screen().is_ok());
+        assert!(terminal.enable_raw_mode().is_ok());
+        assert!(terminal.enable_mouse_capture().is_ok());
+        assert!(terminal.disable_mouse_capture().is_ok());
+        assert!(terminal.terminal.enable_mouse_capture().is_ok());
+        drop(terminal);
+        assert_eq!(modes.is_raw_mode(), false);
+        assert_eq!(modes.is_alternate_screen(), true);
+        assert_eq!(modes.is_mouse_capture(), true);
+    }
+
+    #[test]
+    fn terminal_bridge_should_not_restore_terminal_on_drop_after_restore() {
+        let mut terminal = TerminalBridge::new_test(80, 24);
+        let modes = terminal.modes();
+        assert!(terminal.enable_raw_mode().is_ok());
+        assert!(terminal.enter_alternate_screen().is_ok());
+        assert!(terminal.hide_cursor().is_ok());
+        assert!(terminal.restore().is_ok());
+        assert_eq!(modes.is_dirty(), false);
+        assert!(terminal.terminal.enable_raw_mode().is_ok());
+        drop(terminal);
+        assert_eq!(modes.is_raw_mode(), true);
+    }
+
+    #[test]
+    fn panic_hook_should_restore_terminal_once() {
+        let mut terminal = TerminalBridge::new_test(80, 24);
+        let modes = terminal.modes();
+        assert!(terminal.enable_raw_mode().is_ok());
+        assert!(terminal.enter_alternate_screen().is_ok());
+        assert!(modes.is_dirty());
+        terminal.install_panic_hook();
+        assert!(std::panic::catch_unwind(|| panic!("omar")).is_err());
+        assert_eq!(modes.is_dirty(), false);
+        // drop mustn't restore the terminal again
+        assert!(terminal.terminal.enable_raw_mode().is_ok());
+        drop(terminal);
+        assert_eq!(modes.is_raw_mode(), true);
+    }
+}
@@ -2,14 +2,21 @@
 mod crossterm;
 #[cfg(feature = "termion")]
 mod termion;
+mod test_backend;
 
 #[cfg(feature = "crossterm")]
 pub use crossterm::CrosstermTerminalAdapter;
 use ratatui::{CompletedFrame, Frame};
 #[cfg(feature = "termion")]
 pub use termion::{TermionBackend, TermionTerminalAdapter};
+pub use test_backend::{TestTerminalAdapter, TestTerminalModes};
 
-use super::TerminalResult;
+use super::{TerminalError, TerminalResult};
+
+/// A function which restores the terminal to its original state, without accessing the adapter.
+///
+/// See [`TerminalAdapter::restore_fn`]
+pub type RestoreFn = Box<dyn Fn() + Send + Sync + 'static>;
 
 /// TerminalAdapter is a trait that defines the methods that a terminal adapter should implement.
 ///
@@ -36,7 +43,7 @@ pub trait TerminalAdapter {
     /// previous frame to determine what has changed, and only the changes are written to the
     /// terminal. If the render callback does not fully render the frame, the terminal will not be
     /// in a consistent state.
-    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<CompletedFrame>
+    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<CompletedFrame<'_>>
     where
         F: FnOnce(&mut Frame<'_>);
 
@@ -60,4 +67,51 @@ pub trait TerminalAdapter {
 
     /// Disable mouse capture using the terminal adapter
     fn disable_mouse_capture(&mut self) -> TerminalResult<()>;
+
+    /// Enable the reporting of the terminal focus changes using the terminal adapter
+    fn enable_focus_change(&mut self) -> TerminalResult<()> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Disable the reporting of the terminal focus changes using the terminal adapter
+    fn disable_focus_change(&mut self) -> TerminalResult<()> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Get the terminal size as (width, height)
+    fn size(&self) -> TerminalResult<(u16, u16)> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Get the cursor position as (x, y)
+    fn cursor_position(&mut self) -> TerminalResult<(u16, u16)> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Hide the cursor
+    fn hide_cursor(&mut self) -> TerminalResult<()> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Show the cursor
+    fn show_cursor(&mut self) -> TerminalResult<()> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Set the terminal window title
+    fn set_title(&mut self, _title: &str) -> TerminalResult<()> {
+        Err(TerminalError::Unsupported)
+    }
+
+    /// Returns a function which restores the terminal to its original state
+    /// (leave alternate screen, disable raw mode, mouse capture and focus change reporting, show cursor).
+    ///
+    /// The function is called by the panic hook installed with [`super::TerminalBridge::install_panic_hook`],
+    /// so it mustn't access the adapter, which may be borrowed by the panicking thread.
+    /// Errors must be ignored.
+    ///
+    /// The default implementation does nothing.
+    fn restore_fn(&self) -> RestoreFn {
+        Box::new(|| {})
+    }
 }
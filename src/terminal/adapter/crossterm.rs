@@ -1,11 +1,14 @@
-use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::cursor::Show;
+use crossterm::event::{
+    DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+};
 use crossterm::execute;
 use crossterm::terminal::{
-    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle,
 };
-use ratatui::Terminal;
+use ratatui::{Terminal, TerminalOptions, Viewport};
 
-use super::{TerminalAdapter, TerminalResult};
+use super::{RestoreFn, TerminalAdapter, TerminalResult};
 use crate::ratatui::backend::CrosstermBackend;
 use crate::terminal::TerminalError;
 
@@ -19,8 +22,14 @@ pub struct CrosstermTerminalAdapter {
 impl CrosstermTerminalAdapter {
     /// Create a new instance of the CrosstermTerminalAdapter
     pub fn new() -> TerminalResult<Self> {
+        Self::with_viewport(Viewport::Fullscreen)
+    }
+
+    /// Create a new instance of the CrosstermTerminalAdapter, which renders on `viewport`
+    pub fn with_viewport(viewport: Viewport) -> TerminalResult<Self> {
         let backend = CrosstermBackend::new(std::io::stdout());
-        let terminal = Terminal::new(backend).map_err(|_| TerminalError::CannotConnectStdout)?;
+        let terminal = Terminal::with_options(backend, TerminalOptions { viewport })
+            .map_err(|_| TerminalError::CannotConnectStdout)?;
 
         Ok(Self { terminal })
     }
@@ -35,7 +44,7 @@ impl CrosstermTerminalAdapter {
 }
 
 impl TerminalAdapter for CrosstermTerminalAdapter {
-    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<ratatui::CompletedFrame>
+    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<ratatui::CompletedFrame<'_>>
     where
         F: FnOnce(&mut ratatui::Frame<'_>),
     {
@@ -85,4 +94,58 @@ impl TerminalAdapter for CrosstermTerminalAdapter {
         execute!(self.raw_mut().backend_mut(), DisableMouseCapture)
             .map_err(|_| TerminalError::CannotToggleMouseCapture)
     }
+
+    fn enable_focus_change(&mut self) -> TerminalResult<()> {
+        execute!(self.raw_mut().backend_mut(), EnableFocusChange)
+            .map_err(|_| TerminalError::CannotToggleFocusChange)
+    }
+
+    fn disable_focus_change(&mut self) -> TerminalResult<()> {
+        execute!(self.raw_mut().backend_mut(), DisableFocusChange)
+            .map_err(|_| TerminalError::CannotToggleFocusChange)
+    }
+
+    fn size(&self) -> TerminalResult<(u16, u16)> {
+        self.terminal
+            .size()
+            .map(|size| (size.width, size.height))
+            .map_err(|_| TerminalError::CannotGetSize)
+    }
+
+    fn cursor_position(&mut self) -> TerminalResult<(u16, u16)> {
+        self.terminal
+            .get_cursor_position()
+            .map(|pos| (pos.x, pos.y))
+            .map_err(|_| TerminalError::CannotGetCursorPosition)
+    }
+
+    fn hide_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .hide_cursor()
+            .map_err(|_| TerminalError::CannotToggleCursor)
+    }
+
+    fn show_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .show_cursor()
+            .map_err(|_| TerminalError::CannotToggleCursor)
+    }
+
+    fn set_title(&mut self, title: &str) -> TerminalResult<()> {
+        execute!(self.raw_mut().backend_mut(), SetTitle(title))
+            .map_err(|_| TerminalError::CannotSetTitle)
+    }
+
+    fn restore_fn(&self) -> RestoreFn {
+        Box::new(|| {
+            let _ = execute!(
+                std::io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                DisableFocusChange,
+                Show
+            );
+            let _ = disable_raw_mode();
+        })
+    }
 }
@@ -1,14 +1,17 @@
-use std::io::Stdout;
+use std::io::{Stdout, Write as _};
 
 use ratatui::prelude::TermionBackend as TermionLibBackend;
-use ratatui::Terminal;
+use ratatui::{Terminal, TerminalOptions, Viewport};
 use termion::input::MouseTerminal;
 use termion::raw::{IntoRawMode as _, RawTerminal};
 use termion::screen::{AlternateScreen, IntoAlternateScreen as _};
 
-use super::{TerminalAdapter, TerminalResult};
+use super::{RestoreFn, TerminalAdapter, TerminalResult};
 use crate::terminal::TerminalError;
 
+/// Sequence written by [`MouseTerminal`] to disable mouse capture
+const DISABLE_MOUSE: &str = "\x1b[?1006l\x1b[?1015l\x1b[?1002l\x1b[?1000l";
+
 pub type TermionBackend =
     Terminal<TermionLibBackend<MouseTerminal<AlternateScreen<RawTerminal<Stdout>>>>>;
 
@@ -21,6 +24,13 @@ pub struct TermionTerminalAdapter {
 
 impl TermionTerminalAdapter {
     pub fn new() -> TerminalResult<Self> {
+        Self::with_viewport(Viewport::Fullscreen)
+    }
+
+    /// Create a new instance of the TermionTerminalAdapter, which renders on `viewport`.
+    ///
+    /// Raw mode, alternate screen and mouse capture are always enabled with termion.
+    pub fn with_viewport(viewport: Viewport) -> TerminalResult<Self> {
         let stdout = std::io::stdout()
             .into_raw_mode()
             .map_err(|_| TerminalError::CannotConnectStdout)?
@@ -28,8 +38,9 @@ impl TermionTerminalAdapter {
             .map_err(|_| TerminalError::CannotConnectStdout)?;
         let stdout = MouseTerminal::from(stdout);
 
-        let terminal = Terminal::new(TermionLibBackend::new(stdout))
-            .map_err(|_| TerminalError::CannotConnectStdout)?;
+        let terminal =
+            Terminal::with_options(TermionLibBackend::new(stdout), TerminalOptions { viewport })
+                .map_err(|_| TerminalError::CannotConnectStdout)?;
 
         Ok(Self { terminal })
     }
@@ -44,7 +55,7 @@ impl TermionTerminalAdapter {
 }
 
 impl TerminalAdapter for TermionTerminalAdapter {
-    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<ratatui::CompletedFrame>
+    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<ratatui::CompletedFrame<'_>>
     where
         F: FnOnce(&mut ratatui::Frame<'_>),
     {
@@ -82,4 +93,54 @@ impl TerminalAdapter for TermionTerminalAdapter {
     fn enable_mouse_capture(&mut self) -> TerminalResult<()> {
         Err(TerminalError::Unsupported)
     }
+
+    fn size(&self) -> TerminalResult<(u16, u16)> {
+        self.terminal
+            .size()
+            .map(|size| (size.width, size.height))
+            .map_err(|_| TerminalError::CannotGetSize)
+    }
+
+    fn cursor_position(&mut self) -> TerminalResult<(u16, u16)> {
+        self.terminal
+            .get_cursor_position()
+            .map(|pos| (pos.x, pos.y))
+            .map_err(|_| TerminalError::CannotGetCursorPosition)
+    }
+
+    fn hide_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .hide_cursor()
+            .map_err(|_| TerminalError::CannotToggleCursor)
+    }
+
+    fn show_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .show_cursor()
+            .map_err(|_| TerminalError::CannotToggleCursor)
+    }
+
+    fn set_title(&mut self, title: &str) -> TerminalResult<()> {
+        // termion doesn't provide a command to set the title, so write the OSC sequence
+        let backend = self.terminal.backend_mut();
+        write!(backend, "\x1b]0;{title}\x07")
+            .and_then(|_| backend.flush())
+            .map_err(|_| TerminalError::CannotSetTitle)
+    }
+
+    /// NOTE: termion doesn't expose the original terminal attributes, so raw mode can't be disabled here;
+    /// it is disabled when the adapter is dropped (e.g. while unwinding after a panic).
+    fn restore_fn(&self) -> RestoreFn {
+        Box::new(|| {
+            let mut stdout = std::io::stdout();
+            let _ = write!(
+                stdout,
+                "{}{}{}",
+                DISABLE_MOUSE,
+                termion::screen::ToMainScreen,
+                termion::cursor::Show
+            );
+            let _ = stdout.flush();
+        })
+    }
 }
@@ -0,0 +1,211 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
+
+use super::{RestoreFn, TerminalAdapter, TerminalResult};
+use crate::terminal::TerminalError;
+
+/// TestTerminalAdapter is an adapter which renders on an in-memory [`TestBackend`] with a fixed size.
+///
+/// It implements the [`TerminalAdapter`] trait and it can be used to test the rendering of the components,
+/// rendering the view with [`crate::Application::view`] and comparing the resulting [`Buffer`]:
+///
+/// ```rust
+/// use tuirealm::terminal::TerminalBridge;
+///
+/// let mut terminal = TerminalBridge::new_test(80, 24);
+/// assert!(terminal.draw(|f| { /* app.view(&Id::Foo, f, f.area()); */ }).is_ok());
+/// assert_eq!(terminal.buffer().area.width, 80);
+/// ```
+pub struct TestTerminalAdapter {
+    terminal: Terminal<TestBackend>,
+    modes: TestTerminalModes,
+}
+
+/// The terminal modes set on a [`TestTerminalAdapter`].
+///
+/// The modes are shared with the adapter, so they can still be inspected after the adapter has been dropped.
+#[derive(Debug, Clone, Default)]
+pub struct TestTerminalModes {
+    raw_mode: Arc<AtomicBool>,
+    alternate_screen: Arc<AtomicBool>,
+    mouse_capture: Arc<AtomicBool>,
+    cursor_hidden: Arc<AtomicBool>,
+    focus_change: Arc<AtomicBool>,
+}
+
+impl TestTerminalModes {
+    /// Returns whether raw mode is enabled
+    pub fn is_raw_mode(&self) -> bool {
+        self.raw_mode.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether the alternate screen is active
+    pub fn is_alternate_screen(&self) -> bool {
+        self.alternate_screen.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether mouse capture is enabled
+    pub fn is_mouse_capture(&self) -> bool {
+        self.mouse_capture.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether the cursor is hidden
+    pub fn is_cursor_hidden(&self) -> bool {
+        self.cursor_hidden.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether focus change reporting is enabled
+    pub fn is_focus_change(&self) -> bool {
+        self.focus_change.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether any mode is set
+    pub fn is_dirty(&self) -> bool {
+        self.is_raw_mode()
+            || self.is_alternate_screen()
+            || self.is_mouse_capture()
+            || self.is_cursor_hidden()
+            || self.is_focus_change()
+    }
+
+    fn restore(&self) {
+        self.raw_mode.store(false, Ordering::SeqCst);
+        self.alternate_screen.store(false, Ordering::SeqCst);
+        self.mouse_capture.store(false, Ordering::SeqCst);
+        self.cursor_hidden.store(false, Ordering::SeqCst);
+        self.focus_change.store(false, Ordering::SeqCst);
+    }
+}
+
+impl TestTerminalAdapter {
+    /// Create a new instance of the TestTerminalAdapter with a `width` x `height` size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self::with_viewport(width, height, Viewport::Fullscreen)
+    }
+
+    /// Create a new instance of the TestTerminalAdapter with a `width` x `height` size, which renders on `viewport`
+    pub fn with_viewport(width: u16, height: u16, viewport: Viewport) -> Self {
+        Self {
+            terminal: Terminal::with_options(
+                TestBackend::new(width, height),
+                TerminalOptions { viewport },
+            )
+            .expect("the test backend can't fail"),
+            modes: TestTerminalModes::default(),
+        }
+    }
+
+    /// Get the terminal modes set on the adapter
+    pub fn modes(&self) -> TestTerminalModes {
+        self.modes.clone()
+    }
+
+    /// Get the buffer rendered on the last [`TerminalAdapter::draw`]
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    pub fn raw(&self) -> &Terminal<TestBackend> {
+        &self.terminal
+    }
+
+    pub fn raw_mut(&mut self) -> &mut Terminal<TestBackend> {
+        &mut self.terminal
+    }
+}
+
+impl TerminalAdapter for TestTerminalAdapter {
+    fn draw<F>(&mut self, render_callback: F) -> TerminalResult<CompletedFrame<'_>>
+    where
+        F: FnOnce(&mut Frame<'_>),
+    {
+        self.terminal
+            .draw(render_callback)
+            .map_err(|_| TerminalError::CannotDrawFrame)
+    }
+
+    fn clear_screen(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .clear()
+            .map_err(|_| TerminalError::CannotClear)
+    }
+
+    fn enable_raw_mode(&mut self) -> TerminalResult<()> {
+        self.modes.raw_mode.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> TerminalResult<()> {
+        self.modes.raw_mode.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> TerminalResult<()> {
+        self.modes.alternate_screen.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> TerminalResult<()> {
+        self.modes.alternate_screen.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn enable_mouse_capture(&mut self) -> TerminalResult<()> {
+        self.modes.mouse_capture.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn disable_mouse_capture(&mut self) -> TerminalResult<()> {
+        self.modes.mouse_capture.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn enable_focus_change(&mut self) -> TerminalResult<()> {
+        self.modes.focus_change.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn disable_focus_change(&mut self) -> TerminalResult<()> {
+        self.modes.focus_change.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn size(&self) -> TerminalResult<(u16, u16)> {
+        self.terminal
+            .size()
+            .map(|size| (size.width, size.height))
+            .map_err(|_| TerminalError::CannotGetSize)
+    }
+
+    fn cursor_position(&mut self) -> TerminalResult<(u16, u16)> {
+        self.terminal
+            .get_cursor_position()
+            .map(|pos| (pos.x, pos.y))
+            .map_err(|_| TerminalError::CannotGetCursorPosition)
+    }
+
+    fn hide_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .hide_cursor()
+            .map_err(|_| TerminalError::CannotToggleCursor)?;
+        self.modes.cursor_hidden.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> TerminalResult<()> {
+        self.terminal
+            .show_cursor()
+            .map_err(|_| TerminalError::CannotToggleCursor)?;
+        self.modes.cursor_hidden.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn restore_fn(&self) -> RestoreFn {
+        let modes = self.modes.clone();
+        Box::new(move || modes.restore())
+    }
+}
@@ -0,0 +1,219 @@
+//! ## Builder
+//!
+//! This module exposes the builder of the [`TerminalBridge`], which allows to choose the terminal modes to set up
+
+use std::marker::PhantomData;
+
+use ratatui::Viewport;
+
+#[cfg(feature = "crossterm")]
+use super::adapter::CrosstermTerminalAdapter;
+#[cfg(feature = "termion")]
+use super::adapter::TermionTerminalAdapter;
+use super::adapter::TestTerminalAdapter;
+#[cfg(feature = "termion")]
+use super::TerminalError;
+use super::{TerminalAdapter, TerminalBridge, TerminalResult};
+
+/// Builds a [`TerminalBridge`], choosing which terminal modes are set up.
+///
+/// The defaults are the same of [`TerminalBridge::init`]:
+///
+/// - Raw mode is enabled
+/// - Alternate screen buffer enabled
+/// - Mouse capture enabled
+/// - The view is rendered on the whole screen
+/// - A panic hook is installed that restores the terminal before panicking
+///
+/// ```rust
+/// use tuirealm::terminal::{TerminalBridge, TestTerminalAdapter};
+///
+/// // render on the 10 lines below the cursor, without leaving the main screen
+/// let terminal = TerminalBridge::<TestTerminalAdapter>::builder()
+///     .alternate_screen(false)
+///     .mouse_capture(false)
+///     .inline_viewport(10)
+///     .build_test(80, 24)
+///     .unwrap();
+/// ```
+///
+/// Use `build()` to build a [`TerminalBridge`] with the backend chosen for the adapter type.
+#[derive(Debug, Clone)]
+pub struct TerminalBridgeBuilder<T>
+where
+    T: TerminalAdapter,
+{
+    raw_mode: bool,
+    alternate_screen: bool,
+    mouse_capture: bool,
+    panic_hook: bool,
+    viewport: Viewport,
+    adapter: PhantomData<T>,
+}
+
+impl<T> Default for TerminalBridgeBuilder<T>
+where
+    T: TerminalAdapter,
+{
+    fn default() -> Self {
+        Self {
+            raw_mode: true,
+            alternate_screen: true,
+            mouse_capture: true,
+            panic_hook: true,
+            viewport: Viewport::Fullscreen,
+            adapter: PhantomData,
+        }
+    }
+}
+
+impl<T> TerminalBridgeBuilder<T>
+where
+    T: TerminalAdapter,
+{
+    /// Set whether raw mode must be enabled
+    pub fn raw_mode(mut self, enable: bool) -> Self {
+        self.raw_mode = enable;
+        self
+    }
+
+    /// Set whether the view must be rendered on the alternate screen
+    pub fn alternate_screen(mut self, enable: bool) -> Self {
+        self.alternate_screen = enable;
+        self
+    }
+
+    /// Set whether mouse events must be captured
+    pub fn mouse_capture(mut self, enable: bool) -> Self {
+        self.mouse_capture = enable;
+        self
+    }
+
+    /// Set whether to install the panic hook which restores the terminal (see [`TerminalBridge::install_panic_hook`])
+    pub fn panic_hook(mut self, install: bool) -> Self {
+        self.panic_hook = install;
+        self
+    }
+
+    /// Render the view on the `height` lines below the cursor, instead of the whole screen
+    /// (see [`Viewport::Inline`]). This is usually combined with `alternate_screen(false)`.
+    pub fn inline_viewport(mut self, height: u16) -> Self {
+        self.viewport = Viewport::Inline(height);
+        self
+    }
+
+    /// Set the viewport the view is rendered on
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Set up the terminal modes on `adapter` and return the bridge
+    fn setup(self, adapter: T) -> TerminalResult<TerminalBridge<T>> {
+        let mut terminal = TerminalBridge::new(adapter);
+        if self.raw_mode {
+            terminal.enable_raw_mode()?;
+        }
+        if self.alternate_screen {
+            terminal.enter_alternate_screen()?;
+        }
+        // NOTE: some adapters enable mouse capture when entering the alternate screen
+        if self.mouse_capture {
+            terminal.enable_mouse_capture()?;
+        } else if self.alternate_screen {
+            terminal.disable_mouse_capture()?;
+        }
+        if self.panic_hook {
+            terminal.install_panic_hook();
+        }
+        Ok(terminal)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl TerminalBridgeBuilder<CrosstermTerminalAdapter> {
+    /// Build the [`TerminalBridge`] using [`crossterm`] as backend
+    pub fn build(self) -> TerminalResult<TerminalBridge<CrosstermTerminalAdapter>> {
+        let adapter = CrosstermTerminalAdapter::with_viewport(self.viewport.clone())?;
+        self.setup(adapter)
+    }
+}
+
+#[cfg(feature = "termion")]
+impl TerminalBridgeBuilder<TermionTerminalAdapter> {
+    /// Build the [`TerminalBridge`] using [`termion`] as backend.
+    ///
+    /// With termion raw mode, alternate screen and mouse capture can't be disabled,
+    /// so [`TerminalError::Unsupported`] is returned if any of them is disabled.
+    pub fn build(self) -> TerminalResult<TerminalBridge<TermionTerminalAdapter>> {
+        if !(self.raw_mode && self.alternate_screen && self.mouse_capture) {
+            return Err(TerminalError::Unsupported);
+        }
+        let terminal = TerminalBridge::new(TermionTerminalAdapter::with_viewport(self.viewport)?);
+        if self.panic_hook {
+            terminal.install_panic_hook();
+        }
+        Ok(terminal)
+    }
+}
+
+impl TerminalBridgeBuilder<TestTerminalAdapter> {
+    /// Build the [`TerminalBridge`] rendering on an in-memory test backend with a `width` x `height` size
+    pub fn build_test(
+        self,
+        width: u16,
+        height: u16,
+    ) -> TerminalResult<TerminalBridge<TestTerminalAdapter>> {
+        let adapter = TestTerminalAdapter::with_viewport(width, height, self.viewport.clone());
+        self.setup(adapter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_build_terminal_with_defaults() {
+        let terminal = TerminalBridge::<TestTerminalAdapter>::builder()
+            .panic_hook(false)
+            .build_test(80, 24)
+            .unwrap();
+        let modes = terminal.modes();
+        assert_eq!(modes.is_raw_mode(), true);
+        assert_eq!(modes.is_alternate_screen(), true);
+        assert_eq!(modes.is_mouse_capture(), true);
+    }
+
+    #[test]
+    fn should_build_inline_terminal() {
+        let mut terminal = TerminalBridge::<TestTerminalAdapter>::builder()
+            .alternate_screen(false)
+            .mouse_capture(false)
+            .raw_mode(false)
+            .panic_hook(false)
+            .inline_viewport(5)
+            .build_test(80, 24)
+            .unwrap();
+        let modes = terminal.modes();
+        assert_eq!(modes.is_dirty(), false);
+        let mut area = Default::default();
+        assert!(terminal.draw(|f| area = f.area()).is_ok());
+        assert_eq!((area.width, area.height), (80, 5));
+    }
+
+    #[test]
+    fn should_disable_mouse_capture_on_alternate_screen() {
+        let terminal = TerminalBridge::<TestTerminalAdapter>::builder()
+            .mouse_capture(false)
+            .panic_hook(false)
+            .build_test(80, 24)
+            .unwrap();
+        let modes = terminal.modes();
+        assert_eq!(modes.is_alternate_screen(), true);
+        assert_eq!(modes.is_mouse_capture(), false);
+    }
+}
@@ -4,8 +4,10 @@ mod crossterm;
 mod termion;
 
 #[cfg(feature = "crossterm")]
-pub use crossterm::CrosstermInputListener;
+pub use crossterm::{
+    CrosstermEventSource, CrosstermInputListener, CrosstermRawHook, CrosstermTerminalEventSource,
+};
 #[cfg(feature = "termion")]
-pub use termion::TermionInputListener;
+pub use termion::{TermionInputListener, TermionRawHook};
 
 use crate::Event;
@@ -10,11 +10,44 @@ use crossterm::event::{
 
 use super::Event;
 use crate::event::{
-    Key, KeyEvent, KeyModifiers, MediaKeyCode, MouseButton, MouseEvent, MouseEventKind,
+    Key, KeyEvent, KeyEventKind, KeyModifiers, MediaKeyCode, MouseButton, MouseEvent,
+    MouseEventKind,
 };
 use crate::listener::{ListenerResult, Poll};
 use crate::ListenerError;
 
+/// A function which converts a raw crossterm event into an application [`Event`].
+/// If [`None`] is returned, the event is converted with the built-in conversion.
+pub type CrosstermRawHook<U> = fn(XtermEvent) -> Option<Event<U>>;
+
+/// The source of the events read by the [`CrosstermInputListener`].
+///
+/// By default events are read from the terminal of the process with [`CrosstermTerminalEventSource`],
+/// but a different source can be used to read the events from somewhere else
+/// (e.g. from a remote session or from a scripted input in the tests).
+pub trait CrosstermEventSource: Send {
+    /// Returns whether an event is available to be read, waiting up to `timeout`
+    fn poll(&mut self, timeout: Duration) -> std::io::Result<bool>;
+
+    /// Read the next event. It is called only after [`CrosstermEventSource::poll`] has returned `true`.
+    fn read(&mut self) -> std::io::Result<XtermEvent>;
+}
+
+/// The default [`CrosstermEventSource`], which reads the events from the terminal of the process
+/// with [`crossterm::event::poll`] and [`crossterm::event::read`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CrosstermTerminalEventSource;
+
+impl CrosstermEventSource for CrosstermTerminalEventSource {
+    fn poll(&mut self, timeout: Duration) -> std::io::Result<bool> {
+        xterm::poll(timeout)
+    }
+
+    fn read(&mut self) -> std::io::Result<XtermEvent> {
+        xterm::read()
+    }
+}
+
 /// The input listener for crossterm.
 /// If crossterm is enabled, this will already be exported as `InputEventListener` in the `adapter` module
 /// or you can use it directly in the event listener, calling `default_input_listener()` in the `EventListenerCfg`
@@ -25,6 +58,10 @@ where
 {
     ghost: PhantomData<U>,
     interval: Duration,
+    raw_hook: Option<CrosstermRawHook<U>>,
+    source: Box<dyn CrosstermEventSource>,
+    /// Whether focus change reporting must be enabled on the terminal on the next poll
+    enable_focus_change: bool,
 }
 
 impl<U> CrosstermInputListener<U>
@@ -32,11 +69,54 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
 {
     pub fn new(interval: Duration) -> Self {
+        Self::from_source(CrosstermTerminalEventSource, interval)
+    }
+
+    /// Create a new listener which reads the events from `source` instead of the terminal of the process
+    pub fn from_source<S>(source: S, interval: Duration) -> Self
+    where
+        S: CrosstermEventSource + 'static,
+    {
         Self {
             ghost: PhantomData,
             interval: interval / 2,
+            raw_hook: None,
+            source: Box::new(source),
+            enable_focus_change: false,
         }
     }
+
+    /// Set how long each poll waits for an event. (Default: half the interval)
+    pub fn with_poll_timeout(mut self, timeout: Duration) -> Self {
+        self.interval = timeout;
+        self
+    }
+
+    /// Set the hook used to convert the crossterm events.
+    ///
+    /// The hook is called for each event read from the terminal before the built-in conversion, so it can be used
+    /// to map the events which the built-in conversion doesn't handle as you need (e.g. into a user event).
+    pub fn with_raw_hook(mut self, hook: CrosstermRawHook<U>) -> Self {
+        self.raw_hook = Some(hook);
+        self
+    }
+
+    /// Enable the reporting of the terminal focus changes on the first poll, so that [`Event::FocusGained`]
+    /// and [`Event::FocusLost`] are emitted when the terminal window gains or loses focus.
+    ///
+    /// The reporting is disabled when the terminal is restored by [`crate::terminal::TerminalBridge`]
+    /// (see [`crate::terminal::TerminalBridge::disable_focus_change`]).
+    pub fn with_focus_change(mut self) -> Self {
+        self.enable_focus_change = true;
+        self
+    }
+
+    /// Convert the crossterm event `ev`, with the raw hook if any
+    fn convert(&self, ev: XtermEvent) -> Event<U> {
+        self.raw_hook
+            .and_then(|hook| hook(ev.clone()))
+            .unwrap_or_else(|| Event::from(ev))
+    }
 }
 
 impl<U> Poll<U> for CrosstermInputListener<U>
@@ -44,9 +124,16 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
-        match xterm::poll(self.interval) {
-            Ok(true) => xterm::read()
-                .map(|x| Some(Event::from(x)))
+        if self.enable_focus_change {
+            crossterm::execute!(std::io::stdout(), xterm::EnableFocusChange)
+                .map_err(|_| ListenerError::PollFailed)?;
+            self.enable_focus_change = false;
+        }
+        match self.source.poll(self.interval) {
+            Ok(true) => self
+                .source
+                .read()
+                .map(|x| Some(self.convert(x)))
                 .map_err(|_| ListenerError::PollFailed),
             Ok(false) => Ok(None),
             Err(_) => Err(ListenerError::PollFailed),
@@ -60,8 +147,7 @@ where
 {
     fn from(e: XtermEvent) -> Self {
         match e {
-            XtermEvent::Key(key) if key.kind == XtermEventKind::Press => Self::Keyboard(key.into()),
-            XtermEvent::Key(_) => Self::None,
+            XtermEvent::Key(key) => Self::Keyboard(key.into()),
             XtermEvent::Mouse(ev) => Self::Mouse(ev.into()),
             XtermEvent::Resize(w, h) => Self::WindowResize(w, h),
             XtermEvent::FocusGained => Self::FocusGained,
@@ -76,6 +162,17 @@ impl From<XtermKeyEvent> for KeyEvent {
         Self {
             code: e.code.into(),
             modifiers: e.modifiers.into(),
+            kind: e.kind.into(),
+        }
+    }
+}
+
+impl From<XtermEventKind> for KeyEventKind {
+    fn from(k: XtermEventKind) -> Self {
+        match k {
+            XtermEventKind::Press => Self::Press,
+            XtermEventKind::Repeat => Self::Repeat,
+            XtermEventKind::Release => Self::Release,
         }
     }
 }
@@ -125,6 +222,15 @@ impl From<XtermKeyModifiers> for KeyModifiers {
         if k.intersects(XtermKeyModifiers::ALT) {
             km.insert(KeyModifiers::ALT);
         }
+        if k.intersects(XtermKeyModifiers::SUPER) {
+            km.insert(KeyModifiers::SUPER);
+        }
+        if k.intersects(XtermKeyModifiers::HYPER) {
+            km.insert(KeyModifiers::HYPER);
+        }
+        if k.intersects(XtermKeyModifiers::META) {
+            km.insert(KeyModifiers::META);
+        }
         km
     }
 }
@@ -191,9 +297,39 @@ mod test {
     use crossterm::event::{MouseEvent as XtermMouseEvent, MouseEventKind as XtermMouseEventKind};
     use pretty_assertions::assert_eq;
 
+    use std::collections::VecDeque;
+
     use super::*;
     use crate::event::{Key, MediaKeyCode};
-    use crate::mock::MockEvent;
+    use crate::mock::{MockComponentId, MockEvent, MockFooInput, MockMsg};
+    use crate::{Application, EventListenerCfg, PollStrategy};
+
+    /// Event source which returns scripted events
+    struct ScriptedEventSource {
+        events: VecDeque<std::io::Result<XtermEvent>>,
+    }
+
+    impl ScriptedEventSource {
+        fn new(events: Vec<std::io::Result<XtermEvent>>) -> Self {
+            Self {
+                events: events.into(),
+            }
+        }
+    }
+
+    impl CrosstermEventSource for ScriptedEventSource {
+        fn poll(&mut self, _timeout: Duration) -> std::io::Result<bool> {
+            Ok(!self.events.is_empty())
+        }
+
+        fn read(&mut self) -> std::io::Result<XtermEvent> {
+            self.events.pop_front().unwrap()
+        }
+    }
+
+    fn key(ch: char) -> std::io::Result<XtermEvent> {
+        Ok(XtermEvent::Key(XtermKeyEvent::from(XtermKeyCode::Char(ch))))
+    }
 
     #[test]
     fn adapt_crossterm_keycode() {
@@ -215,6 +351,13 @@ mod test {
         assert_eq!(Key::from(XtermKeyCode::Right), Key::Right);
         assert_eq!(Key::from(XtermKeyCode::Tab), Key::Tab);
         assert_eq!(Key::from(XtermKeyCode::Up), Key::Up);
+        assert_eq!(Key::from(XtermKeyCode::CapsLock), Key::CapsLock);
+        assert_eq!(Key::from(XtermKeyCode::ScrollLock), Key::ScrollLock);
+        assert_eq!(Key::from(XtermKeyCode::NumLock), Key::NumLock);
+        assert_eq!(Key::from(XtermKeyCode::PrintScreen), Key::PrintScreen);
+        assert_eq!(Key::from(XtermKeyCode::Pause), Key::Pause);
+        assert_eq!(Key::from(XtermKeyCode::Menu), Key::Menu);
+        assert_eq!(Key::from(XtermKeyCode::KeypadBegin), Key::KeypadBegin);
     }
 
     #[test]
@@ -223,8 +366,31 @@ mod test {
             KeyModifiers::from(
                 XtermKeyModifiers::CONTROL | XtermKeyModifiers::SHIFT | XtermKeyModifiers::ALT
             ),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT | KeyModifiers::ALT
+        );
+        assert_eq!(
+            KeyModifiers::from(XtermKeyModifiers::all()),
             KeyModifiers::all()
         );
+        assert_eq!(
+            KeyModifiers::from(XtermKeyModifiers::SUPER),
+            KeyModifiers::SUPER
+        );
+        assert_eq!(
+            KeyModifiers::from(XtermKeyModifiers::HYPER | XtermKeyModifiers::SHIFT),
+            KeyModifiers::HYPER | KeyModifiers::SHIFT
+        );
+        assert_eq!(
+            KeyModifiers::from(XtermKeyModifiers::META),
+            KeyModifiers::META
+        );
+        assert_eq!(
+            KeyEvent::from(XtermKeyEvent::new(
+                XtermKeyCode::Char('k'),
+                XtermKeyModifiers::SUPER
+            )),
+            KeyEvent::new(Key::Char('k'), KeyModifiers::SUPER)
+        );
         assert_eq!(
             KeyModifiers::from(XtermKeyModifiers::ALT),
             KeyModifiers::ALT
@@ -414,6 +580,122 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_convert_crossterm_event_with_raw_hook() {
+        let listener = CrosstermInputListener::<MockEvent>::new(Duration::from_millis(10))
+            .with_raw_hook(|ev| match ev {
+                XtermEvent::FocusGained => Some(Event::User(MockEvent::Foo)),
+                _ => None,
+            });
+        assert_eq!(
+            listener.convert(XtermEvent::FocusGained),
+            Event::User(MockEvent::Foo)
+        );
+        // fallback to built-in conversion
+        assert_eq!(listener.convert(XtermEvent::FocusLost), Event::FocusLost);
+        // without hook
+        let listener = CrosstermInputListener::<MockEvent>::new(Duration::from_millis(10));
+        assert_eq!(
+            listener.convert(XtermEvent::FocusGained),
+            Event::FocusGained
+        );
+    }
+
+    #[test]
+    fn crossterm_listener_should_read_from_source() {
+        let mut listener = CrosstermInputListener::<MockEvent>::from_source(
+            ScriptedEventSource::new(vec![
+                key('a'),
+                Err(std::io::Error::other("omar")),
+                Ok(XtermEvent::FocusLost),
+            ]),
+            Duration::from_millis(10),
+        );
+        assert_eq!(
+            listener.poll().unwrap(),
+            Some(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+        );
+        assert!(matches!(listener.poll(), Err(ListenerError::PollFailed)));
+        assert_eq!(listener.poll().unwrap(), Some(Event::FocusLost));
+        assert_eq!(listener.poll().unwrap(), None);
+    }
+
+    #[test]
+    fn application_should_receive_scripted_input() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> = Application::init(
+            EventListenerCfg::default().crossterm_input_listener_with_source(
+                ScriptedEventSource::new(vec![key('o'), key('m'), key('a'), key('r')]),
+                Duration::from_millis(10),
+                10,
+            ),
+        );
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let mut messages = Vec::new();
+        while messages.len() < 4 {
+            messages.extend(application.tick(PollStrategy::UpTo(10)).unwrap());
+        }
+        assert_eq!(
+            messages,
+            vec![
+                MockMsg::FooInputChanged(String::from("o")),
+                MockMsg::FooInputChanged(String::from("om")),
+                MockMsg::FooInputChanged(String::from("oma")),
+                MockMsg::FooInputChanged(String::from("omar")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_apply_raw_hook_set_after_input_listener() {
+        let mut listener = EventListenerCfg::<MockEvent>::default()
+            .poll_timeout(Duration::from_millis(100))
+            .crossterm_input_listener_with_source(
+                ScriptedEventSource::new(vec![key('a'), key('b')]),
+                Duration::from_millis(10),
+                1,
+            )
+            .crossterm_raw_hook(|ev| match ev {
+                XtermEvent::Key(XtermKeyEvent {
+                    code: XtermKeyCode::Char('a'),
+                    ..
+                }) => Some(Event::User(MockEvent::Foo)),
+                _ => None,
+            })
+            .start();
+        assert_eq!(listener.poll().unwrap(), Some(Event::User(MockEvent::Foo)));
+        assert_eq!(
+            listener.poll().unwrap(),
+            Some(Event::Keyboard(KeyEvent::from(Key::Char('b'))))
+        );
+        assert!(listener.stop().is_ok());
+    }
+
+    #[test]
+    fn adapt_crossterm_key_event_kind() {
+        type AppEvent = Event<MockEvent>;
+        for (kind, expected) in [
+            (XtermEventKind::Press, KeyEventKind::Press),
+            (XtermEventKind::Repeat, KeyEventKind::Repeat),
+            (XtermEventKind::Release, KeyEventKind::Release),
+        ] {
+            assert_eq!(
+                AppEvent::from(XtermEvent::Key(XtermKeyEvent::new_with_kind(
+                    XtermKeyCode::Char('a'),
+                    XtermKeyModifiers::NONE,
+                    kind
+                ))),
+                Event::Keyboard(KeyEvent::from(Key::Char('a')).with_kind(expected))
+            );
+        }
+    }
+
     #[test]
     fn adapt_crossterm_event() {
         type AppEvent = Event<MockEvent>;
@@ -1,4 +1,7 @@
+use std::io;
 use std::marker::PhantomData;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
 use std::time::Duration;
 
 use termion::event::{Event as TonEvent, Key as TonKey};
@@ -9,13 +12,41 @@ use crate::event::{Key, KeyEvent, KeyModifiers};
 use crate::listener::{ListenerResult, Poll};
 use crate::ListenerError;
 
+/// Maximum amount of events read from stdin which can wait to be polled
+const EVENTS_BUFFER_SIZE: usize = 256;
+
+/// A function which converts a raw termion event into an application [`Event`].
+/// If [`None`] is returned, the event is converted with the built-in conversion.
+pub type TermionRawHook<U> = fn(TonEvent) -> Option<Event<U>>;
+
 /// The input listener for [`termion`].
+///
+/// Reading from stdin with termion is blocking, so the events are read by an internal reader thread
+/// and [`Poll::poll`] never blocks.
+/// The reader thread terminates after the listener is dropped, as soon as it reads the next event from stdin.
+///
+/// The keys are reported as with crossterm: uppercase chars are lowercased with [`KeyModifiers::SHIFT`],
+/// and the modified arrows, home and end keys reported by termion (e.g. `CtrlLeft`) are converted into the key
+/// with its modifiers (e.g. [`Key::Left`] with [`KeyModifiers::CONTROL`]).
+/// The CSI sequences of the modified keys which termion doesn't parse (e.g. `Shift+F5` or `Ctrl+Alt+Up`)
+/// are parsed by the listener.
+///
+/// The following input is not supported:
+///
+/// - termion doesn't report the terminal focus changes, so [`Event::FocusGained`] and [`Event::FocusLost`]
+///   are never emitted;
+/// - `Alt+char` is reported only if the terminal sends the escape and the char together, otherwise
+///   [`Key::Esc`] is followed by the char;
+/// - `Ctrl+Shift+char` and `Ctrl` with non-letter keys depend on the terminal, which often reports them as the plain key;
+/// - mouse, paste and resize events, the media keys and the key release and repeat events.
 #[doc(alias = "InputEventListener")]
 pub struct TermionInputListener<U>
 where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
 {
     ghost: PhantomData<U>,
+    events: Receiver<io::Result<TonEvent>>,
+    raw_hook: Option<TermionRawHook<U>>,
 }
 
 impl<U> TermionInputListener<U>
@@ -23,7 +54,47 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send,
 {
     pub fn new(_interval: Duration) -> Self {
-        Self { ghost: PhantomData }
+        Self::from_events(io::stdin().events())
+    }
+
+    /// Create a new listener which reads the events from `events` instead of stdin
+    /// (e.g. from a remote session or from a scripted input in the tests).
+    ///
+    /// The events are read in the reader thread, so the iterator may block.
+    pub fn from_events<I>(events: I) -> Self
+    where
+        I: Iterator<Item = io::Result<TonEvent>> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::sync_channel(EVENTS_BUFFER_SIZE);
+        thread::spawn(move || {
+            for ev in events {
+                // NOTE: send fails once the listener has been dropped
+                if tx.send(ev).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            ghost: PhantomData,
+            events: rx,
+            raw_hook: None,
+        }
+    }
+
+    /// Set the hook used to convert the termion events.
+    ///
+    /// The hook is called for each event read from stdin before the built-in conversion, so it can be used
+    /// to map the events which the built-in conversion doesn't handle (e.g. mouse events) as you need.
+    pub fn with_raw_hook(mut self, hook: TermionRawHook<U>) -> Self {
+        self.raw_hook = Some(hook);
+        self
+    }
+
+    /// Convert the termion event `ev`, with the raw hook if any
+    fn convert(&self, ev: TonEvent) -> Event<U> {
+        self.raw_hook
+            .and_then(|hook| hook(ev.clone()))
+            .unwrap_or_else(|| Event::from(ev))
     }
 }
 
@@ -32,10 +103,11 @@ where
     U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
 {
     fn poll(&mut self) -> ListenerResult<Option<Event<U>>> {
-        match std::io::stdin().events().next() {
-            Some(Ok(ev)) => Ok(Some(Event::from(ev))),
-            Some(Err(_)) => Err(ListenerError::PollFailed),
-            None => Ok(None),
+        match self.events.try_recv() {
+            Ok(Ok(ev)) => Ok(Some(self.convert(ev))),
+            Ok(Err(_)) => Err(ListenerError::PollFailed),
+            // NOTE: disconnected means that stdin has been closed
+            Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => Ok(None),
         }
     }
 }
@@ -47,6 +119,9 @@ where
     fn from(e: TonEvent) -> Self {
         match e {
             TonEvent::Key(key) => Self::Keyboard(key.into()),
+            TonEvent::Unsupported(bytes) => parse_csi_key(&bytes)
+                .map(Self::Keyboard)
+                .unwrap_or(Self::None),
             _ => Self::None,
         }
     }
@@ -54,63 +129,200 @@ where
 
 impl From<TonKey> for KeyEvent {
     fn from(e: TonKey) -> Self {
-        // Get modifiers
-        let modifiers = match e {
-            TonKey::Alt(c) if c.is_uppercase() => KeyModifiers::ALT | KeyModifiers::SHIFT,
-            TonKey::Ctrl(c) if c.is_uppercase() => KeyModifiers::CONTROL | KeyModifiers::SHIFT,
-            TonKey::Char(c) if c.is_uppercase() => KeyModifiers::SHIFT,
-            TonKey::Alt(_) => KeyModifiers::ALT,
-            TonKey::Ctrl(_) => KeyModifiers::CONTROL,
-            _ => KeyModifiers::NONE,
+        let (code, modifiers) = match e {
+            TonKey::Char(c) => char_key(c),
+            TonKey::Alt(c) => {
+                let (code, modifiers) = char_key(c);
+                (code, modifiers | KeyModifiers::ALT)
+            }
+            TonKey::Ctrl(c) => {
+                let (code, modifiers) = char_key(c);
+                (code, modifiers | KeyModifiers::CONTROL)
+            }
+            TonKey::BackTab => (Key::BackTab, KeyModifiers::NONE),
+            TonKey::Backspace => (Key::Backspace, KeyModifiers::NONE),
+            TonKey::Delete => (Key::Delete, KeyModifiers::NONE),
+            TonKey::Down => (Key::Down, KeyModifiers::NONE),
+            TonKey::End => (Key::End, KeyModifiers::NONE),
+            TonKey::Left => (Key::Left, KeyModifiers::NONE),
+            TonKey::Right => (Key::Right, KeyModifiers::NONE),
+            TonKey::Up => (Key::Up, KeyModifiers::NONE),
+            TonKey::Home => (Key::Home, KeyModifiers::NONE),
+            TonKey::PageUp => (Key::PageUp, KeyModifiers::NONE),
+            TonKey::PageDown => (Key::PageDown, KeyModifiers::NONE),
+            TonKey::Insert => (Key::Insert, KeyModifiers::NONE),
+            TonKey::F(f) => (Key::Function(f), KeyModifiers::NONE),
+            TonKey::Null => (Key::Null, KeyModifiers::NONE),
+            TonKey::Esc => (Key::Esc, KeyModifiers::NONE),
+            TonKey::__IsNotComplete => (Key::Null, KeyModifiers::NONE),
+            TonKey::ShiftLeft => (Key::Left, KeyModifiers::SHIFT),
+            TonKey::AltLeft => (Key::Left, KeyModifiers::ALT),
+            TonKey::CtrlLeft => (Key::Left, KeyModifiers::CONTROL),
+            TonKey::ShiftRight => (Key::Right, KeyModifiers::SHIFT),
+            TonKey::AltRight => (Key::Right, KeyModifiers::ALT),
+            TonKey::CtrlRight => (Key::Right, KeyModifiers::CONTROL),
+            TonKey::ShiftUp => (Key::Up, KeyModifiers::SHIFT),
+            TonKey::AltUp => (Key::Up, KeyModifiers::ALT),
+            TonKey::CtrlUp => (Key::Up, KeyModifiers::CONTROL),
+            TonKey::ShiftDown => (Key::Down, KeyModifiers::SHIFT),
+            TonKey::AltDown => (Key::Down, KeyModifiers::ALT),
+            TonKey::CtrlDown => (Key::Down, KeyModifiers::CONTROL),
+            TonKey::CtrlHome => (Key::Home, KeyModifiers::CONTROL),
+            TonKey::CtrlEnd => (Key::End, KeyModifiers::CONTROL),
         };
-        let code = match e {
-            TonKey::Alt('\n') | TonKey::Char('\n') | TonKey::Ctrl('\n') => Key::Enter,
-            TonKey::Alt('\t') | TonKey::Char('\t') | TonKey::Ctrl('\t') => Key::Tab,
-            TonKey::Alt(c) | TonKey::Char(c) | TonKey::Ctrl(c) => Key::Char(c.to_ascii_lowercase()),
-            TonKey::BackTab => Key::BackTab,
-            TonKey::Backspace => Key::Backspace,
-            TonKey::Delete => Key::Delete,
-            TonKey::Down => Key::Down,
-            TonKey::End => Key::End,
-            TonKey::Left => Key::Left,
-            TonKey::Right => Key::Right,
-            TonKey::Up => Key::Up,
-            TonKey::Home => Key::Home,
-            TonKey::PageUp => Key::PageUp,
-            TonKey::PageDown => Key::PageDown,
-            TonKey::Insert => Key::Insert,
-            TonKey::F(f) => Key::Function(f),
-            TonKey::Null => Key::Null,
-            TonKey::Esc => Key::Esc,
-            TonKey::__IsNotComplete => Key::Null,
-            TonKey::ShiftLeft => Key::ShiftLeft,
-            TonKey::AltLeft => Key::AltLeft,
-            TonKey::CtrlLeft => Key::CtrlLeft,
-            TonKey::ShiftRight => Key::ShiftRight,
-            TonKey::AltRight => Key::AltRight,
-            TonKey::CtrlRight => Key::CtrlRight,
-            TonKey::ShiftUp => Key::ShiftUp,
-            TonKey::AltUp => Key::AltUp,
-            TonKey::CtrlUp => Key::CtrlUp,
-            TonKey::ShiftDown => Key::ShiftDown,
-            TonKey::AltDown => Key::AltDown,
-            TonKey::CtrlDown => Key::CtrlDown,
-            TonKey::CtrlHome => Key::CtrlHome,
-            TonKey::CtrlEnd => Key::CtrlEnd,
-        };
-        Self { code, modifiers }
+        Self::new(code, modifiers)
+    }
+}
+
+/// Convert the char `c` of a termion key; uppercase chars are lowercased with [`KeyModifiers::SHIFT`]
+fn char_key(c: char) -> (Key, KeyModifiers) {
+    match c {
+        '\n' => (Key::Enter, KeyModifiers::NONE),
+        '\t' => (Key::Tab, KeyModifiers::NONE),
+        c if c.is_uppercase() => (Key::Char(c.to_ascii_lowercase()), KeyModifiers::SHIFT),
+        c => (Key::Char(c), KeyModifiers::NONE),
+    }
+}
+
+/// Parse the CSI sequences of the modified keys which termion doesn't parse:
+/// `ESC [ 1 ; m X`, for the arrows, home, end and F1-F4, and `ESC [ n ; m ~`, for the other keys,
+/// where `m - 1` is the bitmask of the modifiers (shift 1, alt 2, ctrl 4, super 8, hyper 16, meta 32).
+fn parse_csi_key(bytes: &[u8]) -> Option<KeyEvent> {
+    let (last, params) = bytes.strip_prefix(b"\x1b[")?.split_last()?;
+    let mut params = std::str::from_utf8(params)
+        .ok()?
+        .split(';')
+        .map(|x| x.parse::<u8>().ok());
+    let number = params.next()??;
+    let modifiers = match params.next() {
+        Some(Some(m)) if m >= 1 => csi_modifiers(m - 1),
+        None => KeyModifiers::NONE,
+        Some(_) => return None,
+    };
+    if params.next().is_some() {
+        return None;
     }
+    let code = match (*last, number) {
+        (b'A', 1) => Key::Up,
+        (b'B', 1) => Key::Down,
+        (b'C', 1) => Key::Right,
+        (b'D', 1) => Key::Left,
+        (b'H', 1) => Key::Home,
+        (b'F', 1) => Key::End,
+        (b'P', 1) => Key::Function(1),
+        (b'Q', 1) => Key::Function(2),
+        (b'R', 1) => Key::Function(3),
+        (b'S', 1) => Key::Function(4),
+        (b'~', 1 | 7) => Key::Home,
+        (b'~', 2) => Key::Insert,
+        (b'~', 3) => Key::Delete,
+        (b'~', 4 | 8) => Key::End,
+        (b'~', 5) => Key::PageUp,
+        (b'~', 6) => Key::PageDown,
+        (b'~', n @ 11..=15) => Key::Function(n - 10),
+        (b'~', n @ 17..=21) => Key::Function(n - 11),
+        (b'~', n @ 23..=26) => Key::Function(n - 12),
+        (b'~', n @ 28..=29) => Key::Function(n - 13),
+        (b'~', n @ 31..=34) => Key::Function(n - 14),
+        _ => return None,
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Convert the modifiers bitmask of a CSI sequence
+fn csi_modifiers(mask: u8) -> KeyModifiers {
+    [
+        (1, KeyModifiers::SHIFT),
+        (2, KeyModifiers::ALT),
+        (4, KeyModifiers::CONTROL),
+        (8, KeyModifiers::SUPER),
+        (16, KeyModifiers::HYPER),
+        (32, KeyModifiers::META),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| mask & bit != 0)
+    .fold(KeyModifiers::NONE, |modifiers, (_, modifier)| {
+        modifiers | modifier
+    })
 }
 
 #[cfg(test)]
 mod test {
 
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
     use pretty_assertions::assert_eq;
     use termion::event::MouseEvent;
 
     use super::*;
     use crate::mock::MockEvent;
 
+    #[test]
+    fn termion_listener_should_not_block() {
+        // events are read slowly
+        let events = (0..2).map(|_| {
+            thread::sleep(Duration::from_millis(500));
+            Ok(TonEvent::Key(TonKey::Char('a')))
+        });
+        let mut listener = TermionInputListener::<MockEvent>::from_events(events);
+        let started = Instant::now();
+        assert!(listener.poll().unwrap().is_none());
+        assert!(started.elapsed() < Duration::from_millis(50));
+        thread::sleep(Duration::from_millis(600));
+        assert_eq!(
+            listener.poll().unwrap(),
+            Some(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+        );
+        // errors
+        let mut listener = TermionInputListener::<MockEvent>::from_events(std::iter::once(Err(
+            io::Error::other("omar"),
+        )));
+        thread::sleep(Duration::from_millis(100));
+        assert!(matches!(listener.poll(), Err(ListenerError::PollFailed)));
+        // closed
+        assert!(listener.poll().unwrap().is_none());
+    }
+
+    #[test]
+    fn termion_listener_should_convert_events_with_raw_hook() {
+        let events = vec![
+            Ok(TonEvent::Mouse(MouseEvent::Hold(1, 1))),
+            Ok(TonEvent::Key(TonKey::Char('a'))),
+        ];
+        let mut listener = TermionInputListener::<MockEvent>::from_events(events.into_iter())
+            .with_raw_hook(|ev| match ev {
+                TonEvent::Mouse(_) => Some(Event::User(MockEvent::Foo)),
+                _ => None,
+            });
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(listener.poll().unwrap(), Some(Event::User(MockEvent::Foo)));
+        assert_eq!(
+            listener.poll().unwrap(),
+            Some(Event::Keyboard(KeyEvent::from(Key::Char('a'))))
+        );
+    }
+
+    #[test]
+    fn termion_reader_should_terminate_when_listener_is_dropped() {
+        let read = Arc::new(AtomicUsize::new(0));
+        let read_t = Arc::clone(&read);
+        let events = std::iter::repeat_with(move || {
+            read_t.fetch_add(1, Ordering::SeqCst);
+            Ok(TonEvent::Key(TonKey::Esc))
+        });
+        let listener = TermionInputListener::<MockEvent>::from_events(events);
+        thread::sleep(Duration::from_millis(100));
+        // the buffer is full
+        assert!(read.load(Ordering::SeqCst) <= EVENTS_BUFFER_SIZE + 2);
+        drop(listener);
+        thread::sleep(Duration::from_millis(100));
+        let total = read.load(Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(read.load(Ordering::SeqCst), total);
+    }
+
     #[test]
     fn adapt_termion_key_event() {
         assert_eq!(
@@ -127,25 +339,20 @@ mod test {
         );
         assert_eq!(
             KeyEvent::from(TonKey::Ctrl('b')),
-            KeyEvent::from(KeyEvent {
-                code: Key::Char('b'),
-                modifiers: KeyModifiers::CONTROL,
-            })
+            KeyEvent::new(Key::Char('b'), KeyModifiers::CONTROL)
         );
         assert_eq!(
             KeyEvent::from(TonKey::Alt('b')),
-            KeyEvent::from(KeyEvent {
-                code: Key::Char('b'),
-                modifiers: KeyModifiers::ALT
-            })
+            KeyEvent::new(Key::Char('b'), KeyModifiers::ALT)
         );
         assert_eq!(
             KeyEvent::from(TonKey::Char('B')),
-            KeyEvent::from(KeyEvent {
-                code: Key::Char('b'),
-                modifiers: KeyModifiers::SHIFT,
-            })
+            KeyEvent::new(Key::Char('b'), KeyModifiers::SHIFT)
         );
+        // NOTE: termion can't report super, hyper and meta
+        assert!(!KeyEvent::from(TonKey::Alt('B'))
+            .modifiers
+            .intersects(KeyModifiers::SUPER | KeyModifiers::HYPER | KeyModifiers::META));
         assert_eq!(KeyEvent::from(TonKey::Delete), KeyEvent::from(Key::Delete));
         assert_eq!(KeyEvent::from(TonKey::Down), KeyEvent::from(Key::Down));
         assert_eq!(KeyEvent::from(TonKey::End), KeyEvent::from(Key::End));
@@ -174,6 +381,90 @@ mod test {
             KeyEvent::from(TonKey::__IsNotComplete),
             KeyEvent::from(Key::Null)
         );
+        assert_eq!(
+            KeyEvent::from(TonKey::Alt('\n')),
+            KeyEvent::new(Key::Enter, KeyModifiers::ALT)
+        );
+        assert_eq!(
+            KeyEvent::from(TonKey::Ctrl('B')),
+            KeyEvent::new(Key::Char('b'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+        );
+        assert_eq!(
+            KeyEvent::from(TonKey::Alt('é')),
+            KeyEvent::new(Key::Char('é'), KeyModifiers::ALT)
+        );
+    }
+
+    #[test]
+    fn adapt_termion_modified_keys() {
+        let keys = [
+            (TonKey::ShiftLeft, Key::Left, KeyModifiers::SHIFT),
+            (TonKey::AltLeft, Key::Left, KeyModifiers::ALT),
+            (TonKey::CtrlLeft, Key::Left, KeyModifiers::CONTROL),
+            (TonKey::ShiftRight, Key::Right, KeyModifiers::SHIFT),
+            (TonKey::AltRight, Key::Right, KeyModifiers::ALT),
+            (TonKey::CtrlRight, Key::Right, KeyModifiers::CONTROL),
+            (TonKey::ShiftUp, Key::Up, KeyModifiers::SHIFT),
+            (TonKey::AltUp, Key::Up, KeyModifiers::ALT),
+            (TonKey::CtrlUp, Key::Up, KeyModifiers::CONTROL),
+            (TonKey::ShiftDown, Key::Down, KeyModifiers::SHIFT),
+            (TonKey::AltDown, Key::Down, KeyModifiers::ALT),
+            (TonKey::CtrlDown, Key::Down, KeyModifiers::CONTROL),
+            (TonKey::CtrlHome, Key::Home, KeyModifiers::CONTROL),
+            (TonKey::CtrlEnd, Key::End, KeyModifiers::CONTROL),
+        ];
+        for (key, code, modifiers) in keys {
+            assert_eq!(KeyEvent::from(key), KeyEvent::new(code, modifiers));
+        }
+    }
+
+    #[test]
+    fn adapt_termion_unsupported_csi_sequences() {
+        type AppEvent = Event<MockEvent>;
+        let sequences: [(&[u8], Key, KeyModifiers); 10] = [
+            (b"\x1b[1;2P", Key::Function(1), KeyModifiers::SHIFT),
+            (b"\x1b[1;5S", Key::Function(4), KeyModifiers::CONTROL),
+            (
+                b"\x1b[1;7A",
+                Key::Up,
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+            ),
+            (
+                b"\x1b[1;4H",
+                Key::Home,
+                KeyModifiers::SHIFT | KeyModifiers::ALT,
+            ),
+            (b"\x1b[15;2~", Key::Function(5), KeyModifiers::SHIFT),
+            (b"\x1b[24;5~", Key::Function(12), KeyModifiers::CONTROL),
+            (b"\x1b[3;3~", Key::Delete, KeyModifiers::ALT),
+            (
+                b"\x1b[6;6~",
+                Key::PageDown,
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            ),
+            (b"\x1b[1;9D", Key::Left, KeyModifiers::SUPER),
+            (b"\x1b[2~", Key::Insert, KeyModifiers::NONE),
+        ];
+        for (bytes, code, modifiers) in sequences {
+            assert_eq!(
+                AppEvent::from(TonEvent::Unsupported(bytes.to_vec())),
+                Event::Keyboard(KeyEvent::new(code, modifiers))
+            );
+        }
+        // unknown sequences
+        for bytes in [
+            &b"\x1b[200~"[..],
+            b"\x1b[1;0A",
+            b"\x1b[1;2;3A",
+            b"\x1b[;2A",
+            b"\x1bOP",
+            b"",
+        ] {
+            assert_eq!(
+                AppEvent::from(TonEvent::Unsupported(bytes.to_vec())),
+                Event::None
+            );
+        }
     }
 
     #[test]
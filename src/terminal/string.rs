@@ -0,0 +1,238 @@
+//! ## String
+//!
+//! This module exposes the helpers to render the view to a string, as plain text or with ANSI escape sequences
+
+use std::fmt::{Debug, Write};
+use std::hash::Hash;
+
+use unicode_width::UnicodeWidthStr;
+
+use super::{TerminalBridge, TestTerminalAdapter};
+use crate::ratatui::buffer::{Buffer, Cell};
+use crate::ratatui::layout::Rect;
+use crate::ratatui::style::{Color, Modifier, Style};
+use crate::Application;
+
+/// A [`TerminalBridge`] which renders on an in-memory buffer, which can then be read as a string
+/// with [`TerminalBridge::plain_text`] or [`TerminalBridge::ansi_text`].
+///
+/// It doesn't need any terminal, nor any backend feature, so it can be used to print the view (e.g. for a `--print-ui` flag),
+/// to write snapshots or to serve the view over the network.
+///
+/// ```rust
+/// use tuirealm::terminal::StringBackendBridge;
+///
+/// let mut terminal = StringBackendBridge::new_test(20, 2);
+/// assert!(terminal.draw(|f| { /* app.view(&Id::Foo, f, f.area()); */ }).is_ok());
+/// assert_eq!(terminal.plain_text(), format!("{0}\n{0}\n", " ".repeat(20)));
+/// ```
+pub type StringBackendBridge = TerminalBridge<TestTerminalAdapter>;
+
+/// Render the components `views`, each one in its area, on a `width` x `height` buffer and return it as plain text.
+///
+/// Components are rendered in order, so the latter ones are drawn on top of the former ones;
+/// components which are not mounted are skipped.
+pub fn render_to_string<K, Msg, UserEvent>(
+    app: &mut Application<K, Msg, UserEvent>,
+    views: &[(K, Rect)],
+    width: u16,
+    height: u16,
+) -> String
+where
+    K: Eq + PartialEq + Clone + Hash + Debug,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    let mut terminal = StringBackendBridge::new_test(width, height);
+    // NOTE: drawing on the test adapter can't fail
+    let _ = terminal.draw(|f| {
+        for (id, area) in views {
+            app.view(id, f, *area);
+        }
+    });
+    terminal.plain_text()
+}
+
+/// Convert `buffer` into plain text: one line for each row, terminated by a new line
+pub fn buffer_to_string(buffer: &Buffer) -> String {
+    let mut text = String::new();
+    for row in rows(buffer) {
+        row.iter().for_each(|cell| text.push_str(cell.symbol()));
+        text.push('\n');
+    }
+    text
+}
+
+/// Convert `buffer` into text with ANSI escape sequences for the cell styles: one line for each row, terminated by
+/// a new line. The style is reset at the end of each row
+pub fn buffer_to_ansi_string(buffer: &Buffer) -> String {
+    let mut text = String::new();
+    for row in rows(buffer) {
+        let mut current = Style::default();
+        for cell in row {
+            let style = without_reset_colors(cell.style());
+            if style != current {
+                push_sgr(&mut text, style);
+                current = style;
+            }
+            text.push_str(cell.symbol());
+        }
+        if current != Style::default() {
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Returns the cells of the buffer, row by row, skipping the cells covered by wide symbols
+fn rows(buffer: &Buffer) -> Vec<Vec<&Cell>> {
+    let width = buffer.area.width as usize;
+    if width == 0 {
+        return Vec::new();
+    }
+    buffer
+        .content()
+        .chunks(width)
+        .map(|row| {
+            let mut skip = 0;
+            row.iter()
+                .filter(|cell| {
+                    if skip > 0 {
+                        skip -= 1;
+                        return false;
+                    }
+                    skip = cell.symbol().width().saturating_sub(1);
+                    true
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns `style` without the colors set to [`Color::Reset`], which are the terminal default colors
+fn without_reset_colors(mut style: Style) -> Style {
+    if style.fg == Some(Color::Reset) {
+        style.fg = None;
+    }
+    if style.bg == Some(Color::Reset) {
+        style.bg = None;
+    }
+    style
+}
+
+/// Push the SGR sequence which resets the style and then sets `style`
+fn push_sgr(text: &mut String, style: Style) {
+    text.push_str("\x1b[0");
+    let modifiers = style.add_modifier - style.sub_modifier;
+    for (modifier, code) in [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ] {
+        if modifiers.contains(modifier) {
+            let _ = write!(text, ";{code}");
+        }
+    }
+    if let Some(fg) = style.fg {
+        push_color(text, fg, 30);
+    }
+    if let Some(bg) = style.bg {
+        push_color(text, bg, 40);
+    }
+    text.push('m');
+}
+
+/// Push the SGR parameters of `color`; `base` is 30 for foreground and 40 for background
+fn push_color(text: &mut String, color: Color, base: u8) {
+    let _ = match color {
+        Color::Reset => write!(text, ";{}", base + 9),
+        Color::Black => write!(text, ";{}", base),
+        Color::Red => write!(text, ";{}", base + 1),
+        Color::Green => write!(text, ";{}", base + 2),
+        Color::Yellow => write!(text, ";{}", base + 3),
+        Color::Blue => write!(text, ";{}", base + 4),
+        Color::Magenta => write!(text, ";{}", base + 5),
+        Color::Cyan => write!(text, ";{}", base + 6),
+        Color::Gray => write!(text, ";{}", base + 7),
+        Color::DarkGray => write!(text, ";{}", base + 60),
+        Color::LightRed => write!(text, ";{}", base + 61),
+        Color::LightGreen => write!(text, ";{}", base + 62),
+        Color::LightYellow => write!(text, ";{}", base + 63),
+        Color::LightBlue => write!(text, ";{}", base + 64),
+        Color::LightMagenta => write!(text, ";{}", base + 65),
+        Color::LightCyan => write!(text, ";{}", base + 66),
+        Color::White => write!(text, ";{}", base + 67),
+        Color::Indexed(i) => write!(text, ";{};5;{}", base + 8, i),
+        Color::Rgb(r, g, b) => write!(text, ";{};2;{};{};{}", base + 8, r, g, b),
+    };
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::{MockComponentId, MockEvent, MockFooInput, MockMsg};
+
+    #[test]
+    fn should_convert_buffer_to_plain_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(0, 0, "omar", Style::default().fg(Color::Red));
+        buffer.set_string(1, 1, "日本", Style::default());
+        assert_eq!(buffer_to_string(&buffer), "omar  \n 日本 \n");
+        assert_eq!(buffer_to_string(&Buffer::empty(Rect::default())), "");
+    }
+
+    #[test]
+    fn should_convert_buffer_to_ansi_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buffer.set_string(
+            0,
+            0,
+            "ab",
+            Style::default()
+                .fg(Color::Red)
+                .bg(Color::Rgb(1, 2, 3))
+                .add_modifier(Modifier::BOLD),
+        );
+        buffer.set_string(0, 1, "cd", Style::default().fg(Color::Indexed(42)));
+        buffer.set_string(2, 1, "ef", Style::default().fg(Color::LightBlue));
+        assert_eq!(
+            buffer_to_ansi_string(&buffer),
+            "\x1b[0;1;31;48;2;1;2;3mab\x1b[0m  \n\x1b[0;38;5;42mcd\x1b[0;94mef\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn should_render_application_to_string() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init_headless();
+        assert!(application
+            .mount(
+                MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+                vec![]
+            )
+            .is_ok());
+        let text = render_to_string(
+            &mut application,
+            &[
+                (MockComponentId::InputFoo, Rect::new(0, 0, 10, 3)),
+                // not mounted
+                (MockComponentId::InputBar, Rect::new(0, 3, 10, 1)),
+            ],
+            10,
+            4,
+        );
+        assert_eq!(text.lines().count(), 4);
+        assert!(text.lines().all(|line| line.chars().count() == 10));
+        assert_eq!(text.lines().last().unwrap(), " ".repeat(10));
+    }
+}
@@ -0,0 +1,20 @@
+//! ## Trace
+//!
+//! This module exposes the macros used to instrument tui-realm with [`tracing`](https://docs.rs/tracing).
+//! The macros expand to nothing if the `tracing` feature is disabled.
+
+/// Emit a `TRACE` level event, if the `tracing` feature is enabled
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!($($arg)*);
+    };
+}
+
+/// Emit a `DEBUG` level event, if the `tracing` feature is enabled
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)*);
+    };
+}
@@ -3,6 +3,7 @@
 //! This module exposes utilities
 
 pub mod parser;
+pub mod text;
 mod types;
 
 // export types
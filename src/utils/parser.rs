@@ -7,7 +7,7 @@ use std::str::FromStr;
 use lazy_regex::{Lazy, Regex};
 
 use super::{Email, PhoneNumber};
-use crate::ratatui::style::Color;
+use crate::ratatui::style::{Color, Modifier, Style};
 /**
  * Regex matches:
  * - group 1: Red
@@ -15,7 +15,7 @@ use crate::ratatui::style::Color;
  * - group 3: Blue
  */
 static COLOR_HEX_REGEX: Lazy<Regex> =
-    lazy_regex!(r"#(:?[0-9a-fA-F]{2})(:?[0-9a-fA-F]{2})(:?[0-9a-fA-F]{2})");
+    lazy_regex!(r"^#([0-9a-fA-F]{2})([0-9a-fA-F]{2})([0-9a-fA-F]{2})$");
 /**
  * Regex matches:
  * - group 2: Red
@@ -23,9 +23,28 @@ static COLOR_HEX_REGEX: Lazy<Regex> =
  * - group 6: blue
  */
 static COLOR_RGB_REGEX: Lazy<Regex> = lazy_regex!(
-    r"^(rgb)?\(?([01]?\d\d?|2[0-4]\d|25[0-5])(\W+)([01]?\d\d?|2[0-4]\d|25[0-5])\W+(([01]?\d\d?|2[0-4]\d|25[0-5])\)?)"
+    r"^(rgb)?\(?([01]?\d\d?|2[0-4]\d|25[0-5])(\W+)([01]?\d\d?|2[0-4]\d|25[0-5])\W+(([01]?\d\d?|2[0-4]\d|25[0-5])\)?)$"
 );
 
+/**
+ * Regex matches:
+ * - group 1: palette index
+ */
+static COLOR_INDEXED_REGEX: Lazy<Regex> = lazy_regex!(r"^color(\d{1,3})$");
+
+/// Text modifiers with the name used by [`parse_style`] and [`style_to_string`]
+const MODIFIERS: [(Modifier, &str); 9] = [
+    (Modifier::BOLD, "bold"),
+    (Modifier::DIM, "dim"),
+    (Modifier::ITALIC, "italic"),
+    (Modifier::UNDERLINED, "underlined"),
+    (Modifier::SLOW_BLINK, "slow-blink"),
+    (Modifier::RAPID_BLINK, "rapid-blink"),
+    (Modifier::REVERSED, "reversed"),
+    (Modifier::HIDDEN, "hidden"),
+    (Modifier::CROSSED_OUT, "crossed-out"),
+];
+
 /**
  * Regex matches:
  * - group 1: name
@@ -87,7 +106,7 @@ pub fn parse_phone_number(s: &str) -> Option<PhoneNumber> {
 ///
 /// Color may be in different format:
 ///
-/// 1. color name:
+/// 1. color name (case insensitive; words may be separated by `-` or `_`, e.g. `light-blue`):
 ///     - Black,
 ///     - Blue,
 ///     - Cyan,
@@ -112,8 +131,11 @@ pub fn parse_phone_number(s: &str) -> Option<PhoneNumber> {
 ///     - rgb(255, 64, 32)
 ///     - rgb(255,64,32)
 ///     - 255, 64, 32
+/// 4. 256-colors palette index:
+///     - color123
 pub fn parse_color(color: &str) -> Option<Color> {
-    match color.to_lowercase().as_str() {
+    let color = color.trim().to_lowercase();
+    match color.replace(['-', '_'], "").as_str() {
         // -- lib colors
         "black" => Some(Color::Black),
         "blue" => Some(Color::Blue),
@@ -261,16 +283,151 @@ pub fn parse_color(color: &str) -> Option<Color> {
         "wheat" => Some(Color::Rgb(245, 222, 179)),
         "whitesmoke" => Some(Color::Rgb(245, 245, 245)),
         "yellowgreen" => Some(Color::Rgb(154, 205, 50)),
-        // -- hex and rgb
-        other => {
-            // Try as hex
-            if let Some(color) = parse_hex_color(other) {
-                Some(color)
-            } else {
-                parse_rgb_color(other)
+        // -- hex, rgb and indexed
+        _ => parse_hex_color(&color)
+            .or_else(|| parse_rgb_color(&color))
+            .or_else(|| parse_indexed_color(&color)),
+    }
+}
+
+/// Convert `color` into a string which can be parsed back with [`parse_color`].
+///
+/// ```rust
+/// use tuirealm::props::Color;
+/// use tuirealm::utils::parser::color_to_string;
+/// assert_eq!(color_to_string(Color::LightBlue), "light-blue");
+/// assert_eq!(color_to_string(Color::Rgb(32, 32, 32)), "#202020");
+/// assert_eq!(color_to_string(Color::Indexed(123)), "color123");
+/// ```
+pub fn color_to_string(color: Color) -> String {
+    match color {
+        Color::Reset => String::from("default"),
+        Color::Black => String::from("black"),
+        Color::Red => String::from("red"),
+        Color::Green => String::from("green"),
+        Color::Yellow => String::from("yellow"),
+        Color::Blue => String::from("blue"),
+        Color::Magenta => String::from("magenta"),
+        Color::Cyan => String::from("cyan"),
+        Color::Gray => String::from("gray"),
+        Color::DarkGray => String::from("dark-gray"),
+        Color::LightRed => String::from("light-red"),
+        Color::LightGreen => String::from("light-green"),
+        Color::LightYellow => String::from("light-yellow"),
+        Color::LightBlue => String::from("light-blue"),
+        Color::LightMagenta => String::from("light-magenta"),
+        Color::LightCyan => String::from("light-cyan"),
+        Color::White => String::from("white"),
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Indexed(i) => format!("color{i}"),
+    }
+}
+
+/// Parse a style from a compact spec made of whitespace separated `key=value` pairs, where keys are:
+///
+/// - `fg`: the foreground color, in any format supported by [`parse_color`]
+/// - `bg`: the background color, in any format supported by [`parse_color`]
+/// - `mods`: comma separated text modifiers (`bold`, `dim`, `italic`, `underlined`, `slow-blink`, `rapid-blink`,
+///   `reversed`, `hidden`, `crossed-out`)
+///
+/// Returns `None` if a key is unknown or a value is invalid.
+///
+/// ```rust
+/// use tuirealm::props::{Color, Style, TextModifiers};
+/// use tuirealm::utils::parser::parse_style;
+/// assert_eq!(
+///     parse_style("fg=red bg=#202020 mods=bold,underlined").unwrap(),
+///     Style::default()
+///         .fg(Color::Red)
+///         .bg(Color::Rgb(32, 32, 32))
+///         .add_modifier(TextModifiers::BOLD | TextModifiers::UNDERLINED)
+/// );
+/// ```
+pub fn parse_style(style: &str) -> Option<Style> {
+    let mut parsed = Style::default();
+    for token in split_style_tokens(style) {
+        let (key, value) = token.split_once('=')?;
+        parsed = match key.to_lowercase().as_str() {
+            "fg" => parsed.fg(parse_color(value)?),
+            "bg" => parsed.bg(parse_color(value)?),
+            "mods" => parsed.add_modifier(parse_modifiers(value)?),
+            _ => return None,
+        };
+    }
+    Some(parsed)
+}
+
+/// Convert `style` into a spec which can be parsed back with [`parse_style`].
+/// The modifiers removed by the style are not reported.
+///
+/// ```rust
+/// use tuirealm::props::{Color, Style, TextModifiers};
+/// use tuirealm::utils::parser::style_to_string;
+/// assert_eq!(
+///     style_to_string(Style::default().fg(Color::Red).add_modifier(TextModifiers::BOLD)),
+///     "fg=red mods=bold"
+/// );
+/// ```
+pub fn style_to_string(style: Style) -> String {
+    let mut spec = Vec::new();
+    if let Some(fg) = style.fg {
+        spec.push(format!("fg={}", color_to_string(fg)));
+    }
+    if let Some(bg) = style.bg {
+        spec.push(format!("bg={}", color_to_string(bg)));
+    }
+    let mods: Vec<&str> = MODIFIERS
+        .iter()
+        .filter(|(modifier, _)| style.add_modifier.contains(*modifier))
+        .map(|(_, name)| *name)
+        .collect();
+    if !mods.is_empty() {
+        spec.push(format!("mods={}", mods.join(",")));
+    }
+    spec.join(" ")
+}
+
+/// Split a style spec at whitespaces, except for the whitespaces between parentheses (e.g. in `fg=rgb(1, 2, 3)`)
+fn split_style_tokens(spec: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+    for (i, c) in spec.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    tokens.push(&spec[start..i]);
+                }
+                continue;
             }
+            _ => {}
         }
+        start.get_or_insert(i);
+    }
+    if let Some(start) = start {
+        tokens.push(&spec[start..]);
     }
+    tokens
+}
+
+/// Parse comma separated text modifiers, such as "bold,underlined".
+/// Modifier names are case insensitive and `-` and `_` are ignored.
+fn parse_modifiers(mods: &str) -> Option<Modifier> {
+    let mut parsed = Modifier::empty();
+    for name in mods.split(',').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+        parsed |= match name.to_lowercase().replace(['-', '_'], "").as_str() {
+            "underline" => Modifier::UNDERLINED,
+            "reverse" => Modifier::REVERSED,
+            "strikethrough" => Modifier::CROSSED_OUT,
+            name => MODIFIERS
+                .iter()
+                .find(|(_, x)| x.replace('-', "") == name)
+                .map(|(modifier, _)| *modifier)?,
+        };
+    }
+    Some(parsed)
 }
 
 /// ### parse_hex_color
@@ -295,6 +452,16 @@ fn parse_hex_color(color: &str) -> Option<Color> {
     })
 }
 
+/// Try to parse a color of the 256-colors palette, such as:
+///
+/// - "color123"
+fn parse_indexed_color(color: &str) -> Option<Color> {
+    COLOR_INDEXED_REGEX
+        .captures(color)
+        .and_then(|groups| u8::from_str(groups.get(1).unwrap().as_str()).ok())
+        .map(Color::Indexed)
+}
+
 /// Try to parse a color in rgb format, such as:
 ///
 /// - "rgb(255, 64, 32)"
@@ -549,4 +716,132 @@ mod test {
         );
         assert!(parse_color("redd").is_none());
     }
+
+    #[test]
+    fn utils_parse_color_formats() {
+        assert_eq!(parse_color("light-blue"), Some(Color::LightBlue));
+        assert_eq!(parse_color("Light_Magenta"), Some(Color::LightMagenta));
+        assert_eq!(parse_color("dark-gray"), Some(Color::DarkGray));
+        assert_eq!(parse_color(" red "), Some(Color::Red));
+        assert_eq!(parse_color("#AA33bc"), Some(Color::Rgb(170, 51, 188)));
+        assert_eq!(parse_color("rgb(255,64,32)"), Some(Color::Rgb(255, 64, 32)));
+        assert_eq!(parse_color("255, 64, 32"), Some(Color::Rgb(255, 64, 32)));
+        assert_eq!(parse_color("color0"), Some(Color::Indexed(0)));
+        assert_eq!(parse_color("Color123"), Some(Color::Indexed(123)));
+        assert_eq!(parse_color("color255"), Some(Color::Indexed(255)));
+        // invalid hex lengths
+        assert_eq!(parse_color("#f0f0f"), None);
+        assert_eq!(parse_color("#f0f0f0f"), None);
+        assert_eq!(parse_color("#f0f0f0f0"), None);
+        assert_eq!(parse_color("#fff"), None);
+        assert_eq!(parse_color("#gg0000"), None);
+        // out of range rgb
+        assert_eq!(parse_color("rgb(256, 0, 0)"), None);
+        assert_eq!(parse_color("rgb(0, 300, 0)"), None);
+        assert_eq!(parse_color("rgb(0, 0, 1000)"), None);
+        assert_eq!(parse_color("rgb(0, 0)"), None);
+        // out of range indexes
+        assert_eq!(parse_color("color256"), None);
+        assert_eq!(parse_color("color1234"), None);
+        assert_eq!(parse_color("color"), None);
+        assert_eq!(parse_color("color-1"), None);
+        assert_eq!(parse_color(""), None);
+    }
+
+    #[test]
+    fn utils_color_to_string() {
+        for color in [
+            Color::Reset,
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::Gray,
+            Color::DarkGray,
+            Color::LightRed,
+            Color::LightGreen,
+            Color::LightYellow,
+            Color::LightBlue,
+            Color::LightMagenta,
+            Color::LightCyan,
+            Color::White,
+            Color::Rgb(0, 128, 255),
+            Color::Indexed(42),
+        ] {
+            assert_eq!(parse_color(&color_to_string(color)), Some(color));
+        }
+        assert_eq!(color_to_string(Color::LightBlue).as_str(), "light-blue");
+        assert_eq!(color_to_string(Color::Rgb(0, 128, 255)).as_str(), "#0080ff");
+        assert_eq!(color_to_string(Color::Indexed(42)).as_str(), "color42");
+    }
+
+    #[test]
+    fn utils_parse_style() {
+        assert_eq!(
+            parse_style("fg=red bg=#202020 mods=bold,underline"),
+            Some(
+                Style::default()
+                    .fg(Color::Red)
+                    .bg(Color::Rgb(32, 32, 32))
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            )
+        );
+        assert_eq!(
+            parse_style("  FG=light-blue   bg=rgb(1, 2, 3)  "),
+            Some(
+                Style::default()
+                    .fg(Color::LightBlue)
+                    .bg(Color::Rgb(1, 2, 3))
+            )
+        );
+        assert_eq!(
+            parse_style("mods=dim,italic,slow_blink,Rapid-Blink,reverse,hidden,strikethrough"),
+            Some(Style::default().add_modifier(
+                Modifier::DIM
+                    | Modifier::ITALIC
+                    | Modifier::SLOW_BLINK
+                    | Modifier::RAPID_BLINK
+                    | Modifier::REVERSED
+                    | Modifier::HIDDEN
+                    | Modifier::CROSSED_OUT
+            ))
+        );
+        assert_eq!(
+            parse_style("fg=color200"),
+            Some(Style::default().fg(Color::Indexed(200)))
+        );
+        assert_eq!(parse_style(""), Some(Style::default()));
+        assert_eq!(parse_style("mods="), Some(Style::default()));
+        // unknown modifier names
+        assert_eq!(parse_style("mods=bold,blinking"), None);
+        assert_eq!(
+            parse_style("mods=underlined,"),
+            Some(Style::default().add_modifier(Modifier::UNDERLINED))
+        );
+        // unknown keys and invalid values
+        assert_eq!(parse_style("color=red"), None);
+        assert_eq!(parse_style("fg=redd"), None);
+        assert_eq!(parse_style("bg=#2020"), None);
+        assert_eq!(parse_style("fg"), None);
+        assert_eq!(parse_style("fg = red"), None);
+    }
+
+    #[test]
+    fn utils_style_to_string() {
+        let style = Style::default()
+            .fg(Color::LightYellow)
+            .bg(Color::Rgb(32, 32, 32))
+            .add_modifier(Modifier::BOLD | Modifier::CROSSED_OUT | Modifier::SLOW_BLINK);
+        assert_eq!(
+            style_to_string(style).as_str(),
+            "fg=light-yellow bg=#202020 mods=bold,slow-blink,crossed-out"
+        );
+        assert_eq!(parse_style(&style_to_string(style)), Some(style));
+        let style = Style::default().add_modifier(Modifier::all());
+        assert_eq!(parse_style(&style_to_string(style)), Some(style));
+        assert_eq!(style_to_string(Style::default()).as_str(), "");
+    }
 }
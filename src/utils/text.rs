@@ -0,0 +1,453 @@
+//! ## Text
+//!
+//! This module exposes utilities to measure, truncate and wrap text according to its width on the terminal,
+//! which accounts for wide (e.g. CJK) characters and zero-width characters (e.g. combining marks).
+
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::props::TextWrap;
+
+/// The ellipsis appended by [`truncate_with_ellipsis`]
+const ELLIPSIS: char = '…';
+
+/// Returns the amount of terminal cells occupied by `s`
+///
+/// ```rust
+/// use tuirealm::utils::text::display_width;
+///
+/// assert_eq!(display_width("omar"), 4);
+/// assert_eq!(display_width("日本"), 4);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.width()
+}
+
+/// Returns the column of a cursor placed before the char at `index` of `s`, which is the width of the
+/// first `index` chars. Positions past the end of `s` occupy a cell each.
+///
+/// ```rust
+/// use tuirealm::utils::text::cursor_column;
+///
+/// assert_eq!(cursor_column("日本", 1), 2);
+/// assert_eq!(cursor_column("日本", 3), 5);
+/// ```
+pub fn cursor_column(s: &str, index: usize) -> usize {
+    let chars = s.chars().count();
+    let width: usize = s.chars().take(index).map(char_width).sum();
+    width + index.saturating_sub(chars)
+}
+
+/// Truncates `s` to fit into `max_width` cells, replacing the truncated part with an ellipsis.
+/// If `s` already fits, it is returned as it is.
+///
+/// ```rust
+/// use tuirealm::utils::text::truncate_with_ellipsis;
+///
+/// assert_eq!(truncate_with_ellipsis("hello world", 8), "hello w…");
+/// assert_eq!(truncate_with_ellipsis("日本語", 4), "日…");
+/// ```
+pub fn truncate_with_ellipsis(s: &str, max_width: u16) -> Cow<'_, str> {
+    let max_width = max_width as usize;
+    if display_width(s) <= max_width {
+        return Cow::Borrowed(s);
+    }
+    if max_width == 0 {
+        return Cow::Borrowed("");
+    }
+    // leave room for the ellipsis
+    let available = max_width - 1;
+    let mut width = 0;
+    let mut truncated = String::with_capacity(s.len());
+    for ch in s.chars() {
+        let ch_width = char_width(ch);
+        if width + ch_width > available {
+            break;
+        }
+        width += ch_width;
+        truncated.push(ch);
+    }
+    truncated.push(ELLIPSIS);
+    Cow::Owned(truncated)
+}
+
+/// Wraps `s` into lines which fit into `width` cells.
+///
+/// Lines are broken at whitespaces; words longer than `width` are split. Newlines in `s` always start a new line.
+/// A character wider than `width` is placed on its own line.
+/// If `width` is `0`, no line is returned.
+///
+/// ```rust
+/// use tuirealm::utils::text::wrap;
+///
+/// assert_eq!(wrap("the quick brown fox", 10), vec!["the quick", "brown fox"]);
+/// ```
+pub fn wrap(s: &str, width: u16) -> Vec<String> {
+    wrap_with(s, width, TextWrap::WordBreak)
+}
+
+/// Wraps `s` into lines which fit into `width` cells, according to `mode`. Newlines in `s` always start a new line.
+///
+/// - [`TextWrap::None`]: lines are not wrapped, so they may exceed `width`
+/// - [`TextWrap::Char`]: lines are broken at the last char which fits, whitespaces included
+/// - [`TextWrap::Word`]: lines are broken at whitespaces; words longer than `width` exceed it
+/// - [`TextWrap::WordBreak`]: the same as [`wrap`]
+///
+/// If `width` is `0`, no line is returned.
+///
+/// ```rust
+/// use tuirealm::props::TextWrap;
+/// use tuirealm::utils::text::wrap_with;
+///
+/// assert_eq!(wrap_with("the quick fox", 6, TextWrap::Char), vec!["the qu", "ick fo", "x"]);
+/// assert_eq!(wrap_with("a wonderful day", 6, TextWrap::Word), vec!["a", "wonderful", "day"]);
+/// ```
+pub fn wrap_with(s: &str, width: u16, mode: TextWrap) -> Vec<String> {
+    let width = width as usize;
+    if width == 0 {
+        return Vec::new();
+    }
+    let mut lines = Vec::new();
+    for paragraph in s.split('\n') {
+        match mode {
+            TextWrap::None => lines.push(paragraph.to_string()),
+            TextWrap::Char => wrap_chars(paragraph, width, &mut lines),
+            TextWrap::Word => wrap_words(paragraph, width, false, &mut lines),
+            TextWrap::WordBreak => wrap_words(paragraph, width, true, &mut lines),
+        }
+    }
+    lines
+}
+
+/// Pushes to `lines` the lines of `paragraph` broken at the last char which fits into `width`
+fn wrap_chars(paragraph: &str, width: usize, lines: &mut Vec<String>) {
+    let mut line = String::new();
+    let mut line_width = 0;
+    for ch in paragraph.chars() {
+        let ch_width = char_width(ch);
+        if line_width + ch_width > width && !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        line.push(ch);
+        line_width += ch_width;
+    }
+    lines.push(line);
+}
+
+/// Pushes to `lines` the lines of `paragraph` broken at whitespaces.
+/// Words longer than `width` are split if `split_long_words` is set
+fn wrap_words(paragraph: &str, width: usize, split_long_words: bool, lines: &mut Vec<String>) {
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in paragraph.split_whitespace() {
+        let word_width = display_width(word);
+        let separator = usize::from(!line.is_empty());
+        if line_width + separator + word_width <= width {
+            if separator > 0 {
+                line.push(' ');
+            }
+            line.push_str(word);
+            line_width += separator + word_width;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if !split_long_words {
+            line.push_str(word);
+            line_width = word_width;
+            continue;
+        }
+        // split the word if it is longer than a line
+        for ch in word.chars() {
+            let ch_width = char_width(ch);
+            if line_width + ch_width > width && !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(ch);
+            line_width += ch_width;
+        }
+    }
+    lines.push(line);
+}
+
+/// Memoizes the lines produced by [`wrap_with`], so that a component can embed it and re-wrap its text only
+/// when the text, the width or the wrap mode change, instead of on each render.
+///
+/// ```rust
+/// use tuirealm::props::TextWrap;
+/// use tuirealm::utils::text::WrapCache;
+///
+/// let mut cache = WrapCache::default();
+/// assert_eq!(cache.get_or_wrap("the quick brown fox", 10, TextWrap::Word), ["the quick", "brown fox"]);
+/// // wrapped only once
+/// assert_eq!(cache.get_or_wrap("the quick brown fox", 10, TextWrap::Word).len(), 2);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct WrapCache {
+    /// Hash of the text, width and mode of the cached lines
+    key: Option<(u64, u16, TextWrap)>,
+    lines: Vec<String>,
+}
+
+impl WrapCache {
+    /// Returns the lines of `text` wrapped into `width` cells according to `mode`.
+    /// The text is wrapped only if it, `width` or `mode` changed since the last call.
+    pub fn get_or_wrap(&mut self, text: &str, width: u16, mode: TextWrap) -> &[String] {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let key = (hasher.finish(), width, mode);
+        if self.key != Some(key) {
+            self.lines = wrap_with(text, width, mode);
+            self.key = Some(key);
+        }
+        &self.lines
+    }
+
+    /// Returns whether the cache holds the lines of a text
+    pub fn is_cached(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// Drop the cached lines, so that the text is wrapped again on the next call to [`WrapCache::get_or_wrap`].
+    /// Call it when the attributes the text depends on change.
+    pub fn invalidate(&mut self) {
+        self.key = None;
+        self.lines = Vec::new();
+    }
+}
+
+/// Returns the edit (Levenshtein) distance between `a` and `b`, which is the minimum amount of chars to insert,
+/// remove or replace to turn `a` into `b`
+///
+/// ```rust
+/// use tuirealm::utils::text::edit_distance;
+///
+/// assert_eq!(edit_distance("kitten", "sitting"), 3);
+/// assert_eq!(edit_distance("omar", "omar"), 0);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // distances between the prefix of `a` processed so far and each prefix of `b`
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let replace = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = replace.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Returns the width of `ch`; control characters have no width
+fn char_width(ch: char) -> usize {
+    ch.width().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// Fixed seed, so that failures can be reproduced
+    const SEED: u64 = 0x7475_6972_6561_6c6d;
+
+    /// Chars of different widths: ascii, CJK, emoji, combining mark
+    const ALPHABET: &[char] = &['a', 'Z', '7', '日', '本', '語', '😀', '\u{301}', ' ', ' '];
+
+    fn random_string(rng: &mut fastrand::Rng) -> String {
+        let len = rng.usize(0..32);
+        (0..len)
+            .map(|_| ALPHABET[rng.usize(..ALPHABET.len())])
+            .collect()
+    }
+
+    #[test]
+    fn should_measure_text() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("omar"), 4);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(cursor_column("", 0), 0);
+        assert_eq!(cursor_column("a日b", 2), 3);
+        assert_eq!(cursor_column("e\u{301}x", 2), 1);
+        assert_eq!(cursor_column("ab", 4), 4);
+    }
+
+    #[test]
+    fn should_compute_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "omar"), 4);
+        assert_eq!(edit_distance("omar", ""), 4);
+        assert_eq!(edit_distance("omar", "omer"), 1);
+        assert_eq!(edit_distance("input", "inptu"), 2);
+        assert_eq!(edit_distance("日本語", "日本"), 1);
+    }
+
+    #[test]
+    fn should_truncate_text() {
+        assert!(matches!(
+            truncate_with_ellipsis("omar", 4),
+            Cow::Borrowed("omar")
+        ));
+        assert_eq!(truncate_with_ellipsis("omar", 0), "");
+        assert_eq!(truncate_with_ellipsis("omar", 1), "…");
+        assert_eq!(truncate_with_ellipsis("omar", 3), "om…");
+        // wide char doesn't fit
+        assert_eq!(truncate_with_ellipsis("a日本", 3), "a…");
+        // combining marks are kept with their char
+        assert_eq!(
+            truncate_with_ellipsis("e\u{301}e\u{301}e\u{301}", 2),
+            "e\u{301}…"
+        );
+    }
+
+    #[test]
+    fn should_wrap_text() {
+        assert_eq!(
+            wrap("the quick brown fox", 10),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap("日本語 日本", 4), vec!["日本", "語", "日本"]);
+        assert_eq!(wrap("a\n\nb", 4), vec!["a", "", "b"]);
+        assert_eq!(wrap("", 4), vec![""]);
+        assert!(wrap("omar", 0).is_empty());
+        // wider than the line
+        assert_eq!(wrap("日", 1), vec!["日"]);
+    }
+
+    #[test]
+    fn should_wrap_text_with_mode() {
+        let s = "a wonderful day\nfoo  bar";
+        assert_eq!(
+            wrap_with(s, 6, TextWrap::None),
+            vec!["a wonderful day", "foo  bar"]
+        );
+        assert_eq!(
+            wrap_with(s, 6, TextWrap::Char),
+            vec!["a wond", "erful ", "day", "foo  b", "ar"]
+        );
+        assert_eq!(
+            wrap_with(s, 6, TextWrap::Word),
+            vec!["a", "wonderful", "day", "foo", "bar"]
+        );
+        assert_eq!(
+            wrap_with(s, 6, TextWrap::WordBreak),
+            vec!["a", "wonder", "ful", "day", "foo", "bar"]
+        );
+        assert_eq!(wrap_with(s, 6, TextWrap::WordBreak), wrap(s, 6));
+        assert_eq!(
+            wrap_with("日本語", 3, TextWrap::Char),
+            vec!["日", "本", "語"]
+        );
+        assert!(wrap_with(s, 0, TextWrap::None).is_empty());
+    }
+
+    #[test]
+    fn wrap_cache_should_rewrap_only_on_change() {
+        let mut cache = WrapCache::default();
+        assert!(!cache.is_cached());
+        assert_eq!(
+            cache.get_or_wrap("the quick brown fox", 10, TextWrap::Word),
+            ["the quick", "brown fox"]
+        );
+        assert!(cache.is_cached());
+        assert_eq!(
+            cache.get_or_wrap("the quick brown fox", 5, TextWrap::Word),
+            ["the", "quick", "brown", "fox"]
+        );
+        assert_eq!(
+            cache.get_or_wrap("the quick brown fox", 5, TextWrap::Char),
+            ["the q", "uick ", "brown", " fox"]
+        );
+        assert_eq!(
+            cache.get_or_wrap("the lazy dog", 5, TextWrap::Char),
+            ["the l", "azy d", "og"]
+        );
+        cache.invalidate();
+        assert!(!cache.is_cached());
+        assert_eq!(
+            cache.get_or_wrap("the lazy dog", 5, TextWrap::Char),
+            ["the l", "azy d", "og"]
+        );
+    }
+
+    #[test]
+    fn wrap_cache_should_be_faster_than_wrapping_on_each_render() {
+        let text: String = (0..3000)
+            .map(|i| format!("line {i}: the quick brown fox jumps over the lazy dog\n"))
+            .collect();
+        let renders = 20;
+        // wrap on each render
+        let started = std::time::Instant::now();
+        for _ in 0..renders {
+            assert!(wrap_with(&text, 20, TextWrap::Word).len() > 3000);
+        }
+        let uncached = started.elapsed();
+        // wrap once, then scroll
+        let mut cache = WrapCache::default();
+        let started = std::time::Instant::now();
+        for _ in 0..renders {
+            assert!(cache.get_or_wrap(&text, 20, TextWrap::Word).len() > 3000);
+        }
+        let cached = started.elapsed();
+        assert!(
+            cached < uncached,
+            "cached: {cached:?}, uncached: {uncached:?}"
+        );
+    }
+
+    #[test]
+    fn truncated_text_should_fit_width() {
+        let mut rng = fastrand::Rng::with_seed(SEED);
+        for _ in 0..1000 {
+            let s = random_string(&mut rng);
+            let max_width = rng.u16(0..24);
+            let truncated = truncate_with_ellipsis(&s, max_width);
+            assert!(
+                display_width(&truncated) <= max_width as usize,
+                "{s:?} {max_width}"
+            );
+            if display_width(&s) <= max_width as usize {
+                assert_eq!(truncated, s.as_str());
+            } else if max_width > 0 {
+                assert!(truncated.ends_with(ELLIPSIS));
+                assert!(s.starts_with(truncated.trim_end_matches(ELLIPSIS)));
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_text_should_fit_width_and_keep_words() {
+        let mut rng = fastrand::Rng::with_seed(SEED);
+        for _ in 0..1000 {
+            let s = random_string(&mut rng);
+            // NOTE: a line may exceed a width of 1 cell with a wide char
+            let width = rng.u16(2..24);
+            let lines = wrap(&s, width);
+            assert!(
+                lines
+                    .iter()
+                    .all(|line| display_width(line) <= width as usize),
+                "{s:?} {width}"
+            );
+            // no char is lost, except for whitespaces
+            let expected: String = s.split_whitespace().collect();
+            let actual: String = lines.iter().flat_map(|x| x.split_whitespace()).collect();
+            assert_eq!(actual, expected, "{s:?} {width}");
+            assert_eq!(cursor_column(&s, s.chars().count()), display_width(&s));
+        }
+    }
+}
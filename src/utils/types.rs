@@ -2,8 +2,12 @@
 //!
 //! This module exposes types used by utilities
 
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
 /// Represents a phone number
 #[derive(Eq, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub struct PhoneNumber {
     /// Prefix number (without `00` or `+`)
     pub prefix: Option<String>,
@@ -32,6 +36,7 @@ impl PhoneNumber {
 
 /// Represents an email address
 #[derive(Eq, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
 pub struct Email {
     /// Address name (e.g. `foo.bar@preema.it` => `foo.bar`)
     pub name: String,
@@ -0,0 +1,172 @@
+//! ## Demo
+//!
+//! Golden-file tests for the rendering of the `demo` example components.
+//!
+//! The view is rendered on a 80x24 test backend and compared against the buffers in `tests/golden/`.
+//! To regenerate the golden files after an intended change, run the tests with `TUIREALM_UPDATE_GOLDEN=1`.
+
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+
+use pretty_assertions::assert_eq;
+use tuirealm::event::{Key, KeyEvent, NoUserEvent};
+use tuirealm::props::{Alignment, Color, TextModifiers};
+use tuirealm::ratatui::layout::{Constraint, Direction, Layout};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    mount_all, Application, AttrValue, Attribute, Event, EventListenerCfg, State, StateValue,
+};
+
+#[path = "../examples/demo/components/mod.rs"]
+#[allow(dead_code)]
+mod components;
+
+use components::{Clock, DigitCounter, Label, LetterCounter};
+
+/// Env variable which, if set, makes the tests regenerate the golden files
+const UPDATE_GOLDEN_ENV: &str = "TUIREALM_UPDATE_GOLDEN";
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    Clock,
+    DigitCounterChanged(isize),
+    DigitCounterBlur,
+    LetterCounterChanged(isize),
+    LetterCounterBlur,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    Clock,
+    DigitCounter,
+    LetterCounter,
+    Label,
+}
+
+/// Mount the demo components as the demo does, with a fixed clock time
+fn application() -> Application<Id, Msg, NoUserEvent> {
+    // NOTE: events are forwarded manually; the tick interval just keeps the listener responsive to stop
+    let mut app: Application<Id, Msg, NoUserEvent> =
+        Application::init(EventListenerCfg::default().tick_interval(Duration::from_millis(100)));
+    assert!(mount_all!(app, {
+        Id::Label => Label::default()
+            .text("Waiting for a Msg...")
+            .alignment(Alignment::Left)
+            .background(Color::Reset)
+            .foreground(Color::LightYellow)
+            .modifiers(TextModifiers::BOLD);
+        Id::Clock => Clock::new(UNIX_EPOCH + Duration::from_secs(45_296))
+            .alignment(Alignment::Center)
+            .background(Color::Reset)
+            .foreground(Color::Cyan)
+            .modifiers(TextModifiers::BOLD);
+        Id::LetterCounter => LetterCounter::new(0);
+        Id::DigitCounter => DigitCounter::new(5);
+    })
+    .is_ok());
+    assert!(app.active(&Id::LetterCounter).is_ok());
+    // set clock text
+    assert_eq!(
+        app.forward(&Id::Clock, Event::Tick(Duration::from_secs(1)))
+            .unwrap(),
+        Some(Msg::Clock)
+    );
+    app
+}
+
+/// Render the view with the demo layout and return the rendered buffer as text
+fn render(app: &mut Application<Id, Msg, NoUserEvent>) -> String {
+    let mut terminal = TerminalBridge::new_test(80, 24);
+    assert!(terminal
+        .draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(1),
+                ])
+                .split(f.area());
+            app.view(&Id::Clock, f, chunks[0]);
+            app.view(&Id::LetterCounter, f, chunks[1]);
+            app.view(&Id::DigitCounter, f, chunks[2]);
+            app.view(&Id::Label, f, chunks[3]);
+        })
+        .is_ok());
+    format!("{:#?}\n", terminal.buffer())
+}
+
+/// Compare `rendered` against the golden file `name`, or write it if [`UPDATE_GOLDEN_ENV`] is set
+fn assert_golden(name: &str, rendered: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{name}.txt"));
+    if std::env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+        std::fs::write(&path, rendered).expect("failed to write golden file");
+        return;
+    }
+    let golden = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing golden file {}; run with {UPDATE_GOLDEN_ENV}=1 to create it",
+            path.display()
+        )
+    });
+    assert_eq!(golden, rendered, "{name} doesn't match its golden file");
+}
+
+#[test]
+fn demo_should_render_initial_view() {
+    let mut app = application();
+    assert_golden("demo_initial", &render(&mut app));
+}
+
+#[test]
+fn demo_should_render_view_after_input() {
+    let mut app = application();
+    // type on letter counter
+    for ch in ['a', 'b'] {
+        assert_eq!(
+            app.forward(
+                &Id::LetterCounter,
+                Event::Keyboard(KeyEvent::from(Key::Char(ch)))
+            )
+            .unwrap(),
+            Some(Msg::LetterCounterChanged(if ch == 'a' { 1 } else { 2 }))
+        );
+    }
+    assert!(app
+        .attr(
+            &Id::Label,
+            Attribute::Text,
+            AttrValue::String(String::from("LetterCounter has now value: 2"))
+        )
+        .is_ok());
+    // move focus to digit counter
+    assert!(app.active(&Id::DigitCounter).is_ok());
+    assert_golden("demo_after_input", &render(&mut app));
+}
+
+#[test]
+fn demo_clock_should_pause_when_terminal_is_unfocused() {
+    let mut app = application();
+    assert_eq!(app.forward(&Id::Clock, Event::FocusLost).unwrap(), None);
+    // time goes on, but the clock is not updated
+    assert_eq!(
+        app.forward(&Id::Clock, Event::Tick(Duration::from_secs(1)))
+            .unwrap(),
+        None
+    );
+    assert_golden("demo_initial", &render(&mut app));
+    assert_eq!(
+        app.forward(&Id::Clock, Event::FocusGained).unwrap(),
+        Some(Msg::Clock)
+    );
+    assert_eq!(
+        app.state(&Id::Clock).unwrap(),
+        State::One(StateValue::U64(45_298))
+    );
+}
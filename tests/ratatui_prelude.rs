@@ -0,0 +1,77 @@
+//! ## Ratatui prelude
+//!
+//! Builds and renders a component using only `tuirealm::` paths, to guarantee that applications and component
+//! libraries don't need to depend on ratatui directly.
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::ratatui_prelude::*;
+use tuirealm::{AttrValue, Attribute, MockComponent, State};
+
+/// A component drawing a progress gauge and a canvas
+struct Meter {
+    percent: u16,
+}
+
+impl MockComponent for Meter {
+    fn view(&mut self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(area);
+        frame.render_widget(
+            Gauge::default()
+                .gauge_style(Style::default().fg(Color::Green))
+                .percent(self.percent),
+            chunks[0],
+        );
+        frame.render_widget(
+            Canvas::default()
+                .marker(symbols::Marker::Dot)
+                .x_bounds([0.0, 10.0])
+                .y_bounds([0.0, 10.0])
+                .paint(|ctx| {
+                    ctx.draw(&canvas::Line {
+                        x1: 0.0,
+                        y1: 0.0,
+                        x2: 10.0,
+                        y2: 0.0,
+                        color: Color::Red,
+                    })
+                }),
+            chunks[1],
+        );
+    }
+
+    fn query(&self, _: Attribute) -> Option<AttrValue> {
+        None
+    }
+
+    fn attr(&mut self, _: Attribute, _: AttrValue) {}
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[test]
+fn should_render_component_with_reexported_ratatui() {
+    let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+    let mut meter = Meter { percent: 100 };
+    terminal.draw(|f| meter.view(f, f.area())).unwrap();
+    let buffer: &Buffer = terminal.backend().buffer();
+    assert_eq!(buffer[(0, 0)].fg, Color::Green);
+    assert_eq!(buffer[(0, 2)].symbol(), "•");
+    assert_eq!(buffer[(0, 2)].fg, Color::Red);
+}
+
+/// The backend types are available with the backend features
+#[cfg(feature = "crossterm")]
+#[test]
+fn should_name_crossterm_backend() {
+    let backend: Option<CrosstermBackend<std::io::Stdout>> = None;
+    assert!(backend.is_none());
+}
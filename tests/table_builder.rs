@@ -0,0 +1,102 @@
+//! ## Table builder
+//!
+//! Counts the allocations made by the `TableBuilder` while building a big table.
+//! The allocations are counted per thread, so that the tests can run in parallel.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use pretty_assertions::assert_eq;
+use tuirealm::props::{Table, TableBuilder, TableExt, TextSpan};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const ROWS: usize = 10_000;
+const COLS: usize = 8;
+
+/// Prepare the cells of the table, so that the allocations of their content are not counted
+fn cells() -> Vec<Vec<TextSpan>> {
+    (0..ROWS)
+        .map(|row| {
+            (0..COLS)
+                .map(|col| TextSpan::from(format!("{row}:{col}")))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the table built by `build` with `cells` and the amount of allocations it made
+fn count_allocations<F>(cells: Vec<Vec<TextSpan>>, build: F) -> (Table, usize)
+where
+    F: FnOnce(Vec<Vec<TextSpan>>) -> Table,
+{
+    let before = ALLOCATIONS.with(Cell::get);
+    let table = build(cells);
+    (table, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn table_builder_with_capacity_should_allocate_once_per_row() {
+    let (legacy, legacy_allocations) = count_allocations(cells(), |cells| {
+        let mut builder = TableBuilder::default();
+        for row in cells {
+            for span in row {
+                builder.add_col(span);
+            }
+            builder.add_row();
+        }
+        builder.build()
+    });
+    let (table, allocations) = count_allocations(cells(), |cells| {
+        let mut builder = TableBuilder::with_capacity(ROWS + 1, COLS);
+        for row in cells {
+            for span in row {
+                builder.add_col(span);
+            }
+            builder.add_row();
+        }
+        builder.build()
+    });
+    assert_eq!(table, legacy);
+    assert_eq!(table.dimensions(), (ROWS + 1, COLS));
+    // each row grows 0 -> 4 -> 8 cells and the table grows ~log2(ROWS) times
+    assert!(legacy_allocations >= 2 * ROWS);
+    // one allocation per row, plus the table
+    assert!(allocations <= ROWS + 2);
+}
+
+#[test]
+fn table_builder_should_extend_rows_without_reallocating() {
+    let (table, allocations) = count_allocations(cells(), |cells| {
+        TableBuilder::with_capacity(ROWS, COLS)
+            .extend_rows(cells)
+            .build()
+    });
+    assert_eq!(table.dimensions(), (ROWS, COLS));
+    assert_eq!(table.row(ROWS - 1).unwrap()[COLS - 1].content, "9999:7");
+    // only the table and its first row are allocated
+    assert_eq!(allocations, 2);
+}